@@ -5,7 +5,7 @@ pub enum Inputs<T> {
     Abi(CheckedValues<T>),
 }
 
-impl<T: From<usize>> Encode<T> for Inputs<T> {
+impl<T: Field> Encode<T> for Inputs<T> {
     fn encode(self) -> Vec<T> {
         match self {
             Inputs::Raw(v) => v,
@@ -17,7 +17,7 @@ impl<T: From<usize>> Encode<T> for Inputs<T> {
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use std::fmt;
-use zokrates_core::typed_absy::{Type, UBitwidth};
+use zokrates_core::typed_absy::{IntType, Type};
 
 use zokrates_field::Field;
 
@@ -28,6 +28,7 @@ pub enum Error {
     Json(String),
     Conversion(String),
     Type(String),
+    Decode(String),
 }
 
 impl fmt::Display for Error {
@@ -36,6 +37,7 @@ impl fmt::Display for Error {
             Error::Json(e) => write!(f, "Invalid JSON: {}", e),
             Error::Conversion(e) => write!(f, "Invalid ZoKrates values: {}", e),
             Error::Type(e) => write!(f, "Type error: {}", e),
+            Error::Decode(e) => write!(f, "Could not decode values: {}", e),
         }
     }
 }
@@ -45,6 +47,8 @@ enum Value<T> {
     U8(u8),
     U16(u16),
     U32(u32),
+    U64(u64),
+    Int(i128),
     Field(T),
     Boolean(bool),
     Array(Vec<Value<T>>),
@@ -56,10 +60,17 @@ enum CheckedValue<T> {
     U8(u8),
     U16(u16),
     U32(u32),
+    U64(u64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
     Field(T),
     Boolean(bool),
     Array(Vec<CheckedValue<T>>),
     Struct(Vec<(String, CheckedValue<T>)>),
+    Tuple(Vec<CheckedValue<T>>),
+    /// The elements of a vector, along with the number of field elements padding it to its capacity
+    Vector(Vec<CheckedValue<T>>, usize),
 }
 
 #[derive(PartialEq, Debug)]
@@ -72,6 +83,8 @@ impl<T: Field> fmt::Display for Value<T> {
             Value::U8(v) => write!(f, "{:#04x}", v),
             Value::U16(v) => write!(f, "{:#06x}", v),
             Value::U32(v) => write!(f, "{:#010x}", v),
+            Value::U64(v) => write!(f, "{:#018x}", v),
+            Value::Int(v) => write!(f, "{}", v),
             Value::Boolean(v) => write!(f, "{}", v),
             Value::Array(v) => write!(
                 f,
@@ -97,9 +110,34 @@ impl<T: Field> Value<T> {
     fn check(self, ty: Type) -> Result<CheckedValue<T>, String> {
         match (self, ty) {
             (Value::Field(f), Type::FieldElement) => Ok(CheckedValue::Field(f)),
-            (Value::U8(f), Type::Uint(UBitwidth::B8)) => Ok(CheckedValue::U8(f)),
-            (Value::U16(f), Type::Uint(UBitwidth::B16)) => Ok(CheckedValue::U16(f)),
-            (Value::U32(f), Type::Uint(UBitwidth::B32)) => Ok(CheckedValue::U32(f)),
+            (Value::U8(f), Type::Uint(IntType::U8)) => Ok(CheckedValue::U8(f)),
+            (Value::U16(f), Type::Uint(IntType::U16)) => Ok(CheckedValue::U16(f)),
+            (Value::U32(f), Type::Uint(IntType::U32)) => Ok(CheckedValue::U32(f)),
+            (Value::U64(f), Type::Uint(IntType::U64)) => Ok(CheckedValue::U64(f)),
+            (Value::Int(v), Type::Uint(IntType::I8)) => i8::try_from(v)
+                .map(CheckedValue::I8)
+                .map_err(|_| format!("Value `{}` doesn't match expected type `i8`", v)),
+            (Value::Field(f), Type::Uint(IntType::I8)) => f
+                .to_dec_string()
+                .parse::<i8>()
+                .map(CheckedValue::I8)
+                .map_err(|_| format!("Value `{}` doesn't match expected type `i8`", f)),
+            (Value::Int(v), Type::Uint(IntType::I16)) => i16::try_from(v)
+                .map(CheckedValue::I16)
+                .map_err(|_| format!("Value `{}` doesn't match expected type `i16`", v)),
+            (Value::Field(f), Type::Uint(IntType::I16)) => f
+                .to_dec_string()
+                .parse::<i16>()
+                .map(CheckedValue::I16)
+                .map_err(|_| format!("Value `{}` doesn't match expected type `i16`", f)),
+            (Value::Int(v), Type::Uint(IntType::I32)) => i32::try_from(v)
+                .map(CheckedValue::I32)
+                .map_err(|_| format!("Value `{}` doesn't match expected type `i32`", v)),
+            (Value::Field(f), Type::Uint(IntType::I32)) => f
+                .to_dec_string()
+                .parse::<i32>()
+                .map(CheckedValue::I32)
+                .map_err(|_| format!("Value `{}` doesn't match expected type `i32`", f)),
             (Value::Boolean(b), Type::Boolean) => Ok(CheckedValue::Boolean(b)),
             (Value::Array(a), Type::Array(array_type)) => {
                 if a.len() != array_type.size {
@@ -137,6 +175,40 @@ impl<T: Field> Value<T> {
                     Ok(CheckedValue::Struct(s))
                 }
             }
+            (Value::Array(a), Type::Tuple(tuple_type)) => {
+                if a.len() != tuple_type.len() {
+                    Err(format!(
+                        "Expected tuple of size {}, found tuple of size {}",
+                        tuple_type.len(),
+                        a.len()
+                    ))
+                } else {
+                    let a = a
+                        .into_iter()
+                        .zip(tuple_type.elements.into_iter())
+                        .map(|(val, ty)| val.check(ty))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(CheckedValue::Tuple(a))
+                }
+            }
+            (Value::Array(a), Type::Vector(vector_type)) => {
+                if a.len() > vector_type.capacity {
+                    Err(format!(
+                        "Expected vector of at most {} element(s), found {}",
+                        vector_type.capacity,
+                        a.len()
+                    ))
+                } else {
+                    // inactive elements are filled with zeros, which is the zero value of every type
+                    let padding =
+                        (vector_type.capacity - a.len()) * vector_type.ty.get_primitive_count();
+                    let a = a
+                        .into_iter()
+                        .map(|val| val.check(*vector_type.ty.clone()))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(CheckedValue::Vector(a, padding))
+                }
+            }
             (v, t) => Err(format!("Value `{}` doesn't match expected type `{}`", v, t)),
         }
     }
@@ -146,22 +218,35 @@ pub trait Encode<T> {
     fn encode(self) -> Vec<T>;
 }
 
-pub trait Decode<T> {
+pub trait Decode<T>: Sized {
     type Expected;
 
-    fn decode(raw: Vec<T>, expected: Self::Expected) -> Self;
+    fn decode(raw: Vec<T>, expected: Self::Expected) -> Result<Self, Error>;
 }
 
-impl<T: From<usize>> Encode<T> for CheckedValue<T> {
+impl<T: Field> Encode<T> for CheckedValue<T> {
     fn encode(self) -> Vec<T> {
         match self {
             CheckedValue::Field(t) => vec![t],
             CheckedValue::U8(t) => vec![T::from(t as usize)],
             CheckedValue::U16(t) => vec![T::from(t as usize)],
             CheckedValue::U32(t) => vec![T::from(t as usize)],
+            CheckedValue::U64(t) => vec![T::from(t as u128)],
+            CheckedValue::I8(t) => vec![T::from(t as u8 as usize)],
+            CheckedValue::I16(t) => vec![T::from(t as u16 as usize)],
+            CheckedValue::I32(t) => vec![T::from(t as u32 as usize)],
             CheckedValue::Boolean(b) => vec![if b { 1.into() } else { 0.into() }],
             CheckedValue::Array(a) => a.into_iter().flat_map(|v| v.encode()).collect(),
             CheckedValue::Struct(s) => s.into_iter().flat_map(|(_, v)| v.encode()).collect(),
+            CheckedValue::Tuple(t) => t.into_iter().flat_map(|v| v.encode()).collect(),
+            CheckedValue::Vector(v, padding) => {
+                let len = v.len();
+                v.into_iter()
+                    .flat_map(|v| v.encode())
+                    .chain((0..padding).map(|_| T::from(0)))
+                    .chain(std::iter::once(T::from(len)))
+                    .collect()
+            }
         }
     }
 }
@@ -169,38 +254,50 @@ impl<T: From<usize>> Encode<T> for CheckedValue<T> {
 impl<T: Field> Decode<T> for CheckedValues<T> {
     type Expected = Vec<Type>;
 
-    fn decode(raw: Vec<T>, expected: Self::Expected) -> Self {
-        CheckedValues(
-            expected
-                .into_iter()
-                .scan(0, |state, e| {
-                    let new_state = *state + e.get_primitive_count();
-                    let res = CheckedValue::decode(raw[*state..new_state].to_vec(), e);
-                    *state = new_state;
-                    Some(res)
-                })
-                .collect(),
-        )
+    fn decode(raw: Vec<T>, expected: Self::Expected) -> Result<Self, Error> {
+        expected
+            .into_iter()
+            .scan(0, |state, e| {
+                let new_state = *state + e.get_primitive_count();
+                let res = CheckedValue::decode(raw[*state..new_state].to_vec(), e);
+                *state = new_state;
+                Some(res)
+            })
+            .collect::<Result<_, _>>()
+            .map(CheckedValues)
     }
 }
 
 impl<T: Field> Decode<T> for CheckedValue<T> {
     type Expected = Type;
 
-    fn decode(raw: Vec<T>, expected: Self::Expected) -> Self {
+    fn decode(raw: Vec<T>, expected: Self::Expected) -> Result<Self, Error> {
         let mut raw = raw;
 
-        match expected {
+        let value = match expected {
             Type::FieldElement => CheckedValue::Field(raw.pop().unwrap()),
-            Type::Uint(UBitwidth::B8) => CheckedValue::U8(
+            Type::Uint(IntType::U8) => CheckedValue::U8(
                 u8::from_str_radix(&raw.pop().unwrap().to_dec_string(), 10).unwrap(),
             ),
-            Type::Uint(UBitwidth::B16) => CheckedValue::U16(
+            Type::Uint(IntType::U16) => CheckedValue::U16(
                 u16::from_str_radix(&raw.pop().unwrap().to_dec_string(), 10).unwrap(),
             ),
-            Type::Uint(UBitwidth::B32) => CheckedValue::U32(
+            Type::Uint(IntType::U32) => CheckedValue::U32(
                 u32::from_str_radix(&raw.pop().unwrap().to_dec_string(), 10).unwrap(),
             ),
+            Type::Uint(IntType::U64) => CheckedValue::U64(
+                u64::from_str_radix(&raw.pop().unwrap().to_dec_string(), 10).unwrap(),
+            ),
+            Type::Uint(IntType::I8) => CheckedValue::I8(
+                u8::from_str_radix(&raw.pop().unwrap().to_dec_string(), 10).unwrap() as i8,
+            ),
+            Type::Uint(IntType::I16) => CheckedValue::I16(
+                u16::from_str_radix(&raw.pop().unwrap().to_dec_string(), 10).unwrap() as i16,
+            ),
+            Type::Uint(IntType::I32) => CheckedValue::I32(
+                u32::from_str_radix(&raw.pop().unwrap().to_dec_string(), 10).unwrap() as i32,
+            ),
+            Type::Uint(_) => unreachable!(),
             Type::Boolean => {
                 let v = raw.pop().unwrap();
                 CheckedValue::Boolean(if v == 0.into() {
@@ -214,7 +311,7 @@ impl<T: Field> Decode<T> for CheckedValue<T> {
             Type::Array(array_type) => CheckedValue::Array(
                 raw.chunks(array_type.ty.get_primitive_count())
                     .map(|c| CheckedValue::decode(c.to_vec(), *array_type.ty.clone()))
-                    .collect(),
+                    .collect::<Result<_, _>>()?,
             ),
             Type::Struct(members) => CheckedValue::Struct(
                 members
@@ -223,15 +320,51 @@ impl<T: Field> Decode<T> for CheckedValue<T> {
                         let new_state = *state + member.ty.get_primitive_count();
                         let res = CheckedValue::decode(raw[*state..new_state].to_vec(), *member.ty);
                         *state = new_state;
-                        Some((member.id, res))
+                        let id = member.id;
+                        Some(res.map(|res| (id, res)))
                     })
-                    .collect(),
+                    .collect::<Result<_, _>>()?,
             ),
-        }
+            Type::Tuple(tuple_type) => CheckedValue::Tuple(
+                tuple_type
+                    .elements
+                    .into_iter()
+                    .scan(0, |state, ty| {
+                        let new_state = *state + ty.get_primitive_count();
+                        let res = CheckedValue::decode(raw[*state..new_state].to_vec(), ty);
+                        *state = new_state;
+                        Some(res)
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            Type::Vector(vector_type) => {
+                // the length is bounded by the circuit, but the witness may come from elsewhere
+                let len = raw.pop().unwrap().to_dec_string();
+                let len = usize::from_str_radix(&len, 10)
+                    .ok()
+                    .filter(|len| *len <= vector_type.capacity)
+                    .ok_or_else(|| {
+                        Error::Decode(format!(
+                            "Invalid length {} for a vector of capacity {}",
+                            len, vector_type.capacity
+                        ))
+                    })?;
+                let count = vector_type.ty.get_primitive_count();
+                CheckedValue::Vector(
+                    raw[..len * count]
+                        .chunks(count)
+                        .map(|c| CheckedValue::decode(c.to_vec(), *vector_type.ty.clone()))
+                        .collect::<Result<_, _>>()?,
+                    (vector_type.capacity - len) * count,
+                )
+            }
+        };
+
+        Ok(value)
     }
 }
 
-impl<T: From<usize>> Encode<T> for CheckedValues<T> {
+impl<T: Field> Encode<T> for CheckedValues<T> {
     fn encode(self) -> Vec<T> {
         self.0.into_iter().flat_map(|v| v.encode()).collect()
     }
@@ -259,6 +392,11 @@ impl<T: Field> TryFrom<serde_json::Value> for Value<T> {
     type Error = String;
     fn try_from(v: serde_json::Value) -> Result<Value<T>, Self::Error> {
         match v {
+            // negative values are signed integers, even though they would parse to field elements
+            serde_json::Value::String(s) if s.starts_with('-') => s
+                .parse::<i128>()
+                .map(|v| Value::Int(v))
+                .map_err(|_| format!("Expected signed integer value, found {}", s)),
             serde_json::Value::String(s) => T::try_from_dec_str(&s)
                 .map(|v| Value::Field(v))
                 .or_else(|_| match s.len() {
@@ -271,6 +409,9 @@ impl<T: Field> TryFrom<serde_json::Value> for Value<T> {
                     10 => u32::from_str_radix(&s[2..], 16)
                         .map(|v| Value::U32(v))
                         .map_err(|_| format!("Expected u32 value, found {}", s)),
+                    18 => u64::from_str_radix(&s[2..], 16)
+                        .map(|v| Value::U64(v))
+                        .map_err(|_| format!("Expected u64 value, found {}", s)),
                     _ => Err(format!("Cannot parse {} to any type", s)),
                 }),
             serde_json::Value::Bool(b) => Ok(Value::Boolean(b)),
@@ -300,6 +441,10 @@ impl<T: Field> Into<serde_json::Value> for CheckedValue<T> {
             CheckedValue::U8(u) => serde_json::Value::String(format!("{:#04x}", u)),
             CheckedValue::U16(u) => serde_json::Value::String(format!("{:#06x}", u)),
             CheckedValue::U32(u) => serde_json::Value::String(format!("{:#010x}", u)),
+            CheckedValue::U64(u) => serde_json::Value::String(format!("{:#018x}", u)),
+            CheckedValue::I8(i) => serde_json::Value::String(i.to_string()),
+            CheckedValue::I16(i) => serde_json::Value::String(i.to_string()),
+            CheckedValue::I32(i) => serde_json::Value::String(i.to_string()),
             CheckedValue::Boolean(b) => serde_json::Value::Bool(b),
             CheckedValue::Array(a) => {
                 serde_json::Value::Array(a.into_iter().map(|e| e.into()).collect())
@@ -307,6 +452,12 @@ impl<T: Field> Into<serde_json::Value> for CheckedValue<T> {
             CheckedValue::Struct(s) => {
                 serde_json::Value::Object(s.into_iter().map(|(k, v)| (k, v.into())).collect())
             }
+            CheckedValue::Tuple(t) => {
+                serde_json::Value::Array(t.into_iter().map(|e| e.into()).collect())
+            }
+            CheckedValue::Vector(v, _) => {
+                serde_json::Value::Array(v.into_iter().map(|e| e.into()).collect())
+            }
         }
     }
 }
@@ -388,6 +539,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn uints() {
+        let s = r#"["0x2a", "0x002a", "0x0000002a", "0x000000000000002a"]"#;
+        assert_eq!(
+            parse::<Bn128Field>(s).unwrap(),
+            Values(vec![
+                Value::U8(42),
+                Value::U16(42),
+                Value::U32(42),
+                Value::U64(42)
+            ])
+        );
+    }
+
+    #[test]
+    fn ints() {
+        let s = r#"["-42", "-170141183460469231731687303715884105728"]"#;
+        assert_eq!(
+            parse::<Bn128Field>(s).unwrap(),
+            Values(vec![Value::Int(-42), Value::Int(i128::MIN)])
+        );
+    }
+
     #[test]
     fn struc() {
         let s = r#"[{"a": "42"}]"#;
@@ -403,7 +577,7 @@ mod tests {
 
     mod strict {
         use super::*;
-        use zokrates_core::typed_absy::types::{StructMember, StructType};
+        use zokrates_core::typed_absy::types::{StructMember, StructType, TupleType, VectorType};
 
         #[test]
         fn fields() {
@@ -504,6 +678,97 @@ mod tests {
                 Error::Type("Value `false` doesn't match expected type `field`".into())
             );
         }
+
+        #[test]
+        fn ints() {
+            let s = r#"["-42", "42", "-1"]"#;
+            assert_eq!(
+                parse_strict::<Bn128Field>(
+                    s,
+                    vec![
+                        Type::Uint(IntType::I8),
+                        Type::Uint(IntType::I16),
+                        Type::Uint(IntType::I32)
+                    ]
+                )
+                .unwrap(),
+                CheckedValues(vec![
+                    CheckedValue::I8(-42),
+                    CheckedValue::I16(42),
+                    CheckedValue::I32(-1)
+                ])
+            );
+
+            let s = r#"["-129"]"#;
+            assert_eq!(
+                parse_strict::<Bn128Field>(s, vec![Type::Uint(IntType::I8)]).unwrap_err(),
+                Error::Type("Value `-129` doesn't match expected type `i8`".into())
+            );
+
+            let s = r#"["128"]"#;
+            assert_eq!(
+                parse_strict::<Bn128Field>(s, vec![Type::Uint(IntType::I8)]).unwrap_err(),
+                Error::Type("Value `128` doesn't match expected type `i8`".into())
+            );
+        }
+
+        #[test]
+        fn tuple() {
+            let s = r#"[["42", true]]"#;
+            assert_eq!(
+                parse_strict::<Bn128Field>(
+                    s,
+                    vec![Type::Tuple(TupleType::new(vec![
+                        Type::FieldElement,
+                        Type::Boolean
+                    ]))]
+                )
+                .unwrap(),
+                CheckedValues(vec![CheckedValue::Tuple(vec![
+                    CheckedValue::Field(42.into()),
+                    CheckedValue::Boolean(true)
+                ])])
+            );
+
+            let s = r#"[["42"]]"#;
+            assert_eq!(
+                parse_strict::<Bn128Field>(
+                    s,
+                    vec![Type::Tuple(TupleType::new(vec![
+                        Type::FieldElement,
+                        Type::Boolean
+                    ]))]
+                )
+                .unwrap_err(),
+                Error::Type("Expected tuple of size 2, found tuple of size 1".into())
+            );
+        }
+
+        #[test]
+        fn vector() {
+            let s = r#"[["1", "2"]]"#;
+            assert_eq!(
+                parse_strict::<Bn128Field>(
+                    s,
+                    vec![Type::Vector(VectorType::new(Type::FieldElement, 3))]
+                )
+                .unwrap(),
+                CheckedValues(vec![CheckedValue::Vector(
+                    vec![CheckedValue::Field(1.into()), CheckedValue::Field(2.into())],
+                    1
+                )])
+            );
+
+            let s = r#"[["1", "2", "3", "4"]]"#;
+            assert_eq!(
+                parse_strict::<Bn128Field>(
+                    s,
+                    vec![Type::Vector(VectorType::new(Type::FieldElement, 3))]
+                )
+                .unwrap_err(),
+                Error::Type("Expected vector of at most 3 element(s), found 4".into())
+            );
+        }
     }
 
     mod encode {
@@ -511,42 +776,159 @@ mod tests {
 
         #[test]
         fn fields() {
-            let v = CheckedValues(vec![CheckedValue::Field(1), CheckedValue::Field(2)]);
-            assert_eq!(v.encode(), vec![1, 2]);
+            let v = CheckedValues::<Bn128Field>(vec![
+                CheckedValue::Field(1.into()),
+                CheckedValue::Field(2.into()),
+            ]);
+            assert_eq!(v.encode(), vec![Bn128Field::from(1), Bn128Field::from(2)]);
         }
 
         #[test]
         fn u8s() {
-            let v = CheckedValues::<usize>(vec![CheckedValue::U8(1), CheckedValue::U8(2)]);
-            assert_eq!(v.encode(), vec![1, 2]);
+            let v = CheckedValues::<Bn128Field>(vec![CheckedValue::U8(1), CheckedValue::U8(2)]);
+            assert_eq!(v.encode(), vec![Bn128Field::from(1), Bn128Field::from(2)]);
+        }
+
+        #[test]
+        fn u64s() {
+            let v = CheckedValues::<Bn128Field>(vec![CheckedValue::U64(1), CheckedValue::U64(2)]);
+            assert_eq!(v.encode(), vec![Bn128Field::from(1), Bn128Field::from(2)]);
+        }
+
+        #[test]
+        fn large_u64() {
+            let v = CheckedValues::<Bn128Field>(vec![CheckedValue::U64(u64::MAX)]);
+            assert_eq!(
+                v.encode(),
+                vec![Bn128Field::try_from_dec_str("18446744073709551615").unwrap()]
+            );
+        }
+
+        #[test]
+        fn i8s() {
+            let v = CheckedValues::<Bn128Field>(vec![CheckedValue::I8(1), CheckedValue::I8(-2)]);
+            assert_eq!(v.encode(), vec![Bn128Field::from(1), Bn128Field::from(254)]);
+        }
+
+        #[test]
+        fn i32s() {
+            let v = CheckedValues::<Bn128Field>(vec![CheckedValue::I32(-1)]);
+            assert_eq!(v.encode(), vec![Bn128Field::from(u32::MAX)]);
         }
 
         #[test]
         fn bools() {
-            let v: CheckedValues<usize> = CheckedValues(vec![
+            let v: CheckedValues<Bn128Field> = CheckedValues(vec![
                 CheckedValue::Boolean(true),
                 CheckedValue::Boolean(false),
             ]);
-            assert_eq!(v.encode(), vec![1, 0]);
+            assert_eq!(v.encode(), vec![Bn128Field::from(1), Bn128Field::from(0)]);
         }
 
         #[test]
         fn array() {
-            let v: CheckedValues<usize> = CheckedValues(vec![CheckedValue::Array(vec![
+            let v: CheckedValues<Bn128Field> = CheckedValues(vec![CheckedValue::Array(vec![
                 CheckedValue::Boolean(true),
                 CheckedValue::Boolean(false),
             ])]);
-            assert_eq!(v.encode(), vec![1, 0]);
+            assert_eq!(v.encode(), vec![Bn128Field::from(1), Bn128Field::from(0)]);
         }
 
         #[test]
         fn struc() {
-            let v: CheckedValues<usize> = CheckedValues(vec![CheckedValue::Struct(
-                vec![("a".to_string(), CheckedValue::Field(42))]
+            let v: CheckedValues<Bn128Field> = CheckedValues(vec![CheckedValue::Struct(
+                vec![("a".to_string(), CheckedValue::Field(42.into()))]
                     .into_iter()
                     .collect(),
             )]);
-            assert_eq!(v.encode(), vec![42]);
+            assert_eq!(v.encode(), vec![Bn128Field::from(42)]);
+        }
+
+        #[test]
+        fn tuple() {
+            let v: CheckedValues<Bn128Field> = CheckedValues(vec![CheckedValue::Tuple(vec![
+                CheckedValue::Field(42.into()),
+                CheckedValue::Boolean(true),
+            ])]);
+            assert_eq!(v.encode(), vec![Bn128Field::from(42), Bn128Field::from(1)]);
+        }
+
+        #[test]
+        fn vector() {
+            let v: CheckedValues<Bn128Field> = CheckedValues(vec![CheckedValue::Vector(
+                vec![
+                    CheckedValue::Field(42.into()),
+                    CheckedValue::Field(43.into()),
+                ],
+                2,
+            )]);
+            assert_eq!(
+                v.encode(),
+                vec![
+                    Bn128Field::from(42),
+                    Bn128Field::from(43),
+                    Bn128Field::from(0),
+                    Bn128Field::from(0),
+                    Bn128Field::from(2)
+                ]
+            );
+        }
+    }
+
+    mod decode {
+        use super::*;
+        use zokrates_core::typed_absy::types::VectorType;
+
+        #[test]
+        fn vector() {
+            let ty = vec![Type::Vector(VectorType::new(Type::FieldElement, 3))];
+
+            let raw: Vec<Bn128Field> = vec![42.into(), 43.into(), 0.into(), 2.into()];
+            assert_eq!(
+                CheckedValues::decode(raw, ty.clone()).unwrap(),
+                CheckedValues(vec![CheckedValue::Vector(
+                    vec![
+                        CheckedValue::Field(42.into()),
+                        CheckedValue::Field(43.into())
+                    ],
+                    1
+                )])
+            );
+
+            let raw: Vec<Bn128Field> = vec![1.into(), 2.into(), 3.into(), 3.into()];
+            assert_eq!(
+                CheckedValues::decode(raw, ty).unwrap(),
+                CheckedValues(vec![CheckedValue::Vector(
+                    vec![
+                        CheckedValue::Field(1.into()),
+                        CheckedValue::Field(2.into()),
+                        CheckedValue::Field(3.into())
+                    ],
+                    0
+                )])
+            );
+        }
+
+        #[test]
+        fn vector_bad_length() {
+            let ty = vec![Type::Vector(VectorType::new(Type::FieldElement, 3))];
+
+            // a length above the capacity
+            let raw: Vec<Bn128Field> = vec![1.into(), 2.into(), 3.into(), 4.into()];
+            assert_eq!(
+                CheckedValues::decode(raw, ty.clone()).unwrap_err(),
+                Error::Decode("Invalid length 4 for a vector of capacity 3".into())
+            );
+
+            // a length which does not fit in a usize
+            let raw: Vec<Bn128Field> = vec![1.into(), 2.into(), 3.into(), Bn128Field::from(-1)];
+            assert_eq!(
+                CheckedValues::decode(raw, ty).unwrap_err(),
+                Error::Decode(format!(
+                    "Invalid length {} for a vector of capacity 3",
+                    Bn128Field::from(-1).to_dec_string()
+                ))
+            );
         }
     }
 }
@@ -21,12 +21,16 @@ use std::string::String;
 use zokrates_abi::Encode;
 use zokrates_core::compile::{check, compile, CompilationArtifacts, CompileError};
 use zokrates_core::ir::{self, ProgEnum};
+use zokrates_core::profile::Profile;
 use zokrates_core::proof_system::{
     bellman::Bellman, gm17::GM17, groth16::G16, zexe::Zexe, SolidityCompatibleField,
 };
 use zokrates_core::proof_system::{Backend, Scheme, SolidityAbi, SolidityCompatibleScheme};
+use zokrates_core::source_map::SourceMap;
 use zokrates_core::typed_absy::abi::Abi;
 use zokrates_core::typed_absy::{types::Signature, Type};
+use zokrates_core::witness_calculator::WitnessCalculator;
+use zokrates_core::witness_generator::{FieldDependency, WitnessGenerator};
 use zokrates_field::{Bls12_377Field, Bls12_381Field, Bn128Field, Bw6_761Field, Field};
 use zokrates_fs_resolver::FileSystemResolver;
 #[cfg(feature = "libsnark")]
@@ -115,6 +119,75 @@ fn cli_export_verifier<T: SolidityCompatibleField, S: SolidityCompatibleScheme<T
     Ok(())
 }
 
+fn cli_export_witness_generator<T: Field>(
+    ir_prog: ir::Prog<T>,
+    sub_matches: &ArgMatches,
+) -> Result<(), String> {
+    println!("Exporting witness generator...");
+
+    let name = sub_matches.value_of("name").unwrap();
+    let output_path = Path::new(sub_matches.value_of("output").unwrap());
+
+    // depend on a local `zokrates_field` if given, otherwise on the one released with this version
+    let field = match sub_matches.value_of("field-path") {
+        Some(path) => FieldDependency::Path(
+            Path::new(path)
+                .canonicalize()
+                .map_err(|why| format!("Couldn't open {}: {}", path, why))?,
+        ),
+        None => FieldDependency::Git {
+            repository: env!("CARGO_PKG_REPOSITORY").to_string(),
+            tag: env!("CARGO_PKG_VERSION").to_string(),
+        },
+    };
+
+    WitnessGenerator::new(name, &ir_prog, &field)
+        .write(output_path)
+        .map_err(|why| format!("Couldn't write {}: {}", output_path.display(), why))?;
+
+    println!(
+        "Witness generator crate written to '{}'",
+        output_path.display()
+    );
+    Ok(())
+}
+
+fn cli_export_r1cs<T: Field>(ir_prog: ir::Prog<T>, sub_matches: &ArgMatches) -> Result<(), String> {
+    println!("Exporting constraint system...");
+
+    let output_path = Path::new(sub_matches.value_of("output").unwrap());
+    let output_file = File::create(&output_path)
+        .map_err(|why| format!("couldn't create {}: {}", output_path.display(), why))?;
+
+    let writer = BufWriter::new(output_file);
+
+    ir::R1cs::new(&ir_prog)
+        .write(writer)
+        .map_err(|why| format!("could not save constraint system: {:?}", why))?;
+
+    println!("Constraint system written to '{}'", output_path.display());
+    Ok(())
+}
+
+fn cli_export_witness_calculator<T: Field>(
+    ir_prog: ir::Prog<T>,
+    sub_matches: &ArgMatches,
+) -> Result<(), String> {
+    println!("Exporting witness calculator...");
+
+    let output_path = Path::new(sub_matches.value_of("output").unwrap());
+
+    WitnessCalculator::new(&ir_prog)
+        .write(output_path)
+        .map_err(|why| format!("Couldn't write {}: {}", output_path.display(), why))?;
+
+    println!(
+        "Witness calculator module written to '{}'",
+        output_path.display()
+    );
+    Ok(())
+}
+
 fn cli_setup<T: Field, S: Scheme<T>, B: Backend<T, S>>(
     program: ir::Prog<T>,
     sub_matches: &ArgMatches,
@@ -237,7 +310,10 @@ fn cli_compute<T: Field>(ir_prog: ir::Prog<T>, sub_matches: &ArgMatches) -> Resu
     }
     .map_err(|e| format!("Could not parse argument: {}", e))?;
 
-    let interpreter = ir::Interpreter::default();
+    let interpreter = match sub_matches.is_present("verbose") {
+        true => ir::Interpreter::verbose(),
+        false => ir::Interpreter::default(),
+    };
 
     let witness = interpreter
         .execute(&ir_prog, &arguments.encode())
@@ -246,7 +322,9 @@ fn cli_compute<T: Field>(ir_prog: ir::Prog<T>, sub_matches: &ArgMatches) -> Resu
     use zokrates_abi::Decode;
 
     let results_json_value: serde_json::Value =
-        zokrates_abi::CheckedValues::decode(witness.return_values(), signature.outputs).into();
+        zokrates_abi::CheckedValues::decode(witness.return_values(), signature.outputs)
+            .map_err(|e| e.to_string())?
+            .into();
 
     println!("\nWitness: \n\n{}", results_json_value);
 
@@ -257,9 +335,11 @@ fn cli_compute<T: Field>(ir_prog: ir::Prog<T>, sub_matches: &ArgMatches) -> Resu
 
     let writer = BufWriter::new(output_file);
 
-    witness
-        .write(writer)
-        .map_err(|why| format!("could not save witness: {:?}", why))?;
+    match sub_matches.value_of("format").unwrap() {
+        "wtns" => ir::Wtns::new(&ir_prog, &witness).and_then(|wtns| wtns.write(writer)),
+        _ => witness.write(writer),
+    }
+    .map_err(|why| format!("could not save witness: {:?}", why))?;
 
     Ok(())
 }
@@ -276,6 +356,8 @@ fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
 
     let hr_output_path = bin_output_path.to_path_buf().with_extension("ztf");
 
+    let source_map_path = bin_output_path.to_path_buf().with_extension("map");
+
     let file = File::open(path.clone())
         .map_err(|why| format!("Couldn't open input file {}: {}", path.display(), why))?;
 
@@ -307,6 +389,10 @@ fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
             )
         })?;
 
+    for warning in artifacts.warnings() {
+        println!("Warning: {}\n", fmt_error(warning));
+    }
+
     let program_flattened = artifacts.prog();
 
     // number of constraints the flattened program will translate to.
@@ -330,6 +416,17 @@ fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
 
     to_writer_pretty(&mut writer, &abi).map_err(|_| "Unable to write data to file.".to_string())?;
 
+    if sub_matches.is_present("source-map") {
+        // serialize source map and write to JSON file
+        let source_map_file = File::create(&source_map_path)
+            .map_err(|why| format!("Couldn't create {}: {}", source_map_path.display(), why))?;
+
+        let mut writer = BufWriter::new(source_map_file);
+
+        to_writer_pretty(&mut writer, artifacts.source_map())
+            .map_err(|_| "Unable to write data to file.".to_string())?;
+    }
+
     if !light {
         // write human-readable output file
         let hr_output_file = File::create(&hr_output_path)
@@ -354,10 +451,96 @@ fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
         println!("Human readable code to '{}'", hr_output_path.display());
     }
 
+    if sub_matches.is_present("source-map") {
+        println!("Source map written to '{}'", source_map_path.display());
+    }
+
     println!("Number of constraints: {}", num_constraints);
     Ok(())
 }
 
+fn read_source_map<T: Field>(ir_prog: &ir::Prog<T>, path: &Path) -> Result<SourceMap, String> {
+    let file =
+        File::open(&path).map_err(|why| format!("Couldn't open {}: {}", path.display(), why))?;
+
+    let source_map: SourceMap = from_reader(BufReader::new(file))
+        .map_err(|why| format!("Couldn't deserialize source map: {}", why))?;
+
+    if source_map.0.len() != ir_prog.main.statements.len() {
+        return Err(format!(
+            "Source map {} does not match the program",
+            path.display()
+        ));
+    }
+
+    Ok(source_map)
+}
+
+fn cli_lookup_constraint<T: Field>(
+    ir_prog: ir::Prog<T>,
+    sub_matches: &ArgMatches,
+) -> Result<(), String> {
+    let index = sub_matches.value_of("index").unwrap();
+    let index: usize = index
+        .parse()
+        .map_err(|_| format!("Invalid constraint index: {}", index))?;
+
+    let path = Path::new(sub_matches.value_of("source-map").unwrap());
+    let source_map = read_source_map(&ir_prog, path)?;
+
+    // constraints are numbered in order, skipping directives
+    let (statement_index, statement) = ir_prog
+        .main
+        .statements
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| match s {
+            ir::Statement::Constraint(..) => true,
+            _ => false,
+        })
+        .nth(index)
+        .ok_or(format!(
+            "Constraint {} not found, the program has {} constraints",
+            index,
+            ir_prog.constraint_count()
+        ))?;
+
+    println!("Constraint {}: {}", index, statement);
+
+    match source_map.get(statement_index) {
+        Some(span) => {
+            println!("Compiled from {}", span);
+            for call in span.stack.iter().rev() {
+                println!("    in {}", call);
+            }
+        }
+        None => println!("Not compiled from a source statement"),
+    }
+
+    Ok(())
+}
+
+fn cli_profile<T: Field>(ir_prog: ir::Prog<T>, sub_matches: &ArgMatches) -> Result<(), String> {
+    let path = Path::new(sub_matches.value_of("source-map").unwrap());
+    let source_map = read_source_map(&ir_prog, path)?;
+
+    let profile = Profile::new(&ir_prog, &source_map);
+
+    println!("{}", profile);
+
+    let output_path = Path::new(sub_matches.value_of("output").unwrap());
+    let output_file = File::create(&output_path)
+        .map_err(|why| format!("Couldn't create {}: {}", output_path.display(), why))?;
+
+    profile
+        .write_folded(BufWriter::new(output_file))
+        .map_err(|why| format!("Couldn't write {}: {}", output_path.display(), why))?;
+
+    println!("Folded stacks written to '{}'", output_path.display());
+
+    Ok(())
+}
+
 fn cli_check<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
     println!("Checking {}\n", sub_matches.value_of("input").unwrap());
     let path = PathBuf::from(sub_matches.value_of("input").unwrap());
@@ -382,7 +565,7 @@ fn cli_check<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
 
     let resolver =
         FileSystemResolver::with_stdlib_root(sub_matches.value_of("stdlib-path").unwrap());
-    let _ = check::<T, _>(source, path, Some(&resolver)).map_err(|e| {
+    let warnings = check::<T, _>(source, path, Some(&resolver)).map_err(|e| {
         format!(
             "Check failed:\n\n{}",
             e.0.iter()
@@ -392,6 +575,10 @@ fn cli_check<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
         )
     })?;
 
+    for warning in warnings {
+        println!("Warning: {}\n", fmt_error(&warning));
+    }
+
     println!("Program checked, no errors found.");
 
     Ok(())
@@ -435,7 +622,14 @@ fn cli() -> Result<(), String> {
     const PROVING_KEY_DEFAULT_PATH: &str = "proving.key";
     const VERIFICATION_CONTRACT_DEFAULT_PATH: &str = "verifier.sol";
     const WITNESS_DEFAULT_PATH: &str = "witness";
+    const WTNS_DEFAULT_PATH: &str = "witness.wtns";
     const JSON_PROOF_PATH: &str = "proof.json";
+    const SOURCE_MAP_DEFAULT_PATH: &str = "out.map";
+    const FOLDED_STACKS_DEFAULT_PATH: &str = "profile.folded";
+    const WITNESS_GENERATOR_DEFAULT_PATH: &str = "witness_generator";
+    const WITNESS_GENERATOR_DEFAULT_NAME: &str = "witness_generator";
+    const WITNESS_CALCULATOR_DEFAULT_PATH: &str = "witness_calculator.wasm";
+    const R1CS_DEFAULT_PATH: &str = "out.r1cs";
     let default_curve = env::var("ZOKRATES_CURVE").unwrap_or(constants::BN128.into());
     let default_backend = env::var("ZOKRATES_BACKEND").unwrap_or(constants::BELLMAN.into());
     let default_scheme = env::var("ZOKRATES_PROVING_SCHEME").unwrap_or(constants::G16.into());
@@ -495,6 +689,10 @@ fn cli() -> Result<(), String> {
             .long("light")
             .help("Skip logs and human readable output")
             .required(false)
+        ).arg(Arg::with_name("source-map")
+            .long("source-map")
+            .help("Write a '.map' file mapping each statement of the compiled program to its location in the source code")
+            .required(false)
         )
      )
     .subcommand(SubCommand::with_name("check")
@@ -524,6 +722,61 @@ fn cli() -> Result<(), String> {
             .default_value(&default_curve)
         )
      )
+    .subcommand(SubCommand::with_name("lookup-constraint")
+        .about("Finds the location in the source code of a constraint, using the source map written by 'compile --source-map'")
+        .arg(Arg::with_name("input")
+            .short("i")
+            .long("input")
+            .help("Path of the binary")
+            .value_name("FILE")
+            .takes_value(true)
+            .required(false)
+            .default_value(FLATTENED_CODE_DEFAULT_PATH)
+        ).arg(Arg::with_name("source-map")
+            .short("m")
+            .long("source-map")
+            .help("Path of the source map")
+            .value_name("FILE")
+            .takes_value(true)
+            .required(false)
+            .default_value(SOURCE_MAP_DEFAULT_PATH)
+        ).arg(Arg::with_name("index")
+            .short("n")
+            .long("index")
+            .help("Index of the constraint, starting from 0")
+            .value_name("INDEX")
+            .takes_value(true)
+            .required(true)
+        )
+    )
+    .subcommand(SubCommand::with_name("profile")
+        .about("Reports the number of constraints and directives compiled from each function call and source line, using the source map written by 'compile --source-map'")
+        .arg(Arg::with_name("input")
+            .short("i")
+            .long("input")
+            .help("Path of the binary")
+            .value_name("FILE")
+            .takes_value(true)
+            .required(false)
+            .default_value(FLATTENED_CODE_DEFAULT_PATH)
+        ).arg(Arg::with_name("source-map")
+            .short("m")
+            .long("source-map")
+            .help("Path of the source map")
+            .value_name("FILE")
+            .takes_value(true)
+            .required(false)
+            .default_value(SOURCE_MAP_DEFAULT_PATH)
+        ).arg(Arg::with_name("output")
+            .short("o")
+            .long("output")
+            .help("Path of the output file, in the folded stacks format used by flamegraph tools")
+            .value_name("FILE")
+            .takes_value(true)
+            .required(false)
+            .default_value(FOLDED_STACKS_DEFAULT_PATH)
+        )
+    )
     .subcommand(SubCommand::with_name("setup")
         .about("Performs a trusted setup for a given constraint system")
         .arg(Arg::with_name("input")
@@ -619,6 +872,7 @@ fn cli() -> Result<(), String> {
     )
     .subcommand(SubCommand::with_name("compute-witness")
         .about("Calculates a witness for a given constraint system")
+        .setting(AppSettings::AllowNegativeNumbers)
         .arg(Arg::with_name("input")
             .short("i")
             .long("input")
@@ -643,6 +897,7 @@ fn cli() -> Result<(), String> {
             .takes_value(true)
             .required(false)
             .default_value(WITNESS_DEFAULT_PATH)
+            .default_value_if("format", Some("wtns"), WTNS_DEFAULT_PATH)
         ).arg(Arg::with_name("arguments")
             .short("a")
             .long("arguments")
@@ -666,6 +921,102 @@ fn cli() -> Result<(), String> {
             .long("light")
             .help("Skip logging the human-readable program")
             .required(false)
+        ).arg(Arg::with_name("verbose")
+            .long("verbose")
+            .help("Print the values passed to `log` statements")
+            .required(false)
+        ).arg(Arg::with_name("format")
+            .short("f")
+            .long("format")
+            .value_name("FORMAT")
+            .help("Format of the witness file, `wtns` being the binary format of circom")
+            .takes_value(true)
+            .possible_values(&["zokrates", "wtns"])
+            .required(false)
+            .default_value("zokrates")
+        )
+    )
+    .subcommand(SubCommand::with_name("export-r1cs")
+        .about("Exports a given constraint system in the binary format of circom")
+        .arg(Arg::with_name("input")
+            .short("i")
+            .long("input")
+            .help("Path of the binary")
+            .value_name("FILE")
+            .takes_value(true)
+            .required(false)
+            .default_value(FLATTENED_CODE_DEFAULT_PATH)
+        ).arg(Arg::with_name("output")
+            .short("o")
+            .long("output")
+            .help("Path of the output file")
+            .value_name("FILE")
+            .takes_value(true)
+            .required(false)
+            .default_value(R1CS_DEFAULT_PATH)
+        ).arg(Arg::with_name("format")
+            .short("f")
+            .long("format")
+            .value_name("FORMAT")
+            .help("Format of the constraint system file")
+            .takes_value(true)
+            .possible_values(&["r1cs"])
+            .required(false)
+            .default_value("r1cs")
+        )
+    )
+    .subcommand(SubCommand::with_name("export-witness-generator")
+        .about("Exports a Rust crate computing the witness of a given constraint system, without depending on the compiler")
+        .arg(Arg::with_name("input")
+            .short("i")
+            .long("input")
+            .help("Path of the binary")
+            .value_name("FILE")
+            .takes_value(true)
+            .required(false)
+            .default_value(FLATTENED_CODE_DEFAULT_PATH)
+        ).arg(Arg::with_name("output")
+            .short("o")
+            .long("output")
+            .help("Path of the directory to write the crate to")
+            .value_name("DIR")
+            .takes_value(true)
+            .required(false)
+            .default_value(WITNESS_GENERATOR_DEFAULT_PATH)
+        ).arg(Arg::with_name("name")
+            .short("n")
+            .long("name")
+            .help("Name of the crate")
+            .value_name("NAME")
+            .takes_value(true)
+            .required(false)
+            .default_value(WITNESS_GENERATOR_DEFAULT_NAME)
+        ).arg(Arg::with_name("field-path")
+            .long("field-path")
+            .help("Path of a local copy of the zokrates_field crate to depend on, instead of the one of this release of ZoKrates")
+            .value_name("DIR")
+            .takes_value(true)
+            .required(false)
+        )
+    )
+    .subcommand(SubCommand::with_name("export-witness-calculator")
+        .about("Exports a standalone WebAssembly module computing the witness of a given constraint system")
+        .arg(Arg::with_name("input")
+            .short("i")
+            .long("input")
+            .help("Path of the binary")
+            .value_name("FILE")
+            .takes_value(true)
+            .required(false)
+            .default_value(FLATTENED_CODE_DEFAULT_PATH)
+        ).arg(Arg::with_name("output")
+            .short("o")
+            .long("output")
+            .help("Path of the output file")
+            .value_name("FILE")
+            .takes_value(true)
+            .required(false)
+            .default_value(WITNESS_CALCULATOR_DEFAULT_PATH)
         )
     )
     .subcommand(SubCommand::with_name("generate-proof")
@@ -806,6 +1157,36 @@ fn cli() -> Result<(), String> {
                 CurveParameter::Bw6_761 => cli_check::<Bw6_761Field>(sub_matches)?,
             }
         }
+        ("lookup-constraint", Some(sub_matches)) => {
+            // read compiled program
+            let path = Path::new(sub_matches.value_of("input").unwrap());
+            let file = File::open(&path)
+                .map_err(|why| format!("Couldn't open {}: {}", path.display(), why))?;
+
+            let mut reader = BufReader::new(file);
+
+            match ProgEnum::deserialize(&mut reader)? {
+                ProgEnum::Bn128Program(p) => cli_lookup_constraint(p, sub_matches)?,
+                ProgEnum::Bls12_377Program(p) => cli_lookup_constraint(p, sub_matches)?,
+                ProgEnum::Bls12_381Program(p) => cli_lookup_constraint(p, sub_matches)?,
+                ProgEnum::Bw6_761Program(p) => cli_lookup_constraint(p, sub_matches)?,
+            }
+        }
+        ("profile", Some(sub_matches)) => {
+            // read compiled program
+            let path = Path::new(sub_matches.value_of("input").unwrap());
+            let file = File::open(&path)
+                .map_err(|why| format!("Couldn't open {}: {}", path.display(), why))?;
+
+            let mut reader = BufReader::new(file);
+
+            match ProgEnum::deserialize(&mut reader)? {
+                ProgEnum::Bn128Program(p) => cli_profile(p, sub_matches)?,
+                ProgEnum::Bls12_377Program(p) => cli_profile(p, sub_matches)?,
+                ProgEnum::Bls12_381Program(p) => cli_profile(p, sub_matches)?,
+                ProgEnum::Bw6_761Program(p) => cli_profile(p, sub_matches)?,
+            }
+        }
         ("compute-witness", Some(sub_matches)) => {
             // read compiled program
             let path = Path::new(sub_matches.value_of("input").unwrap());
@@ -821,6 +1202,51 @@ fn cli() -> Result<(), String> {
                 ProgEnum::Bw6_761Program(p) => cli_compute(p, sub_matches)?,
             }
         }
+        ("export-r1cs", Some(sub_matches)) => {
+            // read compiled program
+            let path = Path::new(sub_matches.value_of("input").unwrap());
+            let file = File::open(&path)
+                .map_err(|why| format!("Couldn't open {}: {}", path.display(), why))?;
+
+            let mut reader = BufReader::new(file);
+
+            match ProgEnum::deserialize(&mut reader)? {
+                ProgEnum::Bn128Program(p) => cli_export_r1cs(p, sub_matches)?,
+                ProgEnum::Bls12_377Program(p) => cli_export_r1cs(p, sub_matches)?,
+                ProgEnum::Bls12_381Program(p) => cli_export_r1cs(p, sub_matches)?,
+                ProgEnum::Bw6_761Program(p) => cli_export_r1cs(p, sub_matches)?,
+            }
+        }
+        ("export-witness-generator", Some(sub_matches)) => {
+            // read compiled program
+            let path = Path::new(sub_matches.value_of("input").unwrap());
+            let file = File::open(&path)
+                .map_err(|why| format!("Couldn't open {}: {}", path.display(), why))?;
+
+            let mut reader = BufReader::new(file);
+
+            match ProgEnum::deserialize(&mut reader)? {
+                ProgEnum::Bn128Program(p) => cli_export_witness_generator(p, sub_matches)?,
+                ProgEnum::Bls12_377Program(p) => cli_export_witness_generator(p, sub_matches)?,
+                ProgEnum::Bls12_381Program(p) => cli_export_witness_generator(p, sub_matches)?,
+                ProgEnum::Bw6_761Program(p) => cli_export_witness_generator(p, sub_matches)?,
+            }
+        }
+        ("export-witness-calculator", Some(sub_matches)) => {
+            // read compiled program
+            let path = Path::new(sub_matches.value_of("input").unwrap());
+            let file = File::open(&path)
+                .map_err(|why| format!("Couldn't open {}: {}", path.display(), why))?;
+
+            let mut reader = BufReader::new(file);
+
+            match ProgEnum::deserialize(&mut reader)? {
+                ProgEnum::Bn128Program(p) => cli_export_witness_calculator(p, sub_matches)?,
+                ProgEnum::Bls12_377Program(p) => cli_export_witness_calculator(p, sub_matches)?,
+                ProgEnum::Bls12_381Program(p) => cli_export_witness_calculator(p, sub_matches)?,
+                ProgEnum::Bw6_761Program(p) => cli_export_witness_calculator(p, sub_matches)?,
+            }
+        }
         ("setup", Some(sub_matches)) => {
             // read compiled program
             let path = Path::new(sub_matches.value_of("input").unwrap());
@@ -4,15 +4,20 @@ extern crate serde_json;
 #[cfg(test)]
 mod integration {
     use assert_cli;
+    use num_bigint::BigUint;
     use serde_json::from_reader;
     use std::fs;
     use std::fs::File;
     use std::io::{BufReader, Read};
     use std::panic;
     use std::path::Path;
+    use std::process::Command;
     use tempdir::TempDir;
+    use wasmi::{ImportsBuilder, ModuleInstance, NopExternals, RuntimeValue};
     use zokrates_abi::{parse_strict, Encode};
+    use zokrates_core::ir::{R1cs, Wtns};
     use zokrates_core::typed_absy::abi::Abi;
+    use zokrates_field::Bn128Field;
 
     macro_rules! map(
     {
@@ -52,6 +57,44 @@ mod integration {
         }
     }
 
+    #[test]
+    #[ignore]
+    fn test_negative_inline_arguments() {
+        let tmp_dir = TempDir::new(".tmp").unwrap();
+        let flattened_path = tmp_dir.path().join("out");
+        let witness_path = tmp_dir.path().join("witness");
+
+        assert_cli::Assert::command(&[
+            "../target/release/zokrates",
+            "compile",
+            "-i",
+            "./tests/code/simple_add.zok",
+            "-o",
+            flattened_path.to_str().unwrap(),
+            "--light",
+        ])
+        .succeeds()
+        .unwrap();
+
+        // `-1` is read as a value of `-a`, not as a flag
+        assert_cli::Assert::command(&[
+            "../target/release/zokrates",
+            "compute-witness",
+            "-i",
+            flattened_path.to_str().unwrap(),
+            "-a",
+            "-1",
+            "2",
+            "-o",
+            witness_path.to_str().unwrap(),
+        ])
+        .succeeds()
+        .unwrap();
+
+        let witness = fs::read_to_string(&witness_path).unwrap();
+        assert!(witness.contains("~out_0 1"));
+    }
+
     fn install_nodejs_deps() {
         let out_dir = concat!(env!("OUT_DIR"), "/contract");
 
@@ -61,6 +104,220 @@ mod integration {
             .unwrap();
     }
 
+    /// The programs whose witness generator crate is built and tested, as building one is slow
+    const WITNESS_GENERATOR_PROGRAMS: &[&str] = &["arithmetics", "n_choose_k"];
+
+    fn test_witness_generator(
+        test_case_path: &Path,
+        flattened_path: &Path,
+        inputs_raw: &[String],
+        witness: &str,
+    ) {
+        let crate_path = test_case_path.join("witness_generator");
+
+        // build the generated crate against the local `zokrates_field`
+        let zokrates_field = fs::canonicalize("../zokrates_field").unwrap();
+
+        assert_cli::Assert::command(&[
+            "../target/release/zokrates",
+            "export-witness-generator",
+            "-i",
+            flattened_path.to_str().unwrap(),
+            "-o",
+            crate_path.to_str().unwrap(),
+            "--field-path",
+            zokrates_field.to_str().unwrap(),
+        ])
+        .succeeds()
+        .unwrap();
+
+        // the generated crate must compute the same witness as the interpreter
+        let index = |variable: &str| match variable {
+            "~one" => String::from("0"),
+            v if v.starts_with("~out_") => format!("VARIABLE_COUNT + {}", &v[5..]),
+            v => format!("{} + 1", &v[1..]),
+        };
+
+        let values: Vec<_> = witness
+            .lines()
+            .map(|line| {
+                let mut parts = line.split(' ');
+                (parts.next().unwrap(), parts.next().unwrap())
+            })
+            .collect();
+
+        let test = format!(
+            r#"use witness_generator::{{compute, Fr, VARIABLE_COUNT}};
+use zokrates_field::Field;
+
+#[test]
+fn witness() {{
+    let inputs: Vec<Fr> = vec![{}];
+    let witness = compute(&inputs).unwrap();
+    assert_eq!(witness.len(), {});
+{}}}
+"#,
+            inputs_raw
+                .iter()
+                .map(|i| format!("Fr::try_from_dec_str({:?}).unwrap()", i))
+                .collect::<Vec<_>>()
+                .join(", "),
+            values.len(),
+            values
+                .iter()
+                .map(|(variable, value)| format!(
+                    "    assert_eq!(witness[{}].to_dec_string(), {:?});\n",
+                    index(variable),
+                    value
+                ))
+                .collect::<String>()
+        );
+
+        fs::create_dir(crate_path.join("tests")).unwrap();
+        fs::write(crate_path.join("tests").join("witness.rs"), test).unwrap();
+
+        // share the build artifacts of `zokrates_field` between test cases
+        let target_path = fs::canonicalize("../target").unwrap();
+
+        let status = Command::new("cargo")
+            .arg("test")
+            .current_dir(&crate_path)
+            .env("CARGO_TARGET_DIR", target_path.join("witness_generator"))
+            .status()
+            .unwrap();
+
+        assert!(status.success());
+    }
+
+    fn test_r1cs(test_case_path: &Path, flattened_path: &Path, inputs_raw: &[String]) {
+        let r1cs_path = test_case_path.join("out.r1cs");
+        let wtns_path = test_case_path.join("witness.wtns");
+
+        assert_cli::Assert::command(&[
+            "../target/release/zokrates",
+            "export-r1cs",
+            "-i",
+            flattened_path.to_str().unwrap(),
+            "-o",
+            r1cs_path.to_str().unwrap(),
+        ])
+        .succeeds()
+        .unwrap();
+
+        let mut compute = vec![
+            "../target/release/zokrates",
+            "compute-witness",
+            "-i",
+            flattened_path.to_str().unwrap(),
+            "-o",
+            wtns_path.to_str().unwrap(),
+            "--format",
+            "wtns",
+        ];
+
+        if inputs_raw.len() > 0 {
+            compute.push("-a");
+
+            for arg in inputs_raw {
+                compute.push(arg);
+            }
+        }
+
+        assert_cli::Assert::command(&compute).succeeds().unwrap();
+
+        let r1cs: R1cs<Bn128Field> = R1cs::read(File::open(&r1cs_path).unwrap()).unwrap();
+        let wtns: Wtns<Bn128Field> = Wtns::read(File::open(&wtns_path).unwrap()).unwrap();
+
+        // the witness must satisfy the constraint system
+        assert_eq!(wtns.0.len(), r1cs.wire_count as usize);
+        assert_eq!(wtns.0[0], Bn128Field::from(1));
+
+        let evaluate = |l: &Vec<(u32, Bn128Field)>| {
+            l.iter()
+                .fold(Bn128Field::from(0), |acc, (wire, coefficient)| {
+                    acc + wtns.0[*wire as usize].clone() * coefficient.clone()
+                })
+        };
+
+        for (a, b, c) in &r1cs.constraints {
+            assert_eq!(evaluate(a) * evaluate(b), evaluate(c));
+        }
+    }
+
+    fn test_witness_calculator(
+        test_case_path: &Path,
+        flattened_path: &Path,
+        inputs_raw: &[String],
+        witness: &str,
+    ) {
+        let wasm_path = test_case_path.join("witness_calculator.wasm");
+
+        assert_cli::Assert::command(&[
+            "../target/release/zokrates",
+            "export-witness-calculator",
+            "-i",
+            flattened_path.to_str().unwrap(),
+            "-o",
+            wasm_path.to_str().unwrap(),
+        ])
+        .succeeds()
+        .unwrap();
+
+        let module = wasmi::Module::from_buffer(fs::read(&wasm_path).unwrap()).unwrap();
+        let instance = ModuleInstance::new(&module, &ImportsBuilder::default())
+            .unwrap()
+            .assert_no_start();
+        let memory = instance
+            .export_by_name("memory")
+            .unwrap()
+            .as_memory()
+            .unwrap()
+            .clone();
+        let get = |name: &str| match instance
+            .invoke_export(name, &[], &mut NopExternals)
+            .unwrap()
+        {
+            Some(RuntimeValue::I32(value)) => value as usize,
+            value => panic!("unexpected return value {:?}", value),
+        };
+
+        let size = get("field_size");
+        assert_eq!(get("input_count"), inputs_raw.len());
+        for (i, input) in inputs_raw.iter().enumerate() {
+            let mut bytes = BigUint::parse_bytes(input.as_bytes(), 10)
+                .unwrap()
+                .to_bytes_le();
+            bytes.resize(size, 0);
+            memory
+                .set((get("input_offset") + i * size) as u32, &bytes)
+                .unwrap();
+        }
+
+        assert_eq!(get("compute"), 0);
+
+        // the module must compute the same witness as the interpreter
+        for line in witness.lines() {
+            let mut parts = line.split(' ');
+            let (variable, value) = (parts.next().unwrap(), parts.next().unwrap());
+            let index = match variable {
+                "~one" => 0,
+                v if v.starts_with("~out_") => {
+                    get("variable_count") + v[5..].parse::<usize>().unwrap()
+                }
+                v => v[1..].parse::<usize>().unwrap() + 1,
+            };
+            let bytes = memory
+                .get((get("witness_offset") + index * size) as u32, size)
+                .unwrap();
+            assert_eq!(
+                BigUint::from_bytes_le(&bytes).to_str_radix(10),
+                value,
+                "{}",
+                variable
+            );
+        }
+    }
+
     fn test_compile_and_witness(
         program_name: &str,
         program_path: &Path,
@@ -211,6 +468,27 @@ mod integration {
             );
         }
 
+        // EXPORT-WITNESS-GENERATOR
+        if WITNESS_GENERATOR_PROGRAMS.contains(&program_name) {
+            test_witness_generator(
+                &tmp_base.join(program_name),
+                &flattened_path,
+                &inputs_raw,
+                &witness,
+            );
+        }
+
+        // EXPORT-R1CS
+        test_r1cs(&tmp_base.join(program_name), &flattened_path, &inputs_raw);
+
+        // EXPORT-WITNESS-CALCULATOR
+        test_witness_calculator(
+            &tmp_base.join(program_name),
+            &flattened_path,
+            &inputs_raw,
+            &witness,
+        );
+
         #[cfg(feature = "libsnark")]
         let backends = map! {
             "bellman" => ["g16"],
@@ -0,0 +1,48 @@
+#[macro_use]
+extern crate criterion;
+extern crate zokrates_core;
+extern crate zokrates_field;
+extern crate zokrates_fs_resolver;
+
+use criterion::Criterion;
+use std::path::PathBuf;
+use zokrates_core::compile::{compile, CompilationArtifacts};
+use zokrates_core::ir::Interpreter;
+use zokrates_field::{Bn128Field, Field};
+use zokrates_fs_resolver::FileSystemResolver;
+
+fn stdlib_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../zokrates_stdlib/stdlib")
+}
+
+fn compile_stdlib<T: Field>(path: &str) -> CompilationArtifacts<T> {
+    let stdlib = std::fs::canonicalize(stdlib_root()).unwrap();
+    let location = stdlib.join(path);
+    let source = std::fs::read_to_string(&location).unwrap();
+    let resolver = FileSystemResolver::with_stdlib_root(stdlib.to_str().unwrap());
+
+    compile(source, location, Some(&resolver)).unwrap()
+}
+
+fn sha256_witness(c: &mut Criterion) {
+    let artifacts = compile_stdlib::<Bn128Field>("hashes/sha256/512bitPacked.zok");
+    let program = artifacts.prog();
+    let inputs = vec![
+        Bn128Field::from(0),
+        Bn128Field::from(0),
+        Bn128Field::from(0),
+        Bn128Field::from(5),
+    ];
+    let interpreter = Interpreter::default();
+
+    c.bench_function("sha256_512bit_packed_witness", |b| {
+        b.iter(|| interpreter.execute(program, &inputs).unwrap())
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = sha256_witness
+}
+criterion_main!(benches);
@@ -2,46 +2,72 @@ use absy;
 use imports;
 
 use num::ToPrimitive;
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 use zokrates_pest_ast as pest;
 
 impl<'ast> From<pest::File<'ast>> for absy::Module<'ast> {
     fn from(prog: pest::File<'ast>) -> absy::Module<'ast> {
         absy::Module::with_symbols(
-            prog.structs
+            prog.types
                 .into_iter()
                 .map(|t| absy::SymbolDeclarationNode::from(t))
                 .chain(
-                    prog.functions
+                    prog.constants
                         .into_iter()
-                        .map(|f| absy::SymbolDeclarationNode::from(f)),
+                        .map(|c| absy::SymbolDeclarationNode::from(c)),
+                )
+                .chain(
+                    prog.symbols
+                        .into_iter()
+                        .map(|s| absy::SymbolDeclarationNode::from(s)),
                 ),
         )
-        .imports(prog.imports.into_iter().map(|i| absy::ImportNode::from(i)))
+        .imports(prog.imports.into_iter().flat_map(|i| import_nodes(i)))
     }
 }
 
-impl<'ast> From<pest::ImportDirective<'ast>> for absy::ImportNode<'ast> {
-    fn from(import: pest::ImportDirective<'ast>) -> absy::ImportNode<'ast> {
-        use absy::NodeValue;
+/// Convert an import directive to one import per imported symbol
+fn import_nodes<'ast>(import: pest::ImportDirective<'ast>) -> Vec<absy::ImportNode<'ast>> {
+    use absy::NodeValue;
 
-        match import {
-            pest::ImportDirective::Main(import) => {
+    match import {
+        pest::ImportDirective::Main(import) => {
+            vec![
                 imports::Import::new(None, std::path::Path::new(import.source.span.as_str()))
                     .alias(import.alias.map(|a| a.span.as_str()))
-                    .span(import.span)
+                    .span(import.span),
+            ]
+        }
+        pest::ImportDirective::From(import) => {
+            let source = std::path::Path::new(import.source.span.as_str());
+            let span = import.span;
+
+            match import.glob {
+                Some(_) => vec![imports::Import::glob(source).span(span)],
+                None => import
+                    .symbols
+                    .into_iter()
+                    .map(|symbol| {
+                        imports::Import::new(Some(symbol.id.span.as_str()), source)
+                            .alias(
+                                symbol
+                                    .alias
+                                    .map(|a| a.span.as_str())
+                                    .or(Some(symbol.id.span.as_str())),
+                            )
+                            .span(span.clone())
+                    })
+                    .collect(),
             }
-            pest::ImportDirective::From(import) => imports::Import::new(
-                Some(import.symbol.span.as_str()),
-                std::path::Path::new(import.source.span.as_str()),
-            )
-            .alias(
-                import
-                    .alias
-                    .map(|a| a.span.as_str())
-                    .or(Some(import.symbol.span.as_str())),
-            )
-            .span(import.span),
+        }
+    }
+}
+
+impl<'ast> From<pest::TypeDefinition<'ast>> for absy::SymbolDeclarationNode<'ast> {
+    fn from(definition: pest::TypeDefinition<'ast>) -> absy::SymbolDeclarationNode<'ast> {
+        match definition {
+            pest::TypeDefinition::Struct(s) => absy::SymbolDeclarationNode::from(s),
+            pest::TypeDefinition::Alias(a) => absy::SymbolDeclarationNode::from(a),
         }
     }
 }
@@ -55,6 +81,11 @@ impl<'ast> From<pest::StructDefinition<'ast>> for absy::SymbolDeclarationNode<'a
         let id = definition.id.span.as_str();
 
         let ty = absy::StructDefinition {
+            generics: definition
+                .generics
+                .into_iter()
+                .map(|g| g.span.as_str())
+                .collect(),
             fields: definition
                 .fields
                 .into_iter()
@@ -71,6 +102,44 @@ impl<'ast> From<pest::StructDefinition<'ast>> for absy::SymbolDeclarationNode<'a
     }
 }
 
+impl<'ast> From<pest::AliasDefinition<'ast>> for absy::SymbolDeclarationNode<'ast> {
+    fn from(definition: pest::AliasDefinition<'ast>) -> absy::SymbolDeclarationNode<'ast> {
+        use absy::NodeValue;
+
+        let span = definition.span;
+
+        let id = definition.id.span.as_str();
+
+        absy::SymbolDeclaration {
+            id,
+            symbol: absy::Symbol::HereAlias(absy::UnresolvedTypeNode::from(definition.ty)),
+        }
+        .span(span)
+    }
+}
+
+impl<'ast> From<pest::ConstantDefinition<'ast>> for absy::SymbolDeclarationNode<'ast> {
+    fn from(definition: pest::ConstantDefinition<'ast>) -> absy::SymbolDeclarationNode<'ast> {
+        use absy::NodeValue;
+
+        let span = definition.span;
+
+        let id = definition.id.span.as_str();
+
+        let constant = absy::ConstantDefinition {
+            ty: absy::UnresolvedTypeNode::from(definition.ty),
+            expression: absy::ExpressionNode::from(definition.expression),
+        }
+        .span(span.clone());
+
+        absy::SymbolDeclaration {
+            id,
+            symbol: absy::Symbol::HereConstant(constant),
+        }
+        .span(span)
+    }
+}
+
 impl<'ast> From<pest::StructField<'ast>> for absy::StructDefinitionFieldNode<'ast> {
     fn from(field: pest::StructField<'ast>) -> absy::StructDefinitionFieldNode {
         use absy::NodeValue;
@@ -85,6 +154,61 @@ impl<'ast> From<pest::StructField<'ast>> for absy::StructDefinitionFieldNode<'as
     }
 }
 
+impl<'ast> From<pest::SymbolDefinition<'ast>> for absy::SymbolDeclarationNode<'ast> {
+    fn from(definition: pest::SymbolDefinition<'ast>) -> absy::SymbolDeclarationNode<'ast> {
+        match definition {
+            pest::SymbolDefinition::Function(f) => absy::SymbolDeclarationNode::from(f),
+            pest::SymbolDefinition::Impl(i) => absy::SymbolDeclarationNode::from(i),
+        }
+    }
+}
+
+impl<'ast> From<pest::ImplDefinition<'ast>> for absy::SymbolDeclarationNode<'ast> {
+    fn from(definition: pest::ImplDefinition<'ast>) -> absy::SymbolDeclarationNode<'ast> {
+        use absy::NodeValue;
+
+        let span = definition.span;
+        let id = definition.id;
+
+        let methods = definition
+            .methods
+            .into_iter()
+            .map(|method| {
+                // a method is a function whose first parameter, `self`, has the type of the struct
+                let receiver = method.receiver.map(|receiver| pest::Parameter {
+                    visibility: None,
+                    ty: pest::Type::Struct(pest::StructType {
+                        id: id.clone(),
+                        explicit_generics: vec![],
+                        span: receiver.span.clone(),
+                    }),
+                    id: pest::IdentifierExpression {
+                        value: String::from(receiver.span.as_str()),
+                        span: receiver.span.clone(),
+                    },
+                    span: receiver.span,
+                });
+
+                absy::SymbolDeclarationNode::from(pest::Function {
+                    export: method.export,
+                    id: method.id,
+                    generics: method.generics,
+                    parameters: receiver.into_iter().chain(method.parameters).collect(),
+                    returns: method.returns,
+                    statements: method.statements,
+                    span: method.span,
+                })
+            })
+            .collect();
+
+        absy::SymbolDeclaration {
+            id: id.span.as_str(),
+            symbol: absy::Symbol::HereImpl(absy::ImplDefinition { methods }.span(span.clone())),
+        }
+        .span(span)
+    }
+}
+
 impl<'ast> From<pest::Function<'ast>> for absy::SymbolDeclarationNode<'ast> {
     fn from(function: pest::Function<'ast>) -> absy::SymbolDeclarationNode<'ast> {
         use absy::NodeValue;
@@ -112,6 +236,12 @@ impl<'ast> From<pest::Function<'ast>> for absy::SymbolDeclarationNode<'ast> {
         let id = function.id.span.as_str();
 
         let function = absy::Function {
+            exported: function.export.is_some(),
+            generics: function
+                .generics
+                .into_iter()
+                .map(|g| g.span.as_str())
+                .collect(),
             arguments: function
                 .parameters
                 .into_iter()
@@ -160,8 +290,11 @@ fn statements_from_statement(statement: pest::Statement) -> Vec<absy::StatementN
     match statement {
         pest::Statement::Definition(s) => statements_from_definition(s),
         pest::Statement::Iteration(s) => vec![absy::StatementNode::from(s)],
+        pest::Statement::VectorIteration(s) => vec![absy::StatementNode::from(s)],
+        pest::Statement::Conditional(s) => vec![absy::StatementNode::from(s)],
         pest::Statement::Assertion(s) => vec![absy::StatementNode::from(s)],
         pest::Statement::Return(s) => vec![absy::StatementNode::from(s)],
+        pest::Statement::Log(s) => vec![absy::StatementNode::from(s)],
     }
 }
 
@@ -178,10 +311,12 @@ fn statements_from_definition(definition: pest::DefinitionStatement) -> Vec<absy
             let e: absy::ExpressionNode = absy::ExpressionNode::from(definition.expression);
 
             let s = match e.value {
-                absy::Expression::FunctionCall(..) => absy::Statement::MultipleDefinition(
-                    vec![absy::AssigneeNode::from(a.a.clone())],
-                    e,
-                ),
+                absy::Expression::FunctionCall(..) | absy::Expression::MethodCall(..) => {
+                    absy::Statement::MultipleDefinition(
+                        vec![absy::AssigneeNode::from(a.a.clone())],
+                        e,
+                    )
+                }
                 _ => absy::Statement::Definition(absy::AssigneeNode::from(a.a.clone()), e),
             };
 
@@ -260,8 +395,27 @@ impl<'ast> From<pest::AssertionStatement<'ast>> for absy::StatementNode<'ast> {
     fn from(statement: pest::AssertionStatement<'ast>) -> absy::StatementNode<'ast> {
         use absy::NodeValue;
 
-        absy::Statement::Assertion(absy::ExpressionNode::from(statement.expression))
-            .span(statement.span)
+        absy::Statement::Assertion(
+            absy::ExpressionNode::from(statement.expression),
+            statement.message.map(|m| m.value),
+        )
+        .span(statement.span)
+    }
+}
+
+impl<'ast> From<pest::LogStatement<'ast>> for absy::StatementNode<'ast> {
+    fn from(statement: pest::LogStatement<'ast>) -> absy::StatementNode<'ast> {
+        use absy::NodeValue;
+
+        absy::Statement::Log(
+            statement.format_string.value,
+            statement
+                .expressions
+                .into_iter()
+                .map(|e| absy::ExpressionNode::from(e))
+                .collect(),
+        )
+        .span(statement.span)
     }
 }
 
@@ -284,6 +438,47 @@ impl<'ast> From<pest::IterationStatement<'ast>> for absy::StatementNode<'ast> {
     }
 }
 
+impl<'ast> From<pest::VectorIterationStatement<'ast>> for absy::StatementNode<'ast> {
+    fn from(statement: pest::VectorIterationStatement<'ast>) -> absy::StatementNode<'ast> {
+        use absy::NodeValue;
+        let vector = absy::ExpressionNode::from(statement.vector);
+        let index = statement.index.span.as_str();
+        let ty = absy::UnresolvedTypeNode::from(statement.ty);
+        let statements: Vec<absy::StatementNode<'ast>> = statement
+            .statements
+            .into_iter()
+            .flat_map(|s| statements_from_statement(s))
+            .collect();
+
+        let var = absy::Variable::new(index, ty).span(statement.index.span);
+
+        absy::Statement::ForEach(var, vector, statements).span(statement.span)
+    }
+}
+
+impl<'ast> From<pest::ConditionalStatement<'ast>> for absy::StatementNode<'ast> {
+    fn from(statement: pest::ConditionalStatement<'ast>) -> absy::StatementNode<'ast> {
+        use absy::NodeValue;
+        let condition = absy::ExpressionNode::from(statement.condition);
+        let consequence = statement
+            .consequence
+            .into_iter()
+            .flat_map(|s| statements_from_statement(s))
+            .collect();
+        let alternative = statement
+            .alternative
+            .map(|a| {
+                a.statements
+                    .into_iter()
+                    .flat_map(|s| statements_from_statement(s))
+                    .collect()
+            })
+            .unwrap_or(vec![]);
+
+        absy::Statement::IfElse(condition, consequence, alternative).span(statement.span)
+    }
+}
+
 impl<'ast> From<pest::Expression<'ast>> for absy::ExpressionNode<'ast> {
     fn from(expression: pest::Expression<'ast>) -> absy::ExpressionNode<'ast> {
         match expression {
@@ -294,8 +489,10 @@ impl<'ast> From<pest::Expression<'ast>> for absy::ExpressionNode<'ast> {
             pest::Expression::Postfix(e) => absy::ExpressionNode::from(e),
             pest::Expression::InlineArray(e) => absy::ExpressionNode::from(e),
             pest::Expression::InlineStruct(e) => absy::ExpressionNode::from(e),
+            pest::Expression::InlineTuple(e) => absy::ExpressionNode::from(e),
             pest::Expression::ArrayInitializer(e) => absy::ExpressionNode::from(e),
             pest::Expression::Unary(e) => absy::ExpressionNode::from(e),
+            pest::Expression::Cast(e) => absy::ExpressionNode::from(e),
         }
     }
 }
@@ -320,6 +517,10 @@ impl<'ast> From<pest::BinaryExpression<'ast>> for absy::ExpressionNode<'ast> {
                 box absy::ExpressionNode::from(*expression.left),
                 box absy::ExpressionNode::from(*expression.right),
             ),
+            pest::BinaryOperator::Rem => absy::Expression::Rem(
+                box absy::ExpressionNode::from(*expression.left),
+                box absy::ExpressionNode::from(*expression.right),
+            ),
             pest::BinaryOperator::Eq => absy::Expression::Eq(
                 box absy::ExpressionNode::from(*expression.left),
                 box absy::ExpressionNode::from(*expression.right),
@@ -480,6 +681,20 @@ impl<'ast> From<pest::InlineStructExpression<'ast>> for absy::ExpressionNode<'as
     }
 }
 
+impl<'ast> From<pest::InlineTupleExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(tuple: pest::InlineTupleExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        use absy::NodeValue;
+        absy::Expression::InlineTuple(
+            tuple
+                .expressions
+                .into_iter()
+                .map(|e| absy::ExpressionNode::from(e))
+                .collect(),
+        )
+        .span(tuple.span)
+    }
+}
+
 impl<'ast> From<pest::ArrayInitializerExpression<'ast>> for absy::ExpressionNode<'ast> {
     fn from(initializer: pest::ArrayInitializerExpression<'ast>) -> absy::ExpressionNode<'ast> {
         use absy::NodeValue;
@@ -503,17 +718,46 @@ impl<'ast> From<pest::UnaryExpression<'ast>> for absy::ExpressionNode<'ast> {
             pest::UnaryOperator::Not(_) => {
                 absy::Expression::Not(Box::new(absy::ExpressionNode::from(*unary.expression)))
             }
+            pest::UnaryOperator::Neg(_) => {
+                absy::Expression::Neg(Box::new(absy::ExpressionNode::from(*unary.expression)))
+            }
         }
         .span(unary.span)
     }
 }
 
+impl<'ast> From<pest::CastExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(cast: pest::CastExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        use absy::NodeValue;
+
+        absy::Expression::Cast(
+            box absy::ExpressionNode::from(*cast.expression),
+            absy::UnresolvedTypeNode::from(cast.ty),
+        )
+        .span(cast.span)
+    }
+}
+
 impl<'ast> From<pest::PostfixExpression<'ast>> for absy::ExpressionNode<'ast> {
     fn from(expression: pest::PostfixExpression<'ast>) -> absy::ExpressionNode<'ast> {
         use absy::NodeValue;
 
         let id_str = expression.id.span.as_str();
-        let id = absy::ExpressionNode::from(expression.id);
+
+        // `A::foo(b)` is a call to the associated function `foo` of the struct `A`
+        let id = match expression.associated_call {
+            Some(c) => absy::Expression::MethodCall(
+                absy::Receiver::Type(id_str.to_string()),
+                c.id.span.as_str(),
+                c.call
+                    .expressions
+                    .into_iter()
+                    .map(|e| absy::ExpressionNode::from(e))
+                    .collect(),
+            )
+            .span(c.span),
+            None => absy::ExpressionNode::from(expression.id),
+        };
 
         // pest::PostFixExpression contains an array of "accesses": `a(34)[42]` is represented as `[a, [Call(34), Select(42)]]`, but absy::ExpressionNode
         // is recursive, so it is `Select(Call(a, 34), 42)`. We apply this transformation here
@@ -528,6 +772,15 @@ impl<'ast> From<pest::PostfixExpression<'ast>> for absy::ExpressionNode<'ast> {
                         .map(|e| absy::ExpressionNode::from(e))
                         .collect(),
                 ),
+                // `a.foo(b)` is a call to the method `foo` of the struct of `a`, with `a` as `self`
+                absy::Expression::Member(box receiver, box method) => absy::Expression::MethodCall(
+                    absy::Receiver::Value(box receiver),
+                    method,
+                    a.expressions
+                        .into_iter()
+                        .map(|e| absy::ExpressionNode::from(e))
+                        .collect(),
+                ),
                 e => unimplemented!("only identifiers are callable, found \"{}\"", e),
             }
             .span(a.span),
@@ -538,6 +791,10 @@ impl<'ast> From<pest::PostfixExpression<'ast>> for absy::ExpressionNode<'ast> {
             pest::Access::Member(m) => {
                 absy::Expression::Member(box acc, box m.id.span.as_str()).span(m.span)
             }
+            pest::Access::Tuple(t) => {
+                absy::Expression::Element(box acc, str::parse::<u32>(&t.index.value).unwrap())
+                    .span(t.span)
+            }
         })
     }
 }
@@ -565,6 +822,22 @@ impl<'ast> From<pest::ConstantExpression<'ast>> for absy::ExpressionNode<'ast> {
                 u32::from_str_radix(&n.value.trim_start_matches("0x"), 16).unwrap(),
             )
             .span(n.span),
+            pest::ConstantExpression::U64(n) => absy::Expression::U64Constant(
+                u64::from_str_radix(&n.value.trim_start_matches("0x"), 16).unwrap(),
+            )
+            .span(n.span),
+            pest::ConstantExpression::I8(n) => absy::Expression::I8Constant(
+                BigInt::parse_bytes(&n.value.trim_end_matches("i8").as_bytes(), 10).unwrap(),
+            )
+            .span(n.span),
+            pest::ConstantExpression::I16(n) => absy::Expression::I16Constant(
+                BigInt::parse_bytes(&n.value.trim_end_matches("i16").as_bytes(), 10).unwrap(),
+            )
+            .span(n.span),
+            pest::ConstantExpression::I32(n) => absy::Expression::I32Constant(
+                BigInt::parse_bytes(&n.value.trim_end_matches("i32").as_bytes(), 10).unwrap(),
+            )
+            .span(n.span),
         }
     }
 }
@@ -599,6 +872,9 @@ impl<'ast> From<pest::Assignee<'ast>> for absy::AssigneeNode<'ast> {
                 pest::AssigneeAccess::Member(m) => {
                     absy::Assignee::Member(box acc, box m.id.span.as_str())
                 }
+                pest::AssigneeAccess::Tuple(t) => {
+                    absy::Assignee::Element(box acc, str::parse::<u32>(&t.index.value).unwrap())
+                }
             }
             .span(span.clone())
         })
@@ -607,7 +883,7 @@ impl<'ast> From<pest::Assignee<'ast>> for absy::AssigneeNode<'ast> {
 
 impl<'ast> From<pest::Type<'ast>> for absy::UnresolvedTypeNode {
     fn from(t: pest::Type<'ast>) -> absy::UnresolvedTypeNode {
-        use absy::types::UnresolvedType;
+        use absy::types::{ArraySize, UnresolvedType};
         use absy::NodeValue;
 
         match t {
@@ -617,6 +893,10 @@ impl<'ast> From<pest::Type<'ast>> for absy::UnresolvedTypeNode {
                 pest::BasicType::U8(t) => UnresolvedType::Uint(8).span(t.span),
                 pest::BasicType::U16(t) => UnresolvedType::Uint(16).span(t.span),
                 pest::BasicType::U32(t) => UnresolvedType::Uint(32).span(t.span),
+                pest::BasicType::U64(t) => UnresolvedType::Uint(64).span(t.span),
+                pest::BasicType::I8(t) => UnresolvedType::Int(8).span(t.span),
+                pest::BasicType::I16(t) => UnresolvedType::Int(16).span(t.span),
+                pest::BasicType::I32(t) => UnresolvedType::Int(32).span(t.span),
             },
             pest::Type::Array(t) => {
                 let inner_type = match t.ty {
@@ -626,9 +906,16 @@ impl<'ast> From<pest::Type<'ast>> for absy::UnresolvedTypeNode {
                         pest::BasicType::U8(t) => UnresolvedType::Uint(8).span(t.span),
                         pest::BasicType::U16(t) => UnresolvedType::Uint(16).span(t.span),
                         pest::BasicType::U32(t) => UnresolvedType::Uint(32).span(t.span),
+                        pest::BasicType::U64(t) => UnresolvedType::Uint(64).span(t.span),
+                        pest::BasicType::I8(t) => UnresolvedType::Int(8).span(t.span),
+                        pest::BasicType::I16(t) => UnresolvedType::Int(16).span(t.span),
+                        pest::BasicType::I32(t) => UnresolvedType::Int(32).span(t.span),
                     },
                     pest::BasicOrStructType::Struct(t) => {
-                        UnresolvedType::User(t.span.as_str().to_string()).span(t.span)
+                        absy::UnresolvedTypeNode::from(pest::Type::Struct(t))
+                    }
+                    pest::BasicOrStructType::Tuple(t) => {
+                        absy::UnresolvedTypeNode::from(pest::Type::Tuple(t))
                     }
                 };
 
@@ -636,21 +923,7 @@ impl<'ast> From<pest::Type<'ast>> for absy::UnresolvedTypeNode {
 
                 t.dimensions
                     .into_iter()
-                    .map(|s| match s {
-                        pest::Expression::Constant(c) => match c {
-                            pest::ConstantExpression::DecimalNumber(n) => {
-                                str::parse::<usize>(&n.value).unwrap()
-                            }
-                            _ => unimplemented!(
-                                "Array size should be a decimal number, found {}",
-                                c.span().as_str()
-                            ),
-                        },
-                        e => unimplemented!(
-                            "Array size should be constant, found {}",
-                            e.span().as_str()
-                        ),
-                    })
+                    .map(array_size_from_expression)
                     .rev()
                     .fold(None, |acc, s| match acc {
                         None => Some(UnresolvedType::array(inner_type.clone(), s)),
@@ -659,17 +932,64 @@ impl<'ast> From<pest::Type<'ast>> for absy::UnresolvedTypeNode {
                     .unwrap()
                     .span(span.clone())
             }
-            pest::Type::Struct(s) => {
-                UnresolvedType::User(s.id.span.as_str().to_string()).span(s.span)
+            pest::Type::Struct(s) => UnresolvedType::User(
+                s.id.span.as_str().to_string(),
+                s.explicit_generics
+                    .into_iter()
+                    .map(|g| match g {
+                        pest::ConstantGenericValue::Value(n) => {
+                            ArraySize::Value(str::parse::<usize>(&n.value).unwrap())
+                        }
+                        pest::ConstantGenericValue::Identifier(id) => {
+                            ArraySize::Identifier(id.span.as_str().to_string())
+                        }
+                    })
+                    .collect(),
+            )
+            .span(s.span),
+            pest::Type::Tuple(t) => UnresolvedType::Tuple(
+                t.elements
+                    .into_iter()
+                    .map(|e| absy::UnresolvedTypeNode::from(e))
+                    .collect(),
+            )
+            .span(t.span),
+            pest::Type::Vector(t) => {
+                let inner_type = absy::UnresolvedTypeNode::from(match t.ty {
+                    pest::BasicOrStructType::Basic(t) => pest::Type::Basic(t),
+                    pest::BasicOrStructType::Struct(t) => pest::Type::Struct(t),
+                    pest::BasicOrStructType::Tuple(t) => pest::Type::Tuple(t),
+                });
+
+                UnresolvedType::vector(inner_type, array_size_from_expression(t.capacity))
+                    .span(t.span)
             }
         }
     }
 }
 
+fn array_size_from_expression(e: pest::Expression) -> absy::types::ArraySize {
+    use absy::types::ArraySize;
+
+    match e {
+        pest::Expression::Constant(c) => match c {
+            pest::ConstantExpression::DecimalNumber(n) => {
+                ArraySize::Value(str::parse::<usize>(&n.value).unwrap())
+            }
+            _ => unimplemented!(
+                "Array size should be a decimal number, found {}",
+                c.span().as_str()
+            ),
+        },
+        pest::Expression::Identifier(id) => ArraySize::Identifier(id.span.as_str().to_string()),
+        e => unimplemented!("Array size should be constant, found {}", e.span().as_str()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use absy::types::{UnresolvedSignature, UnresolvedType};
+    use absy::types::{ArraySize, UnresolvedSignature, UnresolvedType};
     use absy::NodeValue;
 
     #[test]
@@ -681,6 +1001,8 @@ mod tests {
                 id: &source[4..8],
                 symbol: absy::Symbol::HereFunction(
                     absy::Function {
+                        exported: false,
+                        generics: vec![],
                         arguments: vec![],
                         statements: vec![absy::Statement::Return(
                             absy::ExpressionList {
@@ -714,6 +1036,8 @@ mod tests {
                 id: &source[4..8],
                 symbol: absy::Symbol::HereFunction(
                     absy::Function {
+                        exported: false,
+                        generics: vec![],
                         arguments: vec![],
                         statements: vec![absy::Statement::Return(
                             absy::ExpressionList {
@@ -745,6 +1069,8 @@ mod tests {
                 id: &source[4..8],
                 symbol: absy::Symbol::HereFunction(
                     absy::Function {
+                        exported: false,
+                        generics: vec![],
                         arguments: vec![
                             absy::Parameter::private(
                                 absy::Variable::new(
@@ -800,6 +1126,8 @@ mod tests {
                     id: "main",
                     symbol: absy::Symbol::HereFunction(
                         absy::Function {
+                            exported: false,
+                            generics: vec![],
                             arguments: vec![absy::Parameter::private(
                                 absy::Variable::new("a", ty.clone().mock()).into(),
                             )
@@ -828,20 +1156,44 @@ mod tests {
                 ("bool", UnresolvedType::Boolean),
                 (
                     "field[2]",
-                    UnresolvedType::Array(box UnresolvedType::FieldElement.mock(), 2),
+                    UnresolvedType::array(UnresolvedType::FieldElement.mock(), 2),
                 ),
                 (
                     "field[2][3]",
-                    UnresolvedType::Array(
-                        box UnresolvedType::Array(box UnresolvedType::FieldElement.mock(), 3)
-                            .mock(),
+                    UnresolvedType::array(
+                        UnresolvedType::array(UnresolvedType::FieldElement.mock(), 3).mock(),
                         2,
                     ),
                 ),
                 (
                     "bool[2][3]",
-                    UnresolvedType::Array(
-                        box UnresolvedType::Array(box UnresolvedType::Boolean.mock(), 3).mock(),
+                    UnresolvedType::array(
+                        UnresolvedType::array(UnresolvedType::Boolean.mock(), 3).mock(),
+                        2,
+                    ),
+                ),
+                (
+                    "field[N]",
+                    UnresolvedType::array(
+                        UnresolvedType::FieldElement.mock(),
+                        ArraySize::Identifier("N".into()),
+                    ),
+                ),
+                (
+                    "(field, bool)",
+                    UnresolvedType::Tuple(vec![
+                        UnresolvedType::FieldElement.mock(),
+                        UnresolvedType::Boolean.mock(),
+                    ]),
+                ),
+                (
+                    "(field, bool)[2]",
+                    UnresolvedType::array(
+                        UnresolvedType::Tuple(vec![
+                            UnresolvedType::FieldElement.mock(),
+                            UnresolvedType::Boolean.mock(),
+                        ])
+                        .mock(),
                         2,
                     ),
                 ),
@@ -864,6 +1216,8 @@ mod tests {
                     id: "main",
                     symbol: absy::Symbol::HereFunction(
                         absy::Function {
+                            exported: false,
+                            generics: vec![],
                             arguments: vec![],
                             statements: vec![absy::Statement::Return(
                                 absy::ExpressionList {
@@ -950,6 +1304,17 @@ mod tests {
                         .into(),
                     ),
                 ),
+                (
+                    "a.0.1",
+                    absy::Expression::Element(
+                        box absy::Expression::Element(
+                            box absy::Expression::Identifier("a").into(),
+                            0,
+                        )
+                        .into(),
+                        1,
+                    ),
+                ),
             ];
 
             for (source, expected) in vectors {
@@ -1042,6 +1407,7 @@ mod tests {
                     value: String::from("foo"),
                     span: span.clone(),
                 },
+                associated_call: None,
                 accesses: vec![pest::Access::Call(pest::CallAccess {
                     expressions: vec![],
                     span: span.clone(),
@@ -1099,6 +1465,7 @@ mod tests {
                     value: String::from("foo"),
                     span: span.clone(),
                 },
+                associated_call: None,
                 accesses: vec![pest::Access::Call(pest::CallAccess {
                     expressions: vec![],
                     span: span.clone(),
@@ -21,8 +21,9 @@ use std::path::PathBuf;
 use crate::imports::ImportNode;
 use std::fmt;
 
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 use std::collections::HashMap;
+use typed_arena::Arena;
 
 /// An identifier of a function or a variable
 pub type Identifier<'ast> = &'ast str;
@@ -52,7 +53,10 @@ pub struct SymbolDeclaration<'ast> {
 #[derive(PartialEq, Clone)]
 pub enum Symbol<'ast> {
     HereType(StructDefinitionNode<'ast>),
+    HereAlias(UnresolvedTypeNode),
+    HereConstant(ConstantDefinitionNode<'ast>),
     HereFunction(FunctionNode<'ast>),
+    HereImpl(ImplDefinitionNode<'ast>),
     There(SymbolImportNode<'ast>),
     Flat(FlatEmbed),
 }
@@ -61,7 +65,10 @@ impl<'ast> fmt::Debug for Symbol<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Symbol::HereType(t) => write!(f, "HereType({:?})", t),
+            Symbol::HereAlias(t) => write!(f, "HereAlias({:?})", t),
+            Symbol::HereConstant(c) => write!(f, "HereConstant({:?})", c),
             Symbol::HereFunction(fun) => write!(f, "HereFunction({:?})", fun),
+            Symbol::HereImpl(i) => write!(f, "HereImpl({:?})", i),
             Symbol::There(t) => write!(f, "There({:?})", t),
             Symbol::Flat(flat) => write!(f, "Flat({:?})", flat),
         }
@@ -72,7 +79,17 @@ impl<'ast> fmt::Display for SymbolDeclaration<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.symbol {
             Symbol::HereType(ref t) => write!(f, "struct {} {}", self.id, t),
-            Symbol::HereFunction(ref fun) => write!(f, "def {}{}", self.id, fun),
+            Symbol::HereAlias(ref t) => write!(f, "type {} = {}", self.id, t),
+            Symbol::HereConstant(ref c) => write!(
+                f,
+                "const {} {} = {}",
+                c.value.ty, self.id, c.value.expression
+            ),
+            Symbol::HereFunction(ref fun) => match fun.value.exported {
+                true => write!(f, "export def {}{}", self.id, fun),
+                false => write!(f, "def {}{}", self.id, fun),
+            },
+            Symbol::HereImpl(ref i) => write!(f, "impl {} {{\n{}\n}}", self.id, i),
             Symbol::There(ref import) => write!(f, "import {} as {}", import, self.id),
             Symbol::Flat(ref flat_fun) => {
                 write!(f, "def {}{}:\n\t// hidden", self.id, flat_fun.signature())
@@ -103,6 +120,20 @@ impl<'ast> Module<'ast> {
         self.imports = i.into_iter().collect();
         self
     }
+
+    /// Prefixes the ids of the functions declared in `impl` blocks with the id of their struct,
+    /// so that `new` in `impl Point` becomes `Point::new`
+    pub fn qualify_methods(mut self, arena: &'ast Arena<String>) -> Self {
+        for symbol in &mut self.symbols {
+            let id = symbol.value.id;
+            if let Symbol::HereImpl(ref mut i) = symbol.value.symbol {
+                for method in &mut i.value.methods {
+                    method.value.id = arena.alloc(format!("{}::{}", id, method.value.id)).as_str();
+                }
+            }
+        }
+        self
+    }
 }
 
 pub type UnresolvedTypeNode = Node<UnresolvedType>;
@@ -110,6 +141,8 @@ pub type UnresolvedTypeNode = Node<UnresolvedType>;
 /// A struct type definition
 #[derive(Debug, Clone, PartialEq)]
 pub struct StructDefinition<'ast> {
+    /// Generic parameters of the struct, used as array sizes
+    pub generics: Vec<Identifier<'ast>>,
     pub fields: Vec<StructDefinitionFieldNode<'ast>>,
 }
 
@@ -117,7 +150,11 @@ impl<'ast> fmt::Display for StructDefinition<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "{}",
+            "{}{}",
+            match self.generics.len() {
+                0 => String::new(),
+                _ => format!("<{}> ", self.generics.join(", ")),
+            },
             self.fields
                 .iter()
                 .map(|fi| fi.to_string())
@@ -144,6 +181,37 @@ impl<'ast> fmt::Display for StructDefinitionField<'ast> {
 
 type StructDefinitionFieldNode<'ast> = Node<StructDefinitionField<'ast>>;
 
+/// An `impl` block, declaring the methods and associated functions of a struct
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImplDefinition<'ast> {
+    pub methods: Declarations<'ast>,
+}
+
+impl<'ast> fmt::Display for ImplDefinition<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.methods
+                .iter()
+                .map(|m| m.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    }
+}
+
+pub type ImplDefinitionNode<'ast> = Node<ImplDefinition<'ast>>;
+
+/// A constant definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstantDefinition<'ast> {
+    pub ty: UnresolvedTypeNode,
+    pub expression: ExpressionNode<'ast>,
+}
+
+pub type ConstantDefinitionNode<'ast> = Node<ConstantDefinition<'ast>>;
+
 /// An import
 #[derive(Debug, Clone, PartialEq)]
 pub struct SymbolImport<'ast> {
@@ -219,6 +287,10 @@ impl<'ast> fmt::Debug for Module<'ast> {
 /// A function defined locally
 #[derive(Clone, PartialEq)]
 pub struct Function<'ast> {
+    /// Whether the function can be imported from other modules
+    pub exported: bool,
+    /// Generic parameters of the function, used as array sizes
+    pub generics: Vec<Identifier<'ast>>,
     /// Arguments of the function
     pub arguments: Vec<ParameterNode<'ast>>,
     /// Vector of statements that are executed when running the function
@@ -233,7 +305,11 @@ impl<'ast> fmt::Display for Function<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "({}):\n{}",
+            "{}({}):\n{}",
+            match self.generics.len() {
+                0 => String::new(),
+                _ => format!("<{}>", self.generics.join(", ")),
+            },
             self.arguments
                 .iter()
                 .map(|x| format!("{}", x))
@@ -252,7 +328,9 @@ impl<'ast> fmt::Debug for Function<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "Function(arguments: {:?}, ...):\n{}",
+            "Function(exported: {:?}, generics: {:?}, arguments: {:?}, ...):\n{}",
+            self.exported,
+            self.generics,
             self.arguments,
             self.statements
                 .iter()
@@ -269,6 +347,7 @@ pub enum Assignee<'ast> {
     Identifier(Identifier<'ast>),
     Select(Box<AssigneeNode<'ast>>, Box<RangeOrExpression<'ast>>),
     Member(Box<AssigneeNode<'ast>>, Box<Identifier<'ast>>),
+    Element(Box<AssigneeNode<'ast>>, u32),
 }
 
 pub type AssigneeNode<'ast> = Node<Assignee<'ast>>;
@@ -279,6 +358,7 @@ impl<'ast> fmt::Debug for Assignee<'ast> {
             Assignee::Identifier(ref s) => write!(f, "Identifier({:?})", s),
             Assignee::Select(ref a, ref e) => write!(f, "Select({:?}[{:?}])", a, e),
             Assignee::Member(ref s, ref m) => write!(f, "Member({:?}.{:?})", s, m),
+            Assignee::Element(ref t, ref i) => write!(f, "Element({:?}.{:?})", t, i),
         }
     }
 }
@@ -289,6 +369,7 @@ impl<'ast> fmt::Display for Assignee<'ast> {
             Assignee::Identifier(ref s) => write!(f, "{}", s),
             Assignee::Select(ref a, ref e) => write!(f, "{}[{}]", a, e),
             Assignee::Member(ref s, ref m) => write!(f, "{}.{}", s, m),
+            Assignee::Element(ref t, ref i) => write!(f, "{}.{}", t, i),
         }
     }
 }
@@ -299,14 +380,25 @@ pub enum Statement<'ast> {
     Return(ExpressionListNode<'ast>),
     Declaration(VariableNode<'ast>),
     Definition(AssigneeNode<'ast>, ExpressionNode<'ast>),
-    Assertion(ExpressionNode<'ast>),
+    Assertion(ExpressionNode<'ast>, Option<String>),
     For(
         VariableNode<'ast>,
         ExpressionNode<'ast>,
         ExpressionNode<'ast>,
         Vec<StatementNode<'ast>>,
     ),
+    ForEach(
+        VariableNode<'ast>,
+        ExpressionNode<'ast>,
+        Vec<StatementNode<'ast>>,
+    ),
+    IfElse(
+        ExpressionNode<'ast>,
+        Vec<StatementNode<'ast>>,
+        Vec<StatementNode<'ast>>,
+    ),
     MultipleDefinition(Vec<AssigneeNode<'ast>>, ExpressionNode<'ast>),
+    Log(String, Vec<ExpressionNode<'ast>>),
 }
 
 pub type StatementNode<'ast> = Node<Statement<'ast>>;
@@ -317,7 +409,10 @@ impl<'ast> fmt::Display for Statement<'ast> {
             Statement::Return(ref expr) => write!(f, "return {}", expr),
             Statement::Declaration(ref var) => write!(f, "{}", var),
             Statement::Definition(ref lhs, ref rhs) => write!(f, "{} = {}", lhs, rhs),
-            Statement::Assertion(ref e) => write!(f, "assert({})", e),
+            Statement::Assertion(ref e, ref message) => match message {
+                Some(m) => write!(f, "assert({}, \"{}\")", e, m),
+                None => write!(f, "assert({})", e),
+            },
             Statement::For(ref var, ref start, ref stop, ref list) => {
                 write!(f, "for {} in {}..{} do\n", var, start, stop)?;
                 for l in list {
@@ -325,6 +420,24 @@ impl<'ast> fmt::Display for Statement<'ast> {
                 }
                 write!(f, "\tendfor")
             }
+            Statement::ForEach(ref var, ref vector, ref list) => {
+                write!(f, "for {} in {} do\n", var, vector)?;
+                for l in list {
+                    write!(f, "\t\t{}\n", l)?;
+                }
+                write!(f, "\tendfor")
+            }
+            Statement::IfElse(ref condition, ref consequence, ref alternative) => {
+                write!(f, "if {}:\n", condition)?;
+                for l in consequence {
+                    write!(f, "\t\t{}\n", l)?;
+                }
+                write!(f, "\telse:\n")?;
+                for l in alternative {
+                    write!(f, "\t\t{}\n", l)?;
+                }
+                write!(f, "\tendif")
+            }
             Statement::MultipleDefinition(ref ids, ref rhs) => {
                 for (i, id) in ids.iter().enumerate() {
                     write!(f, "{}", id)?;
@@ -334,6 +447,13 @@ impl<'ast> fmt::Display for Statement<'ast> {
                 }
                 write!(f, " = {}", rhs)
             }
+            Statement::Log(ref format_string, ref expressions) => {
+                write!(f, "log(\"{}\"", format_string)?;
+                for e in expressions {
+                    write!(f, ", {}", e)?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -346,7 +466,9 @@ impl<'ast> fmt::Debug for Statement<'ast> {
             Statement::Definition(ref lhs, ref rhs) => {
                 write!(f, "Definition({:?}, {:?})", lhs, rhs)
             }
-            Statement::Assertion(ref e) => write!(f, "Assertion({:?})", e),
+            Statement::Assertion(ref e, ref message) => {
+                write!(f, "Assertion({:?}, {:?})", e, message)
+            }
             Statement::For(ref var, ref start, ref stop, ref list) => {
                 write!(f, "for {:?} in {:?}..{:?} do\n", var, start, stop)?;
                 for l in list {
@@ -354,9 +476,24 @@ impl<'ast> fmt::Debug for Statement<'ast> {
                 }
                 write!(f, "\tendfor")
             }
+            Statement::ForEach(ref var, ref vector, ref list) => {
+                write!(f, "for {:?} in {:?} do\n", var, vector)?;
+                for l in list {
+                    write!(f, "\t\t{:?}\n", l)?;
+                }
+                write!(f, "\tendfor")
+            }
+            Statement::IfElse(ref condition, ref consequence, ref alternative) => write!(
+                f,
+                "IfElse({:?}, {:?}, {:?})",
+                condition, consequence, alternative
+            ),
             Statement::MultipleDefinition(ref lhs, ref rhs) => {
                 write!(f, "MultipleDefinition({:?}, {:?})", lhs, rhs)
             }
+            Statement::Log(ref format_string, ref expressions) => {
+                write!(f, "Log({:?}, {:?})", format_string, expressions)
+            }
         }
     }
 }
@@ -477,11 +614,16 @@ pub enum Expression<'ast> {
     U8Constant(u8),
     U16Constant(u16),
     U32Constant(u32),
+    U64Constant(u64),
+    I8Constant(BigInt),
+    I16Constant(BigInt),
+    I32Constant(BigInt),
     Identifier(Identifier<'ast>),
     Add(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     Sub(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     Mult(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     Div(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
+    Rem(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     Pow(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     IfElse(
         Box<ExpressionNode<'ast>>,
@@ -489,6 +631,11 @@ pub enum Expression<'ast> {
         Box<ExpressionNode<'ast>>,
     ),
     FunctionCall(FunctionIdentifier<'ast>, Vec<ExpressionNode<'ast>>),
+    MethodCall(
+        Receiver<'ast>,
+        FunctionIdentifier<'ast>,
+        Vec<ExpressionNode<'ast>>,
+    ),
     Lt(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     Le(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     Eq(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
@@ -496,20 +643,42 @@ pub enum Expression<'ast> {
     Gt(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     And(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     Not(Box<ExpressionNode<'ast>>),
+    Neg(Box<ExpressionNode<'ast>>),
     InlineArray(Vec<SpreadOrExpression<'ast>>),
     InlineStruct(UserTypeId, Vec<(Identifier<'ast>, ExpressionNode<'ast>)>),
+    InlineTuple(Vec<ExpressionNode<'ast>>),
     Select(Box<ExpressionNode<'ast>>, Box<RangeOrExpression<'ast>>),
     Member(Box<ExpressionNode<'ast>>, Box<Identifier<'ast>>),
+    Element(Box<ExpressionNode<'ast>>, u32),
     Or(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     BitXor(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     BitAnd(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     BitOr(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     LeftShift(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     RightShift(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
+    Cast(Box<ExpressionNode<'ast>>, UnresolvedTypeNode),
 }
 
 pub type ExpressionNode<'ast> = Node<Expression<'ast>>;
 
+/// What a function declared in an `impl` block is called on
+#[derive(Debug, Clone, PartialEq)]
+pub enum Receiver<'ast> {
+    /// A struct instance, passed as `self`, in `a.foo(b)`
+    Value(Box<ExpressionNode<'ast>>),
+    /// A struct, in `A::foo(b)`
+    Type(UserTypeId),
+}
+
+impl<'ast> fmt::Display for Receiver<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Receiver::Value(ref e) => write!(f, "{}.", e),
+            Receiver::Type(ref t) => write!(f, "{}::", t),
+        }
+    }
+}
+
 impl<'ast> fmt::Display for Expression<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -517,11 +686,16 @@ impl<'ast> fmt::Display for Expression<'ast> {
             Expression::U8Constant(ref i) => write!(f, "{}", i),
             Expression::U16Constant(ref i) => write!(f, "{}", i),
             Expression::U32Constant(ref i) => write!(f, "{}", i),
+            Expression::U64Constant(ref i) => write!(f, "{}", i),
+            Expression::I8Constant(ref i) => write!(f, "{}i8", i),
+            Expression::I16Constant(ref i) => write!(f, "{}i16", i),
+            Expression::I32Constant(ref i) => write!(f, "{}i32", i),
             Expression::Identifier(ref var) => write!(f, "{}", var),
             Expression::Add(ref lhs, ref rhs) => write!(f, "({} + {})", lhs, rhs),
             Expression::Sub(ref lhs, ref rhs) => write!(f, "({} - {})", lhs, rhs),
             Expression::Mult(ref lhs, ref rhs) => write!(f, "({} * {})", lhs, rhs),
             Expression::Div(ref lhs, ref rhs) => write!(f, "({} / {})", lhs, rhs),
+            Expression::Rem(ref lhs, ref rhs) => write!(f, "({} % {})", lhs, rhs),
             Expression::Pow(ref lhs, ref rhs) => write!(f, "({}**{})", lhs, rhs),
             Expression::BooleanConstant(b) => write!(f, "{}", b),
             Expression::IfElse(ref condition, ref consequent, ref alternative) => write!(
@@ -539,6 +713,16 @@ impl<'ast> fmt::Display for Expression<'ast> {
                 }
                 write!(f, ")")
             }
+            Expression::MethodCall(ref r, ref i, ref p) => {
+                write!(f, "{}{}(", r, i)?;
+                for (i, param) in p.iter().enumerate() {
+                    write!(f, "{}", param)?;
+                    if i < p.len() - 1 {
+                        write!(f, ", ")?;
+                    }
+                }
+                write!(f, ")")
+            }
             Expression::Lt(ref lhs, ref rhs) => write!(f, "({} < {})", lhs, rhs),
             Expression::Le(ref lhs, ref rhs) => write!(f, "({} <= {})", lhs, rhs),
             Expression::Eq(ref lhs, ref rhs) => write!(f, "({} == {})", lhs, rhs),
@@ -546,6 +730,7 @@ impl<'ast> fmt::Display for Expression<'ast> {
             Expression::Gt(ref lhs, ref rhs) => write!(f, "({} > {})", lhs, rhs),
             Expression::And(ref lhs, ref rhs) => write!(f, "({} && {})", lhs, rhs),
             Expression::Not(ref exp) => write!(f, "!{}", exp),
+            Expression::Neg(ref exp) => write!(f, "-{}", exp),
             Expression::InlineArray(ref exprs) => {
                 write!(f, "[")?;
                 for (i, e) in exprs.iter().enumerate() {
@@ -566,14 +751,29 @@ impl<'ast> fmt::Display for Expression<'ast> {
                 }
                 write!(f, "}}")
             }
+            Expression::InlineTuple(ref exprs) => {
+                write!(f, "(")?;
+                for (i, e) in exprs.iter().enumerate() {
+                    write!(f, "{}", e)?;
+                    if i < exprs.len() - 1 || exprs.len() == 1 {
+                        write!(f, ",")?;
+                    }
+                    if i < exprs.len() - 1 {
+                        write!(f, " ")?;
+                    }
+                }
+                write!(f, ")")
+            }
             Expression::Select(ref array, ref index) => write!(f, "{}[{}]", array, index),
             Expression::Member(ref struc, ref id) => write!(f, "{}.{}", struc, id),
+            Expression::Element(ref tuple, ref index) => write!(f, "{}.{}", tuple, index),
             Expression::Or(ref lhs, ref rhs) => write!(f, "({} || {})", lhs, rhs),
             Expression::BitXor(ref lhs, ref rhs) => write!(f, "({} ^ {})", lhs, rhs),
             Expression::BitAnd(ref lhs, ref rhs) => write!(f, "({} & {})", lhs, rhs),
             Expression::BitOr(ref lhs, ref rhs) => write!(f, "({} | {})", lhs, rhs),
             Expression::LeftShift(ref lhs, ref rhs) => write!(f, "({} << {})", lhs, rhs),
             Expression::RightShift(ref lhs, ref rhs) => write!(f, "({} >> {})", lhs, rhs),
+            Expression::Cast(ref e, ref ty) => write!(f, "({} as {})", e, ty),
         }
     }
 }
@@ -584,12 +784,17 @@ impl<'ast> fmt::Debug for Expression<'ast> {
             Expression::U8Constant(ref i) => write!(f, "{:x}", i),
             Expression::U16Constant(ref i) => write!(f, "{:x}", i),
             Expression::U32Constant(ref i) => write!(f, "{:x}", i),
+            Expression::U64Constant(ref i) => write!(f, "{:x}", i),
+            Expression::I8Constant(ref i) => write!(f, "{}i8", i),
+            Expression::I16Constant(ref i) => write!(f, "{}i16", i),
+            Expression::I32Constant(ref i) => write!(f, "{}i32", i),
             Expression::FieldConstant(ref i) => write!(f, "Num({:?})", i),
             Expression::Identifier(ref var) => write!(f, "Ide({})", var),
             Expression::Add(ref lhs, ref rhs) => write!(f, "Add({:?}, {:?})", lhs, rhs),
             Expression::Sub(ref lhs, ref rhs) => write!(f, "Sub({:?}, {:?})", lhs, rhs),
             Expression::Mult(ref lhs, ref rhs) => write!(f, "Mult({:?}, {:?})", lhs, rhs),
             Expression::Div(ref lhs, ref rhs) => write!(f, "Div({:?}, {:?})", lhs, rhs),
+            Expression::Rem(ref lhs, ref rhs) => write!(f, "Rem({:?}, {:?})", lhs, rhs),
             Expression::Pow(ref lhs, ref rhs) => write!(f, "Pow({:?}, {:?})", lhs, rhs),
             Expression::BooleanConstant(b) => write!(f, "{}", b),
             Expression::IfElse(ref condition, ref consequent, ref alternative) => write!(
@@ -602,6 +807,11 @@ impl<'ast> fmt::Debug for Expression<'ast> {
                 f.debug_list().entries(p.iter()).finish()?;
                 write!(f, ")")
             }
+            Expression::MethodCall(ref r, ref i, ref p) => {
+                write!(f, "MethodCall({:?}, {:?}, (", r, i)?;
+                f.debug_list().entries(p.iter()).finish()?;
+                write!(f, ")")
+            }
             Expression::Lt(ref lhs, ref rhs) => write!(f, "Lt({:?}, {:?})", lhs, rhs),
             Expression::Le(ref lhs, ref rhs) => write!(f, "Le({:?}, {:?})", lhs, rhs),
             Expression::Eq(ref lhs, ref rhs) => write!(f, "Eq({:?}, {:?})", lhs, rhs),
@@ -609,6 +819,7 @@ impl<'ast> fmt::Debug for Expression<'ast> {
             Expression::Gt(ref lhs, ref rhs) => write!(f, "Gt({:?}, {:?})", lhs, rhs),
             Expression::And(ref lhs, ref rhs) => write!(f, "And({:?}, {:?})", lhs, rhs),
             Expression::Not(ref exp) => write!(f, "Not({:?})", exp),
+            Expression::Neg(ref exp) => write!(f, "Neg({:?})", exp),
             Expression::InlineArray(ref exprs) => {
                 write!(f, "InlineArray([")?;
                 f.debug_list().entries(exprs.iter()).finish()?;
@@ -619,10 +830,18 @@ impl<'ast> fmt::Debug for Expression<'ast> {
                 f.debug_list().entries(members.iter()).finish()?;
                 write!(f, "]")
             }
+            Expression::InlineTuple(ref exprs) => {
+                write!(f, "InlineTuple(")?;
+                f.debug_list().entries(exprs.iter()).finish()?;
+                write!(f, ")")
+            }
             Expression::Select(ref array, ref index) => {
                 write!(f, "Select({:?}, {:?})", array, index)
             }
             Expression::Member(ref struc, ref id) => write!(f, "Member({:?}, {:?})", struc, id),
+            Expression::Element(ref tuple, ref index) => {
+                write!(f, "Element({:?}, {:?})", tuple, index)
+            }
             Expression::Or(ref lhs, ref rhs) => write!(f, "Or({:?}, {:?})", lhs, rhs),
             Expression::BitXor(ref lhs, ref rhs) => write!(f, "BitXor({:?}, {:?})", lhs, rhs),
             Expression::BitAnd(ref lhs, ref rhs) => write!(f, "BitAnd({:?}, {:?})", lhs, rhs),
@@ -631,6 +850,7 @@ impl<'ast> fmt::Debug for Expression<'ast> {
             Expression::RightShift(ref lhs, ref rhs) => {
                 write!(f, "RightShift({:?}, {:?})", lhs, rhs)
             }
+            Expression::Cast(ref e, ref ty) => write!(f, "Cast({:?}, {:?})", e, ty),
         }
     }
 }
@@ -7,13 +7,40 @@ pub type MemberId = String;
 
 pub type UserTypeId = String;
 
+/// The size of an array type, either a literal or a reference to a generic parameter
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub enum ArraySize {
+    Value(usize),
+    Identifier(String),
+}
+
+impl fmt::Display for ArraySize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArraySize::Value(v) => write!(f, "{}", v),
+            ArraySize::Identifier(id) => write!(f, "{}", id),
+        }
+    }
+}
+
+impl From<usize> for ArraySize {
+    fn from(v: usize) -> Self {
+        ArraySize::Value(v)
+    }
+}
+
 #[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
 pub enum UnresolvedType {
     FieldElement,
     Boolean,
     Uint(usize),
-    Array(Box<UnresolvedTypeNode>, usize),
-    User(UserTypeId),
+    Int(usize),
+    Array(Box<UnresolvedTypeNode>, ArraySize),
+    /// A user-defined type, along with the values of its generic parameters
+    User(UserTypeId, Vec<ArraySize>),
+    Tuple(Vec<UnresolvedTypeNode>),
+    /// A vector of at most the given number of elements
+    Vector(Box<UnresolvedTypeNode>, ArraySize),
 }
 
 impl fmt::Display for UnresolvedType {
@@ -22,15 +49,46 @@ impl fmt::Display for UnresolvedType {
             UnresolvedType::FieldElement => write!(f, "field"),
             UnresolvedType::Boolean => write!(f, "bool"),
             UnresolvedType::Uint(bitwidth) => write!(f, "u{}", bitwidth),
+            UnresolvedType::Int(bitwidth) => write!(f, "i{}", bitwidth),
             UnresolvedType::Array(ref ty, ref size) => write!(f, "{}[{}]", ty, size),
-            UnresolvedType::User(i) => write!(f, "{}", i),
+            UnresolvedType::User(i, generics) => match generics.len() {
+                0 => write!(f, "{}", i),
+                _ => write!(
+                    f,
+                    "{}<{}>",
+                    i,
+                    generics
+                        .iter()
+                        .map(|g| g.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
+            UnresolvedType::Tuple(elements) => {
+                write!(f, "(")?;
+                for (i, e) in elements.iter().enumerate() {
+                    write!(f, "{}", e)?;
+                    if i < elements.len() - 1 || elements.len() == 1 {
+                        write!(f, ",")?;
+                    }
+                    if i < elements.len() - 1 {
+                        write!(f, " ")?;
+                    }
+                }
+                write!(f, ")")
+            }
+            UnresolvedType::Vector(ref ty, ref capacity) => write!(f, "{}[..{}]", ty, capacity),
         }
     }
 }
 
 impl UnresolvedType {
-    pub fn array(ty: UnresolvedTypeNode, size: usize) -> Self {
-        UnresolvedType::Array(box ty, size)
+    pub fn array<S: Into<ArraySize>>(ty: UnresolvedTypeNode, size: S) -> Self {
+        UnresolvedType::Array(box ty, size.into())
+    }
+
+    pub fn vector<S: Into<ArraySize>>(ty: UnresolvedTypeNode, capacity: S) -> Self {
+        UnresolvedType::Vector(box ty, capacity.into())
     }
 }
 
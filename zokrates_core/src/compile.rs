@@ -10,6 +10,7 @@ use ir;
 use macros;
 use macros::process_macros;
 use semantics::{self, Checker};
+use source_map::SourceMap;
 use static_analysis::Analyse;
 use std::collections::HashMap;
 use std::fmt;
@@ -26,6 +27,7 @@ use zokrates_pest_ast as pest;
 pub struct CompilationArtifacts<T: Field> {
     prog: ir::Prog<T>,
     abi: Abi,
+    warnings: Vec<CompileError>,
 }
 
 impl<T: Field> CompilationArtifacts<T> {
@@ -36,6 +38,17 @@ impl<T: Field> CompilationArtifacts<T> {
     pub fn abi(&self) -> &Abi {
         &self.abi
     }
+
+    /// The problems found in the source code which did not prevent compilation
+    pub fn warnings(&self) -> &[CompileError] {
+        &self.warnings
+    }
+
+    /// The location in the source code of each statement of the program, to be stored alongside
+    /// it as it is not part of the serialized program
+    pub fn source_map(&self) -> &SourceMap {
+        &self.prog.main.source_map
+    }
 }
 
 #[derive(Debug)]
@@ -149,7 +162,7 @@ pub fn compile<T: Field, E: Into<imports::Error>>(
 ) -> Result<CompilationArtifacts<T>, CompileErrors> {
     let arena = Arena::new();
 
-    let (typed_ast, abi) = check_with_arena(source, location, resolver, &arena)?;
+    let (typed_ast, abi, warnings) = check_with_arena(source, location, resolver, &arena)?;
 
     // flatten input program
     let program_flattened = Flattener::flatten(typed_ast);
@@ -169,17 +182,19 @@ pub fn compile<T: Field, E: Into<imports::Error>>(
     Ok(CompilationArtifacts {
         prog: optimized_ir_prog,
         abi,
+        warnings,
     })
 }
 
+/// Check the program without compiling it, returning the warnings found
 pub fn check<'ast, T: Field, E: Into<imports::Error>>(
     source: String,
     location: FilePath,
     resolver: Option<&dyn Resolver<E>>,
-) -> Result<(), CompileErrors> {
+) -> Result<Vec<CompileError>, CompileErrors> {
     let arena = Arena::new();
 
-    check_with_arena::<T, _>(source, location, resolver, &arena).map(|_| ())
+    check_with_arena::<T, _>(source, location, resolver, &arena).map(|(_, _, warnings)| warnings)
 }
 
 fn check_with_arena<'ast, T: Field, E: Into<imports::Error>>(
@@ -187,21 +202,23 @@ fn check_with_arena<'ast, T: Field, E: Into<imports::Error>>(
     location: FilePath,
     resolver: Option<&dyn Resolver<E>>,
     arena: &'ast Arena<String>,
-) -> Result<(ZirProgram<'ast, T>, Abi), CompileErrors> {
+) -> Result<(ZirProgram<'ast, T>, Abi, Vec<CompileError>), CompileErrors> {
     let source = arena.alloc(source);
     let compiled = compile_program::<T, E>(source, location.clone(), resolver, &arena)?;
 
     // check semantics
-    let typed_ast = Checker::check(compiled).map_err(|errors| {
+    let (typed_ast, warnings) = Checker::check(compiled).map_err(|errors| {
         CompileErrors(errors.into_iter().map(|e| CompileError::from(e)).collect())
     })?;
 
+    let warnings = warnings.into_iter().map(CompileError::from).collect();
+
     let abi = typed_ast.abi();
 
     // analyse (unroll and constant propagation)
     let typed_ast = typed_ast.analyse();
 
-    Ok((typed_ast, abi))
+    Ok((typed_ast, abi, warnings))
 }
 
 pub fn compile_program<'ast, T: Field, E: Into<imports::Error>>(
@@ -235,7 +252,7 @@ pub fn compile_module<'ast, T: Field, E: Into<imports::Error>>(
     let ast = process_macros::<T>(ast)
         .map_err(|e| CompileErrors::from(CompileErrorInner::from(e).in_file(&location)))?;
 
-    let module_without_imports: Module = Module::from(ast);
+    let module_without_imports: Module = Module::from(ast).qualify_methods(arena);
 
     Importer::new().apply_imports::<T, E>(
         module_without_imports,
@@ -285,6 +302,61 @@ mod test {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn namespaced_methods() {
+        // functions declared in `impl` blocks are only found through their struct
+        let source = r#"
+struct Point {
+    field x
+}
+impl Point {
+    def new(field x) -> Point:
+        return Point { x: x }
+}
+def main(field a) -> field:
+    return new(a).x
+"#
+        .to_string();
+        let res: Result<CompilationArtifacts<Bn128Field>, CompileErrors> = compile(
+            source,
+            "./path/to/file".into(),
+            None::<&dyn Resolver<io::Error>>,
+        );
+        assert!(res.unwrap_err().0[0].value().to_string().contains(
+            "Function definition for function new with signature (field) -> _ not found"
+        ));
+    }
+
+    #[test]
+    fn source_map() {
+        let source = r#"
+def main(field a) -> field:
+    assert(a == 1)
+    return a
+"#
+        .to_string();
+        let res: CompilationArtifacts<Bn128Field> = compile(
+            source,
+            "./path/to/file".into(),
+            None::<&dyn Resolver<io::Error>>,
+        )
+        .unwrap();
+
+        let statements = &res.prog().main.statements;
+        let source_map = res.source_map();
+
+        assert_eq!(source_map.0.len(), statements.len());
+
+        // the output constraint comes last and is mapped to the return statement
+        let last = statements.len() - 1;
+        for index in 0..last {
+            let span = source_map.get(index).unwrap();
+            assert_eq!(span.file, "./path/to/file");
+            assert_eq!(span.from.line, 3);
+        }
+        assert_eq!(source_map.get(last).unwrap().from.line, 4);
+    }
+
     mod abi {
         use super::*;
         use typed_absy::abi::*;
@@ -370,6 +442,7 @@ struct Bar { field a }
                     inputs: vec![AbiInput {
                         name: "f".into(),
                         public: true,
+                        alias: None,
                         ty: Type::Struct(StructType::new(
                             "foo".into(),
                             "Foo".into(),
@@ -390,5 +463,76 @@ struct Bar { field a }
                 }
             );
         }
+
+        #[test]
+        fn keep_alias_names() {
+            // type aliases are expanded, but their names are kept in the ABI
+
+            // // main.zok
+            // from foo import Hash
+            // type Point = field[2]
+            // def main(Hash h, private Point p)
+            //
+            // // foo.zok
+            // type Hash = u32[8]
+
+            struct CustomResolver;
+
+            impl<E> Resolver<E> for CustomResolver {
+                fn resolve(
+                    &self,
+                    _: PathBuf,
+                    import_location: PathBuf,
+                ) -> Result<(String, PathBuf), E> {
+                    let loc = import_location.display().to_string();
+                    if loc == "foo" {
+                        Ok((
+                            r#"
+type Hash = u32[8]
+"#
+                            .into(),
+                            "foo".into(),
+                        ))
+                    } else {
+                        unreachable!()
+                    }
+                }
+            }
+
+            let main = r#"
+from "foo" import Hash
+type Point = field[2]
+def main(Hash h, private Point p):
+    return
+"#;
+
+            let artifacts = compile::<Bn128Field, io::Error>(
+                main.to_string(),
+                "main".into(),
+                Some(&CustomResolver),
+            )
+            .unwrap();
+
+            assert_eq!(
+                artifacts.abi,
+                Abi {
+                    inputs: vec![
+                        AbiInput {
+                            name: "h".into(),
+                            public: true,
+                            alias: Some("Hash".into()),
+                            ty: Type::array(Type::uint(32), 8),
+                        },
+                        AbiInput {
+                            name: "p".into(),
+                            public: false,
+                            alias: Some("Point".into()),
+                            ty: Type::array(Type::FieldElement, 2),
+                        }
+                    ],
+                    outputs: vec![]
+                }
+            );
+        }
     }
 }
@@ -1,4 +1,5 @@
 use crate::solvers::Solver;
+use crate::source_map::SourceMap;
 use flat_absy::{
     FlatDirective, FlatExpression, FlatExpressionList, FlatFunction, FlatParameter, FlatStatement,
     FlatVariable,
@@ -15,9 +16,18 @@ pub enum FlatEmbed {
     U8ToBits,
     U16ToBits,
     U32ToBits,
+    U64ToBits,
     U8FromBits,
     U16FromBits,
     U32FromBits,
+    U64FromBits,
+    I8ToBits,
+    I16ToBits,
+    I32ToBits,
+    I8FromBits,
+    I16FromBits,
+    I32FromBits,
+    Lookup(usize, usize),
 }
 
 impl FlatEmbed {
@@ -35,6 +45,9 @@ impl FlatEmbed {
             FlatEmbed::U32ToBits => Signature::new()
                 .inputs(vec![Type::uint(32)])
                 .outputs(vec![Type::array(Type::Boolean, 32)]),
+            FlatEmbed::U64ToBits => Signature::new()
+                .inputs(vec![Type::uint(64)])
+                .outputs(vec![Type::array(Type::Boolean, 64)]),
             FlatEmbed::U8FromBits => Signature::new()
                 .outputs(vec![Type::uint(8)])
                 .inputs(vec![Type::array(Type::Boolean, 8)]),
@@ -44,6 +57,33 @@ impl FlatEmbed {
             FlatEmbed::U32FromBits => Signature::new()
                 .outputs(vec![Type::uint(32)])
                 .inputs(vec![Type::array(Type::Boolean, 32)]),
+            FlatEmbed::U64FromBits => Signature::new()
+                .outputs(vec![Type::uint(64)])
+                .inputs(vec![Type::array(Type::Boolean, 64)]),
+            FlatEmbed::I8ToBits => Signature::new()
+                .inputs(vec![Type::int(8)])
+                .outputs(vec![Type::array(Type::Boolean, 8)]),
+            FlatEmbed::I16ToBits => Signature::new()
+                .inputs(vec![Type::int(16)])
+                .outputs(vec![Type::array(Type::Boolean, 16)]),
+            FlatEmbed::I32ToBits => Signature::new()
+                .inputs(vec![Type::int(32)])
+                .outputs(vec![Type::array(Type::Boolean, 32)]),
+            FlatEmbed::I8FromBits => Signature::new()
+                .outputs(vec![Type::int(8)])
+                .inputs(vec![Type::array(Type::Boolean, 8)]),
+            FlatEmbed::I16FromBits => Signature::new()
+                .outputs(vec![Type::int(16)])
+                .inputs(vec![Type::array(Type::Boolean, 16)]),
+            FlatEmbed::I32FromBits => Signature::new()
+                .outputs(vec![Type::int(32)])
+                .inputs(vec![Type::array(Type::Boolean, 32)]),
+            FlatEmbed::Lookup(size, count) => Signature::new()
+                .inputs(vec![
+                    Type::array(Type::FieldElement, *size),
+                    Type::array(Type::FieldElement, *count),
+                ])
+                .outputs(vec![Type::FieldElement; *count]),
         }
     }
 
@@ -57,9 +97,18 @@ impl FlatEmbed {
             FlatEmbed::U8ToBits => "_U8_TO_BITS",
             FlatEmbed::U16ToBits => "_U16_TO_BITS",
             FlatEmbed::U32ToBits => "_U32_TO_BITS",
+            FlatEmbed::U64ToBits => "_U64_TO_BITS",
             FlatEmbed::U8FromBits => "_U8_FROM_BITS",
             FlatEmbed::U16FromBits => "_U16_FROM_BITS",
             FlatEmbed::U32FromBits => "_U32_FROM_BITS",
+            FlatEmbed::U64FromBits => "_U64_FROM_BITS",
+            FlatEmbed::I8ToBits => "_I8_TO_BITS",
+            FlatEmbed::I16ToBits => "_I16_TO_BITS",
+            FlatEmbed::I32ToBits => "_I32_TO_BITS",
+            FlatEmbed::I8FromBits => "_I8_FROM_BITS",
+            FlatEmbed::I16FromBits => "_I16_FROM_BITS",
+            FlatEmbed::I32FromBits => "_I32_FROM_BITS",
+            FlatEmbed::Lookup(..) => "_LOOKUP",
         }
     }
 
@@ -67,6 +116,7 @@ impl FlatEmbed {
     pub fn synthetize<T: Field>(&self) -> FlatFunction<T> {
         match self {
             FlatEmbed::Unpack(bitwidth) => unpack_to_bitwidth(*bitwidth),
+            FlatEmbed::Lookup(size, count) => lookup(*size, *count),
             _ => unreachable!(),
         }
     }
@@ -133,6 +183,7 @@ pub fn unpack_to_bitwidth<T: Field>(bit_width: usize) -> FlatFunction<T> {
             FlatStatement::Condition(
                 bit.clone(),
                 FlatExpression::Mult(box bit.clone(), box bit.clone()),
+                None,
             )
         })
         .collect();
@@ -156,6 +207,7 @@ pub fn unpack_to_bitwidth<T: Field>(bit_width: usize) -> FlatFunction<T> {
             box FlatExpression::Identifier(FlatVariable::new(0)),
             box FlatExpression::Number(T::from(1)),
         ),
+        None,
     ));
 
     statements.insert(
@@ -174,9 +226,233 @@ pub fn unpack_to_bitwidth<T: Field>(bit_width: usize) -> FlatFunction<T> {
     FlatFunction {
         arguments,
         statements,
+        source_map: SourceMap::default(),
     }
 }
 
+/// A `FlatFunction` which returns the values of an array at `count` indices
+///
+/// # Inputs
+/// * size the size of the array
+/// * count the number of indices
+///
+/// # Remarks
+/// * the entries `(k, a[k])` of the table and `(i, a[i])` of the accesses are sorted by a bitonic
+///   network, after which consecutive keys are checked to differ by 0 or 1 and equal keys to
+///   have equal values. The keys range from `0` to `size - 1`, so an index out of bounds fails.
+/// * the cost is `O((size + count) * log^2(size + count))`, instead of `O(size * count)` for
+///   a one-hot selection per index
+pub fn lookup<T: Field>(size: usize, count: usize) -> FlatFunction<T> {
+    assert!(size > 0);
+
+    let arguments: Vec<_> = (0..size + count)
+        .map(|i| FlatParameter::private(FlatVariable::new(i)))
+        .collect();
+
+    let mut counter = size + count;
+    let mut statements = vec![];
+
+    let table: Vec<_> = (0..size)
+        .map(|k| FlatExpression::Identifier(FlatVariable::new(k)))
+        .collect();
+
+    // v_j = a[i_j], which the constraints below enforce
+    let values: Vec<_> = (0..count)
+        .map(|j| {
+            let value = FlatVariable::new(counter);
+            counter += 1;
+            statements.push(FlatStatement::Directive(FlatDirective::new(
+                vec![value],
+                Solver::Select(size),
+                table
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::once(FlatExpression::Identifier(
+                        FlatVariable::new(size + j),
+                    )))
+                    .collect::<Vec<_>>(),
+            )));
+            value
+        })
+        .collect();
+
+    // the entries are padded with copies of the last entry of the table
+    let len = (size + count).next_power_of_two();
+    let mut entries: Vec<_> = (0..size)
+        .map(|k| (FlatExpression::Number(T::from(k)), table[k].clone()))
+        .chain((0..count).map(|j| {
+            (
+                FlatExpression::Identifier(FlatVariable::new(size + j)),
+                FlatExpression::Identifier(values[j]),
+            )
+        }))
+        .chain(
+            std::iter::repeat((
+                FlatExpression::Number(T::from(size - 1)),
+                table[size - 1].clone(),
+            ))
+            .take(len - size - count),
+        )
+        .collect();
+
+    for (lo, hi) in sorting_network(len) {
+        match (&entries[lo].0, &entries[hi].0) {
+            // the order of two entries of the table is known at compile time
+            (FlatExpression::Number(key_lo), FlatExpression::Number(key_hi)) => {
+                if key_lo > key_hi {
+                    entries.swap(lo, hi);
+                }
+            }
+            _ => {
+                let (key_lo, value_lo) = entries[lo].clone();
+                let (key_hi, value_hi) = entries[hi].clone();
+
+                let swap = FlatVariable::new(counter);
+                let key_delta = FlatVariable::new(counter + 1);
+                let value_delta = FlatVariable::new(counter + 2);
+                counter += 3;
+
+                statements.push(FlatStatement::Directive(FlatDirective::new(
+                    vec![swap],
+                    Solver::Gt,
+                    vec![key_lo.clone(), key_hi.clone()],
+                )));
+                statements.push(FlatStatement::Condition(
+                    swap.into(),
+                    FlatExpression::Mult(box swap.into(), box swap.into()),
+                    None,
+                ));
+                statements.push(FlatStatement::Definition(
+                    key_delta,
+                    FlatExpression::Mult(
+                        box swap.into(),
+                        box FlatExpression::Sub(box key_hi.clone(), box key_lo.clone()),
+                    ),
+                ));
+                statements.push(FlatStatement::Definition(
+                    value_delta,
+                    FlatExpression::Mult(
+                        box swap.into(),
+                        box FlatExpression::Sub(box value_hi.clone(), box value_lo.clone()),
+                    ),
+                ));
+
+                entries[lo] = (
+                    FlatExpression::Add(box key_lo, box key_delta.into()),
+                    FlatExpression::Add(box value_lo, box value_delta.into()),
+                );
+                entries[hi] = (
+                    FlatExpression::Sub(box key_hi, box key_delta.into()),
+                    FlatExpression::Sub(box value_hi, box value_delta.into()),
+                );
+            }
+        }
+    }
+
+    // the keys go from `0` to `size - 1`...
+    for (position, key) in vec![(0, 0), (len - 1, size - 1)] {
+        match entries[position].0 {
+            FlatExpression::Number(_) => {}
+            ref e => statements.push(FlatStatement::Condition(
+                e.clone(),
+                FlatExpression::Number(T::from(key)),
+                None,
+            )),
+        }
+    }
+
+    // ...by steps of 0 or 1, and equal keys have equal values
+    for window in entries.windows(2) {
+        match (&window[0].0, &window[1].0) {
+            (FlatExpression::Number(_), FlatExpression::Number(_)) => {}
+            (key0, key1) => {
+                let delta = FlatExpression::Sub(box key1.clone(), box key0.clone());
+                statements.push(FlatStatement::Condition(
+                    FlatExpression::Number(T::from(0)),
+                    FlatExpression::Mult(
+                        box delta.clone(),
+                        box FlatExpression::Sub(
+                            box delta.clone(),
+                            box FlatExpression::Number(T::from(1)),
+                        ),
+                    ),
+                    None,
+                ));
+                statements.push(FlatStatement::Condition(
+                    FlatExpression::Number(T::from(0)),
+                    FlatExpression::Mult(
+                        box FlatExpression::Sub(box FlatExpression::Number(T::from(1)), box delta),
+                        box FlatExpression::Sub(box window[1].1.clone(), box window[0].1.clone()),
+                    ),
+                    None,
+                ));
+            }
+        }
+    }
+
+    statements.push(FlatStatement::Return(FlatExpressionList {
+        expressions: values.into_iter().map(FlatExpression::Identifier).collect(),
+    }));
+
+    FlatFunction {
+        arguments,
+        statements,
+        source_map: SourceMap::default(),
+    }
+}
+
+/// The number of constraints of `lookup(size, count)`, computed without synthetizing it
+pub fn lookup_cost(size: usize, count: usize) -> usize {
+    let len = (size + count).next_power_of_two();
+
+    // whether the key of each entry is known at compile time, which does not depend on its value
+    let mut constant: Vec<_> = (0..len).map(|i| i < size || i >= size + count).collect();
+    let mut cost = 0;
+
+    for (lo, hi) in sorting_network(len) {
+        if !(constant[lo] && constant[hi]) {
+            constant[lo] = false;
+            constant[hi] = false;
+            cost += 3;
+        }
+    }
+
+    cost + vec![0, len - 1]
+        .into_iter()
+        .filter(|i| !constant[*i])
+        .count()
+        + 2 * constant.windows(2).filter(|w| !(w[0] && w[1])).count()
+}
+
+/// The comparators `(lo, hi)` of a bitonic sorting network over `len` entries, after each of
+/// which the smaller entry is at `lo`
+///
+/// # Remarks
+/// * `len` must be a power of two
+fn sorting_network(len: usize) -> Vec<(usize, usize)> {
+    let mut comparators = vec![];
+
+    let mut block = 2;
+    while block <= len {
+        let mut distance = block / 2;
+        while distance > 0 {
+            for i in 0..len {
+                let j = i ^ distance;
+                if j > i {
+                    comparators.push(match i & block == 0 {
+                        true => (i, j),
+                        false => (j, i),
+                    });
+                }
+            }
+            distance /= 2;
+        }
+        block *= 2;
+    }
+
+    comparators
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -219,4 +495,66 @@ mod tests {
             );
         }
     }
+
+    #[cfg(test)]
+    mod lookup {
+        use super::*;
+        use flat_absy::FlatProg;
+        use ir::{Interpreter, Prog};
+
+        fn run(size: usize, inputs: Vec<usize>) -> Result<Vec<Bn128Field>, ()> {
+            let main = lookup(size, inputs.len() - size);
+            let program: Prog<Bn128Field> = FlatProg { main }.into();
+
+            Interpreter::default()
+                .execute(
+                    &program,
+                    &inputs.into_iter().map(Bn128Field::from).collect(),
+                )
+                .map(|witness| witness.return_values())
+                .map_err(|_| ())
+        }
+
+        #[test]
+        fn values() {
+            // a = [42, 43, 44, 45, 46]
+            let a = vec![42, 43, 44, 45, 46];
+
+            assert_eq!(
+                run(5, a.iter().cloned().chain(vec![3, 0, 3, 4]).collect()),
+                Ok(vec![45, 42, 45, 46]
+                    .into_iter()
+                    .map(Bn128Field::from)
+                    .collect())
+            );
+            assert_eq!(
+                run(5, a.iter().cloned().chain(vec![2]).collect()),
+                Ok(vec![Bn128Field::from(44)])
+            );
+        }
+
+        #[test]
+        fn out_of_bounds() {
+            let a = vec![42, 43, 44, 45, 46];
+
+            assert!(run(5, a.iter().cloned().chain(vec![1, 5]).collect()).is_err());
+            assert!(run(5, a.iter().cloned().chain(vec![7, 2]).collect()).is_err());
+        }
+
+        #[test]
+        fn cost() {
+            for (size, count) in vec![(1, 1), (5, 4), (8, 2), (16, 16), (13, 30)] {
+                let constraints = lookup::<Bn128Field>(size, count)
+                    .statements
+                    .into_iter()
+                    .filter(|s| match s {
+                        FlatStatement::Condition(..) | FlatStatement::Definition(..) => true,
+                        _ => false,
+                    })
+                    .count();
+
+                assert_eq!(lookup_cost(size, count), constraints);
+            }
+        }
+    }
 }
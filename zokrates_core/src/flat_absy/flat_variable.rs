@@ -32,6 +32,15 @@ impl FlatVariable {
         (self.id as usize) - 1
     }
 
+    /// Returns the index of this variable among the variables of the same kind: `~one` is `0` and
+    /// `_i` is `i + 1` on one side, `~out_i` is `i` on the other
+    pub fn index(&self) -> usize {
+        match self.id {
+            id if id >= 0 => id as usize,
+            id => (-(id + 1)) as usize,
+        }
+    }
+
     pub fn try_from_human_readable(s: &str) -> Result<Self, &str> {
         if s == "~one" {
             return Ok(FlatVariable::one());
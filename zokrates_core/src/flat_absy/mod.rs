@@ -11,11 +11,31 @@ pub mod flat_variable;
 pub use self::flat_parameter::FlatParameter;
 pub use self::flat_variable::FlatVariable;
 
+use crate::parser::Position;
 use solvers::Solver;
+use source_map::SourceMap;
 use std::collections::HashMap;
 use std::fmt;
 use zokrates_field::Field;
 
+/// Source information attached to the constraint enforcing a user-defined assertion
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AssertionMetadata {
+    pub file: String,
+    pub position: Position,
+    pub message: Option<String>,
+}
+
+impl fmt::Display for AssertionMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Assertion failed at {}:{}", self.file, self.position)?;
+        match &self.message {
+            Some(message) => write!(f, ": {}", message),
+            None => Ok(()),
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub struct FlatProg<T: Field> {
     /// FlatFunctions of the program
@@ -34,12 +54,20 @@ impl<T: Field> fmt::Debug for FlatProg<T> {
     }
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone)]
 pub struct FlatFunction<T: Field> {
     /// Arguments of the function
     pub arguments: Vec<FlatParameter>,
     /// Vector of statements that are executed when running the function
     pub statements: Vec<FlatStatement<T>>,
+    /// The source location of each statement
+    pub source_map: SourceMap,
+}
+
+impl<T: Field> PartialEq for FlatFunction<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.arguments.eq(&other.arguments) && self.statements.eq(&other.statements)
+    }
 }
 
 impl<T: Field> fmt::Display for FlatFunction<T> {
@@ -89,9 +117,14 @@ impl<T: Field> fmt::Debug for FlatFunction<T> {
 #[derive(Clone, PartialEq)]
 pub enum FlatStatement<T: Field> {
     Return(FlatExpressionList<T>),
-    Condition(FlatExpression<T>, FlatExpression<T>),
+    Condition(
+        FlatExpression<T>,
+        FlatExpression<T>,
+        Option<AssertionMetadata>,
+    ),
     Definition(FlatVariable, FlatExpression<T>),
     Directive(FlatDirective<T>),
+    Log(String, Vec<FlatExpression<T>>),
 }
 
 impl<T: Field> fmt::Display for FlatStatement<T> {
@@ -99,8 +132,15 @@ impl<T: Field> fmt::Display for FlatStatement<T> {
         match *self {
             FlatStatement::Definition(ref lhs, ref rhs) => write!(f, "{} = {}", lhs, rhs),
             FlatStatement::Return(ref expr) => write!(f, "return {}", expr),
-            FlatStatement::Condition(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
+            FlatStatement::Condition(ref lhs, ref rhs, _) => write!(f, "{} == {}", lhs, rhs),
             FlatStatement::Directive(ref d) => write!(f, "{}", d),
+            FlatStatement::Log(ref format_string, ref expressions) => {
+                write!(f, "log(\"{}\"", format_string)?;
+                for e in expressions {
+                    write!(f, ", {}", e)?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -110,10 +150,13 @@ impl<T: Field> fmt::Debug for FlatStatement<T> {
         match *self {
             FlatStatement::Definition(ref lhs, ref rhs) => write!(f, "{} = {}", lhs, rhs),
             FlatStatement::Return(ref expr) => write!(f, "FlatReturn({:?})", expr),
-            FlatStatement::Condition(ref lhs, ref rhs) => {
-                write!(f, "FlatCondition({:?}, {:?})", lhs, rhs)
+            FlatStatement::Condition(ref lhs, ref rhs, ref metadata) => {
+                write!(f, "FlatCondition({:?}, {:?}, {:?})", lhs, rhs, metadata)
             }
             FlatStatement::Directive(ref d) => write!(f, "{:?}", d),
+            FlatStatement::Log(ref format_string, ref expressions) => {
+                write!(f, "FlatLog({:?}, {:?})", format_string, expressions)
+            }
         }
     }
 }
@@ -129,9 +172,10 @@ impl<T: Field> FlatStatement<T> {
                 x.apply_substitution(substitution),
             ),
             FlatStatement::Return(x) => FlatStatement::Return(x.apply_substitution(substitution)),
-            FlatStatement::Condition(x, y) => FlatStatement::Condition(
+            FlatStatement::Condition(x, y, metadata) => FlatStatement::Condition(
                 x.apply_substitution(substitution),
                 y.apply_substitution(substitution),
+                metadata,
             ),
             FlatStatement::Directive(d) => {
                 let outputs = d
@@ -151,6 +195,13 @@ impl<T: Field> FlatStatement<T> {
                     ..d
                 })
             }
+            FlatStatement::Log(format_string, expressions) => FlatStatement::Log(
+                format_string,
+                expressions
+                    .into_iter()
+                    .map(|e| e.apply_substitution(substitution))
+                    .collect(),
+            ),
         }
     }
 }
@@ -12,7 +12,8 @@ use self::utils::flat_expression_from_bits;
 use crate::flat_absy::*;
 use crate::ir;
 use crate::solvers::Solver;
-use crate::zir::types::{FunctionIdentifier, FunctionKey, Signature, Type, UBitwidth};
+use crate::source_map::{SourceMap, SourceSpan};
+use crate::zir::types::{FunctionIdentifier, FunctionKey, IntType, Signature, Type};
 use crate::zir::*;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
@@ -32,6 +33,8 @@ pub struct Flattener<'ast, T: Field> {
     flat_cache: HashMap<FunctionKey<'ast>, FlatFunction<T>>,
     /// Cached bit decompositions to avoid re-generating them
     bits_cache: HashMap<FlatExpression<T>, Vec<FlatExpression<T>>>,
+    /// The variable which is `1` if the branch currently being flattened is taken, `0` otherwise
+    condition: Option<FlatVariable>,
 }
 
 trait FlattenOutput<T: Field>: Sized {
@@ -160,6 +163,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
             layout: HashMap::new(),
             flat_cache: HashMap::new(),
             bits_cache: HashMap::new(),
+            condition: None,
         }
     }
 
@@ -276,11 +280,200 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 statements_flattened.push(FlatStatement::Condition(
                     FlatExpression::Number(T::from(1)),
                     or,
+                    None,
                 ));
             }
         }
     }
 
+    /// Returns a flat expression which is 1 if `lhs >= rhs` and 0 otherwise, where `lhs` and `rhs`
+    /// are known to be in range `[0, 2**bitwidth - 1]`
+    ///
+    /// # Arguments
+    ///
+    /// * `statements_flattened` - Vector where new flattened statements can be added.
+    /// * `lhs` - The left operand, of bitwidth `bitwidth`
+    /// * `rhs` - The right operand, of bitwidth `bitwidth`
+    /// * `bitwidth` - The bitwidth of the operands
+    ///
+    /// # Notes
+    ///
+    /// `2**bitwidth + lhs - rhs` is in range `[1, 2**(bitwidth + 1) - 1]`, and its highest bit is set
+    /// iff `lhs >= rhs`. We decompose it into `bitwidth + 1` bits and return the highest one.
+    fn uint_ge_check(
+        &mut self,
+        statements_flattened: &mut FlatStatements<T>,
+        lhs: FlatExpression<T>,
+        rhs: FlatExpression<T>,
+        bitwidth: usize,
+    ) -> FlatExpression<T> {
+        let width = bitwidth + 1;
+
+        assert!(width < T::get_required_bits());
+
+        let shifted_sub = FlatExpression::Sub(
+            box FlatExpression::Add(
+                box FlatExpression::Number(T::from(2).pow(bitwidth)),
+                box lhs,
+            ),
+            box rhs,
+        );
+
+        let sub_bits_be: Vec<FlatVariable> = (0..width).map(|_| self.use_sym()).collect();
+
+        statements_flattened.push(FlatStatement::Directive(FlatDirective::new(
+            sub_bits_be.clone(),
+            Solver::bits(width),
+            vec![shifted_sub.clone()],
+        )));
+
+        // bitness checks
+        for i in 0..width {
+            statements_flattened.push(FlatStatement::Condition(
+                FlatExpression::Identifier(sub_bits_be[i]),
+                FlatExpression::Mult(
+                    box FlatExpression::Identifier(sub_bits_be[i]),
+                    box FlatExpression::Identifier(sub_bits_be[i]),
+                ),
+                None,
+            ));
+        }
+
+        // sum check
+        statements_flattened.push(FlatStatement::Condition(
+            shifted_sub,
+            flat_expression_from_bits(
+                sub_bits_be
+                    .iter()
+                    .map(|b| FlatExpression::Identifier(*b))
+                    .collect(),
+            ),
+            None,
+        ));
+
+        FlatExpression::Identifier(sub_bits_be[0])
+    }
+
+    /// Flattens the operands of an integer comparison
+    ///
+    /// # Arguments
+    ///
+    /// * `symbols` - Available functions in this context
+    /// * `statements_flattened` - Vector where new flattened statements can be added.
+    /// * `lhs` - The left operand, which must be reduced
+    /// * `rhs` - The right operand, which must be reduced
+    ///
+    /// # Notes
+    ///
+    /// Signed operands are shifted by `2**(bitwidth - 1)`, which maps `[-2**(bitwidth - 1), 2**(bitwidth - 1)[`
+    /// to `[0, 2**bitwidth[` while preserving the order, so that they can be compared as unsigned integers.
+    fn flatten_comparison_operands(
+        &mut self,
+        symbols: &ZirFunctionSymbols<'ast, T>,
+        statements_flattened: &mut FlatStatements<T>,
+        lhs: UExpression<'ast, T>,
+        rhs: UExpression<'ast, T>,
+    ) -> (FlatExpression<T>, FlatExpression<T>) {
+        let bitwidth = lhs.bitwidth;
+
+        assert!(lhs.metadata.clone().unwrap().should_reduce.to_bool());
+        assert!(rhs.metadata.clone().unwrap().should_reduce.to_bool());
+
+        let lhs = self.flatten_uint_expression(symbols, statements_flattened, lhs);
+        let rhs = self.flatten_uint_expression(symbols, statements_flattened, rhs);
+
+        match bitwidth.is_signed() {
+            true => {
+                let mut shift = |e: FlatUExpression<T>| {
+                    let sign = self.sign_bit(statements_flattened, bitwidth, e.clone());
+
+                    // e + 2**(bitwidth - 1) - sign * 2**bitwidth
+                    FlatExpression::Sub(
+                        box FlatExpression::Add(
+                            box e.get_field_unchecked(),
+                            box FlatExpression::Number(T::from(2).pow(bitwidth.to_usize() - 1)),
+                        ),
+                        box FlatExpression::Mult(
+                            box FlatExpression::Identifier(sign),
+                            box FlatExpression::Number(T::from(2).pow(bitwidth.to_usize())),
+                        ),
+                    )
+                };
+
+                (shift(lhs), shift(rhs))
+            }
+            false => (lhs.get_field_unchecked(), rhs.get_field_unchecked()),
+        }
+    }
+
+    /// Returns the sign bit of a reduced signed integer
+    fn sign_bit(
+        &mut self,
+        statements_flattened: &mut FlatStatements<T>,
+        bitwidth: IntType,
+        e: FlatUExpression<T>,
+    ) -> FlatVariable {
+        let sign = self.get_bits(e, bitwidth.to_usize(), bitwidth, statements_flattened)[0].clone();
+        self.define(sign, statements_flattened)
+    }
+
+    /// Returns the sign bit and the absolute value of a reduced signed integer
+    fn signed_abs(
+        &mut self,
+        statements_flattened: &mut FlatStatements<T>,
+        bitwidth: IntType,
+        e: FlatUExpression<T>,
+    ) -> (FlatVariable, FlatVariable) {
+        let sign = self.sign_bit(statements_flattened, bitwidth, e.clone());
+        let abs = self.negate_if(
+            statements_flattened,
+            bitwidth,
+            sign,
+            e.get_field_unchecked(),
+        );
+
+        (sign, abs)
+    }
+
+    /// Returns `2**bitwidth - e` if `condition` is `1` and `e` if it is `0`
+    ///
+    /// # Notes
+    ///
+    /// If `e` is lower than `2**bitwidth`, this is the two's complement negation of `e`, except that the
+    /// result is not reduced: negating `0` returns `2**bitwidth`.
+    fn negate_if(
+        &mut self,
+        statements_flattened: &mut FlatStatements<T>,
+        bitwidth: IntType,
+        condition: FlatVariable,
+        e: FlatExpression<T>,
+    ) -> FlatVariable {
+        let e = self.define(e, statements_flattened);
+
+        // condition * (2**bitwidth - 2 * e)
+        let delta = self.define(
+            FlatExpression::Mult(
+                box FlatExpression::Identifier(condition),
+                box FlatExpression::Sub(
+                    box FlatExpression::Number(T::from(2).pow(bitwidth.to_usize())),
+                    box FlatExpression::Mult(
+                        box FlatExpression::Number(T::from(2)),
+                        box FlatExpression::Identifier(e),
+                    ),
+                ),
+            ),
+            statements_flattened,
+        );
+
+        self.define(
+            FlatExpression::Add(
+                box FlatExpression::Identifier(e),
+                box FlatExpression::Identifier(delta),
+            ),
+            statements_flattened,
+        )
+    }
+
     /// Flatten an if/else expression
     ///
     /// # Arguments
@@ -416,6 +609,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                                 box FlatExpression::Identifier(lhs_bits_be[i]),
                                 box FlatExpression::Identifier(lhs_bits_be[i]),
                             ),
+                            None,
                         ));
                     }
 
@@ -435,6 +629,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                     statements_flattened.push(FlatStatement::Condition(
                         FlatExpression::Identifier(lhs_id),
                         lhs_sum,
+                        None,
                     ));
                 }
 
@@ -462,6 +657,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                                 box FlatExpression::Identifier(rhs_bits_be[i]),
                                 box FlatExpression::Identifier(rhs_bits_be[i]),
                             ),
+                            None,
                         ));
                     }
 
@@ -481,6 +677,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                     statements_flattened.push(FlatStatement::Condition(
                         FlatExpression::Identifier(rhs_id),
                         rhs_sum,
+                        None,
                     ));
                 }
 
@@ -515,6 +712,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                             box FlatExpression::Identifier(sub_bits_be[i]),
                             box FlatExpression::Identifier(sub_bits_be[i]),
                         ),
+                        None,
                     ));
                 }
 
@@ -538,7 +736,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                     );
                 }
 
-                statements_flattened.push(FlatStatement::Condition(subtraction_result, expr));
+                statements_flattened.push(FlatStatement::Condition(subtraction_result, expr, None));
 
                 FlatExpression::Identifier(sub_bits_be[bit_width - 1])
             }
@@ -599,6 +797,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 statements_flattened.push(FlatStatement::Condition(
                     FlatExpression::Identifier(name_y),
                     FlatExpression::Mult(box x.clone(), box FlatExpression::Identifier(name_m)),
+                    None,
                 ));
 
                 let res = FlatExpression::Sub(
@@ -609,6 +808,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 statements_flattened.push(FlatStatement::Condition(
                     FlatExpression::Number(T::zero()),
                     FlatExpression::Mult(box res.clone(), box x),
+                    None,
                 ));
 
                 res
@@ -646,6 +846,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 statements_flattened.push(FlatStatement::Condition(
                     FlatExpression::Identifier(name_y),
                     FlatExpression::Mult(box x.clone(), box FlatExpression::Identifier(name_m)),
+                    None,
                 ));
 
                 let res = FlatExpression::Sub(
@@ -656,10 +857,45 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 statements_flattened.push(FlatStatement::Condition(
                     FlatExpression::Number(T::zero()),
                     FlatExpression::Mult(box res.clone(), box x),
+                    None,
                 ));
 
                 res
             }
+            BooleanExpression::UintGe(box lhs, box rhs) => {
+                let bitwidth = lhs.bitwidth.to_usize();
+
+                let (lhs, rhs) =
+                    self.flatten_comparison_operands(symbols, statements_flattened, lhs, rhs);
+
+                self.uint_ge_check(statements_flattened, lhs, rhs, bitwidth)
+            }
+            BooleanExpression::UintLt(box lhs, box rhs) => {
+                let ge = self.flatten_boolean_expression(
+                    symbols,
+                    statements_flattened,
+                    BooleanExpression::UintGe(box lhs, box rhs),
+                );
+                FlatExpression::Sub(box FlatExpression::Number(T::one()), box ge)
+            }
+            BooleanExpression::UintGt(box lhs, box rhs) => {
+                let bitwidth = lhs.bitwidth.to_usize();
+
+                // flatten the operands in source order before swapping them
+                let (lhs, rhs) =
+                    self.flatten_comparison_operands(symbols, statements_flattened, lhs, rhs);
+
+                let ge = self.uint_ge_check(statements_flattened, rhs, lhs, bitwidth);
+                FlatExpression::Sub(box FlatExpression::Number(T::one()), box ge)
+            }
+            BooleanExpression::UintLe(box lhs, box rhs) => {
+                let bitwidth = lhs.bitwidth.to_usize();
+
+                let (lhs, rhs) =
+                    self.flatten_comparison_operands(symbols, statements_flattened, lhs, rhs);
+
+                self.uint_ge_check(statements_flattened, rhs, lhs, bitwidth)
+            }
             BooleanExpression::Le(box lhs, box rhs) => {
                 let lt = self.flatten_boolean_expression(
                     symbols,
@@ -699,6 +935,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                         box FlatExpression::Sub(box y.clone(), box name_x_or_y.clone().into()),
                     ),
                     FlatExpression::Mult(box x.clone(), box y.clone()),
+                    None,
                 ));
                 name_x_or_y.into()
             }
@@ -733,6 +970,20 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 )
                 .get_field_unchecked()
                 .clone(),
+            BooleanExpression::FromField(box e) => {
+                let e = self.flatten_field_expression(symbols, statements_flattened, e);
+
+                let id = self.define(e, statements_flattened);
+
+                // check that the value is a boolean
+                statements_flattened.push(FlatStatement::Condition(
+                    id.into(),
+                    FlatExpression::Mult(box id.into(), box id.into()),
+                    None,
+                ));
+
+                id.into()
+            }
         }
     }
 
@@ -741,7 +992,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
         symbols: &ZirFunctionSymbols<'ast, T>,
         statements_flattened: &mut FlatStatements<T>,
         expression: ZirExpression<'ast, T>,
-        bitwidth: UBitwidth,
+        bitwidth: IntType,
     ) -> Vec<FlatUExpression<T>> {
         let expression = UExpression::try_from(expression).unwrap();
         let from = expression.metadata.clone().unwrap().bitwidth();
@@ -759,7 +1010,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
         symbols: &ZirFunctionSymbols<'ast, T>,
         statements_flattened: &mut FlatStatements<T>,
         bits: Vec<ZirExpression<'ast, T>>,
-        bitwidth: UBitwidth,
+        bitwidth: IntType,
     ) -> FlatUExpression<T> {
         assert_eq!(bits.len(), bitwidth.to_usize());
         let bits: Vec<_> = bits
@@ -799,6 +1050,12 @@ impl<'ast, T: Field> Flattener<'ast, T> {
         let funct = self.get_embed(&key, &symbols);
 
         match funct {
+            crate::embed::FlatEmbed::U64ToBits => self.flatten_u_to_bits(
+                symbols,
+                statements_flattened,
+                param_expressions[0].clone(),
+                64.into(),
+            ),
             crate::embed::FlatEmbed::U32ToBits => self.flatten_u_to_bits(
                 symbols,
                 statements_flattened,
@@ -817,6 +1074,12 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 param_expressions[0].clone(),
                 8.into(),
             ),
+            crate::embed::FlatEmbed::U64FromBits => vec![self.flatten_bits_to_u(
+                symbols,
+                statements_flattened,
+                param_expressions,
+                64.into(),
+            )],
             crate::embed::FlatEmbed::U32FromBits => vec![self.flatten_bits_to_u(
                 symbols,
                 statements_flattened,
@@ -835,6 +1098,42 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 param_expressions,
                 8.into(),
             )],
+            crate::embed::FlatEmbed::I32ToBits => self.flatten_u_to_bits(
+                symbols,
+                statements_flattened,
+                param_expressions[0].clone(),
+                IntType::I32,
+            ),
+            crate::embed::FlatEmbed::I16ToBits => self.flatten_u_to_bits(
+                symbols,
+                statements_flattened,
+                param_expressions[0].clone(),
+                IntType::I16,
+            ),
+            crate::embed::FlatEmbed::I8ToBits => self.flatten_u_to_bits(
+                symbols,
+                statements_flattened,
+                param_expressions[0].clone(),
+                IntType::I8,
+            ),
+            crate::embed::FlatEmbed::I32FromBits => vec![self.flatten_bits_to_u(
+                symbols,
+                statements_flattened,
+                param_expressions,
+                IntType::I32,
+            )],
+            crate::embed::FlatEmbed::I16FromBits => vec![self.flatten_bits_to_u(
+                symbols,
+                statements_flattened,
+                param_expressions,
+                IntType::I16,
+            )],
+            crate::embed::FlatEmbed::I8FromBits => vec![self.flatten_bits_to_u(
+                symbols,
+                statements_flattened,
+                param_expressions,
+                IntType::I8,
+            )],
             funct => {
                 let funct = funct.synthetize();
 
@@ -878,10 +1177,10 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                             let new_rhs = rhs.apply_substitution(&replacement_map);
                             FlatStatement::Definition(new_var, new_rhs)
                         }
-                        FlatStatement::Condition(lhs, rhs) => {
+                        FlatStatement::Condition(lhs, rhs, metadata) => {
                             let new_lhs = lhs.apply_substitution(&replacement_map);
                             let new_rhs = rhs.apply_substitution(&replacement_map);
-                            FlatStatement::Condition(new_lhs, new_rhs)
+                            FlatStatement::Condition(new_lhs, new_rhs, metadata)
                         }
                         FlatStatement::Directive(d) => {
                             let new_outputs = d
@@ -904,6 +1203,13 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                                 inputs: new_inputs,
                             })
                         }
+                        FlatStatement::Log(format_string, expressions) => FlatStatement::Log(
+                            format_string,
+                            expressions
+                                .into_iter()
+                                .map(|e| e.apply_substitution(&replacement_map))
+                                .collect(),
+                        ),
                     })
                     .collect();
 
@@ -1001,6 +1307,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                                 box FlatExpression::Add(box x.clone(), box x.clone()),
                                 box y.clone(),
                             ),
+                            None,
                         ),
                     ]);
 
@@ -1040,7 +1347,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
 
         let res = match expr.into_inner() {
             UExpressionInner::Value(x) => {
-                FlatUExpression::with_field(FlatExpression::Number(T::from(x as usize)))
+                FlatUExpression::with_field(FlatExpression::Number(T::from(x)))
             } // force to be a field element
             UExpressionInner::Identifier(x) => {
                 let field = FlatExpression::Identifier(self.layout.get(&x).unwrap().clone());
@@ -1167,9 +1474,15 @@ impl<'ast, T: Field> Flattener<'ast, T> {
 
                 assert_eq!(e_bits.len(), target_bitwidth.to_usize());
 
+                // signed integers are shifted arithmetically, filling with the sign bit
+                let fill = match target_bitwidth.is_signed() {
+                    true => e_bits[0].clone(),
+                    false => FlatExpression::Number(T::from(0)),
+                };
+
                 FlatUExpression::with_bits(
                     (0..std::cmp::min(by, target_bitwidth.to_usize()))
-                        .map(|_| FlatExpression::Number(T::from(0)))
+                        .map(|_| fill.clone())
                         .chain(e_bits.into_iter().take(
                             target_bitwidth.to_usize()
                                 - std::cmp::min(by, target_bitwidth.to_usize()),
@@ -1208,6 +1521,28 @@ impl<'ast, T: Field> Flattener<'ast, T> {
 
                 FlatUExpression::with_field(FlatExpression::Identifier(res))
             }
+            UExpressionInner::Div(box left, box right) => {
+                let (q, _) = self.euclidean_division(
+                    symbols,
+                    statements_flattened,
+                    target_bitwidth,
+                    left,
+                    right,
+                );
+
+                q
+            }
+            UExpressionInner::Rem(box left, box right) => {
+                let (_, r) = self.euclidean_division(
+                    symbols,
+                    statements_flattened,
+                    target_bitwidth,
+                    left,
+                    right,
+                );
+
+                r
+            }
             UExpressionInner::IfElse(box condition, box consequence, box alternative) => self
                 .flatten_if_else_expression(
                     symbols,
@@ -1217,6 +1552,58 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                     alternative,
                 )
                 .clone(),
+            UExpressionInner::FromField(box e) => {
+                let e = self.flatten_field_expression(symbols, statements_flattened, e);
+
+                // define the value in a fresh variable so that its decomposition is not shared with values of other types
+                let id = self.use_sym();
+                statements_flattened.push(FlatStatement::Definition(id, e));
+                let field = FlatExpression::Identifier(id);
+
+                // decomposing the value to the target bitwidth checks that it is in range
+                let bits = self.get_bits(
+                    FlatUExpression::with_field(field.clone()),
+                    target_bitwidth.to_usize(),
+                    target_bitwidth,
+                    statements_flattened,
+                );
+
+                FlatUExpression::with_field(field).bits(bits)
+            }
+            UExpressionInner::FromUint(box e) => {
+                let from = e.bitwidth;
+
+                let e = self.flatten_uint_expression(symbols, statements_flattened, e);
+
+                match !from.is_signed() && from.to_usize() < target_bitwidth.to_usize() {
+                    // widening an unsigned integer keeps its value
+                    true => {
+                        let bits = e.bits.clone().map(|bits| {
+                            (0..target_bitwidth.to_usize() - from.to_usize())
+                                .map(|_| FlatExpression::Number(T::from(0)))
+                                .chain(bits)
+                                .collect::<Vec<_>>()
+                        });
+
+                        // define the value in a fresh variable so that its decomposition is not shared with values of other types
+                        let id = self.use_sym();
+                        statements_flattened
+                            .push(FlatStatement::Definition(id, e.get_field_unchecked()));
+
+                        FlatUExpression::with_field(FlatExpression::Identifier(id)).bits(bits)
+                    }
+                    false => {
+                        let bits = self.get_bits(e, from.to_usize(), from, statements_flattened);
+
+                        FlatUExpression::with_bits(self.cast_bits(
+                            bits,
+                            from,
+                            target_bitwidth,
+                            statements_flattened,
+                        ))
+                    }
+                }
+            }
             UExpressionInner::Xor(box left, box right) => {
                 let left_metadata = left.metadata.clone().unwrap();
                 let right_metadata = right.metadata.clone().unwrap();
@@ -1256,6 +1643,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                                                 box a,
                                                 box FlatExpression::Sub(box b, box c),
                                             ),
+                                            None,
                                         ),
                                     ]);
                                     ch.into()
@@ -1330,6 +1718,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                                                         box b.clone(),
                                                         box c.clone(),
                                                     ),
+                                                    None,
                                                 ),
                                                 FlatStatement::Condition(
                                                     FlatExpression::Sub(
@@ -1346,6 +1735,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                                                         ),
                                                         box a,
                                                     ),
+                                                    None,
                                                 ),
                                             ]);
                                             maj.into()
@@ -1477,6 +1867,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                                         ),
                                     ),
                                     FlatExpression::Mult(box x, box y),
+                                    None,
                                 ),
                             ]);
                             name.into()
@@ -1520,11 +1911,198 @@ impl<'ast, T: Field> Flattener<'ast, T> {
         res
     }
 
+    /// Flattens the euclidean division of two integers, returning the quotient and the remainder
+    ///
+    /// # Arguments
+    ///
+    /// * `symbols` - Available functions in this context
+    /// * `statements_flattened` - Vector where new flattened statements can be added.
+    /// * `bitwidth` - The bitwidth of the operands
+    /// * `left` - The dividend, which must be reduced
+    /// * `right` - The divisor, which must be reduced
+    ///
+    /// # Notes
+    ///
+    /// Signed integers are divided by dividing their absolute values. The quotient is then negated if
+    /// exactly one of the operands is negative, and the remainder if the dividend is negative, so that
+    /// the division truncates towards zero.
+    fn euclidean_division(
+        &mut self,
+        symbols: &ZirFunctionSymbols<'ast, T>,
+        statements_flattened: &mut FlatStatements<T>,
+        bitwidth: IntType,
+        left: UExpression<'ast, T>,
+        right: UExpression<'ast, T>,
+    ) -> (FlatUExpression<T>, FlatUExpression<T>) {
+        assert!(left.metadata.clone().unwrap().should_reduce.to_bool());
+        assert!(right.metadata.clone().unwrap().should_reduce.to_bool());
+
+        let left_flattened = self.flatten_uint_expression(symbols, statements_flattened, left);
+        let right_flattened = self.flatten_uint_expression(symbols, statements_flattened, right);
+
+        match bitwidth.is_signed() {
+            false => self.unsigned_euclidean_division(
+                statements_flattened,
+                bitwidth,
+                left_flattened.get_field_unchecked(),
+                right_flattened.get_field_unchecked(),
+            ),
+            true => {
+                let (n_sign, n_abs) =
+                    self.signed_abs(statements_flattened, bitwidth, left_flattened);
+                let (d_sign, d_abs) =
+                    self.signed_abs(statements_flattened, bitwidth, right_flattened);
+
+                let (q, r) = self.unsigned_euclidean_division(
+                    statements_flattened,
+                    bitwidth,
+                    FlatExpression::Identifier(n_abs),
+                    FlatExpression::Identifier(d_abs),
+                );
+
+                // q_sign = n_sign xor d_sign
+                let signs_product = self.define(
+                    FlatExpression::Mult(
+                        box FlatExpression::Identifier(n_sign),
+                        box FlatExpression::Identifier(d_sign),
+                    ),
+                    statements_flattened,
+                );
+                let q_sign = self.define(
+                    FlatExpression::Sub(
+                        box FlatExpression::Add(
+                            box FlatExpression::Identifier(n_sign),
+                            box FlatExpression::Identifier(d_sign),
+                        ),
+                        box FlatExpression::Mult(
+                            box FlatExpression::Number(T::from(2)),
+                            box FlatExpression::Identifier(signs_product),
+                        ),
+                    ),
+                    statements_flattened,
+                );
+
+                let q = self.negate_if(
+                    statements_flattened,
+                    bitwidth,
+                    q_sign,
+                    q.get_field_unchecked(),
+                );
+                let r = self.negate_if(
+                    statements_flattened,
+                    bitwidth,
+                    n_sign,
+                    r.get_field_unchecked(),
+                );
+
+                // negating zero yields `2**bitwidth`, so we reduce the results
+                let q_bits = self.get_bits(
+                    FlatUExpression::with_field(FlatExpression::Identifier(q)),
+                    bitwidth.to_usize() + 1,
+                    bitwidth,
+                    statements_flattened,
+                );
+                let r_bits = self.get_bits(
+                    FlatUExpression::with_field(FlatExpression::Identifier(r)),
+                    bitwidth.to_usize() + 1,
+                    bitwidth,
+                    statements_flattened,
+                );
+
+                (
+                    FlatUExpression::with_bits(q_bits),
+                    FlatUExpression::with_bits(r_bits),
+                )
+            }
+        }
+    }
+
+    /// Flattens the euclidean division of two unsigned integers, returning the quotient and the remainder
+    ///
+    /// # Arguments
+    ///
+    /// * `statements_flattened` - Vector where new flattened statements can be added.
+    /// * `bitwidth` - The bitwidth of the operands
+    /// * `left` - The dividend, which must be lower than `2**bitwidth`
+    /// * `right` - The divisor, which must be lower than `2**bitwidth`
+    ///
+    /// # Notes
+    ///
+    /// The quotient `q` and the remainder `r` are computed by a directive, and we check that
+    /// `left == q * right + r` where `q` and `r` fit in `bitwidth` bits and `r < right`. As all values
+    /// are lower than `2**bitwidth`, these checks cannot overflow. `r < right` also ensures that
+    /// `right` is not zero.
+    fn unsigned_euclidean_division(
+        &mut self,
+        statements_flattened: &mut FlatStatements<T>,
+        bitwidth: IntType,
+        left: FlatExpression<T>,
+        right: FlatExpression<T>,
+    ) -> (FlatUExpression<T>, FlatUExpression<T>) {
+        let n = self.define(left, statements_flattened);
+        let d = self.define(right, statements_flattened);
+
+        let q = self.use_sym();
+        let r = self.use_sym();
+
+        statements_flattened.push(FlatStatement::Directive(FlatDirective::new(
+            vec![q, r],
+            Solver::EuclideanDiv,
+            vec![n, d],
+        )));
+
+        // range checks, which also populate the bits cache
+        let q_bits = self.get_bits(
+            FlatUExpression::with_field(FlatExpression::Identifier(q)),
+            bitwidth.to_usize(),
+            bitwidth,
+            statements_flattened,
+        );
+        let r_bits = self.get_bits(
+            FlatUExpression::with_field(FlatExpression::Identifier(r)),
+            bitwidth.to_usize(),
+            bitwidth,
+            statements_flattened,
+        );
+
+        // r < d
+        let r_ge_d = self.uint_ge_check(
+            statements_flattened,
+            FlatExpression::Identifier(r),
+            FlatExpression::Identifier(d),
+            bitwidth.to_usize(),
+        );
+
+        statements_flattened.push(FlatStatement::Condition(
+            FlatExpression::Number(T::from(0)),
+            r_ge_d,
+            None,
+        ));
+
+        // n == q * d + r
+        statements_flattened.push(FlatStatement::Condition(
+            FlatExpression::Sub(
+                box FlatExpression::Identifier(n),
+                box FlatExpression::Identifier(r),
+            ),
+            FlatExpression::Mult(
+                box FlatExpression::Identifier(q),
+                box FlatExpression::Identifier(d),
+            ),
+            None,
+        ));
+
+        (
+            FlatUExpression::with_field(FlatExpression::Identifier(q)).bits(q_bits),
+            FlatUExpression::with_field(FlatExpression::Identifier(r)).bits(r_bits),
+        )
+    }
+
     fn get_bits(
         &mut self,
         e: FlatUExpression<T>,
         from: usize,
-        to: UBitwidth,
+        to: IntType,
         statements_flattened: &mut FlatStatements<T>,
     ) -> Vec<FlatExpression<T>> {
         let to = to.to_usize();
@@ -1587,6 +2165,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                                 box bits[i].clone().into(),
                                 box bits[i].clone().into(),
                             ),
+                            None,
                         )
                     }));
 
@@ -1596,6 +2175,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                     statements_flattened.push(FlatStatement::Condition(
                         e.field.clone().unwrap(),
                         sum.clone(),
+                        None,
                     ));
 
                     // truncate to the `to` lowest bits
@@ -1612,6 +2192,59 @@ impl<'ast, T: Field> Flattener<'ast, T> {
         })
     }
 
+    /// Casts the bits of an integer of bitwidth `from` to the bits of an integer of bitwidth `to`
+    /// with the same value, checking that the value is representable in `to`
+    fn cast_bits(
+        &mut self,
+        bits: Vec<FlatExpression<T>>,
+        from: IntType,
+        to: IntType,
+        statements_flattened: &mut FlatStatements<T>,
+    ) -> Vec<FlatExpression<T>> {
+        let from_width = from.to_usize();
+        let to_width = to.to_usize();
+
+        assert_eq!(bits.len(), from_width);
+
+        let zero = FlatExpression::Number(T::from(0));
+
+        // the sign bit, which is zero for unsigned integers
+        let sign = match from.is_signed() {
+            true => bits[0].clone(),
+            false => zero.clone(),
+        };
+
+        // the bit of weight `2**i`, extending the value with its sign
+        let bit = |i: usize| match i < from_width {
+            true => bits[from_width - 1 - i].clone(),
+            false => sign.clone(),
+        };
+
+        // the bits which do not fit in the target must all be equal to the sign of the result
+        let target_sign = match to.is_signed() {
+            true => bit(to_width - 1),
+            false => zero.clone(),
+        };
+
+        // on top of that, the value must be non-negative if exactly one of the types is signed
+        let sign_check = match (from.is_signed(), to.is_signed()) {
+            // when narrowing, the sign bit is already checked above
+            (true, false) if to_width >= from_width => Some((sign.clone(), zero.clone())),
+            (false, true) => Some((bit(to_width - 1), zero)),
+            _ => None,
+        };
+
+        statements_flattened.extend(
+            (to_width..from_width)
+                .map(|i| (bit(i), target_sign.clone()))
+                .chain(sign_check)
+                .filter(|(left, right)| left != right)
+                .map(|(left, right)| FlatStatement::Condition(left, right, None)),
+        );
+
+        (0..to_width).rev().map(bit).collect()
+    }
+
     /// Flattens a field expression
     ///
     /// # Arguments
@@ -1725,6 +2358,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 statements_flattened.push(FlatStatement::Condition(
                     FlatExpression::Number(T::one()),
                     FlatExpression::Mult(box invb.into(), box new_right.clone().into()),
+                    None,
                 ));
 
                 // # c = a/b
@@ -1738,6 +2372,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 statements_flattened.push(FlatStatement::Condition(
                     new_left.into(),
                     FlatExpression::Mult(box new_right, box inverse.into()),
+                    None,
                 ));
 
                 inverse.into()
@@ -1831,6 +2466,12 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 )
                 .get_field_unchecked()
                 .clone(),
+            FieldElementExpression::FromBool(box e) => {
+                self.flatten_boolean_expression(symbols, statements_flattened, e)
+            }
+            FieldElementExpression::FromUint(box e) => self
+                .flatten_uint_expression(symbols, statements_flattened, e)
+                .get_field_unchecked(),
         }
     }
 
@@ -1841,6 +2482,143 @@ impl<'ast, T: Field> Flattener<'ast, T> {
     /// * `symbols` - Available functions in this context
     /// * `statements_flattened` - Vector where new flattened statements can be added.
     /// * `stat` - `ZirStatement` that will be flattened.
+    /// Flattens a list of statements, mapping the flattened statements to the source span they
+    /// were compiled from
+    ///
+    /// # Arguments
+    /// * `symbols` - Available functions in this context
+    /// * `statements_flattened` - Vector where new flattened statements can be added
+    /// * `source_map` - The spans of `statements_flattened`
+    /// * `span` - The span of the statement currently being flattened
+    /// * `statements` - `ZirStatement`s that will be flattened
+    fn flatten_statements(
+        &mut self,
+        symbols: &ZirFunctionSymbols<'ast, T>,
+        statements_flattened: &mut FlatStatements<T>,
+        source_map: &mut SourceMap,
+        span: &mut Option<SourceSpan>,
+        statements: Vec<ZirStatement<'ast, T>>,
+    ) {
+        for stat in statements {
+            let mut flattened = FlatStatements::new();
+
+            match stat {
+                ZirStatement::IfElse(condition, consequence, alternative) => {
+                    let condition =
+                        self.flatten_boolean_expression(symbols, &mut flattened, condition);
+                    let condition = self.define(condition, &mut flattened);
+
+                    // the consequence is taken if the current branch is taken and the condition holds
+                    let (current, consequence_condition) = match self.condition {
+                        Some(current) => (
+                            FlatExpression::from(current),
+                            self.define(
+                                FlatExpression::Mult(box current.into(), box condition.into()),
+                                &mut flattened,
+                            ),
+                        ),
+                        None => (FlatExpression::Number(T::one()), condition),
+                    };
+
+                    // the alternative is taken if the current branch is taken and the consequence isn't
+                    let alternative_condition = self.define(
+                        FlatExpression::Sub(box current, box consequence_condition.into()),
+                        &mut flattened,
+                    );
+
+                    self.push_statements(statements_flattened, source_map, span, flattened);
+
+                    // bit decompositions made in a branch are only checked if it is taken, so
+                    // they cannot be reused outside of it
+                    let bits_cache = self.bits_cache.clone();
+                    let current =
+                        std::mem::replace(&mut self.condition, Some(consequence_condition));
+
+                    self.flatten_statements(
+                        symbols,
+                        statements_flattened,
+                        source_map,
+                        span,
+                        consequence,
+                    );
+
+                    self.bits_cache = bits_cache.clone();
+                    self.condition = Some(alternative_condition);
+
+                    self.flatten_statements(
+                        symbols,
+                        statements_flattened,
+                        source_map,
+                        span,
+                        alternative,
+                    );
+
+                    self.bits_cache = bits_cache;
+                    self.condition = current;
+                }
+                stat => {
+                    if let ZirStatement::Span(s) = &stat {
+                        *span = Some(s.clone());
+                    }
+
+                    self.flatten_statement(symbols, &mut flattened, stat);
+                    self.push_statements(statements_flattened, source_map, span, flattened);
+                }
+            }
+        }
+    }
+
+    /// Adds `statements` to `statements_flattened`, mapping them to `span`. Inside of a branch,
+    /// the conditions are only enforced if the branch is taken.
+    fn push_statements(
+        &mut self,
+        statements_flattened: &mut FlatStatements<T>,
+        source_map: &mut SourceMap,
+        span: &Option<SourceSpan>,
+        statements: FlatStatements<T>,
+    ) {
+        let statements = match self.condition {
+            Some(condition) => statements
+                .into_iter()
+                .flat_map(|s| self.make_conditional(s, condition))
+                .collect(),
+            None => statements,
+        };
+
+        source_map.extend(std::iter::repeat(span.clone()).take(statements.len()));
+        statements_flattened.extend(statements);
+    }
+
+    /// Turns a `Condition` into one which only holds if `condition` is `1`
+    fn make_conditional(
+        &mut self,
+        statement: FlatStatement<T>,
+        condition: FlatVariable,
+    ) -> FlatStatements<T> {
+        match statement {
+            FlatStatement::Condition(linear, quadratic, metadata) => {
+                let mut statements = FlatStatements::new();
+
+                // condition * (quadratic - linear) == 0 must be quadratic
+                let quadratic = match quadratic.is_linear() {
+                    true => quadratic,
+                    false => self.define(quadratic, &mut statements).into(),
+                };
+
+                statements.push(FlatStatement::Condition(
+                    FlatExpression::Number(T::zero()),
+                    FlatExpression::Mult(
+                        box condition.into(),
+                        box FlatExpression::Sub(box quadratic, box linear),
+                    ),
+                    metadata,
+                ));
+                statements
+            }
+            s => vec![s],
+        }
+    }
+
     fn flatten_statement(
         &mut self,
         symbols: &ZirFunctionSymbols<'ast, T>,
@@ -1863,6 +2641,22 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 // declarations have already been checked
                 ()
             }
+            ZirStatement::Span(_) => {
+                // spans are recorded in the source map when flattening the function
+                ()
+            }
+            ZirStatement::IfElse(..) => {
+                unreachable!("if/else blocks should be flattened with `flatten_statements`")
+            }
+            ZirStatement::Log(format_string, exprs) => {
+                let flat_expressions = exprs
+                    .into_iter()
+                    .map(|expr| self.flatten_expression(symbols, statements_flattened, expr))
+                    .map(|x| x.get_field_unchecked())
+                    .collect::<Vec<_>>();
+
+                statements_flattened.push(FlatStatement::Log(format_string, flat_expressions));
+            }
             ZirStatement::Definition(assignee, expr) => {
                 // define n variables with n the number of primitive types for v_type
                 // assign them to the n primitive types for expr
@@ -1895,7 +2689,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                     None => {}
                 }
             }
-            ZirStatement::Assertion(e) => {
+            ZirStatement::Assertion(e, metadata) => {
                 // naive approach: flatten the boolean to a single field element and constrain it to 1
 
                 let e = self.flatten_boolean_expression(symbols, statements_flattened, e);
@@ -1904,12 +2698,14 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                     statements_flattened.push(FlatStatement::Condition(
                         e,
                         FlatExpression::Number(T::from(1)),
+                        metadata,
                     ));
                 } else {
                     // swap so that left side is linear
                     statements_flattened.push(FlatStatement::Condition(
                         FlatExpression::Number(T::from(1)),
                         e,
+                        metadata,
                     ));
                 }
             }
@@ -1947,7 +2743,17 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                             })
                             .collect();
 
-                        if ["_U32_FROM_BITS", "_U16_FROM_BITS", "_U8_FROM_BITS"].contains(&key.id) {
+                        if [
+                            "_U64_FROM_BITS",
+                            "_U32_FROM_BITS",
+                            "_U16_FROM_BITS",
+                            "_U8_FROM_BITS",
+                            "_I32_FROM_BITS",
+                            "_I16_FROM_BITS",
+                            "_I8_FROM_BITS",
+                        ]
+                        .contains(&key.id)
+                        {
                             let bits = exprs
                                 .into_iter()
                                 .map(|e| {
@@ -1985,14 +2791,22 @@ impl<'ast, T: Field> Flattener<'ast, T> {
             .map(|p| self.use_parameter(&p, &mut statements_flattened))
             .collect();
 
+        // the statements checking the parameters do not come from any source statement
+        let mut source_map = SourceMap(vec![None; statements_flattened.len()]);
+
         // flatten statements in functions and apply substitution
-        for stat in funct.statements {
-            self.flatten_statement(symbols, &mut statements_flattened, stat);
-        }
+        self.flatten_statements(
+            symbols,
+            &mut statements_flattened,
+            &mut source_map,
+            &mut None,
+            funct.statements,
+        );
 
         FlatFunction {
             arguments: arguments_flattened,
             statements: statements_flattened,
+            source_map,
         }
     }
 
@@ -2073,6 +2887,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 statements_flattened.push(FlatStatement::Condition(
                     variable.into(),
                     FlatExpression::Mult(box variable.into(), box variable.into()),
+                    None,
                 ));
             }
             _ => {}
@@ -2167,6 +2982,7 @@ mod tests {
                     expressions: vec![FlatExpression::Identifier(FlatVariable::new(1))],
                 }),
             ],
+            source_map: SourceMap::default(),
         };
 
         let flattened = flattener.flatten_function(&mut HashMap::new(), function);
@@ -2229,6 +3045,7 @@ mod tests {
                     expressions: vec![FlatExpression::Identifier(FlatVariable::new(1))],
                 }),
             ],
+            source_map: SourceMap::default(),
         };
 
         let flattened = flattener.flatten_function(&mut HashMap::new(), function);
@@ -2343,6 +3160,7 @@ mod tests {
                     expressions: vec![FlatExpression::Identifier(FlatVariable::new(6))],
                 }),
             ],
+            source_map: SourceMap::default(),
         };
 
         let flattened = flattener.flatten_function(&mut HashMap::new(), function);
@@ -2475,6 +3293,7 @@ mod tests {
                 FlatStatement::Condition(
                     FlatExpression::Number(Bn128Field::from(1)),
                     FlatExpression::Mult(box invb0.into(), box b0.into()),
+                    None,
                 ),
                 // execute div
                 FlatStatement::Directive(FlatDirective::new(
@@ -2485,6 +3304,7 @@ mod tests {
                 FlatStatement::Condition(
                     five.into(),
                     FlatExpression::Mult(box b0.into(), box sym_0.into()),
+                    None,
                 ),
                 // inputs to second div (res/b)
                 FlatStatement::Definition(sym_1, sym_0.into()),
@@ -2498,6 +3318,7 @@ mod tests {
                 FlatStatement::Condition(
                     FlatExpression::Number(Bn128Field::from(1)),
                     FlatExpression::Mult(box invb1.into(), box b1.into()),
+                    None,
                 ),
                 // execute div
                 FlatStatement::Directive(FlatDirective::new(
@@ -2508,6 +3329,7 @@ mod tests {
                 FlatStatement::Condition(
                     sym_1.into(),
                     FlatExpression::Mult(box b1.into(), box sym_2.into()),
+                    None,
                 ),
             ]
         );
@@ -9,7 +9,7 @@ use crate::compile::compile_module;
 use crate::compile::{CompileErrorInner, CompileErrors};
 use crate::embed::FlatEmbed;
 use crate::parser::Position;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -63,6 +63,7 @@ pub struct Import<'ast> {
     source: ImportPath<'ast>,
     symbol: Option<Identifier<'ast>>,
     alias: Option<Identifier<'ast>>,
+    glob: bool,
 }
 
 pub type ImportNode<'ast> = Node<Import<'ast>>;
@@ -73,6 +74,17 @@ impl<'ast> Import<'ast> {
             symbol,
             source,
             alias: None,
+            glob: false,
+        }
+    }
+
+    /// Import all the symbols declared in a module
+    pub fn glob(source: ImportPath<'ast>) -> Import<'ast> {
+        Import {
+            symbol: None,
+            source,
+            alias: None,
+            glob: true,
         }
     }
 
@@ -89,6 +101,7 @@ impl<'ast> Import<'ast> {
             symbol,
             source,
             alias: Some(alias),
+            glob: false,
         }
     }
 
@@ -104,6 +117,10 @@ impl<'ast> Import<'ast> {
 
 impl<'ast> fmt::Display for Import<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.glob {
+            return write!(f, "from {} import *", self.source.display());
+        }
+
         match self.alias {
             Some(ref alias) => write!(f, "import {} as {}", self.source.display(), alias),
             None => write!(f, "import {}", self.source.display()),
@@ -113,6 +130,10 @@ impl<'ast> fmt::Display for Import<'ast> {
 
 impl<'ast> fmt::Debug for Import<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.glob {
+            return write!(f, "import(source: {}, glob)", self.source.display());
+        }
+
         match self.alias {
             Some(ref alias) => write!(
                 f,
@@ -141,6 +162,8 @@ impl Importer {
         arena: &'ast Arena<String>,
     ) -> Result<Module<'ast>, CompileErrors> {
         let mut symbols: Vec<_> = vec![];
+        // the symbols imported with glob imports, resolved once all imports are known
+        let mut glob_symbols: Vec<(Identifier<'ast>, ModuleId, _)> = vec![];
 
         for import in destination.imports {
             let pos = import.pos();
@@ -148,6 +171,14 @@ impl Importer {
             let alias = import.alias;
             // handle the case of special bellman and packing imports
             if import.source.starts_with("EMBED") {
+                if import.glob {
+                    return Err(CompileErrorInner::ImportError(
+                        Error::new("Cannot glob import an embed").with_pos(Some(pos)),
+                    )
+                    .in_file(&location)
+                    .into());
+                }
+
                 match import.source.to_str().unwrap() {
                     "EMBED/unpack" => {
                         let alias = alias.unwrap_or("unpack");
@@ -160,6 +191,17 @@ impl Importer {
                             .start_end(pos.0, pos.1),
                         );
                     }
+                    "EMBED/u64_to_bits" => {
+                        let alias = alias.unwrap_or("u64_to_bits");
+
+                        symbols.push(
+                            SymbolDeclaration {
+                                id: &alias,
+                                symbol: Symbol::Flat(FlatEmbed::U64ToBits),
+                            }
+                            .start_end(pos.0, pos.1),
+                        );
+                    }
                     "EMBED/u32_to_bits" => {
                         let alias = alias.unwrap_or("u32_to_bits");
 
@@ -193,6 +235,17 @@ impl Importer {
                             .start_end(pos.0, pos.1),
                         );
                     }
+                    "EMBED/u64_from_bits" => {
+                        let alias = alias.unwrap_or("u64_from_bits");
+
+                        symbols.push(
+                            SymbolDeclaration {
+                                id: &alias,
+                                symbol: Symbol::Flat(FlatEmbed::U64FromBits),
+                            }
+                            .start_end(pos.0, pos.1),
+                        );
+                    }
                     "EMBED/u32_from_bits" => {
                         let alias = alias.unwrap_or("u32_from_bits");
 
@@ -226,6 +279,72 @@ impl Importer {
                             .start_end(pos.0, pos.1),
                         );
                     }
+                    "EMBED/i32_to_bits" => {
+                        let alias = alias.unwrap_or("i32_to_bits");
+
+                        symbols.push(
+                            SymbolDeclaration {
+                                id: &alias,
+                                symbol: Symbol::Flat(FlatEmbed::I32ToBits),
+                            }
+                            .start_end(pos.0, pos.1),
+                        );
+                    }
+                    "EMBED/i16_to_bits" => {
+                        let alias = alias.unwrap_or("i16_to_bits");
+
+                        symbols.push(
+                            SymbolDeclaration {
+                                id: &alias,
+                                symbol: Symbol::Flat(FlatEmbed::I16ToBits),
+                            }
+                            .start_end(pos.0, pos.1),
+                        );
+                    }
+                    "EMBED/i8_to_bits" => {
+                        let alias = alias.unwrap_or("i8_to_bits");
+
+                        symbols.push(
+                            SymbolDeclaration {
+                                id: &alias,
+                                symbol: Symbol::Flat(FlatEmbed::I8ToBits),
+                            }
+                            .start_end(pos.0, pos.1),
+                        );
+                    }
+                    "EMBED/i32_from_bits" => {
+                        let alias = alias.unwrap_or("i32_from_bits");
+
+                        symbols.push(
+                            SymbolDeclaration {
+                                id: &alias,
+                                symbol: Symbol::Flat(FlatEmbed::I32FromBits),
+                            }
+                            .start_end(pos.0, pos.1),
+                        );
+                    }
+                    "EMBED/i16_from_bits" => {
+                        let alias = alias.unwrap_or("i16_from_bits");
+
+                        symbols.push(
+                            SymbolDeclaration {
+                                id: &alias,
+                                symbol: Symbol::Flat(FlatEmbed::I16FromBits),
+                            }
+                            .start_end(pos.0, pos.1),
+                        );
+                    }
+                    "EMBED/i8_from_bits" => {
+                        let alias = alias.unwrap_or("i8_from_bits");
+
+                        symbols.push(
+                            SymbolDeclaration {
+                                id: &alias,
+                                symbol: Symbol::Flat(FlatEmbed::I8FromBits),
+                            }
+                            .start_end(pos.0, pos.1),
+                        );
+                    }
                     s => {
                         return Err(CompileErrorInner::ImportError(
                             Error::new(format!("Embed {} not found", s)).with_pos(Some(pos)),
@@ -239,21 +358,6 @@ impl Importer {
                 match resolver {
                     Some(res) => match res.resolve(location.clone(), import.source.to_path_buf()) {
                         Ok((source, new_location)) => {
-                            // generate an alias from the imported path if none was given explicitely
-                            let alias = import.alias.unwrap_or(
-                                std::path::Path::new(import.source)
-                                    .file_stem()
-                                    .ok_or(CompileErrors::from(
-                                        CompileErrorInner::ImportError(Error::new(format!(
-                                            "Could not determine alias for import {}",
-                                            import.source.display()
-                                        )))
-                                        .in_file(&location),
-                                    ))?
-                                    .to_str()
-                                    .unwrap(),
-                            );
-
                             match modules.get(&new_location) {
                                 Some(_) => {}
                                 None => {
@@ -273,6 +377,45 @@ impl Importer {
                                 }
                             };
 
+                            if import.glob {
+                                // import the symbols declared in the module, except its main function and private functions
+                                let mut ids: Vec<_> = modules[&new_location]
+                                    .symbols
+                                    .iter()
+                                    .filter(|s| match s.value.symbol {
+                                        Symbol::HereType(..)
+                                        | Symbol::HereAlias(..)
+                                        | Symbol::HereConstant(..) => true,
+                                        Symbol::HereFunction(ref f) => f.value.exported,
+                                        _ => false,
+                                    })
+                                    .map(|s| s.value.id)
+                                    .filter(|id| *id != "main")
+                                    .collect();
+                                ids.sort();
+                                ids.dedup();
+
+                                glob_symbols.extend(
+                                    ids.into_iter().map(|id| (id, new_location.clone(), pos)),
+                                );
+                                continue;
+                            }
+
+                            // generate an alias from the imported path if none was given explicitely
+                            let alias = import.alias.unwrap_or(
+                                std::path::Path::new(import.source)
+                                    .file_stem()
+                                    .ok_or(CompileErrors::from(
+                                        CompileErrorInner::ImportError(Error::new(format!(
+                                            "Could not determine alias for import {}",
+                                            import.source.display()
+                                        )))
+                                        .in_file(&location),
+                                    ))?
+                                    .to_str()
+                                    .unwrap(),
+                            );
+
                             symbols.push(
                                 SymbolDeclaration {
                                     id: &alias,
@@ -306,6 +449,51 @@ impl Importer {
             }
         }
 
+        // symbols which are declared or imported explicitely take precedence over glob imports
+        let explicit: HashSet<_> = symbols
+            .iter()
+            .chain(destination.symbols.iter())
+            .map(|s| s.value.id)
+            .collect();
+        let mut globbed: HashMap<Identifier<'ast>, ModuleId> = HashMap::new();
+
+        for (id, module_id, pos) in glob_symbols {
+            if explicit.contains(id) {
+                continue;
+            }
+
+            match globbed.get(id) {
+                // the same module can be glob imported more than once
+                Some(other) if *other == module_id => continue,
+                Some(other) => {
+                    return Err(CompileErrorInner::ImportError(
+                        Error::new(format!(
+                            "{} is imported from both {} and {}",
+                            id,
+                            other.display(),
+                            module_id.display()
+                        ))
+                        .with_pos(Some(pos)),
+                    )
+                    .in_file(&location)
+                    .into());
+                }
+                None => {}
+            };
+
+            symbols.push(
+                SymbolDeclaration {
+                    id,
+                    symbol: Symbol::There(
+                        SymbolImport::with_id_in_module(id, module_id.display().to_string())
+                            .start_end(pos.0, pos.1),
+                    ),
+                }
+                .start_end(pos.0, pos.1),
+            );
+            globbed.insert(id, module_id);
+        }
+
         symbols.extend(destination.symbols);
 
         Ok(Module {
@@ -329,6 +517,7 @@ mod tests {
                 symbol: None,
                 source: Path::new("./foo/bar/baz.zok"),
                 alias: None,
+                glob: false,
             }
         );
     }
@@ -341,6 +530,20 @@ mod tests {
                 symbol: None,
                 source: Path::new("./foo/bar/baz.zok"),
                 alias: Some("myalias"),
+                glob: false,
+            }
+        );
+    }
+
+    #[test]
+    fn create_glob() {
+        assert_eq!(
+            Import::glob(Path::new("./foo/bar/baz.zok")),
+            Import {
+                symbol: None,
+                source: Path::new("./foo/bar/baz.zok"),
+                alias: None,
+                glob: true,
             }
         );
     }
@@ -47,11 +47,19 @@ pub fn fold_module<T: Field, F: Folder<T>>(f: &mut F, p: Prog<T>) -> Prog<T> {
 
 pub fn fold_statement<T: Field, F: Folder<T>>(f: &mut F, s: Statement<T>) -> Vec<Statement<T>> {
     match s {
-        Statement::Constraint(quad, lin) => vec![Statement::Constraint(
+        Statement::Constraint(quad, lin, metadata) => vec![Statement::Constraint(
             f.fold_quadratic_combination(quad),
             f.fold_linear_combination(lin),
+            metadata,
         )],
         Statement::Directive(dir) => vec![Statement::Directive(f.fold_directive(dir))],
+        Statement::Log(format_string, expressions) => vec![Statement::Log(
+            format_string,
+            expressions
+                .into_iter()
+                .map(|e| f.fold_quadratic_combination(e))
+                .collect(),
+        )],
     }
 }
 
@@ -86,22 +94,35 @@ pub fn fold_directive<T: Field, F: Folder<T>>(f: &mut F, ds: Directive<T>) -> Di
 }
 
 pub fn fold_function<T: Field, F: Folder<T>>(f: &mut F, fun: Function<T>) -> Function<T> {
+    let arguments = fun
+        .arguments
+        .into_iter()
+        .map(|a| f.fold_argument(a))
+        .collect();
+
+    // the statements a statement is folded into keep its source location
+    let (statements, source_map) = fun
+        .statements
+        .into_iter()
+        .zip(fun.source_map.into_spans())
+        .flat_map(|(s, span)| {
+            f.fold_statement(s)
+                .into_iter()
+                .map(move |s| (s, span.clone()))
+        })
+        .unzip();
+
+    let returns = fun
+        .returns
+        .into_iter()
+        .map(|v| f.fold_variable(v))
+        .collect();
+
     Function {
-        arguments: fun
-            .arguments
-            .into_iter()
-            .map(|a| f.fold_argument(a))
-            .collect(),
-        statements: fun
-            .statements
-            .into_iter()
-            .flat_map(|s| f.fold_statement(s))
-            .collect(),
-        returns: fun
-            .returns
-            .into_iter()
-            .map(|v| f.fold_variable(v))
-            .collect(),
+        arguments,
+        statements,
+        returns,
+        source_map,
         ..fun
     }
 }
@@ -15,6 +15,43 @@ impl<T: Field> From<FlatFunction<T>> for Function<T> {
             })
             .next()
             .unwrap();
+
+        let mut return_span = None;
+
+        let statements: Vec<(Statement<T>, _)> = flat_function
+            .statements
+            .into_iter()
+            .zip(flat_function.source_map.into_spans())
+            .filter_map(|(s, span)| match s {
+                FlatStatement::Return(..) => {
+                    return_span = span;
+                    None
+                }
+                s => Some((s.into(), span)),
+            })
+            .collect();
+
+        // the constraints binding the outputs are mapped to the return statement
+        let (statements, source_map) = statements
+            .into_iter()
+            .chain(
+                return_expressions
+                    .iter()
+                    .cloned()
+                    .enumerate()
+                    .map(|(index, expression)| {
+                        (
+                            Statement::Constraint(
+                                QuadComb::from_flat_expression(expression),
+                                FlatVariable::public(index).into(),
+                                None,
+                            ),
+                            return_span.clone(),
+                        )
+                    }),
+            )
+            .unzip();
+
         Function {
             id: String::from("main"),
             arguments: flat_function.arguments.into_iter().map(|p| p.id).collect(),
@@ -23,25 +60,8 @@ impl<T: Field> From<FlatFunction<T>> for Function<T> {
                 .enumerate()
                 .map(|(index, _)| FlatVariable::public(index))
                 .collect(),
-            statements: flat_function
-                .statements
-                .into_iter()
-                .filter_map(|s| match s {
-                    FlatStatement::Return(..) => None,
-                    s => Some(s.into()),
-                })
-                .chain(
-                    return_expressions
-                        .into_iter()
-                        .enumerate()
-                        .map(|(index, expression)| {
-                            Statement::Constraint(
-                                QuadComb::from_flat_expression(expression),
-                                FlatVariable::public(index).into(),
-                            )
-                        }),
-                )
-                .collect(),
+            statements,
+            source_map,
         }
     }
 }
@@ -99,21 +119,30 @@ impl<T: Field> From<FlatExpression<T>> for LinComb<T> {
 impl<T: Field> From<FlatStatement<T>> for Statement<T> {
     fn from(flat_statement: FlatStatement<T>) -> Statement<T> {
         match flat_statement {
-            FlatStatement::Condition(linear, quadratic) => match quadratic {
+            FlatStatement::Condition(linear, quadratic, metadata) => match quadratic {
                 FlatExpression::Mult(box lhs, box rhs) => Statement::Constraint(
                     QuadComb::from_linear_combinations(lhs.into(), rhs.into()),
                     linear.into(),
+                    metadata,
                 ),
-                e => Statement::Constraint(LinComb::from(e).into(), linear.into()),
+                e => Statement::Constraint(LinComb::from(e).into(), linear.into(), metadata),
             },
             FlatStatement::Definition(var, quadratic) => match quadratic {
                 FlatExpression::Mult(box lhs, box rhs) => Statement::Constraint(
                     QuadComb::from_linear_combinations(lhs.into(), rhs.into()),
                     var.into(),
+                    None,
                 ),
-                e => Statement::Constraint(LinComb::from(e).into(), var.into()),
+                e => Statement::Constraint(LinComb::from(e).into(), var.into(), None),
             },
             FlatStatement::Directive(ds) => Statement::Directive(ds.into()),
+            FlatStatement::Log(format_string, expressions) => Statement::Log(
+                format_string,
+                expressions
+                    .into_iter()
+                    .map(|e| QuadComb::from_flat_expression(e))
+                    .collect(),
+            ),
             _ => panic!("return should be handled at the function level"),
         }
     }
@@ -0,0 +1,548 @@
+//! Serialization of programs and witnesses in the binary formats of iden3, used by circom and
+//! snarkjs
+//!
+//! See https://github.com/iden3/r1csfile/blob/master/doc/r1cs_bin_format.md for the `.r1cs` format.
+//! The `.wtns` format follows the same structure, with a header section followed by the values
+//! of the wires.
+
+use crate::flat_absy::FlatVariable;
+use crate::ir::{LinComb, Prog, Statement, VariableMap, Witness};
+use num_bigint::BigUint;
+use std::io::{self, Read, Write};
+use zokrates_field::Field;
+
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+const R1CS_VERSION: u32 = 1;
+const WTNS_MAGIC: &[u8; 4] = b"wtns";
+const WTNS_VERSION: u32 = 2;
+
+const HEADER_SECTION: u32 = 1;
+const CONSTRAINTS_SECTION: u32 = 2;
+const WIRE_TO_LABEL_SECTION: u32 = 3;
+const WITNESS_SECTION: u32 = 2;
+
+/// The numbering of the variables of a program as wires
+///
+/// Wire `0` is `~one`. It is followed by the outputs, the public arguments, the private arguments
+/// and the other variables of the constraints in order of appearance.
+#[derive(Debug)]
+pub struct Wires {
+    wires: VariableMap<u32>,
+    variables: Vec<FlatVariable>,
+    public_outputs: u32,
+    public_inputs: u32,
+    private_inputs: u32,
+}
+
+impl Wires {
+    pub fn new<T: Field>(program: &Prog<T>) -> Self {
+        let main = &program.main;
+
+        let public_inputs = main
+            .arguments
+            .iter()
+            .zip(program.private.iter())
+            .filter(|(_, private)| !**private)
+            .map(|(v, _)| v);
+        let private_inputs = main
+            .arguments
+            .iter()
+            .zip(program.private.iter())
+            .filter(|(_, private)| **private)
+            .map(|(v, _)| v);
+
+        let mut res = Wires {
+            wires: VariableMap::new(),
+            variables: vec![],
+            public_outputs: main.returns.len() as u32,
+            public_inputs: public_inputs.clone().count() as u32,
+            private_inputs: private_inputs.clone().count() as u32,
+        };
+
+        res.insert(&FlatVariable::one());
+        main.returns
+            .iter()
+            .chain(public_inputs)
+            .chain(private_inputs)
+            .for_each(|v| res.insert(v));
+
+        for statement in &main.statements {
+            if let Statement::Constraint(quad, lin, _) = statement {
+                quad.left
+                    .0
+                    .iter()
+                    .chain(quad.right.0.iter())
+                    .chain(lin.0.iter())
+                    .for_each(|(v, _)| res.insert(v));
+            }
+        }
+
+        res
+    }
+
+    fn insert(&mut self, variable: &FlatVariable) {
+        let variables = &mut self.variables;
+        self.wires.get_or_insert_with(*variable, || {
+            variables.push(*variable);
+            variables.len() as u32 - 1
+        });
+    }
+
+    /// The wire of `variable`
+    pub fn get(&self, variable: &FlatVariable) -> Option<u32> {
+        self.wires.get(variable).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.variables.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.variables.is_empty()
+    }
+
+    /// The variables in the order of their wires
+    pub fn variables(&self) -> &[FlatVariable] {
+        &self.variables
+    }
+}
+
+/// A linear combination of wires, sorted by wire
+pub type WireLinComb<T> = Vec<(u32, T)>;
+
+/// A rank-1 constraint system in the `.r1cs` format
+#[derive(Debug, Clone, PartialEq)]
+pub struct R1cs<T> {
+    pub wire_count: u32,
+    pub public_outputs: u32,
+    pub public_inputs: u32,
+    pub private_inputs: u32,
+    /// The constraints `a * b = c`
+    pub constraints: Vec<(WireLinComb<T>, WireLinComb<T>, WireLinComb<T>)>,
+}
+
+impl<T: Field> R1cs<T> {
+    pub fn new(program: &Prog<T>) -> Self {
+        let wires = Wires::new(program);
+
+        let constraints = program
+            .main
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::Constraint(quad, lin, _) => Some((
+                    wire_linear_combination(&wires, &quad.left),
+                    wire_linear_combination(&wires, &quad.right),
+                    wire_linear_combination(&wires, lin),
+                )),
+                _ => None,
+            })
+            .collect();
+
+        R1cs {
+            wire_count: wires.len() as u32,
+            public_outputs: wires.public_outputs,
+            public_inputs: wires.public_inputs,
+            private_inputs: wires.private_inputs,
+            constraints,
+        }
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let size = field_size::<T>();
+
+        let mut header = vec![];
+        write_field_header::<T>(&mut header);
+        header.extend(&self.wire_count.to_le_bytes());
+        header.extend(&self.public_outputs.to_le_bytes());
+        header.extend(&self.public_inputs.to_le_bytes());
+        header.extend(&self.private_inputs.to_le_bytes());
+        // the wires are their own labels
+        header.extend(&(self.wire_count as u64).to_le_bytes());
+        header.extend(&(self.constraints.len() as u32).to_le_bytes());
+
+        let mut constraints = vec![];
+        for (a, b, c) in &self.constraints {
+            for l in [a, b, c].iter() {
+                constraints.extend(&(l.len() as u32).to_le_bytes());
+                for (wire, coefficient) in l.iter() {
+                    constraints.extend(&wire.to_le_bytes());
+                    write_element(&mut constraints, coefficient, size);
+                }
+            }
+        }
+
+        let mut labels = vec![];
+        for wire in 0..self.wire_count as u64 {
+            labels.extend(&wire.to_le_bytes());
+        }
+
+        writer.write_all(R1CS_MAGIC)?;
+        writer.write_all(&R1CS_VERSION.to_le_bytes())?;
+        writer.write_all(&3u32.to_le_bytes())?;
+        write_section(&mut writer, HEADER_SECTION, &header)?;
+        write_section(&mut writer, CONSTRAINTS_SECTION, &constraints)?;
+        write_section(&mut writer, WIRE_TO_LABEL_SECTION, &labels)?;
+        Ok(())
+    }
+
+    pub fn read<R: Read>(reader: R) -> io::Result<Self> {
+        let sections = read_sections(reader, R1CS_MAGIC, R1CS_VERSION)?;
+
+        let mut header = section(&sections, HEADER_SECTION)?;
+        let size = read_field_header::<T>(&mut header)?;
+        let wire_count = read_u32(&mut header)?;
+        let public_outputs = read_u32(&mut header)?;
+        let public_inputs = read_u32(&mut header)?;
+        let private_inputs = read_u32(&mut header)?;
+        let _labels = read_u64(&mut header)?;
+        let constraint_count = read_u32(&mut header)?;
+
+        let mut bytes = section(&sections, CONSTRAINTS_SECTION)?;
+        let mut read_linear_combination = || -> io::Result<WireLinComb<T>> {
+            let count = read_u32(&mut bytes)?;
+            (0..count)
+                .map(|_| Ok((read_u32(&mut bytes)?, read_element(&mut bytes, size)?)))
+                .collect()
+        };
+        let constraints = (0..constraint_count)
+            .map(|_| {
+                Ok((
+                    read_linear_combination()?,
+                    read_linear_combination()?,
+                    read_linear_combination()?,
+                ))
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(R1cs {
+            wire_count,
+            public_outputs,
+            public_inputs,
+            private_inputs,
+            constraints,
+        })
+    }
+}
+
+/// The values of the wires in the `.wtns` format
+#[derive(Debug, Clone, PartialEq)]
+pub struct Wtns<T>(pub Vec<T>);
+
+impl<T: Field> Wtns<T> {
+    /// Fails if `witness` is missing the value of one of the wires of `program`
+    pub fn new(program: &Prog<T>, witness: &Witness<T>) -> io::Result<Self> {
+        Ok(Wtns(
+            Wires::new(program)
+                .variables()
+                .iter()
+                .map(|v| {
+                    witness
+                        .0
+                        .get(v)
+                        .cloned()
+                        .ok_or_else(|| error(&format!("Missing value of {}", v)))
+                })
+                .collect::<io::Result<_>>()?,
+        ))
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let size = field_size::<T>();
+
+        let mut header = vec![];
+        write_field_header::<T>(&mut header);
+        header.extend(&(self.0.len() as u32).to_le_bytes());
+
+        let mut values = vec![];
+        for value in &self.0 {
+            write_element(&mut values, value, size);
+        }
+
+        writer.write_all(WTNS_MAGIC)?;
+        writer.write_all(&WTNS_VERSION.to_le_bytes())?;
+        writer.write_all(&2u32.to_le_bytes())?;
+        write_section(&mut writer, HEADER_SECTION, &header)?;
+        write_section(&mut writer, WITNESS_SECTION, &values)?;
+        Ok(())
+    }
+
+    pub fn read<R: Read>(reader: R) -> io::Result<Self> {
+        let sections = read_sections(reader, WTNS_MAGIC, WTNS_VERSION)?;
+
+        let mut header = section(&sections, HEADER_SECTION)?;
+        let size = read_field_header::<T>(&mut header)?;
+        let count = read_u32(&mut header)?;
+
+        let mut values = section(&sections, WITNESS_SECTION)?;
+        Ok(Wtns(
+            (0..count)
+                .map(|_| read_element(&mut values, size))
+                .collect::<io::Result<_>>()?,
+        ))
+    }
+}
+
+fn wire_linear_combination<T: Field>(wires: &Wires, l: &LinComb<T>) -> WireLinComb<T> {
+    let mut res: Vec<_> = l
+        .clone()
+        .into_canonical()
+        .0
+        .into_iter()
+        .map(|(variable, coefficient)| (wires.get(&variable).unwrap(), coefficient))
+        .collect();
+    res.sort_by_key(|(wire, _)| *wire);
+    res
+}
+
+fn error(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, message)
+}
+
+fn modulus<T: Field>() -> BigUint {
+    T::max_value().to_biguint() + 1u32
+}
+
+/// The size of a field element in bytes, a multiple of 8
+fn field_size<T: Field>() -> usize {
+    (modulus::<T>().bits() - 1) / 64 * 8 + 8
+}
+
+fn write_field_header<T: Field>(bytes: &mut Vec<u8>) {
+    let size = field_size::<T>();
+    bytes.extend(&(size as u32).to_le_bytes());
+    write_biguint(bytes, &modulus::<T>(), size);
+}
+
+/// Reads the size of the field elements and checks the modulus
+fn read_field_header<T: Field>(bytes: &mut &[u8]) -> io::Result<usize> {
+    let size = read_u32(bytes)? as usize;
+    let mut prime = vec![0; size];
+    bytes.read_exact(&mut prime)?;
+    match BigUint::from_bytes_le(&prime) == modulus::<T>() {
+        true => Ok(size),
+        false => Err(error(&format!(
+            "Expected a file for the {} field",
+            T::name()
+        ))),
+    }
+}
+
+fn write_biguint(bytes: &mut Vec<u8>, value: &BigUint, size: usize) {
+    let mut value = value.to_bytes_le();
+    value.resize(size, 0);
+    bytes.extend(value);
+}
+
+fn write_element<T: Field>(bytes: &mut Vec<u8>, value: &T, size: usize) {
+    write_biguint(bytes, &value.to_biguint(), size)
+}
+
+fn read_element<T: Field>(bytes: &mut &[u8], size: usize) -> io::Result<T> {
+    let mut value = vec![0; size];
+    bytes.read_exact(&mut value)?;
+    T::try_from(BigUint::from_bytes_le(&value)).map_err(|_| error("Invalid field element"))
+}
+
+fn write_section<W: Write>(writer: &mut W, section_type: u32, content: &[u8]) -> io::Result<()> {
+    writer.write_all(&section_type.to_le_bytes())?;
+    writer.write_all(&(content.len() as u64).to_le_bytes())?;
+    writer.write_all(content)
+}
+
+/// Checks the magic number and the version, and returns the sections by type
+fn read_sections<R: Read>(
+    mut reader: R,
+    magic: &[u8; 4],
+    version: u32,
+) -> io::Result<Vec<(u32, Vec<u8>)>> {
+    let mut bytes = vec![];
+    reader.read_to_end(&mut bytes)?;
+    let mut bytes = bytes.as_slice();
+
+    let mut actual_magic = [0; 4];
+    bytes.read_exact(&mut actual_magic)?;
+    if &actual_magic != magic {
+        return Err(error("Wrong magic number"));
+    }
+    if read_u32(&mut bytes)? != version {
+        return Err(error("Unknown version"));
+    }
+
+    let count = read_u32(&mut bytes)?;
+    (0..count)
+        .map(|_| {
+            let section_type = read_u32(&mut bytes)?;
+            let size = read_u64(&mut bytes)? as usize;
+            let mut content = vec![0; size];
+            bytes.read_exact(&mut content)?;
+            Ok((section_type, content))
+        })
+        .collect()
+}
+
+fn section(sections: &[(u32, Vec<u8>)], section_type: u32) -> io::Result<&[u8]> {
+    sections
+        .iter()
+        .find(|(t, _)| *t == section_type)
+        .map(|(_, content)| content.as_slice())
+        .ok_or_else(|| error(&format!("Missing section {}", section_type)))
+}
+
+fn read_u32(bytes: &mut &[u8]) -> io::Result<u32> {
+    let mut buf = [0; 4];
+    bytes.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64(bytes: &mut &[u8]) -> io::Result<u64> {
+    let mut buf = [0; 8];
+    bytes.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{Function, Interpreter, QuadComb};
+    use std::io::Cursor;
+    use zokrates_field::Bn128Field;
+
+    // def main(private _0, _1) -> (1):
+    //     _2 = _0 * _1
+    //     ~out_0 = (_2 + 3 * ~one) * ~one
+    //     _0 * (_1 + _1) == 2 * _2
+    fn program() -> Prog<Bn128Field> {
+        Prog {
+            private: vec![true, false],
+            main: Function {
+                id: "main".to_string(),
+                arguments: vec![FlatVariable::new(0), FlatVariable::new(1)],
+                returns: vec![FlatVariable::public(0)],
+                statements: vec![
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            FlatVariable::new(0).into(),
+                            FlatVariable::new(1).into(),
+                        ),
+                        FlatVariable::new(2),
+                    ),
+                    Statement::constraint(
+                        LinComb::from(FlatVariable::new(2))
+                            + LinComb::summand(3, FlatVariable::one()),
+                        FlatVariable::public(0),
+                    ),
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            FlatVariable::new(0).into(),
+                            LinComb::from(FlatVariable::new(1))
+                                + LinComb::from(FlatVariable::new(1)),
+                        ),
+                        LinComb::summand(2, FlatVariable::new(2)),
+                    ),
+                ],
+                source_map: Default::default(),
+            },
+        }
+    }
+
+    #[test]
+    fn wires() {
+        let wires = Wires::new(&program());
+
+        assert_eq!(
+            wires.variables(),
+            &[
+                FlatVariable::one(),
+                FlatVariable::public(0),
+                FlatVariable::new(1),
+                FlatVariable::new(0),
+                FlatVariable::new(2),
+            ]
+        );
+        assert_eq!(wires.get(&FlatVariable::new(2)), Some(4));
+        assert_eq!(wires.get(&FlatVariable::new(3)), None);
+    }
+
+    #[test]
+    fn r1cs() {
+        let r1cs = R1cs::new(&program());
+
+        assert_eq!(r1cs.wire_count, 5);
+        assert_eq!(
+            (r1cs.public_outputs, r1cs.public_inputs, r1cs.private_inputs),
+            (1, 1, 1)
+        );
+        assert_eq!(
+            r1cs.constraints[1],
+            (
+                vec![(0, Bn128Field::from(1))],
+                vec![(0, Bn128Field::from(3)), (4, Bn128Field::from(1))],
+                vec![(1, Bn128Field::from(1))]
+            )
+        );
+        assert_eq!(
+            r1cs.constraints[2],
+            (
+                vec![(3, Bn128Field::from(1))],
+                vec![(2, Bn128Field::from(2))],
+                vec![(4, Bn128Field::from(2))]
+            )
+        );
+
+        let mut buffer = Cursor::new(vec![]);
+        r1cs.write(&mut buffer).unwrap();
+        let bytes = buffer.into_inner();
+
+        assert_eq!(&bytes[..4], b"r1cs");
+        // magic, version, section count, then the header section of size 4 + 32 + 4 * 4 + 8 + 4
+        assert_eq!(bytes[12..16], HEADER_SECTION.to_le_bytes());
+        assert_eq!(bytes[16..24], 64u64.to_le_bytes());
+        assert_eq!(bytes[24..28], 32u32.to_le_bytes());
+
+        assert_eq!(R1cs::read(bytes.as_slice()).unwrap(), r1cs);
+    }
+
+    #[test]
+    fn wtns() {
+        let program = program();
+        let witness = Interpreter::default()
+            .execute(&program, &vec![Bn128Field::from(3), Bn128Field::from(4)])
+            .unwrap();
+
+        let wtns = Wtns::new(&program, &witness).unwrap();
+        assert_eq!(
+            wtns,
+            Wtns(
+                vec![1, 15, 4, 3, 12]
+                    .into_iter()
+                    .map(Bn128Field::from)
+                    .collect()
+            )
+        );
+
+        let mut buffer = Cursor::new(vec![]);
+        wtns.write(&mut buffer).unwrap();
+        let bytes = buffer.into_inner();
+
+        assert_eq!(&bytes[..4], b"wtns");
+        assert_eq!(Wtns::read(bytes.as_slice()).unwrap(), wtns);
+
+        // the field is checked
+        assert!(Wtns::<zokrates_field::Bls12_381Field>::read(bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn wtns_missing_value() {
+        let program = program();
+        let mut witness = Interpreter::default()
+            .execute(&program, &vec![Bn128Field::from(3), Bn128Field::from(4)])
+            .unwrap();
+        witness.0.remove(&FlatVariable::new(0));
+
+        assert_eq!(
+            Wtns::new(&program, &witness).unwrap_err().to_string(),
+            "Missing value of _0"
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use crate::flat_absy::flat_variable::FlatVariable;
-use crate::ir::{LinComb, Prog, QuadComb, Statement, Witness};
+use crate::flat_absy::AssertionMetadata;
+use crate::ir::{LinComb, Prog, QuadComb, Statement, VariableMap, Witness};
 use ir::Directive;
 use solvers::Solver;
-use std::collections::BTreeMap;
+use std::convert::TryFrom;
 use std::fmt;
 use zokrates_field::Field;
 
@@ -14,12 +15,15 @@ pub struct Interpreter {
     /// Whether we should try to give out-of-range bit decompositions when the input is not a single summand.
     /// Used to do targetted testing of `<` flattening, making sure the bit decomposition we base the result on is unique.
     should_try_out_of_range: bool,
+    /// Whether `log` statements should be printed during execution.
+    verbose: bool,
 }
 
 impl Default for Interpreter {
     fn default() -> Interpreter {
         Interpreter {
             should_try_out_of_range: false,
+            verbose: false,
         }
     }
 }
@@ -28,6 +32,14 @@ impl Interpreter {
     pub fn try_out_of_range() -> Interpreter {
         Interpreter {
             should_try_out_of_range: true,
+            ..Interpreter::default()
+        }
+    }
+
+    pub fn verbose() -> Interpreter {
+        Interpreter {
+            verbose: true,
+            ..Interpreter::default()
         }
     }
 }
@@ -36,15 +48,17 @@ impl Interpreter {
     pub fn execute<T: Field>(&self, program: &Prog<T>, inputs: &Vec<T>) -> ExecutionResult<T> {
         let main = &program.main;
         self.check_inputs(&program, &inputs)?;
-        let mut witness = BTreeMap::new();
+        let mut witness = VariableMap::new();
         witness.insert(FlatVariable::one(), T::one());
         for (arg, value) in main.arguments.iter().zip(inputs.iter()) {
             witness.insert(arg.clone(), value.clone().into());
         }
+        // whether a solver failed so far
+        let mut solver_failed = false;
 
         for statement in main.statements.iter() {
             match statement {
-                Statement::Constraint(quad, lin) => match lin.is_assignee(&witness) {
+                Statement::Constraint(quad, lin, metadata) => match lin.is_assignee(&witness) {
                     true => {
                         let val = quad.evaluate(&witness).unwrap();
                         witness.insert(lin.0.iter().next().unwrap().0.clone(), val);
@@ -53,9 +67,13 @@ impl Interpreter {
                         let lhs_value = quad.evaluate(&witness).unwrap();
                         let rhs_value = lin.evaluate(&witness).unwrap();
                         if lhs_value != rhs_value {
+                            if solver_failed {
+                                return Err(Error::Solver);
+                            }
                             return Err(Error::UnsatisfiedConstraint {
                                 left: lhs_value.to_dec_string(),
                                 right: rhs_value.to_dec_string(),
+                                metadata: metadata.clone(),
                             });
                         }
                     }
@@ -82,18 +100,38 @@ impl Interpreter {
                                     }
                                     continue;
                                 }
-                                Err(_) => return Err(Error::Solver),
+                                // the directive may be in a branch which is not taken, in which
+                                // case its constraints are not enforced: only fail if one is
+                                // unsatisfied later on
+                                Err(_) => {
+                                    for o in d.outputs.iter() {
+                                        witness.insert(o.clone(), T::zero());
+                                    }
+                                    solver_failed = true;
+                                }
                             };
                         }
                     }
                 }
+                Statement::Log(format_string, expressions) => {
+                    if self.verbose {
+                        let values = expressions
+                            .iter()
+                            .map(|e| e.evaluate(&witness))
+                            .collect::<Result<Vec<_>, _>>()
+                            .map_err(|_| Error::Log {
+                                format_string: format_string.clone(),
+                            })?;
+                        println!("{}", format_log(format_string, &values));
+                    }
+                }
             }
         }
 
         Ok(Witness(witness))
     }
 
-    fn try_solve_out_of_range<T: Field>(d: &Directive<T>, witness: &mut BTreeMap<FlatVariable, T>) {
+    fn try_solve_out_of_range<T: Field>(d: &Directive<T>, witness: &mut VariableMap<T>) {
         use num::traits::Pow;
 
         // we target the `2a - 2b` part of the `<` check by only returning out-of-range results
@@ -183,7 +221,39 @@ impl Interpreter {
                 let c = inputs[2].clone();
                 vec![a * (b - c.clone()) + c]
             }
-            Solver::Div => vec![inputs[0].clone() / inputs[1].clone()],
+            Solver::Div => {
+                if inputs[1].is_zero() {
+                    return Err(String::from("Division by zero"));
+                }
+
+                vec![inputs[0].clone() / inputs[1].clone()]
+            }
+            Solver::EuclideanDiv => {
+                if inputs[1].is_zero() {
+                    return Err(String::from("Division by zero"));
+                }
+
+                let n = inputs[0].to_biguint();
+                let d = inputs[1].to_biguint();
+
+                let q = n.clone() / d.clone();
+                let r = n - q.clone() * d;
+
+                vec![T::try_from(q).unwrap(), T::try_from(r).unwrap()]
+            }
+            Solver::Gt => vec![match inputs[0] > inputs[1] {
+                true => T::one(),
+                false => T::zero(),
+            }],
+            // an index out of bounds is not a failure of the solver, as it is caught by the
+            // constraints of the lookup
+            Solver::Select(size) => vec![inputs[*size]
+                .to_dec_string()
+                .parse::<usize>()
+                .ok()
+                .filter(|index| index < size)
+                .map(|index| inputs[index].clone())
+                .unwrap_or_else(T::zero)],
         };
 
         assert_eq!(res.len(), expected_output_count);
@@ -193,7 +263,7 @@ impl Interpreter {
 }
 
 impl<T: Field> LinComb<T> {
-    fn evaluate(&self, witness: &BTreeMap<FlatVariable, T>) -> Result<T, ()> {
+    fn evaluate(&self, witness: &VariableMap<T>) -> Result<T, ()> {
         self.0
             .iter()
             .map(|(var, mult)| witness.get(var).map(|v| v.clone() * mult).ok_or(())) // get each term
@@ -201,7 +271,7 @@ impl<T: Field> LinComb<T> {
             .map(|v| v.iter().fold(T::from(0), |acc, t| acc + t)) // return the sum
     }
 
-    fn is_assignee<U>(&self, witness: &BTreeMap<FlatVariable, U>) -> bool {
+    fn is_assignee<U>(&self, witness: &VariableMap<U>) -> bool {
         self.0.iter().count() == 1
             && self.0.iter().next().unwrap().1 == T::from(1)
             && !witness.contains_key(&self.0.iter().next().unwrap().0)
@@ -209,18 +279,39 @@ impl<T: Field> LinComb<T> {
 }
 
 impl<T: Field> QuadComb<T> {
-    pub fn evaluate(&self, witness: &BTreeMap<FlatVariable, T>) -> Result<T, ()> {
+    pub fn evaluate(&self, witness: &VariableMap<T>) -> Result<T, ()> {
         let left = self.left.evaluate(&witness)?;
         let right = self.right.evaluate(&witness)?;
         Ok(left * right)
     }
 }
 
+/// Replaces the `{}` placeholders of `format_string` with `values`, in order
+fn format_log<T: Field>(format_string: &str, values: &[T]) -> String {
+    let mut parts = format_string.split("{}");
+    let mut res = String::from(parts.next().unwrap());
+    for (value, part) in values.iter().zip(parts) {
+        res.push_str(&value.to_dec_string());
+        res.push_str(part);
+    }
+    res
+}
+
 #[derive(PartialEq, Serialize, Deserialize, Clone)]
 pub enum Error {
-    UnsatisfiedConstraint { left: String, right: String },
+    UnsatisfiedConstraint {
+        left: String,
+        right: String,
+        metadata: Option<AssertionMetadata>,
+    },
     Solver,
-    WrongInputCount { expected: usize, received: usize },
+    WrongInputCount {
+        expected: usize,
+        received: usize,
+    },
+    Log {
+        format_string: String,
+    },
 }
 
 impl fmt::Display for Error {
@@ -229,7 +320,11 @@ impl fmt::Display for Error {
             Error::UnsatisfiedConstraint {
                 ref left,
                 ref right,
-            } => write!(f, "Expected {} to equal {}", left, right),
+                ref metadata,
+            } => match metadata {
+                Some(metadata) => write!(f, "{}", metadata),
+                None => write!(f, "Expected {} to equal {}", left, right),
+            },
             Error::Solver => write!(f, ""),
             Error::WrongInputCount { expected, received } => write!(
                 f,
@@ -239,6 +334,11 @@ impl fmt::Display for Error {
                 received,
                 if received == 1 { "" } else { "s" }
             ),
+            Error::Log { ref format_string } => write!(
+                f,
+                "Could not evaluate the values of log statement \"{}\"",
+                format_string
+            ),
         }
     }
 }
@@ -252,6 +352,7 @@ impl fmt::Debug for Error {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ir::Function;
     use zokrates_field::Bn128Field;
 
     mod eq_condition {
@@ -293,6 +394,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn log_missing_value() {
+        // log("{}", _0) where _0 is never assigned
+        let program: Prog<Bn128Field> = Prog {
+            main: Function {
+                id: String::from("main"),
+                arguments: vec![],
+                returns: vec![],
+                statements: vec![Statement::Log(
+                    String::from("{}"),
+                    vec![QuadComb::from_linear_combinations(
+                        FlatVariable::new(0).into(),
+                        FlatVariable::one().into(),
+                    )],
+                )],
+                source_map: Default::default(),
+            },
+            private: vec![],
+        };
+
+        assert_eq!(
+            Interpreter::verbose()
+                .execute(&program, &vec![])
+                .unwrap_err(),
+            Error::Log {
+                format_string: String::from("{}")
+            }
+        );
+        assert!(Interpreter::default().execute(&program, &vec![]).is_ok());
+    }
+
     #[test]
     fn bits_of_one() {
         let inputs = vec![Bn128Field::from(1)];
@@ -321,4 +453,70 @@ mod tests {
         assert_eq!(res[248], Bn128Field::from(1));
         assert_eq!(res[247], Bn128Field::from(0));
     }
+
+    #[test]
+    fn euclidean_div() {
+        let inputs = vec![Bn128Field::from(42), Bn128Field::from(5)];
+        let interpreter = Interpreter::default();
+        let res = interpreter
+            .execute_solver(&Solver::EuclideanDiv, &inputs)
+            .unwrap();
+        assert_eq!(res, vec![Bn128Field::from(8), Bn128Field::from(2)]);
+    }
+
+    #[test]
+    fn euclidean_div_by_zero() {
+        let inputs = vec![Bn128Field::from(42), Bn128Field::from(0)];
+        let interpreter = Interpreter::default();
+        assert!(interpreter
+            .execute_solver(&Solver::EuclideanDiv, &inputs)
+            .is_err());
+    }
+
+    #[test]
+    fn gt() {
+        let interpreter = Interpreter::default();
+        let gt = |a: i32, b: i32| {
+            interpreter
+                .execute_solver(&Solver::Gt, &vec![Bn128Field::from(a), Bn128Field::from(b)])
+                .unwrap()
+        };
+        assert_eq!(gt(3, 2), vec![Bn128Field::from(1)]);
+        assert_eq!(gt(2, 2), vec![Bn128Field::from(0)]);
+        assert_eq!(gt(-1, 2), vec![Bn128Field::from(1)]);
+    }
+
+    #[test]
+    fn select() {
+        let interpreter = Interpreter::default();
+        let select = |index: i32| {
+            interpreter
+                .execute_solver(
+                    &Solver::Select(3),
+                    &vec![42, 43, 44, index]
+                        .into_iter()
+                        .map(Bn128Field::from)
+                        .collect(),
+                )
+                .unwrap()
+        };
+        assert_eq!(select(1), vec![Bn128Field::from(43)]);
+        assert_eq!(select(3), vec![Bn128Field::from(0)]);
+        assert_eq!(select(-1), vec![Bn128Field::from(0)]);
+    }
+
+    #[test]
+    fn div_by_zero() {
+        let inputs = vec![Bn128Field::from(42), Bn128Field::from(0)];
+        let interpreter = Interpreter::default();
+        assert!(interpreter.execute_solver(&Solver::Div, &inputs).is_err());
+    }
+
+    #[test]
+    fn format_log_values() {
+        let values = vec![Bn128Field::from(42), Bn128Field::from(0)];
+        assert_eq!(format_log("a = {}, b = {}!", &values), "a = 42, b = 0!");
+        assert_eq!(format_log("{}{}", &values), "420");
+        assert_eq!(format_log("no values", &[] as &[Bn128Field]), "no values");
+    }
 }
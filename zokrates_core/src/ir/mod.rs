@@ -1,36 +1,44 @@
 use crate::flat_absy::flat_parameter::FlatParameter;
-use crate::flat_absy::FlatVariable;
+use crate::flat_absy::{AssertionMetadata, FlatVariable};
 use crate::solvers::Solver;
+use crate::source_map::SourceMap;
 use std::fmt;
 use zokrates_field::Field;
 
 mod expression;
 pub mod folder;
 mod from_flat;
+mod iden3;
 mod interpreter;
 mod serialize;
+mod variable_map;
 mod witness;
 
 pub use self::expression::QuadComb;
 pub use self::expression::{CanonicalLinComb, LinComb};
+pub use self::iden3::{R1cs, Wires, Wtns};
 pub use self::serialize::ProgEnum;
 
 pub use self::interpreter::{Error, ExecutionResult, Interpreter};
+pub use self::variable_map::VariableMap;
 pub use self::witness::Witness;
 
 #[derive(Debug, Serialize, Deserialize, Clone, Hash)]
 pub enum Statement<T> {
-    Constraint(QuadComb<T>, LinComb<T>),
+    Constraint(QuadComb<T>, LinComb<T>, Option<AssertionMetadata>),
     Directive(Directive<T>),
+    /// Prints values during witness computation, without constraining them
+    Log(String, Vec<QuadComb<T>>),
 }
 
 impl<T: Field> PartialEq for Statement<T> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Statement::Constraint(l1, r1), Statement::Constraint(l2, r2)) => {
-                l1.eq(l2) && r1.eq(r2)
+            (Statement::Constraint(l1, r1, m1), Statement::Constraint(l2, r2, m2)) => {
+                l1.eq(l2) && r1.eq(r2) && m1.eq(m2)
             }
             (Statement::Directive(d1), Statement::Directive(d2)) => d1.eq(d2),
+            (Statement::Log(f1, e1), Statement::Log(f2, e2)) => f1.eq(f2) && e1.eq(e2),
             _ => false,
         }
     }
@@ -40,11 +48,11 @@ impl<T: Field> Eq for Statement<T> {}
 
 impl<T: Field> Statement<T> {
     pub fn definition<U: Into<QuadComb<T>>>(v: FlatVariable, e: U) -> Self {
-        Statement::Constraint(e.into(), v.into())
+        Statement::Constraint(e.into(), v.into(), None)
     }
 
     pub fn constraint<U: Into<QuadComb<T>>, V: Into<LinComb<T>>>(quad: U, lin: V) -> Self {
-        Statement::Constraint(quad.into(), lin.into())
+        Statement::Constraint(quad.into(), lin.into(), None)
     }
 }
 
@@ -88,8 +96,18 @@ impl<T: Field> fmt::Display for Directive<T> {
 impl<T: Field> fmt::Display for Statement<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Statement::Constraint(ref quad, ref lin) => write!(f, "{} == {}", quad, lin),
+            Statement::Constraint(ref quad, ref lin, _) => write!(f, "{} == {}", quad, lin),
             Statement::Directive(ref s) => write!(f, "{}", s),
+            Statement::Log(ref format_string, ref expressions) => write!(
+                f,
+                "log(\"{}\"{})",
+                format_string,
+                expressions
+                    .iter()
+                    .map(|e| format!(", {}", e))
+                    .collect::<Vec<_>>()
+                    .join("")
+            ),
         }
     }
 }
@@ -100,6 +118,9 @@ pub struct Function<T> {
     pub statements: Vec<Statement<T>>,
     pub arguments: Vec<FlatVariable>,
     pub returns: Vec<FlatVariable>,
+    /// The source location of each statement, emitted separately from the program
+    #[serde(skip)]
+    pub source_map: SourceMap,
 }
 
 impl<T: Field> PartialEq for Function<T> {
@@ -212,6 +233,7 @@ mod tests {
                     FlatVariable::new(42).into(),
                 ),
                 FlatVariable::new(42).into(),
+                None,
             );
             assert_eq!(format!("{}", c), "(1 * _42) * (1 * _42) == 1 * _42")
         }
@@ -82,6 +82,7 @@ mod tests {
                 id: "something".to_string(),
                 returns: vec![],
                 statements: vec![],
+                source_map: Default::default(),
             },
             private: vec![],
         };
@@ -103,6 +104,7 @@ mod tests {
                 id: "something".to_string(),
                 returns: vec![],
                 statements: vec![],
+                source_map: Default::default(),
             },
             private: vec![],
         };
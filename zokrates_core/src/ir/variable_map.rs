@@ -0,0 +1,200 @@
+use crate::flat_absy::FlatVariable;
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::Index;
+
+/// A map from variables to values, stored densely by variable index
+///
+/// Variables are expected to be numbered densely, which is the case for compiled programs
+#[derive(Clone)]
+pub struct VariableMap<V> {
+    /// The values of `~one` at index `0` and of `_i` at index `i + 1`
+    variables: Vec<Option<V>>,
+    /// The values of `~out_i` at index `i`
+    outputs: Vec<Option<V>>,
+}
+
+impl<V> VariableMap<V> {
+    pub fn new() -> Self {
+        VariableMap {
+            variables: vec![],
+            outputs: vec![],
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        VariableMap {
+            variables: Vec::with_capacity(capacity),
+            outputs: vec![],
+        }
+    }
+
+    fn slot(&self, variable: &FlatVariable) -> Option<&Option<V>> {
+        match variable.is_output() {
+            true => self.outputs.get(variable.index()),
+            false => self.variables.get(variable.index()),
+        }
+    }
+
+    fn slot_mut(&mut self, variable: &FlatVariable) -> &mut Option<V> {
+        let values = match variable.is_output() {
+            true => &mut self.outputs,
+            false => &mut self.variables,
+        };
+
+        let index = variable.index();
+        if index >= values.len() {
+            values.resize_with(index + 1, || None);
+        }
+
+        &mut values[index]
+    }
+
+    pub fn get(&self, variable: &FlatVariable) -> Option<&V> {
+        self.slot(variable).and_then(|v| v.as_ref())
+    }
+
+    pub fn contains_key(&self, variable: &FlatVariable) -> bool {
+        self.get(variable).is_some()
+    }
+
+    pub fn insert(&mut self, variable: FlatVariable, value: V) -> Option<V> {
+        self.slot_mut(&variable).replace(value)
+    }
+
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, variable: FlatVariable, f: F) -> &mut V {
+        self.slot_mut(&variable).get_or_insert_with(f)
+    }
+
+    pub fn remove(&mut self, variable: &FlatVariable) -> Option<V> {
+        match variable.is_output() {
+            true => self.outputs.get_mut(variable.index()),
+            false => self.variables.get_mut(variable.index()),
+        }
+        .and_then(|v| v.take())
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Iterates over the entries in the order of the variables: the outputs from the last one, then
+    /// `~one` and the private variables
+    pub fn iter(&self) -> impl Iterator<Item = (FlatVariable, &V)> {
+        self.outputs
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(i, v)| v.as_ref().map(|v| (FlatVariable::public(i), v)))
+            .chain(
+                self.variables
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, v)| v.as_ref().map(|v| (variable(i), v))),
+            )
+    }
+}
+
+// the variable stored at index `i` of `VariableMap::variables`
+fn variable(i: usize) -> FlatVariable {
+    match i {
+        0 => FlatVariable::one(),
+        i => FlatVariable::new(i - 1),
+    }
+}
+
+impl<V> Default for VariableMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: PartialEq> PartialEq for VariableMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for VariableMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, V> Index<&'a FlatVariable> for VariableMap<V> {
+    type Output = V;
+
+    fn index(&self, variable: &FlatVariable) -> &V {
+        self.get(variable)
+            .unwrap_or_else(|| panic!("no value for variable {}", variable))
+    }
+}
+
+impl<V> FromIterator<(FlatVariable, V)> for VariableMap<V> {
+    fn from_iter<I: IntoIterator<Item = (FlatVariable, V)>>(iter: I) -> Self {
+        let mut res = VariableMap::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<V> Extend<(FlatVariable, V)> for VariableMap<V> {
+    fn extend<I: IntoIterator<Item = (FlatVariable, V)>>(&mut self, iter: I) {
+        for (variable, value) in iter {
+            self.insert(variable, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_get_remove() {
+        let mut map = VariableMap::new();
+
+        assert_eq!(map.insert(FlatVariable::new(3), 42), None);
+        assert_eq!(map.insert(FlatVariable::public(1), 1), None);
+        assert_eq!(map.insert(FlatVariable::new(3), 43), Some(42));
+
+        assert_eq!(map.get(&FlatVariable::new(3)), Some(&43));
+        assert_eq!(map.get(&FlatVariable::new(2)), None);
+        assert_eq!(map.get(&FlatVariable::new(100)), None);
+        assert_eq!(map.get(&FlatVariable::public(0)), None);
+        assert_eq!(map[&FlatVariable::public(1)], 1);
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.remove(&FlatVariable::new(3)), Some(43));
+        assert_eq!(map.remove(&FlatVariable::new(3)), None);
+        assert!(!map.contains_key(&FlatVariable::new(3)));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn iter() {
+        // entries are visited in the same order as in a `BTreeMap`
+        let map: VariableMap<_> = vec![
+            (FlatVariable::new(1), 4),
+            (FlatVariable::public(0), 2),
+            (FlatVariable::one(), 3),
+            (FlatVariable::public(1), 1),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![
+                (FlatVariable::public(1), &1),
+                (FlatVariable::public(0), &2),
+                (FlatVariable::one(), &3),
+                (FlatVariable::new(1), &4),
+            ]
+        );
+    }
+}
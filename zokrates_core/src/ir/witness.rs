@@ -1,12 +1,13 @@
 use crate::flat_absy::FlatVariable;
-use std::collections::{BTreeMap, HashMap};
+use crate::ir::VariableMap;
+use std::collections::HashMap;
 use std::fmt;
 use std::io;
 use std::io::{Read, Write};
 use zokrates_field::Field;
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct Witness<T>(pub BTreeMap<FlatVariable, T>);
+pub struct Witness<T>(pub VariableMap<T>);
 
 impl<T: Field> Witness<T> {
     pub fn return_values(&self) -> Vec<T> {
@@ -34,7 +35,7 @@ impl<T: Field> Witness<T> {
     }
 
     pub fn empty() -> Self {
-        Witness(BTreeMap::new())
+        Witness(VariableMap::new())
     }
 
     pub fn write<W: Write>(&self, writer: W) -> io::Result<()> {
@@ -45,7 +46,7 @@ impl<T: Field> Witness<T> {
             .from_writer(writer);
 
         // Write each line of the witness to the file
-        for (variable, value) in &self.0 {
+        for (variable, value) in self.0.iter() {
             wtr.serialize((variable.to_string(), value.to_dec_string()))?;
         }
 
@@ -83,7 +84,7 @@ impl<T: Field> Witness<T> {
                     e => io::Error::new(io::ErrorKind::Other, format!("{:?}", e)),
                 })?
             })
-            .collect::<io::Result<BTreeMap<FlatVariable, T>>>()?;
+            .collect::<io::Result<VariableMap<T>>>()?;
 
         Ok(Witness(map))
     }
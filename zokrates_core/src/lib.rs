@@ -48,5 +48,9 @@ pub mod absy;
 pub mod compile;
 pub mod flat_absy;
 pub mod ir;
+pub mod profile;
 pub mod proof_system;
+pub mod source_map;
 pub mod typed_absy;
+pub mod witness_calculator;
+pub mod witness_generator;
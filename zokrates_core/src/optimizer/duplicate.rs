@@ -34,6 +34,11 @@ impl DuplicateOptimizer {
 
 impl<T: Field> Folder<T> for DuplicateOptimizer {
     fn fold_statement(&mut self, s: Statement<T>) -> Vec<Statement<T>> {
+        // logs have no effect on the constraint system, but each of them should be printed
+        if let Statement::Log(..) = s {
+            return vec![s];
+        }
+
         let hashed = hash(&s);
         let result = match self.seen.get(&hashed) {
             Some(_) => vec![],
@@ -64,6 +69,7 @@ mod tests {
                             LinComb::summand(3, FlatVariable::new(3)),
                         ),
                         LinComb::one(),
+                        None,
                     ),
                     Statement::Constraint(
                         QuadComb::from_linear_combinations(
@@ -71,10 +77,12 @@ mod tests {
                             LinComb::summand(3, FlatVariable::new(3)),
                         ),
                         LinComb::zero(),
+                        None,
                     ),
                 ],
                 returns: vec![],
                 arguments: vec![],
+                source_map: Default::default(),
             },
         };
 
@@ -91,6 +99,7 @@ mod tests {
                 LinComb::summand(3, FlatVariable::new(3)),
             ),
             LinComb::one(),
+            None,
         );
 
         let p: Prog<Bn128Field> = Prog {
@@ -106,12 +115,14 @@ mod tests {
                             LinComb::summand(3, FlatVariable::new(3)),
                         ),
                         LinComb::zero(),
+                        None,
                     ),
                     constraint.clone(),
                     constraint.clone(),
                 ],
                 returns: vec![],
                 arguments: vec![],
+                source_map: Default::default(),
             },
         };
 
@@ -127,10 +138,12 @@ mod tests {
                             LinComb::summand(3, FlatVariable::new(3)),
                         ),
                         LinComb::zero(),
+                        None,
                     ),
                 ],
                 returns: vec![],
                 arguments: vec![],
+                source_map: Default::default(),
             },
         };
 
@@ -7,11 +7,13 @@
 mod directive;
 mod duplicate;
 mod redefinition;
+mod renumber;
 mod tautology;
 
 use self::directive::DirectiveOptimizer;
 use self::duplicate::DuplicateOptimizer;
 use self::redefinition::RedefinitionOptimizer;
+use self::renumber::Renumberer;
 use self::tautology::TautologyOptimizer;
 
 use crate::ir::Prog;
@@ -27,6 +29,8 @@ impl<T: Field> Prog<T> {
         let r = DirectiveOptimizer::optimize(r);
         // remove duplicate constraints
         let r = DuplicateOptimizer::optimize(r);
+        // number the remaining variables densely
+        let r = Renumberer::renumber(r);
         r
     }
 }
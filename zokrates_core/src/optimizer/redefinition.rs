@@ -37,7 +37,7 @@
 //     - otherwise return `c_0`
 
 use crate::flat_absy::flat_variable::FlatVariable;
-use crate::ir::folder::{fold_function, Folder};
+use crate::ir::folder::{fold_function, fold_statement, Folder};
 use crate::ir::LinComb;
 use crate::ir::*;
 use std::collections::{HashMap, HashSet};
@@ -67,7 +67,7 @@ impl<T: Field> RedefinitionOptimizer<T> {
 impl<T: Field> Folder<T> for RedefinitionOptimizer<T> {
     fn fold_statement(&mut self, s: Statement<T>) -> Vec<Statement<T>> {
         match s {
-            Statement::Constraint(quad, lin) => {
+            Statement::Constraint(quad, lin, metadata) => {
                 let quad = self.fold_quadratic_combination(quad);
                 let lin = self.fold_linear_combination(lin);
 
@@ -112,7 +112,7 @@ impl<T: Field> Folder<T> for RedefinitionOptimizer<T> {
                 // decide whether the constraint should be kept
                 match keep_constraint {
                     false => vec![],
-                    true => vec![Statement::Constraint(quad, lin)],
+                    true => vec![Statement::Constraint(quad, lin, metadata)],
                 }
             }
             Statement::Directive(d) => {
@@ -142,15 +142,21 @@ impl<T: Field> Folder<T> for RedefinitionOptimizer<T> {
                     })
                     .collect::<Vec<Result<T, QuadComb<T>>>>();
 
-                match inputs.iter().all(|r| r.is_ok()) {
+                let outputs = match inputs.iter().all(|r| r.is_ok()) {
                     true => {
                         // unwrap inputs to their constant value
-                        let inputs = inputs.into_iter().map(|i| i.unwrap()).collect();
-                        // run the interpereter
-                        let outputs = Interpreter::default()
+                        let inputs = inputs.iter().map(|i| i.clone().unwrap()).collect();
+                        // run the interpereter, which fails if the directive is in a branch
+                        // which is never taken, for example when dividing by zero
+                        Interpreter::default()
                             .execute_solver(&d.solver, &inputs)
-                            .unwrap();
+                            .ok()
+                    }
+                    false => None,
+                };
 
+                match outputs {
+                    Some(outputs) => {
                         assert_eq!(outputs.len(), d.outputs.len());
 
                         // insert the results in the substitution
@@ -160,7 +166,7 @@ impl<T: Field> Folder<T> for RedefinitionOptimizer<T> {
                         }
                         vec![]
                     }
-                    false => {
+                    None => {
                         // reconstruct the input expressions
                         let inputs = inputs
                             .into_iter()
@@ -177,6 +183,7 @@ impl<T: Field> Folder<T> for RedefinitionOptimizer<T> {
                     }
                 }
             }
+            s => fold_statement(self, s),
         }
     }
 
@@ -244,6 +251,7 @@ mod tests {
             arguments: vec![x],
             statements: vec![Statement::definition(y, x), Statement::definition(z, y)],
             returns: vec![z.into()],
+            source_map: Default::default(),
         };
 
         let optimized: Function<Bn128Field> = Function {
@@ -251,6 +259,7 @@ mod tests {
             arguments: vec![x],
             statements: vec![Statement::definition(z, x)],
             returns: vec![z],
+            source_map: Default::default(),
         };
 
         let mut optimizer = RedefinitionOptimizer::new();
@@ -271,6 +280,7 @@ mod tests {
             arguments: vec![x],
             statements: vec![Statement::definition(one, x)],
             returns: vec![x.into()],
+            source_map: Default::default(),
         };
 
         let optimized = f.clone();
@@ -306,6 +316,7 @@ mod tests {
                 Statement::constraint(z, y),
             ],
             returns: vec![z.into()],
+            source_map: Default::default(),
         };
 
         let optimized: Function<Bn128Field> = Function {
@@ -313,6 +324,7 @@ mod tests {
             arguments: vec![x],
             statements: vec![Statement::definition(z, x), Statement::constraint(z, x)],
             returns: vec![z.into()],
+            source_map: Default::default(),
         };
 
         let mut optimizer = RedefinitionOptimizer::new();
@@ -349,6 +361,7 @@ mod tests {
                 Statement::definition(w, t),
             ],
             returns: vec![z, w],
+            source_map: Default::default(),
         };
 
         let optimized: Function<Bn128Field> = Function {
@@ -359,6 +372,7 @@ mod tests {
                 Statement::definition(w, Bn128Field::from(1)),
             ],
             returns: vec![z, w],
+            source_map: Default::default(),
         };
 
         let mut optimizer = RedefinitionOptimizer::new();
@@ -403,6 +417,7 @@ mod tests {
                 Statement::definition(r, LinComb::from(a) + LinComb::from(b) + LinComb::from(c)),
             ],
             returns: vec![r],
+            source_map: Default::default(),
         };
 
         let expected: Function<Bn128Field> = Function {
@@ -416,6 +431,7 @@ mod tests {
                 Statement::definition(r, LinComb::summand(6, x) + LinComb::summand(6, y)),
             ],
             returns: vec![r],
+            source_map: Default::default(),
         };
 
         let mut optimizer = RedefinitionOptimizer::new();
@@ -454,6 +470,7 @@ mod tests {
                 Statement::definition(z, LinComb::from(x)),
             ],
             returns: vec![],
+            source_map: Default::default(),
         };
 
         let optimized = f.clone();
@@ -483,6 +500,7 @@ mod tests {
                 Statement::constraint(x, Bn128Field::from(2)),
             ],
             returns: vec![x.into()],
+            source_map: Default::default(),
         };
 
         let optimized = f.clone();
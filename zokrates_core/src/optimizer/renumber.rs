@@ -0,0 +1,114 @@
+//! Module containing the `Renumberer` to number the private variables of a program densely
+//!
+//! Optimizations remove many of the variables introduced during flattening. The remaining private
+//! variables are renamed `_0`, `_1`, ... in order of appearance, so that a witness can be stored in
+//! a vector indexed by variable.
+
+use crate::flat_absy::flat_variable::FlatVariable;
+use crate::ir::folder::Folder;
+use crate::ir::*;
+use std::collections::HashMap;
+use zokrates_field::Field;
+
+#[derive(Debug)]
+pub struct Renumberer {
+    /// Map from the variables of the program to their new names
+    substitution: HashMap<FlatVariable, FlatVariable>,
+}
+
+impl Renumberer {
+    fn new() -> Self {
+        Renumberer {
+            substitution: HashMap::new(),
+        }
+    }
+
+    pub fn renumber<T: Field>(p: Prog<T>) -> Prog<T> {
+        Renumberer::new().fold_module(p)
+    }
+}
+
+impl<T: Field> Folder<T> for Renumberer {
+    fn fold_variable(&mut self, v: FlatVariable) -> FlatVariable {
+        // `~one` and the public outputs keep their names
+        if v == FlatVariable::one() || v.is_output() {
+            return v;
+        }
+
+        let next = FlatVariable::new(self.substitution.len());
+        *self.substitution.entry(v).or_insert(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zokrates_field::Bn128Field;
+
+    #[test]
+    fn renumber() {
+        // def main(_42) -> (1):
+        //     _7 = _42 * _42
+        //     ~out_0 = _7 * ~one
+
+        // ->
+
+        // def main(_0) -> (1):
+        //     _1 = _0 * _0
+        //     ~out_0 = _1 * ~one
+
+        let p: Prog<Bn128Field> = Prog {
+            private: vec![true],
+            main: Function {
+                id: "main".to_string(),
+                arguments: vec![FlatVariable::new(42)],
+                returns: vec![FlatVariable::public(0)],
+                statements: vec![
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            FlatVariable::new(42).into(),
+                            FlatVariable::new(42).into(),
+                        ),
+                        FlatVariable::new(7),
+                    ),
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            FlatVariable::new(7).into(),
+                            FlatVariable::one().into(),
+                        ),
+                        FlatVariable::public(0),
+                    ),
+                ],
+                source_map: Default::default(),
+            },
+        };
+
+        let expected: Prog<Bn128Field> = Prog {
+            private: vec![true],
+            main: Function {
+                id: "main".to_string(),
+                arguments: vec![FlatVariable::new(0)],
+                returns: vec![FlatVariable::public(0)],
+                statements: vec![
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            FlatVariable::new(0).into(),
+                            FlatVariable::new(0).into(),
+                        ),
+                        FlatVariable::new(1),
+                    ),
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            FlatVariable::new(1).into(),
+                            FlatVariable::one().into(),
+                        ),
+                        FlatVariable::public(0),
+                    ),
+                ],
+                source_map: Default::default(),
+            },
+        };
+
+        assert_eq!(Renumberer::renumber(p), expected);
+    }
+}
@@ -0,0 +1,250 @@
+//! Module attributing the constraints of a program to the source code they were compiled from
+//!
+//! @file profile.rs
+//! @date 2020
+
+use crate::ir::{Prog, Statement};
+use crate::source_map::SourceMap;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Write};
+use zokrates_field::Field;
+
+/// The name of the root frame, to which statements without a source location are attributed
+const MAIN: &str = "main";
+
+/// The number of constraints and directives attributed to a location
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Count {
+    pub constraints: usize,
+    pub directives: usize,
+}
+
+impl Count {
+    fn add<T>(&mut self, statement: &Statement<T>) {
+        match statement {
+            Statement::Constraint(..) => self.constraints += 1,
+            Statement::Directive(..) => self.directives += 1,
+            Statement::Log(..) => {}
+        }
+    }
+}
+
+/// A profile of a compiled program
+#[derive(Debug, Default, PartialEq)]
+pub struct Profile {
+    /// The statements of the whole program
+    pub total: Count,
+    /// The statements compiled from each source line, identified by file and line number
+    pub lines: BTreeMap<(String, usize), Count>,
+    /// The statements compiled from each function call site, including nested calls
+    pub calls: BTreeMap<String, Count>,
+    /// The statements compiled from each call stack, from `main` to the source line
+    pub stacks: BTreeMap<Vec<String>, Count>,
+}
+
+impl Profile {
+    /// Attribute the statements of `prog` to the source locations found in `source_map`
+    pub fn new<T: Field>(prog: &Prog<T>, source_map: &SourceMap) -> Self {
+        let mut profile = Profile::default();
+
+        for (index, statement) in prog.main.statements.iter().enumerate() {
+            profile.total.add(statement);
+
+            let mut stack = vec![String::from(MAIN)];
+
+            if let Some(span) = source_map.get(index) {
+                for call in &span.stack {
+                    let call = call.to_string();
+                    profile
+                        .calls
+                        .entry(call.clone())
+                        .or_default()
+                        .add(statement);
+                    stack.push(call);
+                }
+
+                profile
+                    .lines
+                    .entry((span.file.clone(), span.from.line))
+                    .or_default()
+                    .add(statement);
+                stack.push(format!("{}:{}", span.file, span.from.line));
+            }
+
+            profile.stacks.entry(stack).or_default().add(statement);
+        }
+
+        profile
+    }
+
+    /// Write the constraint counts of each call stack in the folded format used by flamegraph tools
+    pub fn write_folded<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for (stack, count) in self.stacks.iter().filter(|(_, c)| c.constraints > 0) {
+            writeln!(writer, "{} {}", stack.join(";"), count.constraints)?;
+        }
+        Ok(())
+    }
+}
+
+/// Write a table of `rows`, sorted by decreasing number of constraints
+fn write_table<'a, I: Iterator<Item = (String, &'a Count)>>(
+    f: &mut fmt::Formatter,
+    title: &str,
+    rows: I,
+) -> fmt::Result {
+    let mut rows: Vec<_> = rows.collect();
+    rows.sort_by(|(_, a), (_, b)| b.constraints.cmp(&a.constraints));
+
+    writeln!(f, "{:>12} {:>12}  {}", "constraints", "directives", title)?;
+    for (name, count) in rows {
+        writeln!(
+            f,
+            "{:>12} {:>12}  {}",
+            count.constraints, count.directives, name
+        )?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_table(
+            f,
+            "call site",
+            self.calls.iter().map(|(call, count)| (call.clone(), count)),
+        )?;
+        writeln!(f)?;
+        write_table(
+            f,
+            "line",
+            self.lines
+                .iter()
+                .map(|((file, line), count)| (format!("{}:{}", file, line), count)),
+        )?;
+        writeln!(f)?;
+        write!(
+            f,
+            "Total: {} constraints, {} directives",
+            self.total.constraints, self.total.directives
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flat_absy::FlatVariable;
+    use crate::ir::{Directive, Function, QuadComb};
+    use crate::parser::Position;
+    use crate::solvers::Solver;
+    use crate::source_map::{Call, SourceSpan};
+    use zokrates_field::Bn128Field;
+
+    fn span(line: usize, stack: Vec<Call>) -> SourceSpan {
+        SourceSpan {
+            file: String::from("./main.zok"),
+            from: Position { line, col: 5 },
+            to: Position { line, col: 17 },
+            stack,
+        }
+    }
+
+    fn constraint() -> Statement<Bn128Field> {
+        Statement::Constraint(
+            QuadComb::from_linear_combinations(
+                FlatVariable::new(0).into(),
+                FlatVariable::new(0).into(),
+            ),
+            FlatVariable::new(0).into(),
+            None,
+        )
+    }
+
+    fn directive() -> Statement<Bn128Field> {
+        Statement::Directive(Directive {
+            inputs: vec![FlatVariable::new(0).into()],
+            outputs: vec![FlatVariable::new(1)],
+            solver: Solver::Bits(1),
+        })
+    }
+
+    fn profile() -> Profile {
+        // def main(field a):
+        //     field b = foo(a)
+        //     return
+        //
+        // def foo(field a) -> field:
+        //     assert(a * a == a)
+        //     return a
+        let call = Call {
+            function: String::from("foo"),
+            site: span(2, vec![]),
+        };
+
+        let prog = Prog {
+            main: Function {
+                id: String::from("main"),
+                statements: vec![constraint(), directive(), constraint(), constraint()],
+                arguments: vec![FlatVariable::new(0)],
+                returns: vec![],
+                source_map: SourceMap::default(),
+            },
+            private: vec![true],
+        };
+
+        let source_map = SourceMap(vec![
+            None,
+            Some(span(6, vec![call.clone()])),
+            Some(span(6, vec![call])),
+            Some(span(2, vec![])),
+        ]);
+
+        Profile::new(&prog, &source_map)
+    }
+
+    #[test]
+    fn counts() {
+        let profile = profile();
+
+        assert_eq!(
+            profile.total,
+            Count {
+                constraints: 3,
+                directives: 1
+            }
+        );
+        assert_eq!(
+            profile.calls.get("foo (./main.zok:2:5)"),
+            Some(&Count {
+                constraints: 1,
+                directives: 1
+            })
+        );
+        assert_eq!(
+            profile.lines.get(&(String::from("./main.zok"), 2)),
+            Some(&Count {
+                constraints: 1,
+                directives: 0
+            })
+        );
+        assert_eq!(
+            profile.lines.get(&(String::from("./main.zok"), 6)),
+            Some(&Count {
+                constraints: 1,
+                directives: 1
+            })
+        );
+    }
+
+    #[test]
+    fn folded() {
+        let mut folded = vec![];
+        profile().write_folded(&mut folded).unwrap();
+
+        assert_eq!(
+            String::from_utf8(folded).unwrap(),
+            "main 1\nmain;./main.zok:2 1\nmain;foo (./main.zok:2:5);./main.zok:6 1\n"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 pub mod groth16;
 
-use crate::ir::{CanonicalLinComb, Prog, Statement, Witness};
+use crate::ir::{CanonicalLinComb, Prog, Statement, VariableMap, Witness};
 use bellman::groth16::Proof;
 use bellman::groth16::{
     create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
@@ -8,7 +8,6 @@ use bellman::groth16::{
 };
 use bellman::pairing::ff::ScalarEngine;
 use bellman::{Circuit, ConstraintSystem, LinearCombination, SynthesisError, Variable};
-use std::collections::BTreeMap;
 use zokrates_field::BellmanFieldExtensions;
 use zokrates_field::Field;
 
@@ -44,7 +43,7 @@ impl<T: Field> Computation<T> {
 fn bellman_combination<T: BellmanFieldExtensions, CS: ConstraintSystem<T::BellmanEngine>>(
     l: CanonicalLinComb<T>,
     cs: &mut CS,
-    symbols: &mut BTreeMap<FlatVariable, Variable>,
+    symbols: &mut VariableMap<Variable>,
     witness: &mut Witness<T>,
 ) -> LinearCombination<T::BellmanEngine> {
     l.0.into_iter()
@@ -52,8 +51,7 @@ fn bellman_combination<T: BellmanFieldExtensions, CS: ConstraintSystem<T::Bellma
             (
                 v.into_bellman(),
                 symbols
-                    .entry(k)
-                    .or_insert_with(|| {
+                    .get_or_insert_with(k, || {
                         match k.is_output() {
                             true => cs.alloc_input(
                                 || format!("{}", k),
@@ -91,7 +89,7 @@ impl<T: BellmanFieldExtensions + Field> Prog<T> {
         witness: Option<Witness<T>>,
     ) -> Result<(), SynthesisError> {
         // mapping from IR variables
-        let mut symbols = BTreeMap::new();
+        let mut symbols = VariableMap::new();
 
         let mut witness = witness.unwrap_or(Witness::empty());
 
@@ -135,7 +133,7 @@ impl<T: BellmanFieldExtensions + Field> Prog<T> {
 
         for statement in main.statements {
             match statement {
-                Statement::Constraint(quad, lin) => {
+                Statement::Constraint(quad, lin, _) => {
                     let a = &bellman_combination(
                         quad.left.into_canonical(),
                         cs,
@@ -275,6 +273,7 @@ mod tests {
                     arguments: vec![],
                     returns: vec![],
                     statements: vec![],
+                    source_map: Default::default(),
                 },
                 private: vec![],
             };
@@ -298,7 +297,9 @@ mod tests {
                     statements: vec![Statement::Constraint(
                         FlatVariable::new(0).into(),
                         FlatVariable::public(0).into(),
+                        None,
                     )],
+                    source_map: Default::default(),
                 },
                 private: vec![true],
             };
@@ -325,7 +326,9 @@ mod tests {
                     statements: vec![Statement::Constraint(
                         FlatVariable::new(0).into(),
                         FlatVariable::public(0).into(),
+                        None,
                     )],
+                    source_map: Default::default(),
                 },
                 private: vec![false],
             };
@@ -352,7 +355,9 @@ mod tests {
                     statements: vec![Statement::Constraint(
                         FlatVariable::one().into(),
                         FlatVariable::public(0).into(),
+                        None,
                     )],
+                    source_map: Default::default(),
                 },
                 private: vec![],
             };
@@ -381,14 +386,17 @@ mod tests {
                                 + LinComb::from(FlatVariable::new(51)))
                             .into(),
                             FlatVariable::public(0).into(),
+                            None,
                         ),
                         Statement::Constraint(
                             (LinComb::from(FlatVariable::one())
                                 + LinComb::from(FlatVariable::new(42)))
                             .into(),
                             FlatVariable::public(1).into(),
+                            None,
                         ),
                     ],
+                    source_map: Default::default(),
                 },
                 private: vec![true, false],
             };
@@ -414,7 +422,9 @@ mod tests {
                     statements: vec![Statement::Constraint(
                         (LinComb::from(FlatVariable::new(42)) + LinComb::one()).into(),
                         FlatVariable::public(0).into(),
+                        None,
                     )],
+                    source_map: Default::default(),
                 },
                 private: vec![false],
             };
@@ -443,7 +453,9 @@ mod tests {
                             + LinComb::from(FlatVariable::new(51)))
                         .into(),
                         FlatVariable::public(0).into(),
+                        None,
                     )],
+                    source_map: Default::default(),
                 },
                 private: vec![true, false],
             };
@@ -250,8 +250,8 @@ pub fn r1cs_program<T: Field>(
 
     // first pass through statements to populate `variables`
     for (quad, lin) in main.statements.iter().filter_map(|s| match s {
-        Statement::Constraint(quad, lin) => Some((quad, lin)),
-        Statement::Directive(..) => None,
+        Statement::Constraint(quad, lin, _) => Some((quad, lin)),
+        _ => None,
     }) {
         for (k, _) in &quad.left.0 {
             provide_variable_idx(&mut variables, &k);
@@ -270,8 +270,8 @@ pub fn r1cs_program<T: Field>(
 
     // second pass to convert program to raw sparse vectors
     for (quad, lin) in main.statements.into_iter().filter_map(|s| match s {
-        Statement::Constraint(quad, lin) => Some((quad, lin)),
-        Statement::Directive(..) => None,
+        Statement::Constraint(quad, lin, _) => Some((quad, lin)),
+        _ => None,
     }) {
         a.push(
             quad.left
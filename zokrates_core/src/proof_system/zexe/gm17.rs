@@ -266,7 +266,9 @@ mod tests {
                 statements: vec![Statement::Constraint(
                     FlatVariable::new(0).into(),
                     FlatVariable::public(0).into(),
+                    None,
                 )],
+                source_map: Default::default(),
             },
             private: vec![false],
         };
@@ -295,7 +297,9 @@ mod tests {
                 statements: vec![Statement::Constraint(
                     FlatVariable::new(0).into(),
                     FlatVariable::public(0).into(),
+                    None,
                 )],
+                source_map: Default::default(),
             },
             private: vec![false],
         };
@@ -1,6 +1,6 @@
 pub mod gm17;
 
-use crate::ir::{CanonicalLinComb, Prog, Statement, Witness};
+use crate::ir::{CanonicalLinComb, Prog, Statement, VariableMap, Witness};
 use zexe_gm17::Proof;
 use zexe_gm17::{
     create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
@@ -13,7 +13,6 @@ use r1cs_core::{
     ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, LinearCombination,
     SynthesisError, Variable,
 };
-use std::collections::BTreeMap;
 use zokrates_field::{Field, ZexeFieldExtensions};
 
 pub use self::parse::*;
@@ -47,7 +46,7 @@ impl<T: Field> Computation<T> {
 fn zexe_combination<T: Field + ZexeFieldExtensions>(
     l: CanonicalLinComb<T>,
     cs: &mut ConstraintSystem<<<T as ZexeFieldExtensions>::ZexeEngine as PairingEngine>::Fr>,
-    symbols: &mut BTreeMap<FlatVariable, Variable>,
+    symbols: &mut VariableMap<Variable>,
     witness: &mut Witness<T>,
 ) -> Result<
     LinearCombination<<<T as ZexeFieldExtensions>::ZexeEngine as PairingEngine>::Fr>,
@@ -59,8 +58,7 @@ fn zexe_combination<T: Field + ZexeFieldExtensions>(
                 (
                     v.into_zexe(),
                     symbols
-                        .entry(k)
-                        .or_insert_with(|| {
+                        .get_or_insert_with(k, || {
                             match k.is_output() {
                                 true => cs.new_input_variable(|| {
                                     Ok(witness
@@ -94,7 +92,7 @@ impl<T: Field + ZexeFieldExtensions> Prog<T> {
         witness: Option<Witness<T>>,
     ) -> Result<(), SynthesisError> {
         // mapping from IR variables
-        let mut symbols = BTreeMap::new();
+        let mut symbols = VariableMap::new();
 
         let mut witness = witness.unwrap_or(Witness::empty());
 
@@ -135,7 +133,7 @@ impl<T: Field + ZexeFieldExtensions> Prog<T> {
 
                 for statement in main.statements {
                     match statement {
-                        Statement::Constraint(quad, lin) => {
+                        Statement::Constraint(quad, lin, _) => {
                             let a = zexe_combination(
                                 quad.left.clone().into_canonical(),
                                 &mut cs,
@@ -8,15 +8,19 @@ use crate::absy::Identifier;
 use crate::absy::*;
 use crate::typed_absy::*;
 use crate::typed_absy::{Parameter, Variable};
+use num::ToPrimitive;
+use num_bigint::BigInt;
 use std::collections::{hash_map::Entry, BTreeSet, HashMap, HashSet};
 use std::fmt;
 use std::path::PathBuf;
+use std::rc::Rc;
 use zokrates_field::Field;
 
 use crate::parser::Position;
+use crate::static_analysis::Propagator;
 
-use crate::absy::types::{UnresolvedSignature, UnresolvedType, UserTypeId};
-use crate::typed_absy::types::{FunctionKey, Signature, StructLocation, Type};
+use crate::absy::types::{ArraySize, UnresolvedSignature, UnresolvedType, UserTypeId};
+use crate::typed_absy::types::{FunctionKey, Signature, StructLocation, TupleType, Type};
 
 use std::hash::{Hash, Hasher};
 use typed_absy::types::{ArrayType, StructMember};
@@ -44,6 +48,84 @@ impl ErrorInner {
 
 type TypeMap = HashMap<ModuleId, HashMap<UserTypeId, Type>>;
 
+type TemplateMap<'ast> = HashMap<Identifier<'ast>, Vec<FunctionTemplate<'ast>>>;
+
+type ConstantMap<'ast> = HashMap<Identifier<'ast>, Constant<'ast>>;
+
+type StructTemplateMap<'ast> = HashMap<UserTypeId, StructTemplate<'ast>>;
+
+/// A module-level constant, whose expression gets checked again wherever the constant is used
+#[derive(Clone, Debug)]
+struct Constant<'ast> {
+    /// The module the constant is declared in
+    module_id: ModuleId,
+    expression: ExpressionNode<'ast>,
+    /// The constants in scope at the declaration of the constant
+    constants: Rc<ConstantMap<'ast>>,
+    /// The value of the constant, if it can be used as an array size
+    size: Option<usize>,
+}
+
+/// A generic function, which gets checked once for each set of generic values it is called with
+#[derive(Clone, Debug)]
+struct FunctionTemplate<'ast> {
+    /// The module the function is declared in
+    module_id: ModuleId,
+    /// The id of the function in that module
+    id: Identifier<'ast>,
+    function: FunctionNode<'ast>,
+    /// The functions in scope at the declaration of the function
+    functions: HashSet<FunctionKey<'ast>>,
+    /// The generic functions in scope at the declaration of the function
+    templates: TemplateMap<'ast>,
+    /// The constants in scope at the declaration of the function
+    constants: ConstantMap<'ast>,
+    /// The generic structs in scope at the declaration of the function
+    structs: StructTemplateMap<'ast>,
+}
+
+/// A generic struct, which gets checked once for each set of generic values it is used with
+#[derive(Clone, Debug)]
+struct StructTemplate<'ast> {
+    /// The module the struct is declared in
+    module_id: ModuleId,
+    /// The id of the struct in that module
+    id: Identifier<'ast>,
+    definition: StructDefinitionNode<'ast>,
+    /// The generic structs in scope at the declaration of the struct
+    structs: StructTemplateMap<'ast>,
+    /// The constants in scope at the declaration of the struct
+    constants: ConstantMap<'ast>,
+}
+
+/// The generic parameters which can be inferred when unifying a declared type with a concrete one
+struct GenericScope<'ast, 'a> {
+    generics: &'a [Identifier<'ast>],
+    /// The module the type is declared in
+    module_id: &'a ModuleId,
+    /// The generic structs in scope at the declaration of the type
+    structs: &'a StructTemplateMap<'ast>,
+}
+
+/// A call to a generic function for which an instance needs to be checked
+#[derive(Debug)]
+struct Instantiation<'ast> {
+    template: FunctionTemplate<'ast>,
+    /// The values of the generic parameters
+    generics: HashMap<Identifier<'ast>, usize>,
+    /// The key of the instance in the module of the template
+    key: FunctionKey<'ast>,
+    /// The module the call happens in, and the key of the instance there
+    call_site: (ModuleId, FunctionKey<'ast>),
+}
+
+/// A function declared in an `impl` block, which gets imported along with its struct
+#[derive(Clone, Debug)]
+enum Method<'ast> {
+    Function(FunctionKey<'ast>),
+    Template(FunctionTemplate<'ast>),
+}
+
 /// The global state of the program during semantic checks
 #[derive(Debug)]
 struct State<'ast, T: Field> {
@@ -53,13 +135,27 @@ struct State<'ast, T: Field> {
     typed_modules: TypedModules<'ast, T>,
     /// The user-defined types, which we keep track at this phase only. In later phases, we rely only on basic types and combinations thereof
     types: TypeMap,
+    /// The generic functions, which are only kept as templates and instantiated when called
+    templates: HashMap<ModuleId, TemplateMap<'ast>>,
+    /// The constants, which are inlined wherever they are used
+    constants: HashMap<ModuleId, ConstantMap<'ast>>,
+    /// The generic structs, which are only kept as templates and instantiated when used
+    structs: HashMap<ModuleId, StructTemplateMap<'ast>>,
+    /// The exported methods of the structs, which are imported along with them
+    methods: HashMap<ModuleId, HashMap<UserTypeId, Vec<Method<'ast>>>>,
+    /// The functions which are not exported, which cannot be imported from other modules
+    private: HashMap<ModuleId, HashMap<Identifier<'ast>, (Position, Position)>>,
+    /// The functions called so far, identified by the module they are called from
+    calls: HashSet<(ModuleId, Identifier<'ast>)>,
 }
 
-/// A symbol for a given name: either a type or a group of functions. Not both!
+/// A symbol for a given name: either a type, a constant or a group of functions. Not several!
 #[derive(PartialEq, Hash, Eq, Debug)]
 enum SymbolType {
     Type,
+    Constant,
     Functions(BTreeSet<Signature>),
+    GenericFunctions,
 }
 
 /// A data structure to keep track of all symbols in a module
@@ -82,14 +178,27 @@ impl SymbolUnifier {
         }
     }
 
+    fn insert_constant<S: Into<String>>(&mut self, id: S) -> bool {
+        let s_type = self.symbols.entry(id.into());
+        match s_type {
+            // if anything is already called `id`, we cannot introduce this constant
+            Entry::Occupied(..) => false,
+            // otherwise, we can!
+            Entry::Vacant(v) => {
+                v.insert(SymbolType::Constant);
+                true
+            }
+        }
+    }
+
     fn insert_function<S: Into<String>>(&mut self, id: S, signature: Signature) -> bool {
         let s_type = self.symbols.entry(id.into());
         match s_type {
             // if anything is already called `id`, it depends what it is
             Entry::Occupied(mut o) => {
                 match o.get_mut() {
-                    // if it's a Type, then we can't introduce a function
-                    SymbolType::Type => false,
+                    // if it's a Type, a Constant or a generic function, then we can't introduce a function
+                    SymbolType::Type | SymbolType::Constant | SymbolType::GenericFunctions => false,
                     // if it's a Function, we can introduce a new function only if it has a different signature
                     SymbolType::Functions(signatures) => signatures.insert(signature),
                 }
@@ -101,6 +210,21 @@ impl SymbolUnifier {
             }
         }
     }
+
+    fn insert_generic_function<S: Into<String>>(&mut self, id: S) -> bool {
+        let s_type = self.symbols.entry(id.into());
+        match s_type {
+            // generic functions can only be overloaded with other generic functions
+            Entry::Occupied(o) => match o.get() {
+                SymbolType::GenericFunctions => true,
+                _ => false,
+            },
+            Entry::Vacant(v) => {
+                v.insert(SymbolType::GenericFunctions);
+                true
+            }
+        }
+    }
 }
 
 impl<'ast, T: Field> State<'ast, T> {
@@ -109,6 +233,12 @@ impl<'ast, T: Field> State<'ast, T> {
             modules,
             typed_modules: HashMap::new(),
             types: HashMap::new(),
+            templates: HashMap::new(),
+            constants: HashMap::new(),
+            structs: HashMap::new(),
+            methods: HashMap::new(),
+            private: HashMap::new(),
+            calls: HashSet::new(),
         }
     }
 }
@@ -226,7 +356,20 @@ impl<'ast> Eq for ScopedVariable<'ast> {}
 pub struct Checker<'ast> {
     scope: HashSet<ScopedVariable<'ast>>,
     functions: HashSet<FunctionKey<'ast>>,
+    templates: TemplateMap<'ast>,
+    /// The constants in scope
+    constants: ConstantMap<'ast>,
+    /// The generic structs in scope
+    structs: StructTemplateMap<'ast>,
+    /// The values of the generic parameters of the function being checked
+    generics: HashMap<Identifier<'ast>, usize>,
+    /// The generic function instances required by the calls checked so far
+    instantiations: Vec<Instantiation<'ast>>,
+    /// The functions called so far, identified by the module they are called from
+    calls: HashSet<(ModuleId, Identifier<'ast>)>,
     level: usize,
+    /// Whether to mark checked statements with their location in the source code
+    source_spans: bool,
 }
 
 impl<'ast> Checker<'ast> {
@@ -234,7 +377,14 @@ impl<'ast> Checker<'ast> {
         Checker {
             scope: HashSet::new(),
             functions: HashSet::new(),
+            templates: HashMap::new(),
+            constants: HashMap::new(),
+            structs: HashMap::new(),
+            generics: HashMap::new(),
+            instantiations: vec![],
+            calls: HashSet::new(),
             level: 0,
+            source_spans: false,
         }
     }
 
@@ -243,14 +393,21 @@ impl<'ast> Checker<'ast> {
     /// # Arguments
     ///
     /// * `prog` - The `Program` to be checked
-    pub fn check<T: Field>(prog: Program<'ast>) -> Result<TypedProgram<'ast, T>, Vec<Error>> {
-        Checker::new().check_program(prog)
+    /// Check `prog`, returning the typed program along with warnings about it
+    pub fn check<T: Field>(
+        prog: Program<'ast>,
+    ) -> Result<(TypedProgram<'ast, T>, Vec<Error>), Vec<Error>> {
+        Checker {
+            source_spans: true,
+            ..Checker::new()
+        }
+        .check_program(prog)
     }
 
     fn check_program<T: Field>(
         &mut self,
         program: Program<'ast>,
-    ) -> Result<TypedProgram<'ast, T>, Vec<Error>> {
+    ) -> Result<(TypedProgram<'ast, T>, Vec<Error>), Vec<Error>> {
         let mut state = State::new(program.modules);
 
         let mut errors = vec![];
@@ -276,10 +433,37 @@ impl<'ast> Checker<'ast> {
             },
         )?;
 
-        Ok(TypedProgram {
-            main: program.main,
-            modules: state.typed_modules,
-        })
+        // warn about the private functions which are never called
+        let mut warnings = vec![];
+
+        for (module_id, private) in &state.private {
+            for (id, pos) in private {
+                if !state.calls.contains(&(module_id.clone(), *id)) {
+                    warnings.push(
+                        ErrorInner {
+                            pos: Some(*pos),
+                            message: format!("Private function {} is never called", id),
+                        }
+                        .in_file(module_id),
+                    );
+                }
+            }
+        }
+
+        warnings.sort_by_key(|w| {
+            (
+                w.module_id.clone(),
+                w.inner.pos.map(|(from, _)| (from.line, from.col)),
+            )
+        });
+
+        Ok((
+            TypedProgram {
+                main: program.main,
+                modules: state.typed_modules,
+            },
+            warnings,
+        ))
     }
 
     fn check_struct_type_declaration(
@@ -297,9 +481,11 @@ impl<'ast> Checker<'ast> {
         let mut fields_set = HashSet::new();
 
         for field in s.fields {
+            let field_pos = field.pos();
             let member_id = field.value.id.to_string();
             match self
                 .check_type(field.value.ty, module_id, &types)
+                .and_then(|t| Checker::check_not_vector(t, field_pos))
                 .map(|t| (member_id, t))
             {
                 Ok(f) => match fields_set.insert(f.0.clone()) {
@@ -329,6 +515,66 @@ impl<'ast> Checker<'ast> {
         )))
     }
 
+    fn check_constant_definition<T: Field>(
+        &self,
+        c: ConstantDefinitionNode<'ast>,
+        module_id: &ModuleId,
+        types: &TypeMap,
+    ) -> Result<Constant<'ast>, ErrorInner> {
+        let pos = c.pos();
+        let c = c.value;
+
+        let ty = self.check_type(c.ty, module_id, types)?;
+
+        let mut constant = Constant {
+            module_id: module_id.clone(),
+            expression: c.expression,
+            constants: Rc::new(self.constants.clone()),
+            size: None,
+        };
+
+        let checked: TypedExpression<T> = Checker::check_constant(&constant, types)?;
+
+        if checked.get_type() != ty {
+            return Err(ErrorInner {
+                pos: Some(pos),
+                message: format!(
+                    "Expression {} of type {} cannot be assigned to constant of type {}",
+                    checked,
+                    checked.get_type(),
+                    ty
+                ),
+            });
+        }
+
+        // constants which evaluate to a literal can be used as array sizes
+        constant.size = match Propagator::propagate_expression(checked) {
+            TypedExpression::FieldElement(FieldElementExpression::Number(n)) => {
+                n.to_dec_string().parse().ok()
+            }
+            TypedExpression::Uint(e) => match e.as_inner() {
+                UExpressionInner::Value(v) => Some(*v as usize),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        Ok(constant)
+    }
+
+    /// Check the expression of a constant in the scope of its declaration
+    fn check_constant<T: Field>(
+        constant: &Constant<'ast>,
+        types: &TypeMap,
+    ) -> Result<TypedExpression<'ast, T>, ErrorInner> {
+        let mut checker = Checker {
+            constants: (*constant.constants).clone(),
+            ..Checker::new()
+        };
+
+        checker.check_expression(constant.expression.clone(), &constant.module_id, types)
+    }
+
     fn check_symbol_declaration<T: Field>(
         &mut self,
         declaration: SymbolDeclarationNode<'ast>,
@@ -344,12 +590,52 @@ impl<'ast> Checker<'ast> {
 
         match declaration.symbol.clone() {
             Symbol::HereType(t) => {
-                match self.check_struct_type_declaration(
+                // the members of a generic struct are checked once here for arbitrary values of its generic parameters
+                let mut checker = Checker {
+                    constants: self.constants.clone(),
+                    structs: self.structs.clone(),
+                    generics: t.value.generics.iter().map(|g| (*g, 0)).collect(),
+                    ..Checker::new()
+                };
+
+                match checker.check_struct_type_declaration(
                     declaration.id.to_string(),
                     t.clone(),
                     module_id,
                     &state.types,
                 ) {
+                    Ok(_) if !t.value.generics.is_empty() => {
+                        match symbol_unifier.insert_type(declaration.id) {
+                            false => errors.push(
+                                ErrorInner {
+                                    pos: Some(pos),
+                                    message: format!(
+                                        "{} conflicts with another symbol",
+                                        declaration.id,
+                                    ),
+                                }
+                                .in_file(module_id),
+                            ),
+                            true => {}
+                        };
+
+                        // generic structs are instantiated when they are used, in the scope of their declaration
+                        let template = StructTemplate {
+                            module_id: module_id.clone(),
+                            id: declaration.id,
+                            definition: t,
+                            structs: self.structs.clone(),
+                            constants: self.constants.clone(),
+                        };
+
+                        self.structs
+                            .insert(declaration.id.to_string(), template.clone());
+                        state
+                            .structs
+                            .entry(module_id.clone())
+                            .or_default()
+                            .insert(declaration.id.to_string(), template);
+                    }
                     Ok(ty) => {
                         match symbol_unifier.insert_type(declaration.id) {
                             false => errors.push(
@@ -376,6 +662,100 @@ impl<'ast> Checker<'ast> {
                     })),
                 }
             }
+            Symbol::HereAlias(t) => match self.check_type(t, module_id, &state.types) {
+                Ok(ty) => {
+                    match symbol_unifier.insert_type(declaration.id) {
+                        false => errors.push(
+                            ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "{} conflicts with another symbol",
+                                    declaration.id,
+                                ),
+                            }
+                            .in_file(module_id),
+                        ),
+                        true => {}
+                    };
+                    state
+                        .types
+                        .entry(module_id.clone())
+                        .or_default()
+                        .insert(declaration.id.to_string(), ty);
+                }
+                Err(e) => errors.push(e.in_file(module_id)),
+            },
+            Symbol::HereConstant(c) => {
+                match self.check_constant_definition(c, module_id, &state.types) {
+                    Ok(constant) => {
+                        match symbol_unifier.insert_constant(declaration.id) {
+                            false => errors.push(
+                                ErrorInner {
+                                    pos: Some(pos),
+                                    message: format!(
+                                        "{} conflicts with another symbol",
+                                        declaration.id,
+                                    ),
+                                }
+                                .in_file(module_id),
+                            ),
+                            true => {}
+                        };
+                        self.constants.insert(declaration.id, constant.clone());
+                        state
+                            .constants
+                            .entry(module_id.clone())
+                            .or_default()
+                            .insert(declaration.id, constant);
+                    }
+                    Err(e) => errors.push(e.in_file(module_id)),
+                }
+            }
+            Symbol::HereFunction(f) if f.value.generics.len() > 0 => {
+                match self.check_generics(&f) {
+                    Ok(()) => {
+                        match symbol_unifier.insert_generic_function(declaration.id) {
+                            false => errors.push(
+                                ErrorInner {
+                                    pos: Some(pos),
+                                    message: format!(
+                                        "{} conflicts with another symbol",
+                                        declaration.id,
+                                    ),
+                                }
+                                .in_file(module_id),
+                            ),
+                            true => {}
+                        };
+
+                        // generic functions are checked when they are called, in the scope of their declaration
+                        let template = FunctionTemplate {
+                            module_id: module_id.clone(),
+                            id: declaration.id,
+                            function: f,
+                            functions: self.functions.clone(),
+                            templates: self.templates.clone(),
+                            constants: self.constants.clone(),
+                            structs: self.structs.clone(),
+                        };
+
+                        self.templates
+                            .entry(declaration.id)
+                            .or_default()
+                            .push(template.clone());
+                        state
+                            .templates
+                            .entry(module_id.clone())
+                            .or_default()
+                            .entry(declaration.id)
+                            .or_default()
+                            .push(template);
+                    }
+                    Err(e) => {
+                        errors.extend(e.into_iter().map(|inner| inner.in_file(module_id)));
+                    }
+                }
+            }
             Symbol::HereFunction(f) => match self.check_function(f, module_id, &state.types) {
                 Ok(funct) => {
                     match symbol_unifier.insert_function(declaration.id, funct.signature.clone()) {
@@ -401,30 +781,141 @@ impl<'ast> Checker<'ast> {
                             .signature(funct.signature.clone()),
                         TypedFunctionSymbol::Here(funct),
                     );
+
+                    // check the instances of generic functions this function calls
+                    match self.check_instantiations(module_id, state, functions) {
+                        Ok(()) => {}
+                        Err(e) => errors.extend(e),
+                    }
                 }
                 Err(e) => {
                     errors.extend(e.into_iter().map(|inner| inner.in_file(module_id)));
                 }
             },
+            Symbol::HereImpl(i) => {
+                match state
+                    .types
+                    .get(module_id)
+                    .and_then(|types| types.get(declaration.id))
+                {
+                    // methods are namespaced by their struct, so they are declared along with it
+                    Some(Type::Struct(s))
+                        if s.canonical_location.module != *module_id
+                            || s.canonical_location.name != declaration.id =>
+                    {
+                        errors.push(
+                            ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Cannot implement methods for {}, which is not declared in this module",
+                                    declaration.id
+                                ),
+                            }
+                            .in_file(module_id),
+                        )
+                    }
+                    Some(Type::Struct(..)) => {
+                        let mut methods = vec![];
+
+                        // methods are checked as ordinary functions, and only the exported ones get imported along with the struct
+                        for method in i.value.methods {
+                            let method_id = method.value.id;
+                            let function = match &method.value.symbol {
+                                Symbol::HereFunction(f) => f.clone(),
+                                _ => unreachable!("impl blocks only contain functions"),
+                            };
+
+                            match self.check_symbol_declaration(
+                                method,
+                                module_id,
+                                state,
+                                functions,
+                                symbol_unifier,
+                            ) {
+                                Ok(()) if function.value.exported => {
+                                    match function.value.generics.len() {
+                                        0 => {
+                                            let signature = self
+                                                .check_signature(
+                                                    function.value.signature,
+                                                    module_id,
+                                                    &state.types,
+                                                )
+                                                .unwrap();
+                                            methods.push(Method::Function(
+                                                FunctionKey::with_id(method_id)
+                                                    .signature(signature),
+                                            ));
+                                        }
+                                        _ => methods.push(Method::Template(
+                                            state.templates[module_id][method_id]
+                                                .last()
+                                                .unwrap()
+                                                .clone(),
+                                        )),
+                                    }
+                                }
+                                Ok(()) => {}
+                                Err(e) => errors.extend(e),
+                            }
+                        }
+
+                        state
+                            .methods
+                            .entry(module_id.clone())
+                            .or_default()
+                            .entry(declaration.id.to_string())
+                            .or_default()
+                            .extend(methods);
+                    }
+                    _ => errors.push(
+                        ErrorInner {
+                            pos: Some(pos),
+                            message: format!(
+                                "Cannot implement methods for {}, which is not a struct",
+                                declaration.id
+                            ),
+                        }
+                        .in_file(module_id),
+                    ),
+                }
+            }
             Symbol::There(import) => {
                 let pos = import.pos();
                 let import = import.value;
 
-                match Checker::new().check_module(&import.module_id, state) {
+                let mut checker = Checker {
+                    source_spans: self.source_spans,
+                    ..Checker::new()
+                };
+
+                match checker.check_module(&import.module_id, state) {
                     Ok(()) => {
-                        // find candidates in the checked module
-                        let function_candidates: Vec<_> = state
-                            .typed_modules
+                        // find generic candidates in the checked module
+                        let template_candidates: Vec<_> = state
+                            .templates
                             .get(&import.module_id)
-                            .unwrap()
-                            .functions
-                            .iter()
-                            .filter(|(k, _)| k.id == import.symbol_id)
-                            .map(|(_, v)| FunctionKey {
-                                id: import.symbol_id.clone(),
-                                signature: v.signature(&state.typed_modules).clone(),
-                            })
-                            .collect();
+                            .and_then(|templates| templates.get(import.symbol_id))
+                            .cloned()
+                            .unwrap_or_default();
+
+                        // find candidates in the checked module. If the symbol is generic, the functions with that id are
+                        // instances of it, which we do not import
+                        let function_candidates: Vec<_> = match template_candidates.len() {
+                            0 => state
+                                .typed_modules
+                                .get(&import.module_id)
+                                .unwrap()
+                                .functions
+                                .iter()
+                                .filter(|(k, _)| k.id == import.symbol_id)
+                                .map(|(_, v)| FunctionKey {
+                                    id: import.symbol_id.clone(),
+                                    signature: v.signature(&state.typed_modules).clone(),
+                                })
+                                .collect(),
+                            _ => vec![],
+                        };
 
                         // find candidates in the types
                         let type_candidate = state
@@ -434,10 +925,24 @@ impl<'ast> Checker<'ast> {
                             .get(import.symbol_id)
                             .cloned();
 
-                        match (function_candidates.len(), type_candidate) {
-                            (0, Some(t)) => {
+                        // find candidates in the constants
+                        let constant_candidate = state
+                            .constants
+                            .get(&import.module_id)
+                            .and_then(|constants| constants.get(import.symbol_id))
+                            .cloned();
+
+                        // find candidates in the generic structs
+                        let struct_candidate = state
+                            .structs
+                            .get(&import.module_id)
+                            .and_then(|structs| structs.get(import.symbol_id))
+                            .cloned();
+
+                        match (function_candidates.len() + template_candidates.len(), type_candidate, constant_candidate, struct_candidate) {
+                            (0, Some(t), None, None) => {
 
-                                // rename the type to the declared symbol
+                                // rename the type to the declared symbol. Aliases of other types are imported as the type they stand for
                                 let t = match t {
                                     Type::Struct(t) => Type::Struct(StructType {
                                         location: Some(StructLocation {
@@ -446,7 +951,7 @@ impl<'ast> Checker<'ast> {
                                         }),
                                         ..t
                                     }),
-                                    _ => unreachable!()
+                                    t => t
                                 };
 
                                 // we imported a type, so the symbol it gets bound to should not already exist
@@ -469,8 +974,95 @@ impl<'ast> Checker<'ast> {
                                     .entry(module_id.clone())
                                     .or_default()
                                     .insert(declaration.id.to_string(), t.clone());
+
+                                // the exported methods of a struct are imported along with it
+                                let methods = state.methods.get(&import.module_id).and_then(|methods| methods.get(import.symbol_id)).cloned().unwrap_or_default();
+
+                                for method in &methods {
+                                    match method {
+                                        Method::Function(key) => {
+                                            match symbol_unifier.insert_function(key.id, key.signature.clone()) {
+                                                false => {
+                                                    errors.push(ErrorInner {
+                                                        pos: Some(pos),
+                                                        message: format!(
+                                                            "{} conflicts with another symbol",
+                                                            key.id,
+                                                        ),
+                                                    }.in_file(module_id));
+                                                },
+                                                true => {}
+                                            };
+
+                                            self.functions.insert(key.clone());
+                                            functions.insert(
+                                                key.clone(),
+                                                TypedFunctionSymbol::There(
+                                                    key.clone(),
+                                                    import.module_id.clone(),
+                                                ),
+                                            );
+                                        }
+                                        Method::Template(template) => {
+                                            match symbol_unifier.insert_generic_function(template.id) {
+                                                false => {
+                                                    errors.push(ErrorInner {
+                                                        pos: Some(pos),
+                                                        message: format!(
+                                                            "{} conflicts with another symbol",
+                                                            template.id,
+                                                        ),
+                                                    }.in_file(module_id));
+                                                },
+                                                true => {}
+                                            };
+
+                                            self.templates.entry(template.id).or_default().push(template.clone());
+                                            state.templates.entry(module_id.clone()).or_default().entry(template.id).or_default().push(template.clone());
+                                        }
+                                    }
+                                }
+
+                                // keep track of the imported methods so that they can be imported from this module
+                                state.methods.entry(module_id.clone()).or_default().insert(declaration.id.to_string(), methods);
+                            }
+                            (0, None, None, Some(s)) => {
+                                // we imported a generic struct, so the symbol it gets bound to should not already exist
+                                match symbol_unifier.insert_type(declaration.id) {
+                                    false => {
+                                        errors.push(ErrorInner {
+                                            pos: Some(pos),
+                                            message: format!(
+                                                "{} conflicts with another symbol",
+                                                declaration.id,
+                                            ),
+                                        }.in_file(module_id));
+                                    }
+                                    true => {}
+                                };
+                                self.structs.insert(declaration.id.to_string(), s.clone());
+                                // keep track of the imported generic structs so that they can be imported from this module
+                                state.structs.entry(module_id.clone()).or_default().insert(declaration.id.to_string(), s);
+                            }
+                            (0, None, Some(c), None) => {
+                                // we imported a constant, so the symbol it gets bound to should not already exist
+                                match symbol_unifier.insert_constant(declaration.id) {
+                                    false => {
+                                        errors.push(ErrorInner {
+                                            pos: Some(pos),
+                                            message: format!(
+                                                "{} conflicts with another symbol",
+                                                declaration.id,
+                                            ),
+                                        }.in_file(module_id));
+                                    }
+                                    true => {}
+                                };
+                                self.constants.insert(declaration.id, c.clone());
+                                // keep track of the imported constants so that they can be imported from this module
+                                state.constants.entry(module_id.clone()).or_default().insert(declaration.id, c);
                             }
-                            (0, None) => {
+                            (0, None, None, None) => {
                                 errors.push(ErrorInner {
                                     pos: Some(pos),
                                     message: format!(
@@ -479,7 +1071,16 @@ impl<'ast> Checker<'ast> {
                                     ),
                                 }.in_file(module_id));
                             }
-                            (_, Some(_)) => unreachable!("collision in module we're importing from should have been caught when checking it"),
+                            (_, Some(_), _, _) | (_, _, Some(_), _) | (_, _, _, Some(_)) => unreachable!("collision in module we're importing from should have been caught when checking it"),
+                            _ if state.private.get(&import.module_id).map(|private| private.contains_key(import.symbol_id)).unwrap_or(false) => {
+                                errors.push(ErrorInner {
+                                    pos: Some(pos),
+                                    message: format!(
+                                        "Function {} is private in module {}",
+                                        import.symbol_id, import.module_id.display(),
+                                    ),
+                                }.in_file(module_id));
+                            }
                             _ => {
                                 for candidate in function_candidates {
 
@@ -505,6 +1106,25 @@ impl<'ast> Checker<'ast> {
                                         ),
                                     );
                                 }
+
+                                if template_candidates.len() > 0 {
+                                    match symbol_unifier.insert_generic_function(declaration.id) {
+                                        false => {
+                                            errors.push(ErrorInner {
+                                                pos: Some(pos),
+                                                message: format!(
+                                                    "{} conflicts with another symbol",
+                                                    declaration.id,
+                                                ),
+                                            }.in_file(module_id));
+                                        },
+                                        true => {}
+                                    };
+
+                                    self.templates.entry(declaration.id).or_default().extend(template_candidates.clone());
+                                    // keep track of the imported templates so that they can be imported from this module
+                                    state.templates.entry(module_id.clone()).or_default().entry(declaration.id).or_default().extend(template_candidates);
+                                }
                             }
                         };
                     }
@@ -572,6 +1192,35 @@ impl<'ast> Checker<'ast> {
                 // we keep track of the introduced symbols to avoid colisions between types and functions
                 let mut symbol_unifier = SymbolUnifier::default();
 
+                // the functions declared in the module, including the methods declared in `impl` blocks
+                let declared_functions: Vec<_> = module
+                    .symbols
+                    .iter()
+                    .flat_map(|s| match &s.value.symbol {
+                        Symbol::HereImpl(i) => i.value.methods.iter().collect(),
+                        _ => vec![s],
+                    })
+                    .filter_map(|s| match &s.value.symbol {
+                        Symbol::HereFunction(f) => Some((s.value.id, f)),
+                        _ => None,
+                    })
+                    .collect();
+
+                // functions are private unless at least one of their declarations is exported. `main` is always public
+                let exported: HashSet<_> = declared_functions
+                    .iter()
+                    .filter(|(_, f)| f.value.exported)
+                    .map(|(id, _)| *id)
+                    .collect();
+
+                let private = declared_functions
+                    .iter()
+                    .filter(|(id, _)| *id != "main" && !exported.contains(id))
+                    .map(|(id, f)| (*id, f.pos()))
+                    .collect();
+
+                state.private.insert(module_id.clone(), private);
+
                 // we go through symbol declarations and check them
                 for declaration in module.symbols {
                     match self.check_symbol_declaration(
@@ -586,6 +1235,8 @@ impl<'ast> Checker<'ast> {
                             errors.extend(e);
                         }
                     }
+
+                    state.calls.extend(self.calls.drain());
                 }
 
                 Some(TypedModule {
@@ -633,6 +1284,151 @@ impl<'ast> Checker<'ast> {
         }
     }
 
+    fn check_generics(&self, funct_node: &FunctionNode<'ast>) -> Result<(), Vec<ErrorInner>> {
+        let pos = funct_node.pos();
+        let funct = &funct_node.value;
+
+        let mut errors = vec![];
+        let mut generics_set = HashSet::new();
+
+        // generic parameters can only be inferred from the arguments of a call, so they must appear in the inputs
+        fn appears_in(g: &str, ty: &UnresolvedType) -> bool {
+            match ty {
+                UnresolvedType::Array(inner, size) => {
+                    *size == ArraySize::Identifier(g.to_string()) || appears_in(g, &inner.value)
+                }
+                UnresolvedType::Tuple(elements) => elements.iter().any(|e| appears_in(g, &e.value)),
+                UnresolvedType::Vector(inner, capacity) => {
+                    *capacity == ArraySize::Identifier(g.to_string()) || appears_in(g, &inner.value)
+                }
+                UnresolvedType::User(_, sizes) => {
+                    sizes.contains(&ArraySize::Identifier(g.to_string()))
+                }
+                _ => false,
+            }
+        }
+
+        for g in &funct.generics {
+            if !generics_set.insert(g) {
+                errors.push(ErrorInner {
+                    pos: Some(pos),
+                    message: format!("Duplicate generic parameter {}", g),
+                });
+            }
+
+            if !funct
+                .signature
+                .inputs
+                .iter()
+                .any(|ty| appears_in(g, &ty.value))
+            {
+                errors.push(ErrorInner {
+                    pos: Some(pos),
+                    message: format!(
+                        "Generic parameter {} must be used in the type of an argument",
+                        g
+                    ),
+                });
+            }
+        }
+
+        match errors.len() {
+            0 => Ok(()),
+            _ => Err(errors),
+        }
+    }
+
+    /// Check the instances of generic functions required by the calls checked so far
+    ///
+    /// # Arguments
+    ///
+    /// * `module_id` - The module currently being checked
+    /// * `state` - The global state, where the instances in other modules get inserted
+    /// * `functions` - The functions of the module currently being checked, where the instances in this module get inserted
+    fn check_instantiations<T: Field>(
+        &mut self,
+        module_id: &ModuleId,
+        state: &mut State<'ast, T>,
+        functions: &mut HashMap<FunctionKey<'ast>, TypedFunctionSymbol<'ast, T>>,
+    ) -> Result<(), Vec<Error>> {
+        for instantiation in std::mem::replace(&mut self.instantiations, vec![]) {
+            let template = instantiation.template;
+
+            // the instance is checked in the scope of the declaration of the template
+            let mut checker = Checker {
+                functions: template.functions,
+                templates: template.templates,
+                constants: template.constants,
+                structs: template.structs,
+                generics: instantiation.generics,
+                source_spans: self.source_spans,
+                ..Checker::new()
+            };
+
+            let funct = checker
+                .check_function(template.function, &template.module_id, &state.types)
+                .map_err(|e| {
+                    e.into_iter()
+                        .map(|inner| inner.in_file(&template.module_id))
+                        .collect::<Vec<_>>()
+                })?;
+
+            state.calls.extend(checker.calls.drain());
+
+            let mut insert = |target: &ModuleId, key, symbol| match target == module_id {
+                true => {
+                    functions.insert(key, symbol);
+                }
+                false => {
+                    state
+                        .typed_modules
+                        .get_mut(target)
+                        .unwrap()
+                        .functions
+                        .insert(key, symbol);
+                }
+            };
+
+            insert(
+                &template.module_id,
+                instantiation.key.clone(),
+                TypedFunctionSymbol::Here(funct),
+            );
+
+            // if the call happens in another module or under another name, point to the instance from there
+            let (call_module_id, call_key) = instantiation.call_site;
+            if call_module_id != template.module_id || call_key != instantiation.key {
+                insert(
+                    &call_module_id,
+                    call_key,
+                    TypedFunctionSymbol::There(instantiation.key, template.module_id.clone()),
+                );
+            }
+
+            // the instance itself may call generic functions
+            checker.check_instantiations(module_id, state, functions)?;
+        }
+
+        Ok(())
+    }
+
+    /// The marker for a statement at `pos` in `module_id`, if source spans are enabled
+    fn span<T: Field>(
+        &self,
+        module_id: &ModuleId,
+        pos: (Position, Position),
+    ) -> Option<TypedStatement<'ast, T>> {
+        match self.source_spans {
+            true => Some(TypedStatement::Span(SourceSpan {
+                file: module_id.display().to_string(),
+                from: pos.0,
+                to: pos.1,
+                stack: vec![],
+            })),
+            false => None,
+        }
+    }
+
     fn check_for_var(&self, var: &VariableNode) -> Result<(), ErrorInner> {
         match var.value.get_type() {
             UnresolvedType::FieldElement => Ok(()),
@@ -652,6 +1448,7 @@ impl<'ast> Checker<'ast> {
         self.enter_scope();
 
         let mut errors = vec![];
+        let pos = funct_node.pos();
         let funct = funct_node.value;
         let mut arguments_checked = vec![];
         let mut signature = None;
@@ -670,40 +1467,63 @@ impl<'ast> Checker<'ast> {
 
         let mut statements_checked = vec![];
 
+        // in an instance of a generic function, the generic parameters are defined as field elements
+        for g in funct.generics {
+            let var = Variable::field_element(g);
+
+            match self.insert_into_scope(var.clone()) {
+                true => {
+                    statements_checked.push(TypedStatement::Declaration(var.clone()));
+                    statements_checked.push(TypedStatement::Definition(
+                        TypedAssignee::Identifier(var),
+                        FieldElementExpression::Number(T::from(self.generics[g])).into(),
+                    ));
+                }
+                false => errors.push(ErrorInner {
+                    pos: Some(pos),
+                    message: format!("Generic parameter {} conflicts with an argument", g),
+                }),
+            }
+        }
+
         match self.check_signature(funct.signature, module_id, types) {
             Ok(s) => {
                 for stat in funct.statements.into_iter() {
                     let pos = stat.pos();
 
+                    statements_checked.extend(self.span(module_id, pos));
+
                     match self.check_statement(stat, module_id, types) {
-                        Ok(statement) => {
-                            match &statement {
-                                TypedStatement::Return(e) => {
-                                    match e.iter().map(|e| e.get_type()).collect::<Vec<_>>()
-                                        == s.outputs
-                                    {
-                                        true => {}
-                                        false => errors.push(ErrorInner {
-                                            pos: Some(pos),
-                                            message: format!(
-                                                "Expected ({}) in return statement, found ({})",
-                                                s.outputs
-                                                    .iter()
-                                                    .map(|t| t.to_string())
-                                                    .collect::<Vec<_>>()
-                                                    .join(", "),
-                                                e.iter()
-                                                    .map(|e| e.get_type())
-                                                    .map(|t| t.to_string())
-                                                    .collect::<Vec<_>>()
-                                                    .join(", ")
-                                            ),
-                                        }),
+                        Ok(statements) => {
+                            for statement in statements {
+                                match &statement {
+                                    TypedStatement::Return(e) => {
+                                        match e.iter().map(|e| e.get_type()).collect::<Vec<_>>()
+                                            == s.outputs
+                                        {
+                                            true => {}
+                                            false => errors.push(ErrorInner {
+                                                pos: Some(pos),
+                                                message: format!(
+                                                    "Expected ({}) in return statement, found ({})",
+                                                    s.outputs
+                                                        .iter()
+                                                        .map(|t| t.to_string())
+                                                        .collect::<Vec<_>>()
+                                                        .join(", "),
+                                                    e.iter()
+                                                        .map(|e| e.get_type())
+                                                        .map(|t| t.to_string())
+                                                        .collect::<Vec<_>>()
+                                                        .join(", ")
+                                                ),
+                                            }),
+                                        }
                                     }
-                                }
-                                _ => {}
-                            };
-                            statements_checked.push(statement);
+                                    _ => {}
+                                };
+                                statements_checked.push(statement);
+                            }
                         }
                         Err(e) => {
                             errors.extend(e);
@@ -736,11 +1556,23 @@ impl<'ast> Checker<'ast> {
         module_id: &ModuleId,
         types: &TypeMap,
     ) -> Result<Parameter<'ast>, Vec<ErrorInner>> {
+        let alias = match p.value.id.value.get_type() {
+            UnresolvedType::User(id, _) => Some(id),
+            _ => None,
+        };
+
         let var = self.check_variable(p.value.id, module_id, types)?;
 
+        // keep the name of the alias if it is not the name of the resolved type
+        let alias = alias.filter(|alias| match var.get_type() {
+            Type::Struct(s) => s.name() != alias,
+            _ => true,
+        });
+
         Ok(Parameter {
             id: var,
             private: p.value.private,
+            alias,
         })
     }
 
@@ -796,24 +1628,146 @@ impl<'ast> Checker<'ast> {
             UnresolvedType::FieldElement => Ok(Type::FieldElement),
             UnresolvedType::Boolean => Ok(Type::Boolean),
             UnresolvedType::Uint(bitwidth) => Ok(Type::uint(bitwidth)),
-            UnresolvedType::Array(t, size) => Ok(Type::Array(ArrayType::new(
-                self.check_type(*t, module_id, types)?,
-                size,
-            ))),
-            UnresolvedType::User(id) => {
-                types
-                    .get(module_id)
-                    .unwrap()
-                    .get(&id)
-                    .cloned()
-                    .ok_or_else(|| ErrorInner {
-                        pos: Some(pos),
-                        message: format!("Undefined type {}", id),
+            UnresolvedType::Int(bitwidth) => Ok(Type::int(bitwidth)),
+            UnresolvedType::Array(t, size) => {
+                let size = self.check_array_size(size, pos)?;
+
+                Ok(Type::Array(ArrayType::new(
+                    Checker::check_not_vector(self.check_type(*t, module_id, types)?, pos)?,
+                    size,
+                )))
+            }
+            UnresolvedType::User(id, generics) => match self.structs.get(&id) {
+                Some(template) => {
+                    let declared = &template.definition.value.generics;
+
+                    if declared.len() != generics.len() {
+                        return Err(ErrorInner {
+                            pos: Some(pos),
+                            message: format!(
+                                "Struct {} expects {} generic parameter(s), found {}",
+                                id,
+                                declared.len(),
+                                generics.len()
+                            ),
+                        });
+                    }
+
+                    let values = generics
+                        .into_iter()
+                        .map(|g| self.check_array_size(g, pos))
+                        .collect::<Result<_, _>>()?;
+
+                    Ok(Checker::instantiate_struct(template, values, types))
+                }
+                None => {
+                    let ty = types
+                        .get(module_id)
+                        .unwrap()
+                        .get(&id)
+                        .cloned()
+                        .ok_or_else(|| ErrorInner {
+                            pos: Some(pos),
+                            message: format!("Undefined type {}", id),
+                        })?;
+
+                    match generics.len() {
+                        0 => Ok(ty),
+                        _ => Err(ErrorInner {
+                            pos: Some(pos),
+                            message: format!("Type {} does not take generic parameters", id),
+                        }),
+                    }
+                }
+            },
+            UnresolvedType::Tuple(elements) => Ok(Type::Tuple(TupleType::new(
+                elements
+                    .into_iter()
+                    .map(|e| {
+                        self.check_type(e, module_id, types)
+                            .and_then(|t| Checker::check_not_vector(t, pos))
                     })
+                    .collect::<Result<_, _>>()?,
+            ))),
+            UnresolvedType::Vector(t, capacity) => {
+                let capacity = self.check_array_size(capacity, pos)?;
+
+                Ok(Type::Vector(VectorType::new(
+                    Checker::check_not_vector(self.check_type(*t, module_id, types)?, pos)?,
+                    capacity,
+                )))
             }
         }
     }
 
+    // the length of a vector is only known at runtime, so vectors cannot be nested in other types
+    fn check_not_vector(ty: Type, pos: (Position, Position)) -> Result<Type, ErrorInner> {
+        match ty {
+            Type::Vector(..) => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!("Vectors cannot be used inside other types, found {}", ty),
+            }),
+            ty => Ok(ty),
+        }
+    }
+
+    fn check_array_size(
+        &self,
+        size: ArraySize,
+        pos: (Position, Position),
+    ) -> Result<usize, ErrorInner> {
+        match size {
+            ArraySize::Value(size) => Ok(size),
+            ArraySize::Identifier(id) => match self.generics.get(id.as_str()) {
+                Some(size) => Ok(*size),
+                None => match self.constants.get(id.as_str()) {
+                    Some(constant) => constant.size.ok_or_else(|| ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Constant {} cannot be used as an array size, as its value is not a valid size",
+                            id
+                        ),
+                    }),
+                    None => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!("Undefined generic parameter {}", id),
+                    }),
+                },
+            },
+        }
+    }
+
+    /// Instantiate a generic struct for the given values of its generic parameters
+    fn instantiate_struct(
+        template: &StructTemplate<'ast>,
+        values: Vec<usize>,
+        types: &TypeMap,
+    ) -> Type {
+        let mut checker = Checker {
+            constants: template.constants.clone(),
+            structs: template.structs.clone(),
+            generics: template
+                .definition
+                .value
+                .generics
+                .iter()
+                .cloned()
+                .zip(values)
+                .collect(),
+            ..Checker::new()
+        };
+
+        // the members were checked at the declaration of the template, so this cannot fail
+        checker
+            .check_struct_type_declaration(
+                template.id.to_string(),
+                template.definition.clone(),
+                &template.module_id,
+                types,
+            )
+            .unwrap()
+    }
+
     fn check_variable(
         &self,
         v: crate::absy::VariableNode<'ast>,
@@ -827,15 +1781,48 @@ impl<'ast> Checker<'ast> {
         ))
     }
 
+    fn check_branch<T: Field>(
+        &mut self,
+        statements: Vec<StatementNode<'ast>>,
+        module_id: &ModuleId,
+        types: &TypeMap,
+    ) -> Result<Vec<TypedStatement<'ast, T>>, Vec<ErrorInner>> {
+        // variables declared in a branch are not visible outside of it
+        self.enter_scope();
+
+        let mut checked_statements = vec![];
+
+        for stat in statements {
+            let pos = stat.pos();
+
+            match stat.value {
+                Statement::Return(..) => {
+                    return Err(vec![ErrorInner {
+                        pos: Some(pos),
+                        message: "Return statements are not allowed in `if/else` blocks".into(),
+                    }])
+                }
+                _ => {}
+            }
+
+            checked_statements.extend(self.span(module_id, pos));
+            let checked_stats = self.check_statement(stat, module_id, types)?;
+            checked_statements.extend(checked_stats);
+        }
+
+        self.exit_scope();
+        Ok(checked_statements)
+    }
+
     fn check_statement<T: Field>(
         &mut self,
         stat: StatementNode<'ast>,
         module_id: &ModuleId,
         types: &TypeMap,
-    ) -> Result<TypedStatement<'ast, T>, Vec<ErrorInner>> {
+    ) -> Result<Vec<TypedStatement<'ast, T>>, Vec<ErrorInner>> {
         let pos = stat.pos();
 
-        match stat.value {
+        let statement = match stat.value {
             Statement::Return(list) => {
                 let mut expression_list_checked = vec![];
 
@@ -863,7 +1850,7 @@ impl<'ast> Checker<'ast> {
                 // we create multidef when rhs is a function call to benefit from inference
                 // check rhs is not a function call here
                 match expr.value {
-					Expression::FunctionCall(..) => panic!("Parser should not generate Definition where the right hand side is a FunctionCall"),
+					Expression::FunctionCall(..) | Expression::MethodCall(..) => panic!("Parser should not generate Definition where the right hand side is a FunctionCall"),
 					_ => {}
 				}
 
@@ -893,13 +1880,20 @@ impl<'ast> Checker<'ast> {
                 }
                 .map_err(|e| vec![e])
             }
-            Statement::Assertion(e) => {
+            Statement::Assertion(e, message) => {
                 let e = self
                     .check_expression(e, module_id, &types)
                     .map_err(|e| vec![e])?;
 
                 match e {
-                    TypedExpression::Boolean(e) => Ok(TypedStatement::Assertion(e)),
+                    TypedExpression::Boolean(e) => Ok(TypedStatement::Assertion(
+                        e,
+                        Some(AssertionMetadata {
+                            file: module_id.display().to_string(),
+                            position: pos.0,
+                            message,
+                        }),
+                    )),
                     e => Err(ErrorInner {
                         pos: Some(pos),
                         message: format!(
@@ -954,116 +1948,378 @@ impl<'ast> Checker<'ast> {
                 let mut checked_statements = vec![];
 
                 for stat in statements {
-                    let checked_stat = self.check_statement(stat, module_id, types)?;
-                    checked_statements.push(checked_stat);
+                    checked_statements.extend(self.span(module_id, stat.pos()));
+                    let checked_stats = self.check_statement(stat, module_id, types)?;
+                    checked_statements.extend(checked_stats);
                 }
 
                 self.exit_scope();
                 Ok(TypedStatement::For(var, from, to, checked_statements))
             }
-            Statement::MultipleDefinition(assignees, rhs) => {
-                match rhs.value {
-                    // Right side has to be a function call
-                    Expression::FunctionCall(fun_id, arguments) => {
+            Statement::ForEach(var, vector, statements) => {
+                let vector = match self
+                    .check_expression(vector, module_id, &types)
+                    .map_err(|e| vec![e])?
+                {
+                    TypedExpression::Vector(v) => Ok(v),
+                    e => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Expected a vector to iterate over, found {}",
+                            e.get_type()
+                        ),
+                    }),
+                }
+                .map_err(|e| vec![e])?;
 
-                        // check lhs assignees are defined
-                        let (assignees, errors): (Vec<_>, Vec<_>) = assignees.into_iter().map(|a| self.check_assignee::<T>(a, module_id, types)).partition(|r| r.is_ok());
+                self.enter_scope();
 
-                        if errors.len() > 0 {
-                            return Err(errors.into_iter().map(|e| e.unwrap_err()).collect());
-                        }
+                let var_pos = var.pos();
+                let var = self.check_variable(var, module_id, types)?;
 
-                        // constrain assignees to being identifiers
-                        let (variables, errors): (Vec<_>, Vec<_>) = assignees.into_iter().map(|a| match a.unwrap() {
-                            TypedAssignee::Identifier(v) => Ok(v),
-                            a => Err(ErrorInner {
-                                pos: Some(pos),
-                                message: format!("Only assignment to identifiers is supported, found {}", a)
-                            })
-                        }).partition(|r| r.is_ok());
+                let element_type = *vector.ty().ty.clone();
+                let capacity = vector.ty().capacity;
 
-                        if errors.len() > 0 {
-                            return Err(errors.into_iter().map(|e| e.unwrap_err()).collect());
-                        }
+                if var.get_type() != element_type {
+                    return Err(vec![ErrorInner {
+                        pos: Some(var_pos),
+                        message: format!(
+                            "Expected iteration variable {} to be of type {}, found {}",
+                            var.id,
+                            element_type,
+                            var.get_type()
+                        ),
+                    }]);
+                }
 
-                        let variables: Vec<_> = variables.into_iter().map(|v| v.unwrap()).collect();
+                // iterate over the full capacity of the vector and only run the body for the
+                // elements below its runtime length
+                let index =
+                    Variable::field_element(CoreIdentifier::Internal("vector_index", self.level));
 
-                        let vars_types = variables.iter().map(|a| Some(a.get_type().clone())).collect();
+                self.insert_into_scope(var.clone());
 
-                        // find argument types
-                        let mut arguments_checked = vec![];
-                        for arg in arguments {
-                            let arg_checked = self.check_expression(arg, module_id, &types).map_err(|e| vec![e])?;
-                            arguments_checked.push(arg_checked);
-                        }
+                let elements = ArrayExpressionInner::VectorElements(box vector.clone())
+                    .annotate(element_type.clone(), capacity);
+                let i = FieldElementExpression::Identifier(index.id.clone());
+
+                let element: TypedExpression<'ast, T> = match element_type {
+                    Type::FieldElement => FieldElementExpression::select(elements, i).into(),
+                    Type::Boolean => BooleanExpression::select(elements, i).into(),
+                    Type::Uint(..) => UExpression::select(elements, i).into(),
+                    Type::Array(..) => ArrayExpression::select(elements, i).into(),
+                    Type::Struct(..) => StructExpression::select(elements, i).into(),
+                    Type::Tuple(..) => TupleExpression::select(elements, i).into(),
+                    Type::Vector(..) => unreachable!("vectors cannot be nested in other types"),
+                };
 
-                        let arguments_types =
-                            arguments_checked.iter().map(|a| a.get_type()).collect();
+                let mut checked_statements = vec![
+                    TypedStatement::Declaration(var.clone()),
+                    TypedStatement::Definition(TypedAssignee::Identifier(var), element),
+                ];
 
-                        let query = FunctionQuery::new(&fun_id, &arguments_types, &vars_types);
+                for stat in statements {
+                    checked_statements.extend(self.span(module_id, stat.pos()));
+                    let checked_stats = self.check_statement(stat, module_id, types)?;
+                    checked_statements.extend(checked_stats);
+                }
 
-                        let f = self.find_function(&query);
+                self.exit_scope();
 
-                        match f {
-                    		// the function has to be defined
-                    		Some(f) => {
+                let length = FieldElementExpression::FromUint(
+                    box UExpressionInner::VectorLength(box vector).annotate(IntType::U32),
+                );
 
-                                let call = TypedExpressionList::FunctionCall(f.clone(), arguments_checked, f.signature.outputs.clone());
+                Ok(TypedStatement::For(
+                    index.clone(),
+                    FieldElementExpression::Number(T::from(0)),
+                    FieldElementExpression::Number(T::from(capacity)),
+                    vec![TypedStatement::IfElse(
+                        BooleanExpression::Lt(
+                            box FieldElementExpression::Identifier(index.id),
+                            box length,
+                        ),
+                        checked_statements,
+                        vec![],
+                    )],
+                ))
+            }
+            Statement::IfElse(condition, consequence, alternative) => {
+                let condition = self
+                    .check_expression(condition, module_id, &types)
+                    .map_err(|e| vec![e])?;
 
-                                Ok(TypedStatement::MultipleDefinition(variables, call))
-                    		},
-                    		None => Err(ErrorInner {                         pos: Some(pos),
- message: format!("Function definition for function {} with signature {} not found.", fun_id, query) }),
-                    	}
-                    }
-                    _ => Err(ErrorInner {
+                let condition = match condition {
+                    TypedExpression::Boolean(condition) => Ok(condition),
+                    c => Err(ErrorInner {
                         pos: Some(pos),
-                        message: format!("{} should be a function call", rhs),
+                        message: format!(
+                            "{{condition}} after `if` should be a boolean, found {}",
+                            c.get_type()
+                        ),
                     }),
-                }.map_err(|e| vec![e])
+                }
+                .map_err(|e| vec![e])?;
+
+                let consequence = self.check_branch(consequence, module_id, types)?;
+                let alternative = self.check_branch(alternative, module_id, types)?;
+
+                Ok(TypedStatement::IfElse(condition, consequence, alternative))
             }
-        }
+            Statement::MultipleDefinition(assignees, rhs) => {
+                return self.check_multiple_definition(assignees, rhs, pos, module_id, types)
+            }
+            Statement::Log(format_string, expressions) => {
+                let placeholder_count = format_string.matches("{}").count();
+
+                if placeholder_count != expressions.len() {
+                    return Err(vec![ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Format string \"{}\" expects {} argument(s), found {}",
+                            format_string,
+                            placeholder_count,
+                            expressions.len()
+                        ),
+                    }]);
+                }
+
+                let expressions = expressions
+                    .into_iter()
+                    .map(|e| {
+                        let e = self.check_expression(e, module_id, &types)?;
+                        match e.get_type() {
+                            Type::FieldElement | Type::Boolean | Type::Uint(..) => Ok(e),
+                            ty => Err(ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Expected {} to be of type field, bool or uint, found {}",
+                                    e, ty
+                                ),
+                            }),
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| vec![e])?;
+
+                Ok(TypedStatement::Log(format_string, expressions))
+            }
+        };
+
+        statement.map(|s| vec![s])
     }
 
-    fn check_assignee<T: Field>(
+    fn check_multiple_definition<T: Field>(
         &mut self,
-        assignee: AssigneeNode<'ast>,
+        assignees: Vec<AssigneeNode<'ast>>,
+        rhs: ExpressionNode<'ast>,
+        pos: (Position, Position),
         module_id: &ModuleId,
         types: &TypeMap,
-    ) -> Result<TypedAssignee<'ast, T>, ErrorInner> {
-        let pos = assignee.pos();
-        // check that the assignee is declared
-        match assignee.value {
-            Assignee::Identifier(variable_name) => match self.get_scope(&variable_name) {
-                Some(var) => Ok(TypedAssignee::Identifier(Variable::with_id_and_type(
-                    variable_name,
-                    var.id._type.clone(),
-                ))),
-                None => Err(ErrorInner {
-                    pos: Some(assignee.pos()),
-                    message: format!("Variable `{}` is undeclared", variable_name),
-                }),
-            },
-            Assignee::Select(box assignee, box index) => {
-                let checked_assignee = self.check_assignee(assignee, module_id, &types)?;
+    ) -> Result<Vec<TypedStatement<'ast, T>>, Vec<ErrorInner>> {
+        match rhs.value {
+            // Right side has to be a function call
+            call @ Expression::FunctionCall(..) | call @ Expression::MethodCall(..) => {
+                // check lhs assignees are defined
+                let (assignees, errors): (Vec<_>, Vec<_>) = assignees
+                    .into_iter()
+                    .map(|a| self.check_assignee::<T>(a, module_id, types))
+                    .partition(|r| r.is_ok());
 
-                let ty = checked_assignee.get_type();
-                match ty {
-                    Type::Array(..) => {
-                        let checked_index = match index {
-                            RangeOrExpression::Expression(e) => {
-                                self.check_expression(e, module_id, &types)?
-                            }
-                            r => unimplemented!(
-                                "Using slices in assignments is not supported yet, found {}",
-                                r
+                if errors.len() > 0 {
+                    return Err(errors.into_iter().map(|e| e.unwrap_err()).collect());
+                }
+
+                // constrain assignees to being identifiers
+                let (variables, errors): (Vec<_>, Vec<_>) = assignees
+                    .into_iter()
+                    .map(|a| match a.unwrap() {
+                        TypedAssignee::Identifier(v) => Ok(v),
+                        a => Err(ErrorInner {
+                            pos: Some(pos),
+                            message: format!(
+                                "Only assignment to identifiers is supported, found {}",
+                                a
                             ),
-                        };
+                        }),
+                    })
+                    .partition(|r| r.is_ok());
 
-                        let checked_typed_index = match checked_index {
-                            TypedExpression::FieldElement(e) => Ok(e),
-                            e => Err(ErrorInner {
-                                pos: Some(pos),
+                if errors.len() > 0 {
+                    return Err(errors.into_iter().map(|e| e.unwrap_err()).collect());
+                }
+
+                let variables: Vec<_> = variables.into_iter().map(|v| v.unwrap()).collect();
+
+                let vars_types = variables
+                    .iter()
+                    .map(|a| Some(a.get_type().clone()))
+                    .collect();
+
+                // find argument types
+                let (fun_id, arguments_checked) = self
+                    .check_call(call, pos, module_id, &types)
+                    .map_err(|e| vec![e])?;
+
+                // the length of a vector is a builtin
+                if let (true, [v], [TypedExpression::Vector(e)]) = (
+                    fun_id == "len",
+                    &variables[..],
+                    &arguments_checked[..],
+                ) {
+                    return match v.get_type() {
+                        Type::Uint(IntType::U32) => Ok(vec![TypedStatement::Definition(
+                            TypedAssignee::Identifier(v.clone()),
+                            UExpressionInner::VectorLength(box e.clone())
+                                .annotate(IntType::U32)
+                                .into(),
+                        )]),
+                        ty => Err(ErrorInner {
+                            pos: Some(pos),
+                            message: format!(
+                                "Expression {}.len() of type u32 cannot be assigned to {} of type {}",
+                                e, v.id, ty
+                            ),
+                        }),
+                    }
+                    .map_err(|e| vec![e]);
+                }
+
+                let arguments_types = arguments_checked.iter().map(|a| a.get_type()).collect();
+
+                let query = FunctionQuery::new(&fun_id, &arguments_types, &vars_types);
+
+                let not_found = ErrorInner {
+                    pos: Some(pos),
+                    message: format!(
+                        "Function definition for function {} with signature {} not found.",
+                        fun_id, query
+                    ),
+                };
+
+                match self.find_function(&query, module_id, types) {
+                    // the function has to be defined
+                    Some(f) => {
+                        let call = TypedExpressionList::FunctionCall(
+                            f.clone(),
+                            arguments_checked,
+                            f.signature.outputs.clone(),
+                        );
+
+                        Ok(vec![TypedStatement::MultipleDefinition(variables, call)])
+                    }
+                    // otherwise, destructure a single tuple returned by the function
+                    None if variables.len() > 1 => {
+                        let tuple_type =
+                            TupleType::new(variables.iter().map(|v| v.get_type()).collect());
+
+                        let tuple_query = FunctionQuery::new(
+                            &fun_id,
+                            &arguments_types,
+                            &vec![Some(Type::Tuple(tuple_type.clone()))],
+                        );
+
+                        match self.find_function(&tuple_query, module_id, types) {
+                            Some(f) => {
+                                let tuple = Variable::with_id_and_type(
+                                    CoreIdentifier::Internal("tuple", self.level),
+                                    Type::Tuple(tuple_type.clone()),
+                                );
+                                let call = TypedExpressionList::FunctionCall(
+                                    f.clone(),
+                                    arguments_checked,
+                                    f.signature.outputs.clone(),
+                                );
+                                let t = TupleExpressionInner::Identifier(tuple.id.clone())
+                                    .annotate(tuple_type);
+
+                                let definitions =
+                                    variables.into_iter().enumerate().map(|(index, v)| {
+                                        TypedStatement::Definition(
+                                            TypedAssignee::Identifier(v),
+                                            Self::tuple_element(t.clone(), index as u32),
+                                        )
+                                    });
+
+                                Ok(vec![
+                                    TypedStatement::Declaration(tuple.clone()),
+                                    TypedStatement::MultipleDefinition(vec![tuple], call),
+                                ]
+                                .into_iter()
+                                .chain(definitions)
+                                .collect())
+                            }
+                            None => Err(not_found),
+                        }
+                    }
+                    None => Err(not_found),
+                }
+            }
+            _ => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!("{} should be a function call", rhs),
+            }),
+        }
+        .map_err(|e| vec![e])
+    }
+
+    /// Access the element at `index` of the tuple `t`
+    fn tuple_element<T: Field>(
+        t: TupleExpression<'ast, T>,
+        index: u32,
+    ) -> TypedExpression<'ast, T> {
+        let ty = t.ty().elements[index as usize].clone();
+
+        match ty {
+            Type::FieldElement => FieldElementExpression::element(t, index).into(),
+            Type::Boolean => BooleanExpression::element(t, index).into(),
+            Type::Uint(..) => UExpression::element(t, index).into(),
+            Type::Array(..) => ArrayExpression::element(t, index).into(),
+            Type::Struct(..) => StructExpression::element(t, index).into(),
+            Type::Tuple(..) => TupleExpression::element(t, index).into(),
+            Type::Vector(..) => unreachable!("vectors cannot be nested in other types"),
+        }
+    }
+
+    fn check_assignee<T: Field>(
+        &mut self,
+        assignee: AssigneeNode<'ast>,
+        module_id: &ModuleId,
+        types: &TypeMap,
+    ) -> Result<TypedAssignee<'ast, T>, ErrorInner> {
+        let pos = assignee.pos();
+        // check that the assignee is declared
+        match assignee.value {
+            Assignee::Identifier(variable_name) => match self.get_scope(&variable_name) {
+                Some(var) => Ok(TypedAssignee::Identifier(Variable::with_id_and_type(
+                    variable_name,
+                    var.id._type.clone(),
+                ))),
+                None => Err(ErrorInner {
+                    pos: Some(assignee.pos()),
+                    message: format!("Variable `{}` is undeclared", variable_name),
+                }),
+            },
+            Assignee::Select(box assignee, box index) => {
+                let checked_assignee = self.check_assignee(assignee, module_id, &types)?;
+
+                let ty = checked_assignee.get_type();
+                match ty {
+                    Type::Array(..) => {
+                        let checked_index = match index {
+                            RangeOrExpression::Expression(e) => {
+                                self.check_expression(e, module_id, &types)?
+                            }
+                            r => unimplemented!(
+                                "Using slices in assignments is not supported yet, found {}",
+                                r
+                            ),
+                        };
+
+                        let checked_typed_index = match checked_index {
+                            TypedExpression::FieldElement(e) => Ok(e),
+                            e => Err(ErrorInner {
+                                pos: Some(pos),
 
                                 message: format!(
                                     "Expected array {} index to have type field, found {}",
@@ -1110,6 +2366,28 @@ impl<'ast> Checker<'ast> {
                     }),
                 }
             }
+            Assignee::Element(box assignee, index) => {
+                let checked_assignee = self.check_assignee(assignee, module_id, &types)?;
+
+                let ty = checked_assignee.get_type();
+                match &ty {
+                    Type::Tuple(tuple_type) => match (index as usize) < tuple_type.len() {
+                        true => Ok(TypedAssignee::Element(box checked_assignee, index)),
+                        false => Err(ErrorInner {
+                            pos: Some(pos),
+                            message: format!("{} doesn't have element {}", ty, index),
+                        }),
+                    },
+                    ty => Err(ErrorInner {
+                        pos: Some(pos),
+
+                        message: format!(
+                            "Cannot access element {} on {} as of type {}",
+                            index, checked_assignee, ty,
+                        ),
+                    }),
+                }
+            }
         }
     }
 
@@ -1167,6 +2445,15 @@ impl<'ast> Checker<'ast> {
                                     )
                                     .annotate(members.clone())
                                     .into(),
+                                    Type::Tuple(elements) => TupleExpressionInner::Select(
+                                        box e.clone().annotate(Type::Tuple(elements.clone()), size),
+                                        box FieldElementExpression::Number(T::from(i)),
+                                    )
+                                    .annotate(elements.clone())
+                                    .into(),
+                                    Type::Vector(..) => {
+                                        unreachable!("vectors cannot be nested in other types")
+                                    }
                                 })
                                 .collect()),
                         }
@@ -1220,11 +2507,23 @@ impl<'ast> Checker<'ast> {
                         Type::Struct(members) => Ok(StructExpressionInner::Identifier(name.into())
                             .annotate(members)
                             .into()),
+                        Type::Tuple(elements) => Ok(TupleExpressionInner::Identifier(name.into())
+                            .annotate(elements)
+                            .into()),
+                        Type::Vector(vector_type) => {
+                            Ok(VectorExpressionInner::Identifier(name.into())
+                                .annotate(vector_type)
+                                .into())
+                        }
+                    },
+                    // if it is not a variable, it may be a constant, in which case we inline it
+                    None => match self.constants.get(name) {
+                        Some(constant) => Checker::check_constant(constant, types),
+                        None => Err(ErrorInner {
+                            pos: Some(pos),
+                            message: format!("Identifier \"{}\" is undefined", name),
+                        }),
                     },
-                    None => Err(ErrorInner {
-                        pos: Some(pos),
-                        message: format!("Identifier \"{}\" is undefined", name),
-                    }),
                 }
             }
             Expression::Add(box e1, box e2) => {
@@ -1337,11 +2636,69 @@ impl<'ast> Checker<'ast> {
                     (TypedExpression::FieldElement(e1), TypedExpression::FieldElement(e2)) => {
                         Ok(FieldElementExpression::Div(box e1, box e2).into())
                     }
+                    (TypedExpression::Uint(e1), TypedExpression::Uint(e2)) => {
+                        if e1.get_type() != e2.get_type() {
+                            return Err(ErrorInner {
+                                pos: Some(pos),
+
+                                message: format!(
+                                    "Cannot apply `/` to {:?}, {:?}",
+                                    e1.get_type(),
+                                    e2.get_type()
+                                ),
+                            });
+                        }
+
+                        match e2.as_inner() {
+                            UExpressionInner::Value(0) => Err(ErrorInner {
+                                pos: Some(pos),
+                                message: format!("Division by zero in {} / {}", e1, e2),
+                            }),
+                            _ => Ok(UExpression::div(e1, e2).into()),
+                        }
+                    }
                     (t1, t2) => Err(ErrorInner {
                         pos: Some(pos),
 
                         message: format!(
-                            "Expected only field elements, found {:?}, {:?}",
+                            "Cannot apply `/` to {:?}, {:?}",
+                            t1.get_type(),
+                            t2.get_type()
+                        ),
+                    }),
+                }
+            }
+            Expression::Rem(box e1, box e2) => {
+                let e1_checked = self.check_expression(e1, module_id, &types)?;
+                let e2_checked = self.check_expression(e2, module_id, &types)?;
+
+                match (e1_checked, e2_checked) {
+                    (TypedExpression::Uint(e1), TypedExpression::Uint(e2)) => {
+                        if e1.get_type() != e2.get_type() {
+                            return Err(ErrorInner {
+                                pos: Some(pos),
+
+                                message: format!(
+                                    "Cannot apply `%` to {:?}, {:?}",
+                                    e1.get_type(),
+                                    e2.get_type()
+                                ),
+                            });
+                        }
+
+                        match e2.as_inner() {
+                            UExpressionInner::Value(0) => Err(ErrorInner {
+                                pos: Some(pos),
+                                message: format!("Division by zero in {} % {}", e1, e2),
+                            }),
+                            _ => Ok(UExpression::rem(e1, e2).into()),
+                        }
+                    }
+                    (t1, t2) => Err(ErrorInner {
+                        pos: Some(pos),
+
+                        message: format!(
+                            "Cannot apply `%` to {:?}, {:?}",
                             t1.get_type(),
                             t2.get_type()
                         ),
@@ -1393,6 +2750,14 @@ impl<'ast> Checker<'ast> {
                                     let ty = consequence.ty().clone();
                                     Ok(StructExpressionInner::IfElse(box condition, box consequence, box alternative).annotate(ty).into())
                                 },
+                                (TypedExpression::Tuple(consequence), TypedExpression::Tuple(alternative)) => {
+                                    let ty = consequence.ty().clone();
+                                    Ok(TupleExpressionInner::IfElse(box condition, box consequence, box alternative).annotate(ty).into())
+                                },
+                                (TypedExpression::Vector(consequence), TypedExpression::Vector(alternative)) => {
+                                    let ty = consequence.ty().clone();
+                                    Ok(VectorExpressionInner::IfElse(box condition, box consequence, box alternative).annotate(ty).into())
+                                },
                                 (TypedExpression::Uint(consequence), TypedExpression::Uint(alternative)) => {
                                     let bitwidth = consequence.bitwidth();
                                     Ok(UExpressionInner::IfElse(box condition, box consequence, box alternative).annotate(bitwidth).into())
@@ -1428,12 +2793,21 @@ impl<'ast> Checker<'ast> {
             Expression::U8Constant(n) => Ok(UExpressionInner::Value(n.into()).annotate(8).into()),
             Expression::U16Constant(n) => Ok(UExpressionInner::Value(n.into()).annotate(16).into()),
             Expression::U32Constant(n) => Ok(UExpressionInner::Value(n.into()).annotate(32).into()),
-            Expression::FunctionCall(fun_id, arguments) => {
+            Expression::U64Constant(n) => Ok(UExpressionInner::Value(n.into()).annotate(64).into()),
+            Expression::I8Constant(n) => Self::check_signed_constant(n, IntType::I8, pos),
+            Expression::I16Constant(n) => Self::check_signed_constant(n, IntType::I16, pos),
+            Expression::I32Constant(n) => Self::check_signed_constant(n, IntType::I32, pos),
+            call @ Expression::FunctionCall(..) | call @ Expression::MethodCall(..) => {
                 // check the arguments
-                let mut arguments_checked = vec![];
-                for arg in arguments {
-                    let arg_checked = self.check_expression(arg, module_id, &types)?;
-                    arguments_checked.push(arg_checked);
+                let (fun_id, arguments_checked) = self.check_call(call, pos, module_id, types)?;
+
+                // the length of a vector is a builtin
+                if let (true, [TypedExpression::Vector(v)]) =
+                    (fun_id == "len", &arguments_checked[..])
+                {
+                    return Ok(UExpressionInner::VectorLength(box v.clone())
+                        .annotate(IntType::U32)
+                        .into());
                 }
 
                 let mut arguments_types = vec![];
@@ -1445,7 +2819,7 @@ impl<'ast> Checker<'ast> {
                 // we use type inference to determine the type of the return, so we don't specify it
                 let query = FunctionQuery::new(&fun_id, &arguments_types, &vec![None]);
 
-                let f = self.find_function(&query);
+                let f = self.find_function(&query, module_id, types);
 
                 match f {
                     // the function has to be defined
@@ -1487,6 +2861,15 @@ impl<'ast> Checker<'ast> {
                                 )
                                 .annotate(members.clone())
                                 .into()),
+                                Type::Tuple(elements) => Ok(TupleExpressionInner::FunctionCall(
+                                    FunctionKey {
+                                        id: f.id.clone(),
+                                        signature: f.signature.clone(),
+                                    },
+                                    arguments_checked,
+                                )
+                                .annotate(elements.clone())
+                                .into()),
                                 Type::Array(array_type) => Ok(ArrayExpressionInner::FunctionCall(
                                     FunctionKey {
                                         id: f.id.clone(),
@@ -1496,6 +2879,17 @@ impl<'ast> Checker<'ast> {
                                 )
                                 .annotate(*array_type.ty.clone(), array_type.size.clone())
                                 .into()),
+                                Type::Vector(vector_type) => {
+                                    Ok(VectorExpressionInner::FunctionCall(
+                                        FunctionKey {
+                                            id: f.id.clone(),
+                                            signature: f.signature.clone(),
+                                        },
+                                        arguments_checked,
+                                    )
+                                    .annotate(vector_type.clone())
+                                    .into())
+                                }
                             },
                             n => Err(ErrorInner {
                                 pos: Some(pos),
@@ -1524,6 +2918,22 @@ impl<'ast> Checker<'ast> {
                     (TypedExpression::FieldElement(e1), TypedExpression::FieldElement(e2)) => {
                         Ok(BooleanExpression::Lt(box e1, box e2).into())
                     }
+                    (TypedExpression::Uint(e1), TypedExpression::Uint(e2)) => {
+                        if e1.get_type() == e2.get_type() {
+                            Ok(BooleanExpression::UintLt(box e1, box e2).into())
+                        } else {
+                            Err(ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Cannot compare {} of type {} to {} of type {}",
+                                    e1,
+                                    e1.get_type(),
+                                    e2,
+                                    e2.get_type()
+                                ),
+                            })
+                        }
+                    }
                     (e1, e2) => Err(ErrorInner {
                         pos: Some(pos),
                         message: format!(
@@ -1543,6 +2953,22 @@ impl<'ast> Checker<'ast> {
                     (TypedExpression::FieldElement(e1), TypedExpression::FieldElement(e2)) => {
                         Ok(BooleanExpression::Le(box e1, box e2).into())
                     }
+                    (TypedExpression::Uint(e1), TypedExpression::Uint(e2)) => {
+                        if e1.get_type() == e2.get_type() {
+                            Ok(BooleanExpression::UintLe(box e1, box e2).into())
+                        } else {
+                            Err(ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Cannot compare {} of type {} to {} of type {}",
+                                    e1,
+                                    e1.get_type(),
+                                    e2,
+                                    e2.get_type()
+                                ),
+                            })
+                        }
+                    }
                     (e1, e2) => Err(ErrorInner {
                         pos: Some(pos),
                         message: format!(
@@ -1632,6 +3058,22 @@ impl<'ast> Checker<'ast> {
                     (TypedExpression::FieldElement(e1), TypedExpression::FieldElement(e2)) => {
                         Ok(BooleanExpression::Ge(box e1, box e2).into())
                     }
+                    (TypedExpression::Uint(e1), TypedExpression::Uint(e2)) => {
+                        if e1.get_type() == e2.get_type() {
+                            Ok(BooleanExpression::UintGe(box e1, box e2).into())
+                        } else {
+                            Err(ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Cannot compare {} of type {} to {} of type {}",
+                                    e1,
+                                    e1.get_type(),
+                                    e2,
+                                    e2.get_type()
+                                ),
+                            })
+                        }
+                    }
                     (e1, e2) => Err(ErrorInner {
                         pos: Some(pos),
                         message: format!(
@@ -1651,6 +3093,22 @@ impl<'ast> Checker<'ast> {
                     (TypedExpression::FieldElement(e1), TypedExpression::FieldElement(e2)) => {
                         Ok(BooleanExpression::Gt(box e1, box e2).into())
                     }
+                    (TypedExpression::Uint(e1), TypedExpression::Uint(e2)) => {
+                        if e1.get_type() == e2.get_type() {
+                            Ok(BooleanExpression::UintGt(box e1, box e2).into())
+                        } else {
+                            Err(ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Cannot compare {} of type {} to {} of type {}",
+                                    e1,
+                                    e1.get_type(),
+                                    e2,
+                                    e2.get_type()
+                                ),
+                            })
+                        }
+                    }
                     (e1, e2) => Err(ErrorInner {
                         pos: Some(pos),
                         message: format!(
@@ -1772,6 +3230,15 @@ impl<'ast> Checker<'ast> {
                                             )
                                             .annotate(*array_ty.ty, array_ty.size)
                                             .into(),
+                                            Type::Tuple(tuple_ty) => TupleExpressionInner::Select(
+                                                box array.clone(),
+                                                box FieldElementExpression::Number(T::from(i)),
+                                            )
+                                            .annotate(tuple_ty)
+                                            .into(),
+                                            Type::Vector(..) => unreachable!(
+                                                "vectors cannot be nested in other types"
+                                            ),
                                         })
                                         .collect(),
                                 )
@@ -1799,6 +3266,10 @@ impl<'ast> Checker<'ast> {
                                     Type::Boolean => Ok(BooleanExpression::select(a, i).into()),
                                     Type::Array(..) => Ok(ArrayExpression::select(a, i).into()),
                                     Type::Struct(..) => Ok(StructExpression::select(a, i).into()),
+                                    Type::Tuple(..) => Ok(TupleExpression::select(a, i).into()),
+                                    Type::Vector(..) => {
+                                        unreachable!("vectors cannot be nested in other types")
+                                    }
                                 }
                             }
                             (a, e) => Err(ErrorInner {
@@ -1838,6 +3309,12 @@ impl<'ast> Checker<'ast> {
                                 Type::Struct(..) => {
                                     Ok(StructExpression::member(s.clone(), id.to_string()).into())
                                 }
+                                Type::Tuple(..) => {
+                                    Ok(TupleExpression::member(s.clone(), id.to_string()).into())
+                                }
+                                Type::Vector(..) => {
+                                    unreachable!("vectors cannot be nested in other types")
+                                }
                             },
                             None => Err(ErrorInner {
                                 pos: Some(pos),
@@ -1855,24 +3332,62 @@ impl<'ast> Checker<'ast> {
                     }),
                 }
             }
-            Expression::InlineArray(expressions) => {
-                // check each expression, getting its type
-                let mut expressions_checked = vec![];
-                for e in expressions {
-                    let e_checked = self.check_spread_or_expression(e, module_id, &types)?;
-                    expressions_checked.extend(e_checked);
-                }
-
-                // we infer the type to be the type of the first element
-                let inferred_type = expressions_checked.get(0).unwrap().get_type().clone();
+            Expression::Element(box e, index) => {
+                let e = self.check_expression(e, module_id, &types)?;
 
-                match inferred_type {
-                    Type::FieldElement => {
-                        // we check all expressions have that same type
-                        let mut unwrapped_expressions = vec![];
+                match e {
+                    TypedExpression::Tuple(t) => {
+                        // check that the tuple has that element and return its type if it does
+                        let ty = t.ty().elements.get(index as usize).cloned();
 
-                        for e in expressions_checked {
-                            let unwrapped_e = match e {
+                        match ty {
+                            Some(ty) => match ty {
+                                Type::FieldElement => {
+                                    Ok(FieldElementExpression::element(t, index).into())
+                                }
+                                Type::Boolean => Ok(BooleanExpression::element(t, index).into()),
+                                Type::Uint(..) => Ok(UExpression::element(t, index).into()),
+                                Type::Array(..) => Ok(ArrayExpression::element(t, index).into()),
+                                Type::Struct(..) => Ok(StructExpression::element(t, index).into()),
+                                Type::Tuple(..) => Ok(TupleExpression::element(t, index).into()),
+                                Type::Vector(..) => {
+                                    unreachable!("vectors cannot be nested in other types")
+                                }
+                            },
+                            None => Err(ErrorInner {
+                                pos: Some(pos),
+                                message: format!("{} doesn't have element {}", t.get_type(), index),
+                            }),
+                        }
+                    }
+                    e => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Cannot access element {} on expression of type {}",
+                            index,
+                            e.get_type()
+                        ),
+                    }),
+                }
+            }
+            Expression::InlineArray(expressions) => {
+                // check each expression, getting its type
+                let mut expressions_checked = vec![];
+                for e in expressions {
+                    let e_checked = self.check_spread_or_expression(e, module_id, &types)?;
+                    expressions_checked.extend(e_checked);
+                }
+
+                // we infer the type to be the type of the first element
+                let inferred_type = expressions_checked.get(0).unwrap().get_type().clone();
+
+                match inferred_type {
+                    Type::FieldElement => {
+                        // we check all expressions have that same type
+                        let mut unwrapped_expressions = vec![];
+
+                        for e in expressions_checked {
+                            let unwrapped_e = match e {
                                 TypedExpression::FieldElement(e) => Ok(e),
                                 e => Err(ErrorInner {
                                     pos: Some(pos),
@@ -2047,14 +3562,91 @@ impl<'ast> Checker<'ast> {
                             .annotate(ty, size)
                             .into())
                     }
+                    ty @ Type::Tuple(..) => {
+                        // we check all expressions have that same type
+                        let mut unwrapped_expressions = vec![];
+
+                        for e in expressions_checked {
+                            let unwrapped_e = match e {
+                                TypedExpression::Tuple(e) => {
+                                    if e.get_type() == ty {
+                                        Ok(e)
+                                    } else {
+                                        Err(ErrorInner {
+                                            pos: Some(pos),
+
+                                            message: format!(
+                                                "Expected {} to have type {}, but type is {}",
+                                                e,
+                                                ty,
+                                                e.get_type()
+                                            ),
+                                        })
+                                    }
+                                }
+                                e => Err(ErrorInner {
+                                    pos: Some(pos),
+
+                                    message: format!(
+                                        "Expected {} to have type {}, but type is {}",
+                                        e,
+                                        ty,
+                                        e.get_type()
+                                    ),
+                                }),
+                            }?;
+                            unwrapped_expressions.push(unwrapped_e.into());
+                        }
+
+                        let size = unwrapped_expressions.len();
+
+                        Ok(ArrayExpressionInner::Value(unwrapped_expressions)
+                            .annotate(ty, size)
+                            .into())
+                    }
+                    ty @ Type::Vector(..) => Err(Checker::check_not_vector(ty, pos).unwrap_err()),
                 }
             }
             Expression::InlineStruct(id, inline_members) => {
-                let ty = self.check_type(
-                    UnresolvedType::User(id.clone()).at(42, 42, 42),
-                    module_id,
-                    &types,
-                )?;
+                // the generic parameters of a generic struct are inferred from the types of the values of its members
+                let mut checked_members = HashMap::new();
+
+                let ty = match self.structs.get(&id).cloned() {
+                    Some(template) => {
+                        for (member_id, value) in inline_members.clone() {
+                            checked_members.insert(
+                                member_id.to_string(),
+                                self.check_expression(value, module_id, &types)?,
+                            );
+                        }
+
+                        let member_types = checked_members
+                            .iter()
+                            .map(|(id, e)| (id.clone(), e.get_type()))
+                            .collect();
+
+                        let values =
+                            Checker::infer_struct_generics(&template, &member_types, &types)
+                                .ok_or_else(|| ErrorInner {
+                                    pos: Some(pos),
+                                    message: format!(
+                                        "Cannot infer the generic parameters of {} from {}",
+                                        id,
+                                        Expression::InlineStruct(
+                                            id.clone(),
+                                            inline_members.clone()
+                                        ),
+                                    ),
+                                })?;
+
+                        Checker::instantiate_struct(&template, values, &types)
+                    }
+                    None => self.check_type(
+                        UnresolvedType::User(id.clone(), vec![]).at(42, 42, 42),
+                        module_id,
+                        &types,
+                    )?,
+                };
                 let struct_type = match ty {
                     Type::Struct(struct_type) => struct_type,
                     _ => unreachable!(),
@@ -2086,8 +3678,10 @@ impl<'ast> Checker<'ast> {
                 for member in struct_type.iter() {
                     match inline_members_map.remove(member.id.as_str()) {
                         Some(value) => {
-                            let expression_checked =
-                                self.check_expression(value, module_id, &types)?;
+                            let expression_checked = match checked_members.remove(&member.id) {
+                                Some(e) => e,
+                                None => self.check_expression(value, module_id, &types)?,
+                            };
                             let checked_type = expression_checked.get_type();
                             if checked_type != *member.ty {
                                 return Err(ErrorInner {
@@ -2123,6 +3717,25 @@ impl<'ast> Checker<'ast> {
                     .annotate(struct_type)
                     .into())
             }
+            Expression::InlineTuple(expressions) => {
+                // the type of an inline tuple is inferred from the types of its elements
+                let mut expressions_checked = vec![];
+                for e in expressions {
+                    let e_checked = self.check_expression(e, module_id, &types)?;
+                    expressions_checked.push(e_checked);
+                }
+
+                let tuple_type = TupleType::new(
+                    expressions_checked
+                        .iter()
+                        .map(|e| Checker::check_not_vector(e.get_type(), pos))
+                        .collect::<Result<_, _>>()?,
+                );
+
+                Ok(TupleExpressionInner::Value(expressions_checked)
+                    .annotate(tuple_type)
+                    .into())
+            }
             Expression::And(box e1, box e2) => {
                 let e1_checked = self.check_expression(e1, module_id, &types)?;
                 let e2_checked = self.check_expression(e2, module_id, &types)?;
@@ -2293,6 +3906,96 @@ impl<'ast> Checker<'ast> {
                     }),
                 }
             }
+            Expression::Neg(box e) => {
+                let e_checked = self.check_expression(e, module_id, &types)?;
+                match e_checked {
+                    TypedExpression::Uint(e) if e.bitwidth.is_signed() => Ok(e.neg().into()),
+                    e => Err(ErrorInner {
+                        pos: Some(pos),
+
+                        message: format!(
+                            "Cannot apply `-` to {}, only signed integers can be negated",
+                            e.get_type()
+                        ),
+                    }),
+                }
+            }
+            Expression::Cast(box e, ty) => {
+                let e_checked = self.check_expression(e, module_id, &types)?;
+                let ty = self.check_type(ty, module_id, &types)?;
+
+                Self::check_cast(e_checked, ty, pos)
+            }
+        }
+    }
+
+    fn check_cast<T: Field>(
+        e: TypedExpression<'ast, T>,
+        ty: Type,
+        pos: (Position, Position),
+    ) -> Result<TypedExpression<'ast, T>, ErrorInner> {
+        // casting to the same type does nothing
+        if e.get_type() == ty {
+            return Ok(e);
+        }
+
+        match (e, ty) {
+            (TypedExpression::Boolean(e), Type::FieldElement) => {
+                Ok(FieldElementExpression::FromBool(box e).into())
+            }
+            (TypedExpression::FieldElement(e), Type::Boolean) => {
+                Ok(BooleanExpression::FromField(box e).into())
+            }
+            (TypedExpression::Uint(e), Type::FieldElement) if !e.bitwidth.is_signed() => {
+                Ok(FieldElementExpression::FromUint(box e).into())
+            }
+            (TypedExpression::FieldElement(e), Type::Uint(bitwidth)) if !bitwidth.is_signed() => {
+                Ok(UExpressionInner::FromField(box e).annotate(bitwidth).into())
+            }
+            (TypedExpression::Uint(e), Type::Uint(bitwidth)) => {
+                Ok(UExpressionInner::FromUint(box e).annotate(bitwidth).into())
+            }
+            (TypedExpression::Uint(e), Type::Array(array_type))
+                if *array_type.ty == Type::Boolean && array_type.size == e.bitwidth.to_usize() =>
+            {
+                Ok(ArrayExpressionInner::FromUint(box e)
+                    .annotate(Type::Boolean, array_type.size)
+                    .into())
+            }
+            (TypedExpression::Array(e), Type::Uint(bitwidth))
+                if *e.inner_type() == Type::Boolean && e.size() == bitwidth.to_usize() =>
+            {
+                Ok(UExpressionInner::FromBits(box e).annotate(bitwidth).into())
+            }
+            (e, ty) => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!("Cannot cast {} to {}", e.get_type(), ty),
+            }),
+        }
+    }
+
+    fn check_signed_constant<T: Field>(
+        n: BigInt,
+        bitwidth: IntType,
+        pos: (Position, Position),
+    ) -> Result<TypedExpression<'ast, T>, ErrorInner> {
+        let max = (1i128 << (bitwidth.to_usize() - 1)) - 1;
+        let min = -max - 1;
+
+        match n.to_i128() {
+            Some(v) if v >= min && v <= max => Ok(UExpressionInner::Value(bitwidth.from_signed(v))
+                .annotate(bitwidth)
+                .into()),
+            _ => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!(
+                    "Constant {} not in the representable range [{}, {}] of type {}",
+                    n,
+                    min,
+                    max,
+                    Type::Uint(bitwidth)
+                ),
+            }),
         }
     }
 
@@ -2313,8 +4016,272 @@ impl<'ast> Checker<'ast> {
         })
     }
 
-    fn find_function(&self, query: &FunctionQuery<'ast>) -> Option<FunctionKey<'ast>> {
-        query.match_funcs(&self.functions)
+    /// Checks the arguments of a call and resolves the id of the function it calls. Methods and
+    /// associated functions are looked up in the `impl` blocks of the struct they are called on
+    fn check_call<T: Field>(
+        &mut self,
+        call: Expression<'ast>,
+        pos: (Position, Position),
+        module_id: &ModuleId,
+        types: &TypeMap,
+    ) -> Result<(Identifier<'ast>, Vec<TypedExpression<'ast, T>>), ErrorInner> {
+        let (receiver, fun_id, arguments) = match call {
+            Expression::FunctionCall(fun_id, arguments) => (None, fun_id, arguments),
+            Expression::MethodCall(receiver, fun_id, arguments) => {
+                (Some(receiver), fun_id, arguments)
+            }
+            e => unreachable!("expected a function call, found {}", e),
+        };
+
+        let mut arguments_checked = vec![];
+
+        let fun_id = match receiver {
+            None => fun_id,
+            Some(receiver) => {
+                let ty = match receiver {
+                    // the receiver of a method is its first argument
+                    Receiver::Value(box e) => {
+                        let e = self.check_expression(e, module_id, types)?;
+                        let ty = e.get_type();
+                        arguments_checked.push(e);
+                        ty
+                    }
+                    Receiver::Type(id) => types
+                        .get(module_id)
+                        .and_then(|types| types.get(&id))
+                        .cloned()
+                        .ok_or_else(|| ErrorInner {
+                            pos: Some(pos),
+                            message: format!("Undefined type {}", id),
+                        })?,
+                };
+
+                match ty {
+                    // the length of a vector is a builtin
+                    Type::Vector(..) if fun_id == "len" => fun_id,
+                    Type::Struct(struct_type) => {
+                        self.method_id(&struct_type, fun_id)
+                            .ok_or_else(|| ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Struct {} has no method or associated function {}",
+                                    struct_type.name(),
+                                    fun_id
+                                ),
+                            })?
+                    }
+                    ty => {
+                        return Err(ErrorInner {
+                            pos: Some(pos),
+                            message: format!("Type {} has no method {}", ty, fun_id),
+                        })
+                    }
+                }
+            }
+        };
+
+        for arg in arguments {
+            arguments_checked.push(self.check_expression(arg, module_id, types)?);
+        }
+
+        Ok((fun_id, arguments_checked))
+    }
+
+    /// The id of the function `name` declared in an `impl` block of the struct `ty`, if it is in scope
+    fn method_id(&self, ty: &StructType, name: &str) -> Option<Identifier<'ast>> {
+        let id = format!("{}::{}", ty.canonical_location.name, name);
+
+        self.functions
+            .iter()
+            .map(|key| key.id)
+            .chain(self.templates.keys().cloned())
+            .find(|i| *i == id)
+    }
+
+    fn find_function(
+        &mut self,
+        query: &FunctionQuery<'ast>,
+        module_id: &ModuleId,
+        types: &TypeMap,
+    ) -> Option<FunctionKey<'ast>> {
+        self.calls.insert((module_id.clone(), query.id));
+
+        query.match_funcs(&self.functions).or_else(|| {
+            // if no function matches, try to instantiate a generic one
+            let templates = self.templates.get(query.id).cloned().unwrap_or_default();
+
+            templates.into_iter().find_map(|template| {
+                let generics = Checker::infer_generics(&template, query, types)?;
+
+                let signature = Checker {
+                    structs: template.structs.clone(),
+                    generics: generics.clone(),
+                    ..Checker::new()
+                }
+                .check_signature(
+                    template.function.value.signature.clone(),
+                    &template.module_id,
+                    types,
+                )
+                .ok()?;
+
+                let call_key = FunctionKey::with_id(query.id).signature(signature.clone());
+
+                if !query.match_func(&call_key) {
+                    return None;
+                }
+
+                // further calls with the same signature now resolve to this instance
+                self.functions.insert(call_key.clone());
+                self.instantiations.push(Instantiation {
+                    key: FunctionKey::with_id(template.id).signature(signature),
+                    call_site: (module_id.clone(), call_key.clone()),
+                    generics,
+                    template,
+                });
+
+                Some(call_key)
+            })
+        })
+    }
+
+    /// Infer the values of the generic parameters of a template from the types of the arguments of a call
+    fn infer_generics(
+        template: &FunctionTemplate<'ast>,
+        query: &FunctionQuery<'ast>,
+        types: &TypeMap,
+    ) -> Option<HashMap<Identifier<'ast>, usize>> {
+        let funct = &template.function.value;
+
+        if funct.signature.inputs.len() != query.inputs.len() {
+            return None;
+        }
+
+        let mut generics = HashMap::new();
+
+        let scope = GenericScope {
+            generics: &funct.generics,
+            module_id: &template.module_id,
+            structs: &template.structs,
+        };
+
+        for (unresolved, ty) in funct.signature.inputs.iter().zip(query.inputs.iter()) {
+            if !Checker::unify(&scope, unresolved, ty, types, &mut generics) {
+                return None;
+            }
+        }
+
+        match funct.generics.iter().all(|g| generics.contains_key(g)) {
+            true => Some(generics),
+            false => None,
+        }
+    }
+
+    /// Infer the values of the generic parameters of a struct from the types of its members
+    fn infer_struct_generics(
+        template: &StructTemplate<'ast>,
+        members: &HashMap<String, Type>,
+        types: &TypeMap,
+    ) -> Option<Vec<usize>> {
+        let definition = &template.definition.value;
+
+        if definition.fields.len() != members.len() {
+            return None;
+        }
+
+        let scope = GenericScope {
+            generics: &definition.generics,
+            module_id: &template.module_id,
+            structs: &template.structs,
+        };
+
+        let mut generics = HashMap::new();
+
+        for field in &definition.fields {
+            let ty = members.get(field.value.id)?;
+            if !Checker::unify(&scope, &field.value.ty, ty, types, &mut generics) {
+                return None;
+            }
+        }
+
+        definition
+            .generics
+            .iter()
+            .map(|g| generics.get(g).cloned())
+            .collect()
+    }
+
+    fn unify_size(
+        scope: &GenericScope<'ast, '_>,
+        size: &ArraySize,
+        value: usize,
+        generics: &mut HashMap<Identifier<'ast>, usize>,
+    ) -> bool {
+        match size {
+            ArraySize::Value(size) => *size == value,
+            ArraySize::Identifier(id) => match scope.generics.iter().find(|g| **g == id.as_str()) {
+                Some(g) => *generics.entry(*g).or_insert(value) == value,
+                None => false,
+            },
+        }
+    }
+
+    fn unify(
+        scope: &GenericScope<'ast, '_>,
+        unresolved: &UnresolvedTypeNode,
+        ty: &Type,
+        types: &TypeMap,
+        generics: &mut HashMap<Identifier<'ast>, usize>,
+    ) -> bool {
+        match (&unresolved.value, ty) {
+            (UnresolvedType::Array(inner, size), Type::Array(array_type)) => {
+                Checker::unify_size(scope, size, array_type.size, generics)
+                    && Checker::unify(scope, inner, &array_type.ty, types, generics)
+            }
+            (UnresolvedType::Tuple(elements), Type::Tuple(tuple_type)) => {
+                elements.len() == tuple_type.len()
+                    && elements
+                        .iter()
+                        .zip(tuple_type.iter())
+                        .all(|(e, ty)| Checker::unify(scope, e, ty, types, generics))
+            }
+            (UnresolvedType::Vector(inner, capacity), Type::Vector(vector_type)) => {
+                Checker::unify_size(scope, capacity, vector_type.capacity, generics)
+                    && Checker::unify(scope, inner, &vector_type.ty, types, generics)
+            }
+            (UnresolvedType::User(id, sizes), Type::Struct(struct_type)) if !sizes.is_empty() => {
+                match scope.structs.get(id) {
+                    Some(template)
+                        if struct_type.canonical_location
+                            == StructLocation {
+                                module: template.module_id.clone(),
+                                name: template.id.to_string(),
+                            } =>
+                    {
+                        let members = struct_type
+                            .iter()
+                            .map(|m| (m.id.clone(), (*m.ty).clone()))
+                            .collect();
+
+                        match Checker::infer_struct_generics(template, &members, types) {
+                            Some(values) => {
+                                sizes.len() == values.len()
+                                    && sizes.iter().zip(values).all(|(size, value)| {
+                                        Checker::unify_size(scope, size, value, generics)
+                                    })
+                            }
+                            None => false,
+                        }
+                    }
+                    _ => false,
+                }
+            }
+            // other types do not depend on generic parameters
+            _ => Checker::new()
+                .check_type(unresolved.clone(), scope.module_id, types)
+                .map(|t| &t == ty)
+                .unwrap_or(false),
+        }
     }
 
     fn enter_scope(&mut self) {
@@ -2426,24 +4393,306 @@ mod tests {
         }
     }
 
-    mod symbols {
+    mod comparison {
         use super::*;
 
-        /// Helper function to create ((): return)
-        fn function0() -> FunctionNode<'static> {
-            let statements: Vec<StatementNode> = vec![Statement::Return(
-                ExpressionList {
-                    expressions: vec![],
-                }
-                .mock(),
-            )
-            .mock()];
+        #[test]
+        fn uint() {
+            let types = HashMap::new();
+            let module_id = "".into();
 
-            let arguments = vec![];
+            // 1u8 < 2u8
+            let e = Expression::Lt(
+                box Expression::U8Constant(1).mock(),
+                box Expression::U8Constant(2).mock(),
+            )
+            .mock();
+            assert_eq!(
+                Checker::new().check_expression::<Bn128Field>(e, &module_id, &types),
+                Ok(BooleanExpression::UintLt(
+                    box UExpressionInner::Value(1).annotate(8),
+                    box UExpressionInner::Value(2).annotate(8)
+                )
+                .into())
+            );
 
-            let signature = UnresolvedSignature::new();
+            // 1u32 >= 2u32
+            let e = Expression::Ge(
+                box Expression::U32Constant(1).mock(),
+                box Expression::U32Constant(2).mock(),
+            )
+            .mock();
+            assert_eq!(
+                Checker::new().check_expression::<Bn128Field>(e, &module_id, &types),
+                Ok(BooleanExpression::UintGe(
+                    box UExpressionInner::Value(1).annotate(32),
+                    box UExpressionInner::Value(2).annotate(32)
+                )
+                .into())
+            );
+        }
+
+        #[test]
+        fn uint_bitwidth_mismatch() {
+            let types = HashMap::new();
+            let module_id = "".into();
+
+            // 1u8 <= 2u16
+            let e = Expression::Le(
+                box Expression::U8Constant(1).mock(),
+                box Expression::U16Constant(2).mock(),
+            )
+            .mock();
+            assert!(Checker::new()
+                .check_expression::<Bn128Field>(e, &module_id, &types)
+                .is_err());
+        }
+    }
+
+    mod division {
+        use super::*;
+
+        #[test]
+        fn uint() {
+            let types = HashMap::new();
+            let module_id = "".into();
+
+            // 42u8 % 5u8
+            let e = Expression::Rem(
+                box Expression::U8Constant(42).mock(),
+                box Expression::U8Constant(5).mock(),
+            )
+            .mock();
+            assert_eq!(
+                Checker::new().check_expression::<Bn128Field>(e, &module_id, &types),
+                Ok(UExpressionInner::Rem(
+                    box UExpressionInner::Value(42).annotate(8),
+                    box UExpressionInner::Value(5).annotate(8)
+                )
+                .annotate(8)
+                .into())
+            );
+        }
+
+        #[test]
+        fn by_constant_zero() {
+            let types = HashMap::new();
+            let module_id = "".into();
+
+            // 42u32 / 0u32
+            let e = Expression::Div(
+                box Expression::U32Constant(42).mock(),
+                box Expression::U32Constant(0).mock(),
+            )
+            .mock();
+            assert_eq!(
+                Checker::new()
+                    .check_expression::<Bn128Field>(e, &module_id, &types)
+                    .unwrap_err()
+                    .message,
+                "Division by zero in 0x2a / 0x0"
+            );
+        }
+
+        #[test]
+        fn field_remainder() {
+            let types = HashMap::new();
+            let module_id = "".into();
+
+            // 42 % 5
+            let e = Expression::Rem(
+                box Expression::FieldConstant(BigUint::from(42u32)).mock(),
+                box Expression::FieldConstant(BigUint::from(5u32)).mock(),
+            )
+            .mock();
+            assert!(Checker::new()
+                .check_expression::<Bn128Field>(e, &module_id, &types)
+                .is_err());
+        }
+    }
+
+    mod signed {
+        use super::*;
+
+        #[test]
+        fn constant() {
+            let types = HashMap::new();
+            let module_id = "".into();
+
+            // -1i8
+            let e = Expression::I8Constant(BigInt::from(-1)).mock();
+            assert_eq!(
+                Checker::new().check_expression::<Bn128Field>(e, &module_id, &types),
+                Ok(UExpressionInner::Value(0xff).annotate(IntType::I8).into())
+            );
+
+            // -128i8
+            let e = Expression::I8Constant(BigInt::from(-128)).mock();
+            assert_eq!(
+                Checker::new().check_expression::<Bn128Field>(e, &module_id, &types),
+                Ok(UExpressionInner::Value(0x80).annotate(IntType::I8).into())
+            );
+        }
+
+        #[test]
+        fn constant_out_of_range() {
+            let types = HashMap::new();
+            let module_id = "".into();
+
+            // 128i8
+            let e = Expression::I8Constant(BigInt::from(128)).mock();
+            assert_eq!(
+                Checker::new()
+                    .check_expression::<Bn128Field>(e, &module_id, &types)
+                    .unwrap_err()
+                    .message,
+                "Constant 128 not in the representable range [-128, 127] of type i8"
+            );
+        }
+
+        #[test]
+        fn negation() {
+            let types = HashMap::new();
+            let module_id = "".into();
+
+            // -(1i32)
+            let e = Expression::Neg(box Expression::I32Constant(BigInt::from(1)).mock()).mock();
+            assert_eq!(
+                Checker::new().check_expression::<Bn128Field>(e, &module_id, &types),
+                Ok(UExpressionInner::Sub(
+                    box UExpressionInner::Value(0).annotate(IntType::I32),
+                    box UExpressionInner::Value(1).annotate(IntType::I32)
+                )
+                .annotate(IntType::I32)
+                .into())
+            );
+
+            // -(1u32)
+            let e = Expression::Neg(box Expression::U32Constant(1).mock()).mock();
+            assert!(Checker::new()
+                .check_expression::<Bn128Field>(e, &module_id, &types)
+                .is_err());
+        }
+
+        #[test]
+        fn sign_mismatch() {
+            let types = HashMap::new();
+            let module_id = "".into();
+
+            // 1i32 + 1u32
+            let e = Expression::Add(
+                box Expression::I32Constant(BigInt::from(1)).mock(),
+                box Expression::U32Constant(1).mock(),
+            )
+            .mock();
+            assert!(Checker::new()
+                .check_expression::<Bn128Field>(e, &module_id, &types)
+                .is_err());
+        }
+    }
+
+    mod cast {
+        use super::*;
+
+        #[test]
+        fn uint_to_uint() {
+            let types = HashMap::new();
+            let module_id = "".into();
+
+            // 1u8 as u32
+            let e = Expression::Cast(
+                box Expression::U8Constant(1).mock(),
+                UnresolvedType::Uint(32).mock(),
+            )
+            .mock();
+            assert_eq!(
+                Checker::new().check_expression::<Bn128Field>(e, &module_id, &types),
+                Ok(UExpressionInner::FromUint(
+                    box UExpressionInner::Value(1).annotate(IntType::U8)
+                )
+                .annotate(IntType::U32)
+                .into())
+            );
+        }
+
+        #[test]
+        fn uint_to_bits() {
+            let types = HashMap::new();
+            let module_id = "".into();
+
+            // 1u8 as bool[8]
+            let e = Expression::Cast(
+                box Expression::U8Constant(1).mock(),
+                UnresolvedType::Array(box UnresolvedType::Boolean.mock(), ArraySize::Value(8))
+                    .mock(),
+            )
+            .mock();
+            assert_eq!(
+                Checker::new().check_expression::<Bn128Field>(e, &module_id, &types),
+                Ok(ArrayExpressionInner::FromUint(
+                    box UExpressionInner::Value(1).annotate(IntType::U8)
+                )
+                .annotate(Type::Boolean, 8)
+                .into())
+            );
+
+            // 1u8 as bool[32]
+            let e = Expression::Cast(
+                box Expression::U8Constant(1).mock(),
+                UnresolvedType::Array(box UnresolvedType::Boolean.mock(), ArraySize::Value(32))
+                    .mock(),
+            )
+            .mock();
+            assert_eq!(
+                Checker::new()
+                    .check_expression::<Bn128Field>(e, &module_id, &types)
+                    .unwrap_err()
+                    .message,
+                "Cannot cast u8 to bool[32]"
+            );
+        }
+
+        #[test]
+        fn field_to_signed() {
+            let types = HashMap::new();
+            let module_id = "".into();
+
+            // 1 as i8
+            let e = Expression::Cast(
+                box Expression::FieldConstant(BigUint::from(1u32)).mock(),
+                UnresolvedType::Int(8).mock(),
+            )
+            .mock();
+            assert_eq!(
+                Checker::new()
+                    .check_expression::<Bn128Field>(e, &module_id, &types)
+                    .unwrap_err()
+                    .message,
+                "Cannot cast field to i8"
+            );
+        }
+    }
+
+    mod symbols {
+        use super::*;
+
+        /// Helper function to create ((): return)
+        fn function0() -> FunctionNode<'static> {
+            let statements: Vec<StatementNode> = vec![Statement::Return(
+                ExpressionList {
+                    expressions: vec![],
+                }
+                .mock(),
+            )
+            .mock()];
+
+            let arguments = vec![];
+
+            let signature = UnresolvedSignature::new();
 
             Function {
+                exported: false,
+                generics: vec![],
                 arguments,
                 statements,
                 signature,
@@ -2471,6 +4720,8 @@ mod tests {
                 UnresolvedSignature::new().inputs(vec![UnresolvedType::FieldElement.mock()]);
 
             Function {
+                exported: false,
+                generics: vec![],
                 arguments,
                 statements,
                 signature,
@@ -2479,11 +4730,16 @@ mod tests {
         }
 
         fn struct0() -> StructDefinitionNode<'static> {
-            StructDefinition { fields: vec![] }.mock()
+            StructDefinition {
+                generics: vec![],
+                fields: vec![],
+            }
+            .mock()
         }
 
         fn struct1() -> StructDefinitionNode<'static> {
             StructDefinition {
+                generics: vec![],
                 fields: vec![StructDefinitionField {
                     id: "foo".into(),
                     ty: UnresolvedType::FieldElement.mock(),
@@ -2565,15 +4821,19 @@ mod tests {
         }
 
         #[test]
-        fn duplicate_function_declaration() {
-            // def foo():
-            //   return
+        fn imported_private_function() {
+            // foo.zok
             // def foo():
-            //   return
-            //
-            // should fail
+            // 		return
+            // def main():
+            // 		return
 
-            let module = Module {
+            // bar.zok
+            // from "./foo.zok" import foo
+
+            // should fail as `foo` is not exported
+
+            let foo: Module = Module {
                 symbols: vec![
                     SymbolDeclaration {
                         id: "foo",
@@ -2581,7 +4841,7 @@ mod tests {
                     }
                     .mock(),
                     SymbolDeclaration {
-                        id: "foo",
+                        id: "main",
                         symbol: Symbol::HereFunction(function0()),
                     }
                     .mock(),
@@ -2589,33 +4849,87 @@ mod tests {
                 imports: vec![],
             };
 
+            let bar: Module = Module {
+                symbols: vec![SymbolDeclaration {
+                    id: "foo",
+                    symbol: Symbol::There(SymbolImport::with_id_in_module("foo", "foo").mock()),
+                }
+                .mock()],
+                imports: vec![],
+            };
+
             let mut state = State::<Bn128Field>::new(
-                vec![(PathBuf::from(MODULE_ID).into(), module)]
+                vec![("foo".into(), foo), ("bar".into(), bar)]
                     .into_iter()
                     .collect(),
             );
 
             let mut checker = Checker::new();
+
             assert_eq!(
-                checker
-                    .check_module(&PathBuf::from(MODULE_ID).into(), &mut state)
-                    .unwrap_err()[0]
+                checker.check_module(&"bar".into(), &mut state).unwrap_err()[0]
                     .inner
                     .message,
-                "foo conflicts with another symbol"
+                "Function foo is private in module foo"
             );
         }
 
         #[test]
-        fn overloaded_function_declaration() {
+        fn imported_exported_function() {
+            // foo.zok
+            // export def foo():
+            // 		return
+
+            // bar.zok
+            // from "./foo.zok" import foo
+
+            // should succeed
+
+            let foo: Module = Module {
+                symbols: vec![SymbolDeclaration {
+                    id: "foo",
+                    symbol: Symbol::HereFunction(
+                        Function {
+                            exported: true,
+                            ..function0().value
+                        }
+                        .mock(),
+                    ),
+                }
+                .mock()],
+                imports: vec![],
+            };
+
+            let bar: Module = Module {
+                symbols: vec![SymbolDeclaration {
+                    id: "foo",
+                    symbol: Symbol::There(SymbolImport::with_id_in_module("foo", "foo").mock()),
+                }
+                .mock()],
+                imports: vec![],
+            };
+
+            let mut state = State::<Bn128Field>::new(
+                vec![("foo".into(), foo), ("bar".into(), bar)]
+                    .into_iter()
+                    .collect(),
+            );
+
+            let mut checker = Checker::new();
+
+            assert_eq!(checker.check_module(&"bar".into(), &mut state), Ok(()));
+        }
+
+        #[test]
+        fn unused_private_function() {
             // def foo():
-            //   return
-            // def foo(a):
-            //   return
-            //
-            // should succeed as overloading is allowed
+            // 		return
+            // def main():
+            // 		return
 
-            let module = Module {
+            // should warn that `foo` is never called
+
+            let module: Module = Module {
                 symbols: vec![
                     SymbolDeclaration {
                         id: "foo",
@@ -2623,32 +4937,146 @@ mod tests {
                     }
                     .mock(),
                     SymbolDeclaration {
-                        id: "foo",
-                        symbol: Symbol::HereFunction(function1()),
+                        id: "main",
+                        symbol: Symbol::HereFunction(function0()),
                     }
                     .mock(),
                 ],
                 imports: vec![],
             };
 
-            let mut state = State::<Bn128Field>::new(
-                vec![(PathBuf::from(MODULE_ID), module)]
-                    .into_iter()
-                    .collect(),
-            );
+            let program = Program {
+                modules: vec![("main".into(), module)].into_iter().collect(),
+                main: "main".into(),
+            };
 
-            let mut checker = Checker::new();
+            let (_, warnings) = Checker::new().check_program::<Bn128Field>(program).unwrap();
+
+            assert_eq!(warnings.len(), 1);
+            assert_eq!(warnings[0].module_id, PathBuf::from("main"));
             assert_eq!(
-                checker.check_module(&PathBuf::from(MODULE_ID), &mut state),
-                Ok(())
+                warnings[0].inner.message,
+                "Private function foo is never called"
             );
-            assert!(state
-                .typed_modules
-                .get(&PathBuf::from(MODULE_ID))
-                .unwrap()
-                .functions
-                .contains_key(&FunctionKey::with_id("foo").signature(Signature::new())));
-            assert!(state
+        }
+
+        #[test]
+        fn impl_for_non_struct() {
+            // impl Foo {}
+            //
+            // should fail as there is no struct `Foo`
+
+            let module = Module {
+                symbols: vec![SymbolDeclaration {
+                    id: "Foo",
+                    symbol: Symbol::HereImpl(ImplDefinition { methods: vec![] }.mock()),
+                }
+                .mock()],
+                imports: vec![],
+            };
+
+            let mut state = State::<Bn128Field>::new(
+                vec![(PathBuf::from(MODULE_ID).into(), module)]
+                    .into_iter()
+                    .collect(),
+            );
+
+            let mut checker = Checker::new();
+            assert_eq!(
+                checker
+                    .check_module(&PathBuf::from(MODULE_ID).into(), &mut state)
+                    .unwrap_err()[0]
+                    .inner
+                    .message,
+                "Cannot implement methods for Foo, which is not a struct"
+            );
+        }
+
+        #[test]
+        fn duplicate_function_declaration() {
+            // def foo():
+            //   return
+            // def foo():
+            //   return
+            //
+            // should fail
+
+            let module = Module {
+                symbols: vec![
+                    SymbolDeclaration {
+                        id: "foo",
+                        symbol: Symbol::HereFunction(function0()),
+                    }
+                    .mock(),
+                    SymbolDeclaration {
+                        id: "foo",
+                        symbol: Symbol::HereFunction(function0()),
+                    }
+                    .mock(),
+                ],
+                imports: vec![],
+            };
+
+            let mut state = State::<Bn128Field>::new(
+                vec![(PathBuf::from(MODULE_ID).into(), module)]
+                    .into_iter()
+                    .collect(),
+            );
+
+            let mut checker = Checker::new();
+            assert_eq!(
+                checker
+                    .check_module(&PathBuf::from(MODULE_ID).into(), &mut state)
+                    .unwrap_err()[0]
+                    .inner
+                    .message,
+                "foo conflicts with another symbol"
+            );
+        }
+
+        #[test]
+        fn overloaded_function_declaration() {
+            // def foo():
+            //   return
+            // def foo(a):
+            //   return
+            //
+            // should succeed as overloading is allowed
+
+            let module = Module {
+                symbols: vec![
+                    SymbolDeclaration {
+                        id: "foo",
+                        symbol: Symbol::HereFunction(function0()),
+                    }
+                    .mock(),
+                    SymbolDeclaration {
+                        id: "foo",
+                        symbol: Symbol::HereFunction(function1()),
+                    }
+                    .mock(),
+                ],
+                imports: vec![],
+            };
+
+            let mut state = State::<Bn128Field>::new(
+                vec![(PathBuf::from(MODULE_ID), module)]
+                    .into_iter()
+                    .collect(),
+            );
+
+            let mut checker = Checker::new();
+            assert_eq!(
+                checker.check_module(&PathBuf::from(MODULE_ID), &mut state),
+                Ok(())
+            );
+            assert!(state
+                .typed_modules
+                .get(&PathBuf::from(MODULE_ID))
+                .unwrap()
+                .functions
+                .contains_key(&FunctionKey::with_id("foo").signature(Signature::new())));
+            assert!(state
                 .typed_modules
                 .get(&PathBuf::from(MODULE_ID))
                 .unwrap()
@@ -2713,7 +5141,13 @@ mod tests {
                     .mock(),
                     SymbolDeclaration {
                         id: "foo",
-                        symbol: Symbol::HereType(StructDefinition { fields: vec![] }.mock()),
+                        symbol: Symbol::HereType(
+                            StructDefinition {
+                                generics: vec![],
+                                fields: vec![],
+                            }
+                            .mock(),
+                        ),
                     }
                     .mock(),
                 ],
@@ -2849,6 +5283,7 @@ mod tests {
             scope,
             functions,
             level,
+            ..Checker::new()
         }
     }
 
@@ -2927,6 +5362,8 @@ mod tests {
             .mock(),
         ];
         let foo = Function {
+            exported: false,
+            generics: vec![],
             arguments: foo_args,
             statements: foo_statements,
             signature: UnresolvedSignature {
@@ -2946,6 +5383,8 @@ mod tests {
         .mock()];
 
         let bar = Function {
+            exported: false,
+            generics: vec![],
             arguments: bar_args,
             statements: bar_statements,
             signature: UnresolvedSignature {
@@ -3012,6 +5451,8 @@ mod tests {
         ];
 
         let foo = Function {
+            exported: false,
+            generics: vec![],
             arguments: foo_args,
             statements: foo_statements,
             signature: UnresolvedSignature {
@@ -3041,6 +5482,8 @@ mod tests {
             .mock(),
         ];
         let bar = Function {
+            exported: false,
+            generics: vec![],
             arguments: bar_args,
             statements: bar_statements,
             signature: UnresolvedSignature {
@@ -3060,6 +5503,8 @@ mod tests {
         .mock()];
 
         let main = Function {
+            exported: false,
+            generics: vec![],
             arguments: main_args,
             statements: main_statements,
             signature: UnresolvedSignature {
@@ -3122,6 +5567,8 @@ mod tests {
             .mock(),
         ];
         let foo = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: foo_statements,
             signature: UnresolvedSignature {
@@ -3188,6 +5635,8 @@ mod tests {
         )];
 
         let foo = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: foo_statements,
             signature: UnresolvedSignature {
@@ -3246,6 +5695,8 @@ mod tests {
         let functions = vec![foo].into_iter().collect();
 
         let bar = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: bar_statements,
             signature: UnresolvedSignature {
@@ -3283,6 +5734,7 @@ mod tests {
                 box Expression::FunctionCall("foo", vec![]).mock(),
             )
             .mock(),
+            None,
         )
         .mock()];
 
@@ -3297,6 +5749,8 @@ mod tests {
         let functions = vec![foo].into_iter().collect();
 
         let bar = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: bar_statements,
             signature: UnresolvedSignature {
@@ -3338,6 +5792,8 @@ mod tests {
         ];
 
         let bar = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: bar_statements,
             signature: UnresolvedSignature {
@@ -3384,6 +5840,8 @@ mod tests {
         .mock()];
 
         let foo = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![crate::absy::Parameter {
                 id: absy::Variable::new("x", UnresolvedType::FieldElement.mock()).mock(),
                 private: false,
@@ -3427,6 +5885,8 @@ mod tests {
         ];
 
         let main = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: main_statements,
             signature: UnresolvedSignature {
@@ -3489,6 +5949,8 @@ mod tests {
         .mock()];
 
         let foo = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: foo_statements,
             signature: UnresolvedSignature {
@@ -3520,6 +5982,8 @@ mod tests {
         ];
 
         let main = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: main_statements,
             signature: UnresolvedSignature {
@@ -3589,6 +6053,8 @@ mod tests {
         .mock()];
 
         let foo = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: foo_statements,
             signature: UnresolvedSignature {
@@ -3636,6 +6102,8 @@ mod tests {
         ];
 
         let main = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: main_statements,
             signature: UnresolvedSignature {
@@ -3688,10 +6156,13 @@ mod tests {
                 box Expression::FunctionCall("foo", vec![]).mock(),
             )
             .mock(),
+            None,
         )
         .mock()];
 
         let bar = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: bar_statements,
             signature: UnresolvedSignature {
@@ -3733,6 +6204,8 @@ mod tests {
         .mock()];
 
         let bar = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: bar_statements,
             signature: UnresolvedSignature {
@@ -3832,6 +6305,8 @@ mod tests {
         functions.insert(foo);
 
         let bar = Function {
+            exported: false,
+            generics: vec![],
             arguments: vec![],
             statements: bar_statements,
             signature: UnresolvedSignature {
@@ -3893,6 +6368,8 @@ mod tests {
         let main2_arguments = vec![];
 
         let main1 = Function {
+            exported: false,
+            generics: vec![],
             arguments: main1_arguments,
             statements: main1_statements,
             signature: UnresolvedSignature {
@@ -3903,6 +6380,8 @@ mod tests {
         .mock();
 
         let main2 = Function {
+            exported: false,
+            generics: vec![],
             arguments: main2_arguments,
             statements: main2_statements,
             signature: UnresolvedSignature {
@@ -3958,7 +6437,7 @@ mod tests {
         let types = HashMap::new();
         let module_id = "".into();
         let mut checker = Checker::new();
-        let _: Result<TypedStatement<Bn128Field>, Vec<ErrorInner>> = checker.check_statement(
+        let _: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker.check_statement(
             Statement::Declaration(
                 absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
             )
@@ -3966,7 +6445,7 @@ mod tests {
             &module_id,
             &types,
         );
-        let s2_checked: Result<TypedStatement<Bn128Field>, Vec<ErrorInner>> = checker
+        let s2_checked: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker
             .check_statement(
                 Statement::Declaration(
                     absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
@@ -3995,7 +6474,7 @@ mod tests {
         let module_id = "".into();
 
         let mut checker = Checker::new();
-        let _: Result<TypedStatement<Bn128Field>, Vec<ErrorInner>> = checker.check_statement(
+        let _: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker.check_statement(
             Statement::Declaration(
                 absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
             )
@@ -4003,7 +6482,7 @@ mod tests {
             &module_id,
             &types,
         );
-        let s2_checked: Result<TypedStatement<Bn128Field>, Vec<ErrorInner>> = checker
+        let s2_checked: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker
             .check_statement(
                 Statement::Declaration(
                     absy::Variable::new("a", UnresolvedType::Boolean.mock()).mock(),
@@ -4021,91 +6500,446 @@ mod tests {
         );
     }
 
-    mod structs {
-        use super::*;
+    #[test]
+    fn assertion_metadata() {
+        //   assert(true, "message")
+        //
+        // should carry the message and the position of the assertion
 
-        /// solver function to create a module at location "" with a single symbol `Foo { foo: field }`
-        fn create_module_with_foo(
-            s: StructDefinition<'static>,
-        ) -> (Checker<'static>, State<'static, Bn128Field>) {
-            let module_id: PathBuf = "".into();
+        let types = HashMap::new();
+        let module_id = "foo".into();
 
-            let module: Module = Module {
-                imports: vec![],
-                symbols: vec![SymbolDeclaration {
-                    id: "Foo",
-                    symbol: Symbol::HereType(s.mock()),
-                }
-                .mock()],
-            };
+        let mut checker = Checker::new();
+        let checked: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker
+            .check_statement(
+                Statement::Assertion(
+                    Expression::BooleanConstant(true).mock(),
+                    Some("message".into()),
+                )
+                .mock(),
+                &module_id,
+                &types,
+            );
+        assert_eq!(
+            checked,
+            Ok(vec![TypedStatement::Assertion(
+                BooleanExpression::Value(true),
+                Some(AssertionMetadata {
+                    file: "foo".into(),
+                    position: Position::mock(),
+                    message: Some("message".into())
+                })
+            )])
+        );
+    }
 
-            let mut state =
-                State::<Bn128Field>::new(vec![(module_id.clone(), module)].into_iter().collect());
+    #[test]
+    fn log() {
+        //   log("{} and {}", 1, true)
+        //
+        // should check the arguments and keep the format string
 
-            let mut checker = Checker::new();
+        let types = HashMap::new();
+        let module_id = "foo".into();
 
-            checker.check_module(&module_id, &mut state).unwrap();
+        let mut checker = Checker::new();
+        let checked: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker
+            .check_statement(
+                Statement::Log(
+                    "{} and {}".into(),
+                    vec![
+                        Expression::FieldConstant(BigUint::from(1u32)).mock(),
+                        Expression::BooleanConstant(true).mock(),
+                    ],
+                )
+                .mock(),
+                &module_id,
+                &types,
+            );
+        assert_eq!(
+            checked,
+            Ok(vec![TypedStatement::Log(
+                "{} and {}".into(),
+                vec![
+                    FieldElementExpression::Number(Bn128Field::from(1)).into(),
+                    BooleanExpression::Value(true).into()
+                ]
+            )])
+        );
+    }
 
-            (checker, state)
-        }
+    #[test]
+    fn log_wrong_argument_count() {
+        //   log("{} and {}", 1)
+        //
+        // should fail as the format string expects two arguments
 
-        /// tests about declaring a type
-        mod declaration {
-            use super::*;
+        let types = HashMap::new();
+        let module_id = "foo".into();
 
-            #[test]
-            fn empty_def() {
-                // an empty struct should be allowed to be defined
-                let module_id = "".into();
-                let types = HashMap::new();
-                let declaration = StructDefinition { fields: vec![] }.mock();
+        let mut checker = Checker::new();
+        let checked: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker
+            .check_statement(
+                Statement::Log(
+                    "{} and {}".into(),
+                    vec![Expression::FieldConstant(BigUint::from(1u32)).mock()],
+                )
+                .mock(),
+                &module_id,
+                &types,
+            );
+        assert_eq!(
+            checked,
+            Err(vec![ErrorInner {
+                pos: Some((Position::mock(), Position::mock())),
+                message: "Format string \"{} and {}\" expects 2 argument(s), found 1".into()
+            }])
+        );
+    }
 
-                let expected_type = Type::Struct(StructType::new("".into(), "Foo".into(), vec![]));
+    #[test]
+    fn if_else_scope() {
+        //   if true:
+        //     field a = 1
+        //   endif
+        //   a = 2
+        //
+        // should fail as `a` is only declared in the branch
 
-                assert_eq!(
-                    Checker::new().check_struct_type_declaration(
-                        "Foo".into(),
-                        declaration,
-                        &module_id,
-                        &types
-                    ),
-                    Ok(expected_type)
-                );
-            }
+        let types = HashMap::new();
+        let module_id = "foo".into();
 
-            #[test]
-            fn valid_def() {
-                // a valid struct should be allowed to be defined
-                let module_id = "".into();
-                let types = HashMap::new();
-                let declaration = StructDefinition {
-                    fields: vec![
-                        StructDefinitionField {
-                            id: "foo",
-                            ty: UnresolvedType::FieldElement.mock(),
-                        }
+        let mut checker = Checker::new();
+        let checked: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker
+            .check_statement(
+                Statement::IfElse(
+                    Expression::BooleanConstant(true).mock(),
+                    vec![
+                        Statement::Declaration(
+                            absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
+                        )
                         .mock(),
-                        StructDefinitionField {
-                            id: "bar",
-                            ty: UnresolvedType::Boolean.mock(),
-                        }
+                        Statement::Definition(
+                            Assignee::Identifier("a").mock(),
+                            Expression::FieldConstant(BigUint::from(1u32)).mock(),
+                        )
                         .mock(),
                     ],
-                }
-                .mock();
-
-                let expected_type = Type::Struct(StructType::new(
-                    "".into(),
-                    "Foo".into(),
-                    vec![
-                        StructMember::new("foo".into(), Type::FieldElement),
-                        StructMember::new("bar".into(), Type::Boolean),
-                    ],
-                ));
+                    vec![],
+                )
+                .mock(),
+                &module_id,
+                &types,
+            );
+        assert_eq!(
+            checked,
+            Ok(vec![TypedStatement::IfElse(
+                BooleanExpression::Value(true),
+                vec![
+                    TypedStatement::Declaration(typed_absy::Variable::field_element("a")),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(typed_absy::Variable::field_element("a")),
+                        FieldElementExpression::Number(Bn128Field::from(1)).into()
+                    )
+                ],
+                vec![]
+            )])
+        );
 
-                assert_eq!(
-                    Checker::new().check_struct_type_declaration(
-                        "Foo".into(),
+        let checked: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker
+            .check_statement(
+                Statement::Definition(
+                    Assignee::Identifier("a").mock(),
+                    Expression::FieldConstant(BigUint::from(2u32)).mock(),
+                )
+                .mock(),
+                &module_id,
+                &types,
+            );
+        assert_eq!(
+            checked,
+            Err(vec![ErrorInner {
+                pos: Some((Position::mock(), Position::mock())),
+                message: "Variable `a` is undeclared".into()
+            }])
+        );
+    }
+
+    #[test]
+    fn if_else_condition_type() {
+        //   if 1:
+        //   endif
+        //
+        // should fail as the condition is not a boolean
+
+        let types = HashMap::new();
+        let module_id = "foo".into();
+
+        let mut checker = Checker::new();
+        let checked: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker
+            .check_statement(
+                Statement::IfElse(
+                    Expression::FieldConstant(BigUint::from(1u32)).mock(),
+                    vec![],
+                    vec![],
+                )
+                .mock(),
+                &module_id,
+                &types,
+            );
+        assert_eq!(
+            checked,
+            Err(vec![ErrorInner {
+                pos: Some((Position::mock(), Position::mock())),
+                message: "{condition} after `if` should be a boolean, found field".into()
+            }])
+        );
+    }
+
+    #[test]
+    fn return_in_if_else() {
+        //   if true:
+        //     return
+        //   endif
+        //
+        // should fail as branches cannot return
+
+        let types = HashMap::new();
+        let module_id = "foo".into();
+
+        let mut checker = Checker::new();
+        let checked: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker
+            .check_statement(
+                Statement::IfElse(
+                    Expression::BooleanConstant(true).mock(),
+                    vec![Statement::Return(ExpressionList::new().mock()).mock()],
+                    vec![],
+                )
+                .mock(),
+                &module_id,
+                &types,
+            );
+        assert_eq!(
+            checked,
+            Err(vec![ErrorInner {
+                pos: Some((Position::mock(), Position::mock())),
+                message: "Return statements are not allowed in `if/else` blocks".into()
+            }])
+        );
+    }
+
+    #[test]
+    fn source_spans() {
+        // def foo() -> field:
+        //   return 1
+        //
+        // should mark the return statement with its location when source spans are enabled
+
+        let foo = Function {
+            exported: false,
+            generics: vec![],
+            arguments: vec![],
+            statements: vec![Statement::Return(
+                ExpressionList {
+                    expressions: vec![Expression::FieldConstant(BigUint::from(1u32)).mock()],
+                }
+                .mock(),
+            )
+            .mock()],
+            signature: UnresolvedSignature {
+                inputs: vec![],
+                outputs: vec![UnresolvedType::FieldElement.mock()],
+            },
+        }
+        .mock();
+
+        let foo_checked = TypedFunction {
+            arguments: vec![],
+            statements: vec![
+                TypedStatement::Span(SourceSpan {
+                    file: "foo".into(),
+                    from: Position::mock(),
+                    to: Position::mock(),
+                    stack: vec![],
+                }),
+                TypedStatement::Return(vec![
+                    FieldElementExpression::Number(Bn128Field::from(1)).into()
+                ]),
+            ],
+            signature: Signature {
+                inputs: vec![],
+                outputs: vec![Type::FieldElement],
+            },
+        };
+
+        let types = HashMap::new();
+        let module_id = "foo".into();
+
+        let mut checker = Checker {
+            source_spans: true,
+            ..Checker::new()
+        };
+        assert_eq!(
+            checker.check_function::<Bn128Field>(foo, &module_id, &types),
+            Ok(foo_checked)
+        );
+    }
+
+    mod aliases {
+        use super::*;
+
+        #[test]
+        fn alias_in_struct() {
+            // an alias should resolve to the type it stands for
+
+            // type Hash = u32[8]
+            // struct Foo { Hash h }
+
+            let module_id: PathBuf = "".into();
+
+            let module: Module = Module {
+                imports: vec![],
+                symbols: vec![
+                    SymbolDeclaration {
+                        id: "Hash",
+                        symbol: Symbol::HereAlias(
+                            UnresolvedType::array(UnresolvedType::Uint(32).mock(), 8).mock(),
+                        ),
+                    }
+                    .mock(),
+                    SymbolDeclaration {
+                        id: "Foo",
+                        symbol: Symbol::HereType(
+                            StructDefinition {
+                                generics: vec![],
+                                fields: vec![StructDefinitionField {
+                                    id: "h",
+                                    ty: UnresolvedType::User("Hash".into(), vec![]).mock(),
+                                }
+                                .mock()],
+                            }
+                            .mock(),
+                        ),
+                    }
+                    .mock(),
+                ],
+            };
+
+            let mut state =
+                State::<Bn128Field>::new(vec![(module_id.clone(), module)].into_iter().collect());
+
+            assert!(Checker::new().check_module(&module_id, &mut state).is_ok());
+            assert_eq!(
+                state
+                    .types
+                    .get(&module_id)
+                    .unwrap()
+                    .get(&"Foo".to_string())
+                    .unwrap(),
+                &Type::Struct(StructType::new(
+                    module_id,
+                    "Foo".into(),
+                    vec![StructMember::new(
+                        "h".into(),
+                        Type::array(Type::uint(32), 8)
+                    )]
+                ))
+            );
+        }
+
+        #[test]
+        fn conflict_with_struct() {
+            // an alias cannot have the name of another type
+
+            // struct Foo {}
+            // type Foo = field
+
+            let module_id: PathBuf = "".into();
+
+            let module: Module = Module {
+                imports: vec![],
+                symbols: vec![
+                    SymbolDeclaration {
+                        id: "Foo",
+                        symbol: Symbol::HereType(
+                            StructDefinition {
+                                generics: vec![],
+                                fields: vec![],
+                            }
+                            .mock(),
+                        ),
+                    }
+                    .mock(),
+                    SymbolDeclaration {
+                        id: "Foo",
+                        symbol: Symbol::HereAlias(UnresolvedType::FieldElement.mock()),
+                    }
+                    .mock(),
+                ],
+            };
+
+            let mut state =
+                State::<Bn128Field>::new(vec![(module_id.clone(), module)].into_iter().collect());
+
+            assert_eq!(
+                Checker::new()
+                    .check_module(&module_id, &mut state)
+                    .unwrap_err()[0]
+                    .inner
+                    .message,
+                "Foo conflicts with another symbol"
+            );
+        }
+    }
+
+    mod structs {
+        use super::*;
+
+        /// solver function to create a module at location "" with a single symbol `Foo { foo: field }`
+        fn create_module_with_foo(
+            s: StructDefinition<'static>,
+        ) -> (Checker<'static>, State<'static, Bn128Field>) {
+            let module_id: PathBuf = "".into();
+
+            let module: Module = Module {
+                imports: vec![],
+                symbols: vec![SymbolDeclaration {
+                    id: "Foo",
+                    symbol: Symbol::HereType(s.mock()),
+                }
+                .mock()],
+            };
+
+            let mut state =
+                State::<Bn128Field>::new(vec![(module_id.clone(), module)].into_iter().collect());
+
+            let mut checker = Checker::new();
+
+            checker.check_module(&module_id, &mut state).unwrap();
+
+            (checker, state)
+        }
+
+        /// tests about declaring a type
+        mod declaration {
+            use super::*;
+
+            #[test]
+            fn empty_def() {
+                // an empty struct should be allowed to be defined
+                let module_id = "".into();
+                let types = HashMap::new();
+                let declaration = StructDefinition {
+                    generics: vec![],
+                    fields: vec![],
+                }
+                .mock();
+
+                let expected_type = Type::Struct(StructType::new("".into(), "Foo".into(), vec![]));
+
+                assert_eq!(
+                    Checker::new().check_struct_type_declaration(
+                        "Foo".into(),
                         declaration,
                         &module_id,
                         &types
@@ -4115,12 +6949,12 @@ mod tests {
             }
 
             #[test]
-            fn preserve_order() {
-                // two structs with inverted members are not equal
+            fn valid_def() {
+                // a valid struct should be allowed to be defined
                 let module_id = "".into();
                 let types = HashMap::new();
-
-                let declaration0 = StructDefinition {
+                let declaration = StructDefinition {
+                    generics: vec![],
                     fields: vec![
                         StructDefinitionField {
                             id: "foo",
@@ -4136,34 +6970,78 @@ mod tests {
                 }
                 .mock();
 
-                let declaration1 = StructDefinition {
-                    fields: vec![
-                        StructDefinitionField {
-                            id: "bar",
-                            ty: UnresolvedType::Boolean.mock(),
-                        }
-                        .mock(),
-                        StructDefinitionField {
-                            id: "foo",
-                            ty: UnresolvedType::FieldElement.mock(),
-                        }
-                        .mock(),
+                let expected_type = Type::Struct(StructType::new(
+                    "".into(),
+                    "Foo".into(),
+                    vec![
+                        StructMember::new("foo".into(), Type::FieldElement),
+                        StructMember::new("bar".into(), Type::Boolean),
                     ],
-                }
-                .mock();
+                ));
 
-                assert_ne!(
+                assert_eq!(
                     Checker::new().check_struct_type_declaration(
                         "Foo".into(),
-                        declaration0,
+                        declaration,
                         &module_id,
                         &types
                     ),
-                    Checker::new().check_struct_type_declaration(
-                        "Foo".into(),
-                        declaration1,
-                        &module_id,
-                        &types
+                    Ok(expected_type)
+                );
+            }
+
+            #[test]
+            fn preserve_order() {
+                // two structs with inverted members are not equal
+                let module_id = "".into();
+                let types = HashMap::new();
+
+                let declaration0 = StructDefinition {
+                    generics: vec![],
+                    fields: vec![
+                        StructDefinitionField {
+                            id: "foo",
+                            ty: UnresolvedType::FieldElement.mock(),
+                        }
+                        .mock(),
+                        StructDefinitionField {
+                            id: "bar",
+                            ty: UnresolvedType::Boolean.mock(),
+                        }
+                        .mock(),
+                    ],
+                }
+                .mock();
+
+                let declaration1 = StructDefinition {
+                    generics: vec![],
+                    fields: vec![
+                        StructDefinitionField {
+                            id: "bar",
+                            ty: UnresolvedType::Boolean.mock(),
+                        }
+                        .mock(),
+                        StructDefinitionField {
+                            id: "foo",
+                            ty: UnresolvedType::FieldElement.mock(),
+                        }
+                        .mock(),
+                    ],
+                }
+                .mock();
+
+                assert_ne!(
+                    Checker::new().check_struct_type_declaration(
+                        "Foo".into(),
+                        declaration0,
+                        &module_id,
+                        &types
+                    ),
+                    Checker::new().check_struct_type_declaration(
+                        "Foo".into(),
+                        declaration1,
+                        &module_id,
+                        &types
                     )
                 );
             }
@@ -4175,6 +7053,7 @@ mod tests {
                 let types = HashMap::new();
 
                 let declaration = StructDefinition {
+                    generics: vec![],
                     fields: vec![
                         StructDefinitionField {
                             id: "foo",
@@ -4204,6 +7083,53 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn generic() {
+                // a generic struct should be instantiated for the values of its generic parameters
+
+                // struct Foo<N> = { foo: field[N] }
+                let (checker, state) = create_module_with_foo(StructDefinition {
+                    generics: vec!["N"],
+                    fields: vec![StructDefinitionField {
+                        id: "foo",
+                        ty: UnresolvedType::array(
+                            UnresolvedType::FieldElement.mock(),
+                            ArraySize::Identifier("N".into()),
+                        )
+                        .mock(),
+                    }
+                    .mock()],
+                });
+
+                assert_eq!(
+                    checker.check_type(
+                        UnresolvedType::User("Foo".into(), vec![ArraySize::Value(2)]).mock(),
+                        &"".into(),
+                        &state.types
+                    ),
+                    Ok(Type::Struct(StructType::new(
+                        "".into(),
+                        "Foo".into(),
+                        vec![StructMember::new(
+                            "foo".into(),
+                            Type::array(Type::FieldElement, 2)
+                        )]
+                    )))
+                );
+
+                assert_eq!(
+                    checker
+                        .check_type(
+                            UnresolvedType::User("Foo".into(), vec![]).mock(),
+                            &"".into(),
+                            &state.types
+                        )
+                        .unwrap_err()
+                        .message,
+                    "Struct Foo expects 1 generic parameter(s), found 0"
+                );
+            }
+
             #[test]
             fn recursive() {
                 // a struct wrapping another struct should be allowed to be defined
@@ -4220,6 +7146,7 @@ mod tests {
                             id: "Foo",
                             symbol: Symbol::HereType(
                                 StructDefinition {
+                                    generics: vec![],
                                     fields: vec![StructDefinitionField {
                                         id: "foo",
                                         ty: UnresolvedType::FieldElement.mock(),
@@ -4234,9 +7161,10 @@ mod tests {
                             id: "Bar",
                             symbol: Symbol::HereType(
                                 StructDefinition {
+                                    generics: vec![],
                                     fields: vec![StructDefinitionField {
                                         id: "foo",
-                                        ty: UnresolvedType::User("Foo".into()).mock(),
+                                        ty: UnresolvedType::User("Foo".into(), vec![]).mock(),
                                     }
                                     .mock()],
                                 }
@@ -4288,9 +7216,10 @@ mod tests {
                         id: "Bar",
                         symbol: Symbol::HereType(
                             StructDefinition {
+                                generics: vec![],
                                 fields: vec![StructDefinitionField {
                                     id: "foo",
-                                    ty: UnresolvedType::User("Foo".into()).mock(),
+                                    ty: UnresolvedType::User("Foo".into(), vec![]).mock(),
                                 }
                                 .mock()],
                             }
@@ -4321,9 +7250,10 @@ mod tests {
                         id: "Foo",
                         symbol: Symbol::HereType(
                             StructDefinition {
+                                generics: vec![],
                                 fields: vec![StructDefinitionField {
                                     id: "foo",
-                                    ty: UnresolvedType::User("Foo".into()).mock(),
+                                    ty: UnresolvedType::User("Foo".into(), vec![]).mock(),
                                 }
                                 .mock()],
                             }
@@ -4356,9 +7286,10 @@ mod tests {
                             id: "Foo",
                             symbol: Symbol::HereType(
                                 StructDefinition {
+                                    generics: vec![],
                                     fields: vec![StructDefinitionField {
                                         id: "bar",
-                                        ty: UnresolvedType::User("Bar".into()).mock(),
+                                        ty: UnresolvedType::User("Bar".into(), vec![]).mock(),
                                     }
                                     .mock()],
                                 }
@@ -4370,9 +7301,10 @@ mod tests {
                             id: "Bar",
                             symbol: Symbol::HereType(
                                 StructDefinition {
+                                    generics: vec![],
                                     fields: vec![StructDefinitionField {
                                         id: "foo",
-                                        ty: UnresolvedType::User("Foo".into()).mock(),
+                                        ty: UnresolvedType::User("Foo".into(), vec![]).mock(),
                                     }
                                     .mock()],
                                 }
@@ -4405,6 +7337,7 @@ mod tests {
                 // Bar
 
                 let (checker, state) = create_module_with_foo(StructDefinition {
+                    generics: vec![],
                     fields: vec![StructDefinitionField {
                         id: "foo",
                         ty: UnresolvedType::FieldElement.mock(),
@@ -4414,7 +7347,7 @@ mod tests {
 
                 assert_eq!(
                     checker.check_type(
-                        UnresolvedType::User("Foo".into()).mock(),
+                        UnresolvedType::User("Foo".into(), vec![]).mock(),
                         &PathBuf::from(MODULE_ID).into(),
                         &state.types
                     ),
@@ -4428,7 +7361,7 @@ mod tests {
                 assert_eq!(
                     checker
                         .check_type(
-                            UnresolvedType::User("Bar".into()).mock(),
+                            UnresolvedType::User("Bar".into(), vec![]).mock(),
                             &PathBuf::from(MODULE_ID).into(),
                             &state.types
                         )
@@ -4445,6 +7378,7 @@ mod tests {
                 // an undefined type cannot be used as parameter
 
                 let (checker, state) = create_module_with_foo(StructDefinition {
+                    generics: vec![],
                     fields: vec![StructDefinitionField {
                         id: "foo",
                         ty: UnresolvedType::FieldElement.mock(),
@@ -4455,9 +7389,11 @@ mod tests {
                 assert_eq!(
                     checker.check_parameter(
                         absy::Parameter {
-                            id:
-                                absy::Variable::new("a", UnresolvedType::User("Foo".into()).mock(),)
-                                    .mock(),
+                            id: absy::Variable::new(
+                                "a",
+                                UnresolvedType::User("Foo".into(), vec![]).mock(),
+                            )
+                            .mock(),
                             private: true,
                         }
                         .mock(),
@@ -4473,7 +7409,8 @@ mod tests {
                                 vec![StructMember::new("foo".into(), Type::FieldElement)]
                             ))
                         ),
-                        private: true
+                        private: true,
+                        alias: None
                     })
                 );
 
@@ -4483,7 +7420,7 @@ mod tests {
                             absy::Parameter {
                                 id: absy::Variable::new(
                                     "a",
-                                    UnresolvedType::User("Bar".into()).mock(),
+                                    UnresolvedType::User("Bar".into(), vec![]).mock(),
                                 )
                                 .mock(),
                                 private: true,
@@ -4505,6 +7442,7 @@ mod tests {
                 // an undefined type cannot be used in a variable declaration
 
                 let (mut checker, state) = create_module_with_foo(StructDefinition {
+                    generics: vec![],
                     fields: vec![StructDefinitionField {
                         id: "foo",
                         ty: UnresolvedType::FieldElement.mock(),
@@ -4515,8 +7453,11 @@ mod tests {
                 assert_eq!(
                     checker.check_statement::<Bn128Field>(
                         Statement::Declaration(
-                            absy::Variable::new("a", UnresolvedType::User("Foo".into()).mock(),)
-                                .mock()
+                            absy::Variable::new(
+                                "a",
+                                UnresolvedType::User("Foo".into(), vec![]).mock(),
+                            )
+                            .mock()
                         )
                         .mock(),
                         &PathBuf::from(MODULE_ID).into(),
@@ -4538,7 +7479,7 @@ mod tests {
                             absy::Parameter {
                                 id: absy::Variable::new(
                                     "a",
-                                    UnresolvedType::User("Bar".into()).mock(),
+                                    UnresolvedType::User("Bar".into(), vec![]).mock(),
                                 )
                                 .mock(),
                                 private: true,
@@ -4566,6 +7507,7 @@ mod tests {
                 // Foo { foo: 42 }.foo
 
                 let (mut checker, state) = create_module_with_foo(StructDefinition {
+                    generics: vec![],
                     fields: vec![StructDefinitionField {
                         id: "foo",
                         ty: UnresolvedType::FieldElement.mock(),
@@ -4614,6 +7556,7 @@ mod tests {
                 // Foo { foo: 42 }.bar
 
                 let (mut checker, state) = create_module_with_foo(StructDefinition {
+                    generics: vec![],
                     fields: vec![StructDefinitionField {
                         id: "foo",
                         ty: UnresolvedType::FieldElement.mock(),
@@ -4655,6 +7598,7 @@ mod tests {
                 // a A value cannot be defined with B as id, even if A and B have the same members
 
                 let (mut checker, state) = create_module_with_foo(StructDefinition {
+                    generics: vec![],
                     fields: vec![StructDefinitionField {
                         id: "foo",
                         ty: UnresolvedType::FieldElement.mock(),
@@ -4690,6 +7634,7 @@ mod tests {
                 // Foo foo = Foo { foo: 42, bar: true }
 
                 let (mut checker, state) = create_module_with_foo(StructDefinition {
+                    generics: vec![],
                     fields: vec![
                         StructDefinitionField {
                             id: "foo",
@@ -4744,6 +7689,7 @@ mod tests {
                 // Foo foo = Foo { bar: true, foo: 42 }
 
                 let (mut checker, state) = create_module_with_foo(StructDefinition {
+                    generics: vec![],
                     fields: vec![
                         StructDefinitionField {
                             id: "foo",
@@ -4798,6 +7744,7 @@ mod tests {
                 // Foo foo = Foo { foo: 42 }
 
                 let (mut checker, state) = create_module_with_foo(StructDefinition {
+                    generics: vec![],
                     fields: vec![
                         StructDefinitionField {
                             id: "foo",
@@ -4842,6 +7789,7 @@ mod tests {
                 // Foo { foo: 42, baz: 42 } // error
 
                 let (mut checker, state) = create_module_with_foo(StructDefinition {
+                    generics: vec![],
                     fields: vec![
                         StructDefinitionField {
                             id: "foo",
@@ -4905,6 +7853,273 @@ mod tests {
         }
     }
 
+    mod tuples {
+        use super::*;
+
+        #[test]
+        fn ty() {
+            // a tuple type is resolved element by element
+
+            // (field, bool[2])
+            let module_id: PathBuf = "".into();
+            let types = HashMap::new();
+
+            assert_eq!(
+                Checker::new().check_type(
+                    UnresolvedType::Tuple(vec![
+                        UnresolvedType::FieldElement.mock(),
+                        UnresolvedType::array(UnresolvedType::Boolean.mock(), 2).mock(),
+                    ])
+                    .mock(),
+                    &module_id,
+                    &types
+                ),
+                Ok(Type::Tuple(TupleType::new(vec![
+                    Type::FieldElement,
+                    Type::array(Type::Boolean, 2)
+                ])))
+            );
+        }
+
+        #[test]
+        fn element() {
+            // accessing an element of a tuple should succeed and return the right type
+
+            // (42, true).1
+            let types = HashMap::new();
+
+            assert_eq!(
+                Checker::new().check_expression::<Bn128Field>(
+                    Expression::Element(
+                        box Expression::InlineTuple(vec![
+                            Expression::FieldConstant(BigUint::from(42u32)).mock(),
+                            Expression::BooleanConstant(true).mock()
+                        ])
+                        .mock(),
+                        1
+                    )
+                    .mock(),
+                    &PathBuf::from(MODULE_ID).into(),
+                    &types
+                ),
+                Ok(BooleanExpression::Element(
+                    box TupleExpressionInner::Value(vec![
+                        FieldElementExpression::Number(Bn128Field::from(42u32)).into(),
+                        BooleanExpression::Value(true).into()
+                    ])
+                    .annotate(TupleType::new(vec![Type::FieldElement, Type::Boolean])),
+                    1
+                )
+                .into())
+            );
+        }
+
+        #[test]
+        fn element_out_of_bounds() {
+            // accessing an element past the end of a tuple should fail
+
+            // (42, true).2
+            let types = HashMap::new();
+
+            assert_eq!(
+                Checker::new()
+                    .check_expression::<Bn128Field>(
+                        Expression::Element(
+                            box Expression::InlineTuple(vec![
+                                Expression::FieldConstant(BigUint::from(42u32)).mock(),
+                                Expression::BooleanConstant(true).mock()
+                            ])
+                            .mock(),
+                            2
+                        )
+                        .mock(),
+                        &PathBuf::from(MODULE_ID).into(),
+                        &types
+                    )
+                    .unwrap_err()
+                    .message,
+                "(field, bool) doesn't have element 2"
+            );
+        }
+
+        #[test]
+        fn element_on_non_tuple() {
+            // accessing an element of a field should fail
+
+            // 42.0
+            let types = HashMap::new();
+
+            assert_eq!(
+                Checker::new()
+                    .check_expression::<Bn128Field>(
+                        Expression::Element(
+                            box Expression::FieldConstant(BigUint::from(42u32)).mock(),
+                            0
+                        )
+                        .mock(),
+                        &PathBuf::from(MODULE_ID).into(),
+                        &types
+                    )
+                    .unwrap_err()
+                    .message,
+                "Cannot access element 0 on expression of type field"
+            );
+        }
+
+        #[test]
+        fn destructuring() {
+            // def foo() -> ((field, bool)):
+            //   return (1, true)
+            // def bar() -> (field):
+            //   field a
+            //   bool b
+            //   a, b = foo()
+            //   return a
+            //
+            // should destructure the tuple returned by `foo` into the existing variables
+            let bar_statements: Vec<StatementNode> = vec![
+                Statement::Declaration(
+                    absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
+                )
+                .mock(),
+                Statement::Declaration(
+                    absy::Variable::new("b", UnresolvedType::Boolean.mock()).mock(),
+                )
+                .mock(),
+                Statement::MultipleDefinition(
+                    vec![
+                        Assignee::Identifier("a").mock(),
+                        Assignee::Identifier("b").mock(),
+                    ],
+                    Expression::FunctionCall("foo", vec![]).mock(),
+                )
+                .mock(),
+                Statement::Return(
+                    ExpressionList {
+                        expressions: vec![Expression::Identifier("a").mock()],
+                    }
+                    .mock(),
+                )
+                .mock(),
+            ];
+
+            let tuple_type = TupleType::new(vec![Type::FieldElement, Type::Boolean]);
+            let tuple = typed_absy::Variable::with_id_and_type(
+                CoreIdentifier::Internal("tuple", 1),
+                Type::Tuple(tuple_type.clone()),
+            );
+            let t = TupleExpressionInner::Identifier(tuple.id.clone()).annotate(tuple_type.clone());
+
+            let foo = FunctionKey::with_id("foo")
+                .signature(Signature::new().outputs(vec![Type::Tuple(tuple_type.clone())]));
+
+            let bar_statements_checked: Vec<TypedStatement<Bn128Field>> = vec![
+                TypedStatement::Declaration(typed_absy::Variable::field_element("a")),
+                TypedStatement::Declaration(typed_absy::Variable::boolean("b")),
+                TypedStatement::Declaration(tuple.clone()),
+                TypedStatement::MultipleDefinition(
+                    vec![tuple],
+                    TypedExpressionList::FunctionCall(
+                        foo.clone(),
+                        vec![],
+                        vec![Type::Tuple(tuple_type)],
+                    ),
+                ),
+                TypedStatement::Definition(
+                    TypedAssignee::Identifier(typed_absy::Variable::field_element("a")),
+                    FieldElementExpression::Element(box t.clone(), 0).into(),
+                ),
+                TypedStatement::Definition(
+                    TypedAssignee::Identifier(typed_absy::Variable::boolean("b")),
+                    BooleanExpression::Element(box t, 1).into(),
+                ),
+                TypedStatement::Return(vec![FieldElementExpression::Identifier("a".into()).into()]),
+            ];
+
+            let mut functions = HashSet::new();
+            functions.insert(foo);
+
+            let bar = Function {
+                exported: false,
+                generics: vec![],
+                arguments: vec![],
+                statements: bar_statements,
+                signature: UnresolvedSignature {
+                    inputs: vec![],
+                    outputs: vec![UnresolvedType::FieldElement.mock()],
+                },
+            }
+            .mock();
+
+            let bar_checked = TypedFunction {
+                arguments: vec![],
+                statements: bar_statements_checked,
+                signature: Signature {
+                    inputs: vec![],
+                    outputs: vec![Type::FieldElement],
+                },
+            };
+
+            let types = HashMap::new();
+            let module_id = "".into();
+
+            let mut checker = new_with_args(HashSet::new(), 0, functions);
+            assert_eq!(
+                checker.check_function(bar, &module_id, &types),
+                Ok(bar_checked)
+            );
+        }
+    }
+
+    mod vectors {
+        use super::*;
+
+        #[test]
+        fn ty() {
+            // field[2][..3]
+            let module_id: PathBuf = "".into();
+            let types = HashMap::new();
+
+            assert_eq!(
+                Checker::new().check_type(
+                    UnresolvedType::vector(
+                        UnresolvedType::array(UnresolvedType::FieldElement.mock(), 2).mock(),
+                        3
+                    )
+                    .mock(),
+                    &module_id,
+                    &types
+                ),
+                Ok(Type::vector(Type::array(Type::FieldElement, 2), 3))
+            );
+        }
+
+        #[test]
+        fn nested() {
+            // vectors cannot be used inside other types
+
+            // field[..3][2]
+            let module_id: PathBuf = "".into();
+            let types = HashMap::new();
+
+            assert_eq!(
+                Checker::new()
+                    .check_type(
+                        UnresolvedType::array(
+                            UnresolvedType::vector(UnresolvedType::FieldElement.mock(), 3).mock(),
+                            2
+                        )
+                        .mock(),
+                        &module_id,
+                        &types
+                    )
+                    .unwrap_err()
+                    .message,
+                "Vectors cannot be used inside other types, found field[..3]"
+            );
+        }
+    }
+
     mod assignee {
         use super::*;
         use num_bigint::BigUint;
@@ -5033,4 +8248,553 @@ mod tests {
             );
         }
     }
+
+    mod generics {
+        use super::*;
+
+        /// Helper function to create
+        /// def foo<N>(field[N] a) -> field[N]:
+        ///     return a
+        fn generic_identity() -> FunctionNode<'static> {
+            let array_type = UnresolvedType::array(
+                UnresolvedType::FieldElement.mock(),
+                ArraySize::Identifier("N".into()),
+            );
+
+            Function {
+                exported: false,
+                generics: vec!["N"],
+                arguments: vec![absy::Parameter::private(
+                    absy::Variable::new("a", array_type.clone().mock()).mock(),
+                )
+                .mock()],
+                statements: vec![Statement::Return(
+                    ExpressionList {
+                        expressions: vec![Expression::Identifier("a").mock()],
+                    }
+                    .mock(),
+                )
+                .mock()],
+                signature: UnresolvedSignature::new()
+                    .inputs(vec![array_type.clone().mock()])
+                    .outputs(vec![array_type.mock()]),
+            }
+            .mock()
+        }
+
+        #[test]
+        fn instantiate_for_each_size() {
+            // def foo<N>(field[N] a) -> field[N]:
+            //     return a
+            // def main(field[2] a, field[3] b):
+            //     field[2] c = foo(a)
+            //     field[3] d = foo(b)
+            //     return
+            //
+            // should create two instances of `foo`
+
+            let call = |target: &'static str, argument: &'static str, size: usize| {
+                vec![
+                    Statement::Declaration(
+                        absy::Variable::new(
+                            target,
+                            UnresolvedType::array(UnresolvedType::FieldElement.mock(), size).mock(),
+                        )
+                        .mock(),
+                    )
+                    .mock(),
+                    Statement::MultipleDefinition(
+                        vec![Assignee::Identifier(target).mock()],
+                        Expression::FunctionCall(
+                            "foo",
+                            vec![Expression::Identifier(argument).mock()],
+                        )
+                        .mock(),
+                    )
+                    .mock(),
+                ]
+            };
+
+            let main = Function {
+                exported: false,
+                generics: vec![],
+                arguments: vec![
+                    absy::Parameter::private(
+                        absy::Variable::new(
+                            "a",
+                            UnresolvedType::array(UnresolvedType::FieldElement.mock(), 2).mock(),
+                        )
+                        .mock(),
+                    )
+                    .mock(),
+                    absy::Parameter::private(
+                        absy::Variable::new(
+                            "b",
+                            UnresolvedType::array(UnresolvedType::FieldElement.mock(), 3).mock(),
+                        )
+                        .mock(),
+                    )
+                    .mock(),
+                ],
+                statements: call("c", "a", 2)
+                    .into_iter()
+                    .chain(call("d", "b", 3))
+                    .chain(std::iter::once(
+                        Statement::Return(
+                            ExpressionList {
+                                expressions: vec![],
+                            }
+                            .mock(),
+                        )
+                        .mock(),
+                    ))
+                    .collect(),
+                signature: UnresolvedSignature::new().inputs(vec![
+                    UnresolvedType::array(UnresolvedType::FieldElement.mock(), 2).mock(),
+                    UnresolvedType::array(UnresolvedType::FieldElement.mock(), 3).mock(),
+                ]),
+            }
+            .mock();
+
+            let module = Module {
+                symbols: vec![
+                    SymbolDeclaration {
+                        id: "foo",
+                        symbol: Symbol::HereFunction(generic_identity()),
+                    }
+                    .mock(),
+                    SymbolDeclaration {
+                        id: "main",
+                        symbol: Symbol::HereFunction(main),
+                    }
+                    .mock(),
+                ],
+                imports: vec![],
+            };
+
+            let mut state =
+                State::<Bn128Field>::new(vec![("".into(), module)].into_iter().collect());
+
+            let mut checker = Checker::new();
+            assert_eq!(checker.check_module(&"".into(), &mut state), Ok(()));
+
+            let instance_key = |size| {
+                FunctionKey::with_id("foo").signature(
+                    Signature::new()
+                        .inputs(vec![Type::array(Type::FieldElement, size)])
+                        .outputs(vec![Type::array(Type::FieldElement, size)]),
+                )
+            };
+
+            let functions = &state
+                .typed_modules
+                .get(&PathBuf::from(""))
+                .unwrap()
+                .functions;
+
+            assert_eq!(functions.len(), 3);
+            assert!(functions.contains_key(&instance_key(2)));
+            assert!(functions.contains_key(&instance_key(3)));
+        }
+
+        #[test]
+        fn generic_not_in_arguments() {
+            // def foo<N>(field a) -> field:
+            //     return a
+            //
+            // should fail as `N` cannot be inferred
+
+            let foo = Function {
+                exported: false,
+                generics: vec!["N"],
+                arguments: vec![absy::Parameter::private(
+                    absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
+                )
+                .mock()],
+                statements: vec![Statement::Return(
+                    ExpressionList {
+                        expressions: vec![Expression::Identifier("a").mock()],
+                    }
+                    .mock(),
+                )
+                .mock()],
+                signature: UnresolvedSignature::new()
+                    .inputs(vec![UnresolvedType::FieldElement.mock()])
+                    .outputs(vec![UnresolvedType::FieldElement.mock()]),
+            }
+            .mock();
+
+            let module = Module {
+                symbols: vec![SymbolDeclaration {
+                    id: "foo",
+                    symbol: Symbol::HereFunction(foo),
+                }
+                .mock()],
+                imports: vec![],
+            };
+
+            let mut state =
+                State::<Bn128Field>::new(vec![("".into(), module)].into_iter().collect());
+
+            let mut checker = Checker::new();
+            assert_eq!(
+                checker.check_module(&"".into(), &mut state).unwrap_err()[0]
+                    .inner
+                    .message,
+                "Generic parameter N must be used in the type of an argument"
+            );
+        }
+
+        #[test]
+        fn generic_outside_of_generic_function() {
+            // field[N] a
+            //
+            // should fail as `N` is not defined
+
+            let types = HashMap::new();
+            let module_id = "".into();
+            let mut checker: Checker = Checker::new();
+
+            assert_eq!(
+                checker
+                    .check_statement::<Bn128Field>(
+                        Statement::Declaration(
+                            absy::Variable::new(
+                                "a",
+                                UnresolvedType::array(
+                                    UnresolvedType::FieldElement.mock(),
+                                    ArraySize::Identifier("N".into()),
+                                )
+                                .mock(),
+                            )
+                            .mock(),
+                        )
+                        .mock(),
+                        &module_id,
+                        &types,
+                    )
+                    .unwrap_err()[0]
+                    .message,
+                "Undefined generic parameter N"
+            );
+        }
+    }
+
+    mod constant_definitions {
+        use super::*;
+
+        /// Helper function to create
+        /// const <ty> <id> = <expression>
+        fn constant(
+            id: &'static str,
+            ty: UnresolvedType,
+            expression: Expression<'static>,
+        ) -> SymbolDeclarationNode<'static> {
+            SymbolDeclaration {
+                id,
+                symbol: Symbol::HereConstant(
+                    absy::ConstantDefinition {
+                        ty: ty.mock(),
+                        expression: expression.mock(),
+                    }
+                    .mock(),
+                ),
+            }
+            .mock()
+        }
+
+        #[test]
+        fn inline_in_function() {
+            // const field FOO = 42
+            // def main() -> field:
+            //     return FOO
+            //
+            // should return 42
+
+            let main = Function {
+                exported: false,
+                generics: vec![],
+                arguments: vec![],
+                statements: vec![Statement::Return(
+                    ExpressionList {
+                        expressions: vec![Expression::Identifier("FOO").mock()],
+                    }
+                    .mock(),
+                )
+                .mock()],
+                signature: UnresolvedSignature::new()
+                    .inputs(vec![])
+                    .outputs(vec![UnresolvedType::FieldElement.mock()]),
+            }
+            .mock();
+
+            let module = Module {
+                symbols: vec![
+                    constant(
+                        "FOO",
+                        UnresolvedType::FieldElement,
+                        Expression::FieldConstant(BigUint::from(42u32)),
+                    ),
+                    SymbolDeclaration {
+                        id: "main",
+                        symbol: Symbol::HereFunction(main),
+                    }
+                    .mock(),
+                ],
+                imports: vec![],
+            };
+
+            let mut state =
+                State::<Bn128Field>::new(vec![("".into(), module)].into_iter().collect());
+
+            let mut checker = Checker::new();
+            assert_eq!(checker.check_module(&"".into(), &mut state), Ok(()));
+
+            let main_key = FunctionKey::with_id("main")
+                .signature(Signature::new().outputs(vec![Type::FieldElement]));
+
+            match state
+                .typed_modules
+                .get(&PathBuf::from(""))
+                .unwrap()
+                .functions[&main_key]
+            {
+                TypedFunctionSymbol::Here(ref f) => assert_eq!(
+                    f.statements,
+                    vec![TypedStatement::Return(vec![
+                        FieldElementExpression::Number(Bn128Field::from(42)).into()
+                    ])]
+                ),
+                _ => unreachable!(),
+            }
+        }
+
+        /// Helper function to create
+        /// def main(field[N] a):
+        ///     return
+        fn array_size_main() -> SymbolDeclarationNode<'static> {
+            let main = Function {
+                exported: false,
+                generics: vec![],
+                arguments: vec![absy::Parameter::private(
+                    absy::Variable::new(
+                        "a",
+                        UnresolvedType::array(
+                            UnresolvedType::FieldElement.mock(),
+                            ArraySize::Identifier("N".into()),
+                        )
+                        .mock(),
+                    )
+                    .mock(),
+                )
+                .mock()],
+                statements: vec![Statement::Return(
+                    ExpressionList {
+                        expressions: vec![],
+                    }
+                    .mock(),
+                )
+                .mock()],
+                signature: UnresolvedSignature::new().inputs(vec![UnresolvedType::array(
+                    UnresolvedType::FieldElement.mock(),
+                    ArraySize::Identifier("N".into()),
+                )
+                .mock()]),
+            }
+            .mock();
+
+            SymbolDeclaration {
+                id: "main",
+                symbol: Symbol::HereFunction(main),
+            }
+            .mock()
+        }
+
+        #[test]
+        fn array_size() {
+            // const field N = 2
+            // def main(field[N] a):
+            //     return
+            //
+            // should accept `field[2]` as input
+
+            let module = Module {
+                symbols: vec![
+                    constant(
+                        "N",
+                        UnresolvedType::FieldElement,
+                        Expression::FieldConstant(BigUint::from(2u32)),
+                    ),
+                    array_size_main(),
+                ],
+                imports: vec![],
+            };
+
+            let mut state =
+                State::<Bn128Field>::new(vec![("".into(), module)].into_iter().collect());
+
+            let mut checker = Checker::new();
+            assert_eq!(checker.check_module(&"".into(), &mut state), Ok(()));
+
+            let main_key = FunctionKey::with_id("main")
+                .signature(Signature::new().inputs(vec![Type::array(Type::FieldElement, 2)]));
+
+            assert!(state
+                .typed_modules
+                .get(&PathBuf::from(""))
+                .unwrap()
+                .functions
+                .contains_key(&main_key));
+        }
+
+        #[test]
+        fn computed_array_size() {
+            // const u32 N = 2 * 4
+            // def main(field[N] a):
+            //     return
+            //
+            // should accept `field[8]` as input
+
+            let module = Module {
+                symbols: vec![
+                    constant(
+                        "N",
+                        UnresolvedType::Uint(32),
+                        Expression::Mult(
+                            box Expression::U32Constant(2).mock(),
+                            box Expression::U32Constant(4).mock(),
+                        ),
+                    ),
+                    array_size_main(),
+                ],
+                imports: vec![],
+            };
+
+            let mut state =
+                State::<Bn128Field>::new(vec![("".into(), module)].into_iter().collect());
+
+            let mut checker = Checker::new();
+            assert_eq!(checker.check_module(&"".into(), &mut state), Ok(()));
+
+            let main_key = FunctionKey::with_id("main")
+                .signature(Signature::new().inputs(vec![Type::array(Type::FieldElement, 8)]));
+
+            assert!(state
+                .typed_modules
+                .get(&PathBuf::from(""))
+                .unwrap()
+                .functions
+                .contains_key(&main_key));
+        }
+
+        #[test]
+        fn invalid_array_size() {
+            // const bool N = true
+            // def main(field[N] a):
+            //     return
+            //
+            // should fail
+
+            let module = Module {
+                symbols: vec![
+                    constant(
+                        "N",
+                        UnresolvedType::Boolean,
+                        Expression::BooleanConstant(true),
+                    ),
+                    array_size_main(),
+                ],
+                imports: vec![],
+            };
+
+            let mut state =
+                State::<Bn128Field>::new(vec![("".into(), module)].into_iter().collect());
+
+            let mut checker = Checker::new();
+            assert_eq!(
+                checker.check_module(&"".into(), &mut state).unwrap_err()[0]
+                    .inner
+                    .message,
+                "Constant N cannot be used as an array size, as its value is not a valid size"
+            );
+        }
+
+        #[test]
+        fn type_mismatch() {
+            // const bool FOO = 42
+            //
+            // should fail
+
+            let module = Module {
+                symbols: vec![constant(
+                    "FOO",
+                    UnresolvedType::Boolean,
+                    Expression::FieldConstant(BigUint::from(42u32)),
+                )],
+                imports: vec![],
+            };
+
+            let mut state =
+                State::<Bn128Field>::new(vec![("".into(), module)].into_iter().collect());
+
+            let mut checker = Checker::new();
+            assert_eq!(
+                checker.check_module(&"".into(), &mut state).unwrap_err()[0]
+                    .inner
+                    .message,
+                "Expression 42 of type field cannot be assigned to constant of type bool"
+            );
+        }
+
+        #[test]
+        fn constant_function_conflict() {
+            // const field foo = 42
+            // def foo():
+            //     return
+            //
+            // should fail
+
+            let module = Module {
+                symbols: vec![
+                    constant(
+                        "foo",
+                        UnresolvedType::FieldElement,
+                        Expression::FieldConstant(BigUint::from(42u32)),
+                    ),
+                    SymbolDeclaration {
+                        id: "foo",
+                        symbol: Symbol::HereFunction(
+                            Function {
+                                exported: false,
+                                generics: vec![],
+                                arguments: vec![],
+                                statements: vec![Statement::Return(
+                                    ExpressionList {
+                                        expressions: vec![],
+                                    }
+                                    .mock(),
+                                )
+                                .mock()],
+                                signature: UnresolvedSignature::new(),
+                            }
+                            .mock(),
+                        ),
+                    }
+                    .mock(),
+                ],
+                imports: vec![],
+            };
+
+            let mut state =
+                State::<Bn128Field>::new(vec![("".into(), module)].into_iter().collect());
+
+            let mut checker = Checker::new();
+            assert_eq!(
+                checker.check_module(&"".into(), &mut state).unwrap_err()[0]
+                    .inner
+                    .message,
+                "foo conflicts with another symbol"
+            );
+        }
+    }
 }
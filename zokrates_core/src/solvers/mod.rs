@@ -6,10 +6,13 @@ pub enum Solver {
     ConditionEq,
     Bits(usize),
     Div,
+    EuclideanDiv,
     Xor,
     Or,
     ShaAndXorAndXorAnd,
     ShaCh,
+    Gt,
+    Select(usize),
 }
 
 impl fmt::Display for Solver {
@@ -24,10 +27,13 @@ impl Solver {
             Solver::ConditionEq => (1, 2),
             Solver::Bits(bit_width) => (1, *bit_width),
             Solver::Div => (2, 1),
+            Solver::EuclideanDiv => (2, 2),
             Solver::Xor => (2, 1),
             Solver::Or => (2, 1),
             Solver::ShaAndXorAndXorAnd => (3, 1),
             Solver::ShaCh => (3, 1),
+            Solver::Gt => (2, 1),
+            Solver::Select(size) => (size + 1, 1),
         }
     }
 }
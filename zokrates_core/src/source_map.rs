@@ -0,0 +1,107 @@
+//! Module containing the mapping from compiled statements back to the source code
+//!
+//! @file source_map.rs
+//! @date 2020
+
+use crate::parser::Position;
+use std::fmt;
+
+/// The location of a statement in the source code
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceSpan {
+    pub file: String,
+    pub from: Position,
+    pub to: Position,
+    /// The inlined calls which led to this statement, starting from `main`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stack: Vec<Call>,
+}
+
+/// A function call which was inlined
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Call {
+    /// The id of the function called
+    pub function: String,
+    /// The location of the call
+    pub site: SourceSpan,
+}
+
+impl fmt::Display for Call {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.function, self.site)
+    }
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.from)
+    }
+}
+
+/// For each statement of a function, the location of the source statement it was compiled from.
+/// Statements which do not originate from a source statement, such as the checks on the
+/// arguments of `main`, are mapped to `None`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SourceMap(pub Vec<Option<SourceSpan>>);
+
+impl SourceMap {
+    /// Returns the location of the statement at `index`, if any
+    pub fn get(&self, index: usize) -> Option<&SourceSpan> {
+        self.0.get(index).and_then(|span| span.as_ref())
+    }
+
+    /// Returns an iterator over the spans of the statements, followed by `None` indefinitely, so
+    /// that it can be zipped with statements which are not covered by this map
+    pub fn into_spans(self) -> impl Iterator<Item = Option<SourceSpan>> {
+        self.0.into_iter().chain(std::iter::repeat(None))
+    }
+}
+
+impl Extend<Option<SourceSpan>> for SourceMap {
+    fn extend<I: IntoIterator<Item = Option<SourceSpan>>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(line: usize) -> SourceSpan {
+        SourceSpan {
+            file: String::from("./main.zok"),
+            from: Position { line, col: 5 },
+            to: Position { line, col: 17 },
+            stack: vec![],
+        }
+    }
+
+    #[test]
+    fn get() {
+        let map = SourceMap(vec![None, Some(span(2))]);
+
+        assert_eq!(map.get(0), None);
+        assert_eq!(map.get(1), Some(&span(2)));
+        assert_eq!(map.get(2), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(span(2).to_string(), "./main.zok:2:5");
+
+        let call = Call {
+            function: String::from("foo"),
+            site: span(3),
+        };
+        assert_eq!(call.to_string(), "foo (./main.zok:3:5)");
+    }
+
+    #[test]
+    fn pad_spans() {
+        let map = SourceMap(vec![Some(span(2))]);
+
+        let spans: Vec<_> = map.into_spans().take(3).collect();
+
+        assert_eq!(spans, vec![Some(span(2)), None, None]);
+    }
+}
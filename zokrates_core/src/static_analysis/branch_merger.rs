@@ -0,0 +1,518 @@
+//! Module containing the merging of the definitions made in `if/else` blocks
+//!
+//! For example:
+//! ```zokrates
+//! if c:
+//!     a = 42
+//! endif
+//! ```
+//!
+//! Would become
+//! ```zokrates
+//! bool #CONDITION_0 = c
+//! if #CONDITION_0:
+//!     bool #CONDITION_1 = #CONDITION_0
+//!     a = if #CONDITION_1 then 42 else a fi
+//! endif
+//! ```
+//!
+//! Variables declared inside a branch are not visible outside of it, so their definitions are kept as is.
+//! The blocks themselves are kept so that the assertions they contain are only enforced when the branch is taken.
+//!
+//! @file branch_merger.rs
+//! @date 2020
+
+use crate::typed_absy::folder::*;
+use crate::typed_absy::*;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use typed_absy::identifier::CoreIdentifier;
+use typed_absy::types::Type;
+use zokrates_field::Field;
+
+pub struct BranchMerger<'ast> {
+    // the number of variables introduced so far
+    count: usize,
+    // the condition under which the current branch is taken, if we are in a branch
+    condition: Option<Identifier<'ast>>,
+    // the variables declared in the current branch
+    declared: HashSet<Identifier<'ast>>,
+}
+
+impl<'ast> BranchMerger<'ast> {
+    fn new() -> Self {
+        BranchMerger {
+            count: 0,
+            condition: None,
+            declared: HashSet::new(),
+        }
+    }
+
+    pub fn merge<T: Field>(p: TypedProgram<'ast, T>) -> TypedProgram<'ast, T> {
+        BranchMerger::new().fold_program(p)
+    }
+
+    fn issue_variable(&mut self, name: &'static str, ty: Type) -> Variable<'ast> {
+        let v = Variable::with_id_and_type(CoreIdentifier::Internal(name, self.count), ty);
+        self.count += 1;
+        v
+    }
+
+    // fold the statements of a branch taken when `condition` holds
+    fn fold_branch<T: Field>(
+        &mut self,
+        condition: BooleanExpression<'ast, T>,
+        statements: Vec<TypedStatement<'ast, T>>,
+    ) -> Vec<TypedStatement<'ast, T>> {
+        if statements.is_empty() {
+            return vec![];
+        }
+
+        let v = self.issue_variable("CONDITION", Type::Boolean);
+
+        let condition = match self.condition.clone() {
+            Some(outer) => {
+                BooleanExpression::And(box BooleanExpression::Identifier(outer), box condition)
+            }
+            None => condition,
+        };
+
+        let outer_condition = std::mem::replace(&mut self.condition, Some(v.id.clone()));
+        let outer_declared = std::mem::replace(&mut self.declared, HashSet::new());
+
+        let res = vec![
+            TypedStatement::Declaration(v.clone()),
+            TypedStatement::Definition(TypedAssignee::Identifier(v), condition.into()),
+        ]
+        .into_iter()
+        .chain(statements.into_iter().flat_map(|s| self.fold_statement(s)))
+        .collect();
+
+        self.condition = outer_condition;
+        self.declared = outer_declared;
+
+        res
+    }
+
+    // whether a definition of `v` must only take effect if the current branch is taken
+    fn is_merged(&self, v: &Variable<'ast>) -> bool {
+        self.condition.is_some() && !self.declared.contains(&v.id)
+    }
+}
+
+fn base<'a, 'ast, T>(a: &'a TypedAssignee<'ast, T>) -> &'a Variable<'ast> {
+    match a {
+        TypedAssignee::Identifier(v) => v,
+        TypedAssignee::Select(box a, _)
+        | TypedAssignee::Member(box a, _)
+        | TypedAssignee::Element(box a, _) => base(a),
+    }
+}
+
+// the expression reading the current value of `a`
+fn read<'ast, T: Field>(a: TypedAssignee<'ast, T>) -> TypedExpression<'ast, T> {
+    match a {
+        TypedAssignee::Identifier(v) => match v._type {
+            Type::FieldElement => FieldElementExpression::Identifier(v.id).into(),
+            Type::Boolean => BooleanExpression::Identifier(v.id).into(),
+            Type::Uint(bitwidth) => UExpressionInner::Identifier(v.id).annotate(bitwidth).into(),
+            Type::Array(array_type) => ArrayExpressionInner::Identifier(v.id)
+                .annotate(*array_type.ty, array_type.size)
+                .into(),
+            Type::Struct(members) => StructExpressionInner::Identifier(v.id)
+                .annotate(members)
+                .into(),
+            Type::Tuple(elements) => TupleExpressionInner::Identifier(v.id)
+                .annotate(elements)
+                .into(),
+            Type::Vector(vector_type) => VectorExpressionInner::Identifier(v.id)
+                .annotate(vector_type)
+                .into(),
+        },
+        a => match a.get_type() {
+            Type::FieldElement => access::<T, FieldElementExpression<'ast, T>>(a).into(),
+            Type::Boolean => access::<T, BooleanExpression<'ast, T>>(a).into(),
+            Type::Uint(..) => access::<T, UExpression<'ast, T>>(a).into(),
+            Type::Array(..) => access::<T, ArrayExpression<'ast, T>>(a).into(),
+            Type::Struct(..) => access::<T, StructExpression<'ast, T>>(a).into(),
+            Type::Tuple(..) => access::<T, TupleExpression<'ast, T>>(a).into(),
+            Type::Vector(..) => unreachable!("vectors cannot be nested in other types"),
+        },
+    }
+}
+
+fn access<'ast, T: Field, U: Select<'ast, T> + Member<'ast, T> + Element<'ast, T>>(
+    a: TypedAssignee<'ast, T>,
+) -> U {
+    match a {
+        TypedAssignee::Select(box a, box index) => {
+            U::select(ArrayExpression::try_from(read(a)).unwrap(), index)
+        }
+        TypedAssignee::Member(box s, id) => {
+            U::member(StructExpression::try_from(read(s)).unwrap(), id)
+        }
+        TypedAssignee::Element(box t, index) => {
+            U::element(TupleExpression::try_from(read(t)).unwrap(), index)
+        }
+        TypedAssignee::Identifier(..) => unreachable!(),
+    }
+}
+
+// the expression selecting `consequence` if `condition` holds, `alternative` otherwise
+fn select<'ast, T: Field>(
+    condition: BooleanExpression<'ast, T>,
+    consequence: TypedExpression<'ast, T>,
+    alternative: TypedExpression<'ast, T>,
+) -> TypedExpression<'ast, T> {
+    match (consequence, alternative) {
+        (TypedExpression::FieldElement(c), TypedExpression::FieldElement(a)) => {
+            FieldElementExpression::if_else(condition, c, a).into()
+        }
+        (TypedExpression::Boolean(c), TypedExpression::Boolean(a)) => {
+            BooleanExpression::if_else(condition, c, a).into()
+        }
+        (TypedExpression::Uint(c), TypedExpression::Uint(a)) => {
+            UExpression::if_else(condition, c, a).into()
+        }
+        (TypedExpression::Array(c), TypedExpression::Array(a)) => {
+            ArrayExpression::if_else(condition, c, a).into()
+        }
+        (TypedExpression::Struct(c), TypedExpression::Struct(a)) => {
+            StructExpression::if_else(condition, c, a).into()
+        }
+        (TypedExpression::Tuple(c), TypedExpression::Tuple(a)) => {
+            TupleExpression::if_else(condition, c, a).into()
+        }
+        (TypedExpression::Vector(c), TypedExpression::Vector(a)) => {
+            VectorExpression::if_else(condition, c, a).into()
+        }
+        _ => unreachable!("the value of a variable cannot change type"),
+    }
+}
+
+impl<'ast, T: Field> Folder<'ast, T> for BranchMerger<'ast> {
+    fn fold_statement(&mut self, s: TypedStatement<'ast, T>) -> Vec<TypedStatement<'ast, T>> {
+        match s {
+            TypedStatement::Declaration(v) => {
+                self.declared.insert(v.id.clone());
+                vec![TypedStatement::Declaration(v)]
+            }
+            TypedStatement::For(v, from, to, statements) => {
+                self.declared.insert(v.id.clone());
+                fold_statement(self, TypedStatement::For(v, from, to, statements))
+            }
+            TypedStatement::Definition(a, e) if self.is_merged(base(&a)) => {
+                let condition = BooleanExpression::Identifier(self.condition.clone().unwrap());
+                let e = select(condition, e, read(a.clone()));
+                vec![TypedStatement::Definition(a, e)]
+            }
+            TypedStatement::MultipleDefinition(variables, list)
+                if variables.iter().any(|v| self.is_merged(v)) =>
+            {
+                let condition = BooleanExpression::Identifier(self.condition.clone().unwrap());
+
+                // define the results in fresh variables, then merge them
+                let (results, merges): (Vec<_>, Vec<_>) = variables
+                    .into_iter()
+                    .map(|v| match self.is_merged(&v) {
+                        true => {
+                            let result = self.issue_variable("BRANCH", v._type.clone());
+                            let merge = TypedStatement::Definition(
+                                TypedAssignee::Identifier(v.clone()),
+                                select(
+                                    condition.clone(),
+                                    read(TypedAssignee::Identifier(result.clone())),
+                                    read(TypedAssignee::Identifier(v)),
+                                ),
+                            );
+                            (result, Some(merge))
+                        }
+                        false => (v, None),
+                    })
+                    .unzip();
+
+                let declarations: Vec<_> = results
+                    .iter()
+                    .zip(merges.iter())
+                    .filter(|(_, merge)| merge.is_some())
+                    .map(|(result, _)| TypedStatement::Declaration(result.clone()))
+                    .collect();
+
+                declarations
+                    .into_iter()
+                    .chain(std::iter::once(TypedStatement::MultipleDefinition(
+                        results, list,
+                    )))
+                    .chain(merges.into_iter().filter_map(|merge| merge))
+                    .collect()
+            }
+            TypedStatement::IfElse(condition, consequence, alternative) => {
+                // evaluate the condition once, before any of the branches modifies its inputs
+                let v = self.issue_variable("CONDITION", Type::Boolean);
+                let c = BooleanExpression::Identifier(v.id.clone());
+
+                let consequence = self.fold_branch(c.clone(), consequence);
+                let alternative =
+                    self.fold_branch(BooleanExpression::Not(box c.clone()), alternative);
+
+                vec![
+                    TypedStatement::Declaration(v.clone()),
+                    TypedStatement::Definition(TypedAssignee::Identifier(v), condition.into()),
+                    TypedStatement::IfElse(c, consequence, alternative),
+                ]
+            }
+            s => fold_statement(self, s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zokrates_field::Bn128Field;
+
+    #[test]
+    fn merge_definitions() {
+        // field a = 1
+        // field b = 2
+        // if a == 1:
+        //     field c = 3
+        //     a = c
+        // else:
+        //     b = 4
+        // endif
+
+        // ->
+
+        // field a = 1
+        // field b = 2
+        // bool #CONDITION_0 = a == 1
+        // if #CONDITION_0:
+        //     bool #CONDITION_1 = #CONDITION_0
+        //     field c = 3
+        //     a = if #CONDITION_1 then c else a fi
+        // else:
+        //     bool #CONDITION_2 = !#CONDITION_0
+        //     b = if #CONDITION_2 then 4 else b fi
+        // endif
+
+        let statements: Vec<TypedStatement<Bn128Field>> = vec![
+            TypedStatement::Declaration(Variable::field_element("a")),
+            TypedStatement::Definition(
+                TypedAssignee::Identifier(Variable::field_element("a")),
+                FieldElementExpression::Number(Bn128Field::from(1)).into(),
+            ),
+            TypedStatement::Declaration(Variable::field_element("b")),
+            TypedStatement::Definition(
+                TypedAssignee::Identifier(Variable::field_element("b")),
+                FieldElementExpression::Number(Bn128Field::from(2)).into(),
+            ),
+            TypedStatement::IfElse(
+                BooleanExpression::FieldEq(
+                    box FieldElementExpression::Identifier("a".into()),
+                    box FieldElementExpression::Number(Bn128Field::from(1)),
+                ),
+                vec![
+                    TypedStatement::Declaration(Variable::field_element("c")),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::field_element("c")),
+                        FieldElementExpression::Number(Bn128Field::from(3)).into(),
+                    ),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::field_element("a")),
+                        FieldElementExpression::Identifier("c".into()).into(),
+                    ),
+                ],
+                vec![TypedStatement::Definition(
+                    TypedAssignee::Identifier(Variable::field_element("b")),
+                    FieldElementExpression::Number(Bn128Field::from(4)).into(),
+                )],
+            ),
+        ];
+
+        let mut merger = BranchMerger::new();
+        let statements: Vec<_> = statements
+            .into_iter()
+            .flat_map(|s| merger.fold_statement(s))
+            .collect();
+
+        let condition = |i| CoreIdentifier::Internal("CONDITION", i);
+
+        let expected = vec![
+            TypedStatement::Declaration(Variable::field_element("a")),
+            TypedStatement::Definition(
+                TypedAssignee::Identifier(Variable::field_element("a")),
+                FieldElementExpression::Number(Bn128Field::from(1)).into(),
+            ),
+            TypedStatement::Declaration(Variable::field_element("b")),
+            TypedStatement::Definition(
+                TypedAssignee::Identifier(Variable::field_element("b")),
+                FieldElementExpression::Number(Bn128Field::from(2)).into(),
+            ),
+            TypedStatement::Declaration(Variable::boolean(condition(0))),
+            TypedStatement::Definition(
+                TypedAssignee::Identifier(Variable::boolean(condition(0))),
+                BooleanExpression::FieldEq(
+                    box FieldElementExpression::Identifier("a".into()),
+                    box FieldElementExpression::Number(Bn128Field::from(1)),
+                )
+                .into(),
+            ),
+            TypedStatement::IfElse(
+                BooleanExpression::Identifier(condition(0).into()),
+                vec![
+                    TypedStatement::Declaration(Variable::boolean(condition(1))),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::boolean(condition(1))),
+                        BooleanExpression::Identifier(condition(0).into()).into(),
+                    ),
+                    TypedStatement::Declaration(Variable::field_element("c")),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::field_element("c")),
+                        FieldElementExpression::Number(Bn128Field::from(3)).into(),
+                    ),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::field_element("a")),
+                        FieldElementExpression::IfElse(
+                            box BooleanExpression::Identifier(condition(1).into()),
+                            box FieldElementExpression::Identifier("c".into()),
+                            box FieldElementExpression::Identifier("a".into()),
+                        )
+                        .into(),
+                    ),
+                ],
+                vec![
+                    TypedStatement::Declaration(Variable::boolean(condition(2))),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::boolean(condition(2))),
+                        BooleanExpression::Not(box BooleanExpression::Identifier(
+                            condition(0).into(),
+                        ))
+                        .into(),
+                    ),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::field_element("b")),
+                        FieldElementExpression::IfElse(
+                            box BooleanExpression::Identifier(condition(2).into()),
+                            box FieldElementExpression::Number(Bn128Field::from(4)),
+                            box FieldElementExpression::Identifier("b".into()),
+                        )
+                        .into(),
+                    ),
+                ],
+            ),
+        ];
+
+        assert_eq!(statements, expected);
+    }
+
+    #[test]
+    fn nested_branches() {
+        // field a = 1
+        // if a == 1:
+        //     if a == 2:
+        //         a = 3
+        //     endif
+        // endif
+
+        // ->
+
+        // field a = 1
+        // bool #CONDITION_0 = a == 1
+        // if #CONDITION_0:
+        //     bool #CONDITION_1 = #CONDITION_0
+        //     bool #CONDITION_2 = a == 2
+        //     if #CONDITION_2:
+        //         bool #CONDITION_3 = #CONDITION_1 && #CONDITION_2
+        //         a = if #CONDITION_3 then 3 else a fi
+        //     endif
+        // endif
+
+        let eq = |v| {
+            BooleanExpression::FieldEq(
+                box FieldElementExpression::Identifier("a".into()),
+                box FieldElementExpression::Number(Bn128Field::from(v)),
+            )
+        };
+
+        let statements: Vec<TypedStatement<Bn128Field>> = vec![
+            TypedStatement::Declaration(Variable::field_element("a")),
+            TypedStatement::Definition(
+                TypedAssignee::Identifier(Variable::field_element("a")),
+                FieldElementExpression::Number(Bn128Field::from(1)).into(),
+            ),
+            TypedStatement::IfElse(
+                eq(1),
+                vec![TypedStatement::IfElse(
+                    eq(2),
+                    vec![TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::field_element("a")),
+                        FieldElementExpression::Number(Bn128Field::from(3)).into(),
+                    )],
+                    vec![],
+                )],
+                vec![],
+            ),
+        ];
+
+        let mut merger = BranchMerger::new();
+        let statements: Vec<_> = statements
+            .into_iter()
+            .flat_map(|s| merger.fold_statement(s))
+            .collect();
+
+        let condition = |i| CoreIdentifier::Internal("CONDITION", i);
+        let define = |i, e: BooleanExpression<'static, Bn128Field>| {
+            vec![
+                TypedStatement::Declaration(Variable::boolean(condition(i))),
+                TypedStatement::Definition(
+                    TypedAssignee::Identifier(Variable::boolean(condition(i))),
+                    e.into(),
+                ),
+            ]
+        };
+
+        let expected: Vec<_> = vec![
+            TypedStatement::Declaration(Variable::field_element("a")),
+            TypedStatement::Definition(
+                TypedAssignee::Identifier(Variable::field_element("a")),
+                FieldElementExpression::Number(Bn128Field::from(1)).into(),
+            ),
+        ]
+        .into_iter()
+        .chain(define(0, eq(1)))
+        .chain(std::iter::once(TypedStatement::IfElse(
+            BooleanExpression::Identifier(condition(0).into()),
+            define(1, BooleanExpression::Identifier(condition(0).into()))
+                .into_iter()
+                .chain(define(2, eq(2)))
+                .chain(std::iter::once(TypedStatement::IfElse(
+                    BooleanExpression::Identifier(condition(2).into()),
+                    define(
+                        3,
+                        BooleanExpression::And(
+                            box BooleanExpression::Identifier(condition(1).into()),
+                            box BooleanExpression::Identifier(condition(2).into()),
+                        ),
+                    )
+                    .into_iter()
+                    .chain(std::iter::once(TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::field_element("a")),
+                        FieldElementExpression::IfElse(
+                            box BooleanExpression::Identifier(condition(3).into()),
+                            box FieldElementExpression::Number(Bn128Field::from(3)),
+                            box FieldElementExpression::Identifier("a".into()),
+                        )
+                        .into(),
+                    )))
+                    .collect(),
+                    vec![],
+                )))
+                .collect(),
+            vec![],
+        )))
+        .collect();
+
+        assert_eq!(statements, expected);
+    }
+}
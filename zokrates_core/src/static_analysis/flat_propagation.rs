@@ -69,9 +69,10 @@ impl<T: Field> FlatStatement<T> {
                 }
                 e => Some(FlatStatement::Definition(var, e)),
             },
-            FlatStatement::Condition(e1, e2) => Some(FlatStatement::Condition(
+            FlatStatement::Condition(e1, e2, metadata) => Some(FlatStatement::Condition(
                 e1.propagate(constants),
                 e2.propagate(constants),
+                metadata,
             )),
             FlatStatement::Directive(d) => Some(FlatStatement::Directive(FlatDirective {
                 inputs: d
@@ -81,6 +82,13 @@ impl<T: Field> FlatStatement<T> {
                     .collect(),
                 ..d
             })),
+            FlatStatement::Log(format_string, expressions) => Some(FlatStatement::Log(
+                format_string,
+                expressions
+                    .into_iter()
+                    .map(|e| e.propagate(constants))
+                    .collect(),
+            )),
         }
     }
 }
@@ -89,12 +97,16 @@ impl<T: Field> Propagate<T> for FlatFunction<T> {
     fn propagate(self) -> FlatFunction<T> {
         let mut constants = HashMap::new();
 
+        let (statements, source_map) = self
+            .statements
+            .into_iter()
+            .zip(self.source_map.into_spans())
+            .filter_map(|(s, span)| s.propagate(&mut constants).map(|s| (s, span)))
+            .unzip();
+
         FlatFunction {
-            statements: self
-                .statements
-                .into_iter()
-                .filter_map(|s| s.propagate(&mut constants))
-                .collect(),
+            statements,
+            source_map,
             ..self
         }
     }
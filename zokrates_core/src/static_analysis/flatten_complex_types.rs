@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 use typed_absy;
-use typed_absy::types::{StructType, UBitwidth};
+use typed_absy::types::{IntType, StructType, TupleType, VectorType};
 use zir;
 use zokrates_field::Field;
 
@@ -23,7 +23,7 @@ fn flatten_identifier_rec<'a>(
         }],
         typed_absy::Type::Uint(bitwidth) => vec![zir::Variable {
             id: zir::Identifier::Source(id),
-            _type: zir::Type::uint(bitwidth.to_usize()),
+            _type: zir::Type::uint(bitwidth),
         }],
         typed_absy::Type::Array(array_type) => (0..array_type.size)
             .flat_map(|i| {
@@ -42,6 +42,27 @@ fn flatten_identifier_rec<'a>(
                 )
             })
             .collect(),
+        typed_absy::Type::Tuple(tuple_type) => tuple_type
+            .elements
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, ty)| {
+                flatten_identifier_rec(zir::SourceIdentifier::Element(box id.clone(), i as u32), ty)
+            })
+            .collect(),
+        // a vector is lowered to an array of `capacity` elements followed by its length
+        typed_absy::Type::Vector(vector_type) => (0..vector_type.capacity)
+            .flat_map(|i| {
+                flatten_identifier_rec(
+                    zir::SourceIdentifier::Select(box id.clone(), i),
+                    *vector_type.ty.clone(),
+                )
+            })
+            .chain(std::iter::once(zir::Variable {
+                id: zir::Identifier::Source(zir::SourceIdentifier::Length(box id)),
+                _type: zir::Type::uint(32),
+            }))
+            .collect(),
     }
 }
 
@@ -94,6 +115,29 @@ impl<'ast, T: Field> Flattener<T> {
         flatten_identifier_rec(id, ty)
     }
 
+    // the length of a vector is only known at runtime and must not exceed its capacity
+    fn fold_vector_length_bound(
+        &mut self,
+        v: &typed_absy::Variable<'ast>,
+    ) -> Option<zir::ZirStatement<'ast, T>> {
+        match v.get_type() {
+            typed_absy::Type::Vector(vector_type) => {
+                let length = zir::UExpressionInner::Identifier(zir::Identifier::Source(
+                    zir::SourceIdentifier::Length(box self.fold_name(v.id.clone())),
+                ))
+                .annotate(32);
+                let capacity =
+                    zir::UExpressionInner::Value(vector_type.capacity as u128).annotate(32);
+
+                Some(zir::ZirStatement::Assertion(
+                    zir::BooleanExpression::UintLe(box length, box capacity),
+                    None,
+                ))
+            }
+            _ => None,
+        }
+    }
+
     fn fold_assignee(
         &mut self,
         a: typed_absy::TypedAssignee<'ast, T>,
@@ -123,6 +167,8 @@ impl<'ast, T: Field> Flattener<T> {
             typed_absy::TypedExpression::Uint(e) => vec![self.fold_uint_expression(e).into()],
             typed_absy::TypedExpression::Array(e) => self.fold_array_expression(e).into(),
             typed_absy::TypedExpression::Struct(e) => self.fold_struct_expression(e).into(),
+            typed_absy::TypedExpression::Tuple(e) => self.fold_tuple_expression(e).into(),
+            typed_absy::TypedExpression::Vector(e) => self.fold_vector_expression(e).into(),
         }
     }
 
@@ -140,6 +186,20 @@ impl<'ast, T: Field> Flattener<T> {
         fold_struct_expression(self, e)
     }
 
+    fn fold_tuple_expression(
+        &mut self,
+        e: typed_absy::TupleExpression<'ast, T>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_tuple_expression(self, e)
+    }
+
+    fn fold_vector_expression(
+        &mut self,
+        e: typed_absy::VectorExpression<'ast, T>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_vector_expression(self, e)
+    }
+
     fn fold_expression_list(
         &mut self,
         es: typed_absy::TypedExpressionList<'ast, T>,
@@ -186,7 +246,7 @@ impl<'ast, T: Field> Flattener<T> {
 
     fn fold_uint_expression_inner(
         &mut self,
-        bitwidth: UBitwidth,
+        bitwidth: IntType,
         e: typed_absy::UExpressionInner<'ast, T>,
     ) -> zir::UExpressionInner<'ast, T> {
         fold_uint_expression_inner(self, bitwidth, e)
@@ -207,6 +267,21 @@ impl<'ast, T: Field> Flattener<T> {
     ) -> Vec<zir::ZirExpression<'ast, T>> {
         fold_struct_expression_inner(self, ty, e)
     }
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType,
+        e: typed_absy::TupleExpressionInner<'ast, T>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_tuple_expression_inner(self, ty, e)
+    }
+
+    fn fold_vector_expression_inner(
+        &mut self,
+        ty: &VectorType,
+        e: typed_absy::VectorExpressionInner<'ast, T>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_vector_expression_inner(self, ty, e)
+    }
 }
 
 pub fn fold_module<'ast, T: Field>(
@@ -234,12 +309,17 @@ pub fn fold_statement<'ast, T: Field>(
                 .collect(),
         )],
         typed_absy::TypedStatement::Definition(a, e) => {
+            let bound = match a {
+                typed_absy::TypedAssignee::Identifier(ref v) => f.fold_vector_length_bound(v),
+                _ => None,
+            };
             let a = f.fold_assignee(a);
             let e = f.fold_expression(e);
             assert_eq!(a.len(), e.len());
             a.into_iter()
                 .zip(e.into_iter())
                 .map(|(a, e)| zir::ZirStatement::Definition(a, e))
+                .chain(bound)
                 .collect()
         }
         typed_absy::TypedStatement::Declaration(v) => {
@@ -248,11 +328,24 @@ pub fn fold_statement<'ast, T: Field>(
                 .map(|v| zir::ZirStatement::Declaration(v))
                 .collect()
         }
-        typed_absy::TypedStatement::Assertion(e) => {
+        typed_absy::TypedStatement::Assertion(e, metadata) => {
             let e = f.fold_boolean_expression(e);
-            vec![zir::ZirStatement::Assertion(e)]
+            vec![zir::ZirStatement::Assertion(e, metadata)]
         }
         typed_absy::TypedStatement::For(..) => unreachable!(),
+        typed_absy::TypedStatement::IfElse(condition, consequence, alternative) => {
+            vec![zir::ZirStatement::IfElse(
+                f.fold_boolean_expression(condition),
+                consequence
+                    .into_iter()
+                    .flat_map(|s| f.fold_statement(s))
+                    .collect(),
+                alternative
+                    .into_iter()
+                    .flat_map(|s| f.fold_statement(s))
+                    .collect(),
+            )]
+        }
         typed_absy::TypedStatement::MultipleDefinition(variables, elist) => {
             vec![zir::ZirStatement::MultipleDefinition(
                 variables
@@ -262,6 +355,16 @@ pub fn fold_statement<'ast, T: Field>(
                 f.fold_expression_list(elist),
             )]
         }
+        typed_absy::TypedStatement::Span(span) => vec![zir::ZirStatement::Span(span)],
+        typed_absy::TypedStatement::Log(format_string, expressions) => {
+            vec![zir::ZirStatement::Log(
+                format_string,
+                expressions
+                    .into_iter()
+                    .flat_map(|e| f.fold_expression(e))
+                    .collect(),
+            )]
+        }
     }
 }
 
@@ -337,6 +440,22 @@ pub fn fold_array_expression_inner<'ast, T: Field>(
 
             s[offset..offset + size].to_vec()
         }
+        typed_absy::ArrayExpressionInner::Element(box tuple, index) => {
+            let elements = tuple.ty().clone();
+
+            let tuple = f.fold_tuple_expression(tuple);
+
+            let offset: usize = elements
+                .iter()
+                .take(index as usize)
+                .map(|ty| ty.get_primitive_count())
+                .sum();
+
+            // we also need the size of this element
+            let size = t.get_primitive_count() * size;
+
+            tuple[offset..offset + size].to_vec()
+        }
         typed_absy::ArrayExpressionInner::Select(box array, box index) => {
             let array = f.fold_array_expression(array);
             let index = f.fold_field_expression(index);
@@ -351,6 +470,17 @@ pub fn fold_array_expression_inner<'ast, T: Field>(
                 _ => unreachable!(),
             }
         }
+        typed_absy::ArrayExpressionInner::FromUint(..) => {
+            unreachable!("casts to bits should have been replaced by embed calls during inlining")
+        }
+        typed_absy::ArrayExpressionInner::VectorElements(box vector) => {
+            let vector = f.fold_vector_expression(vector);
+
+            // the elements come first, followed by the length
+            let size = t.get_primitive_count() * size;
+
+            vector[..size].to_vec()
+        }
     }
 }
 
@@ -430,6 +560,22 @@ pub fn fold_struct_expression_inner<'ast, T: Field>(
 
             s[offset..offset + size].to_vec()
         }
+        typed_absy::StructExpressionInner::Element(box tuple, index) => {
+            let elements = tuple.ty().clone();
+
+            let tuple = f.fold_tuple_expression(tuple);
+
+            let offset: usize = elements
+                .iter()
+                .take(index as usize)
+                .map(|ty| ty.get_primitive_count())
+                .sum();
+
+            // we also need the size of this element
+            let size = t.iter().map(|m| m.ty.get_primitive_count()).sum::<usize>();
+
+            tuple[offset..offset + size].to_vec()
+        }
         typed_absy::StructExpressionInner::Select(box array, box index) => {
             let array = f.fold_array_expression(array);
             let index = f.fold_field_expression(index);
@@ -450,6 +596,164 @@ pub fn fold_struct_expression_inner<'ast, T: Field>(
     }
 }
 
+pub fn fold_tuple_expression_inner<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    t: &TupleType,
+    e: typed_absy::TupleExpressionInner<'ast, T>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    match e {
+        typed_absy::TupleExpressionInner::Identifier(id) => {
+            let variables =
+                flatten_identifier_rec(f.fold_name(id), typed_absy::Type::tuple(t.clone()));
+            variables
+                .into_iter()
+                .map(|v| match v._type {
+                    zir::Type::FieldElement => zir::FieldElementExpression::Identifier(v.id).into(),
+                    zir::Type::Boolean => zir::BooleanExpression::Identifier(v.id).into(),
+                    zir::Type::Uint(bitwidth) => zir::UExpressionInner::Identifier(v.id)
+                        .annotate(bitwidth)
+                        .into(),
+                })
+                .collect()
+        }
+        typed_absy::TupleExpressionInner::Value(exprs) => exprs
+            .into_iter()
+            .flat_map(|e| f.fold_expression(e))
+            .collect(),
+        typed_absy::TupleExpressionInner::FunctionCall(..) => unreachable!(),
+        typed_absy::TupleExpressionInner::IfElse(
+            box condition,
+            box consequence,
+            box alternative,
+        ) => {
+            let condition = f.fold_boolean_expression(condition);
+            let consequence = f.fold_tuple_expression(consequence);
+            let alternative = f.fold_tuple_expression(alternative);
+
+            assert_eq!(consequence.len(), alternative.len());
+
+            use zir::IfElse;
+
+            consequence
+                .into_iter()
+                .zip(alternative.into_iter())
+                .map(|(c, a)| match (c, a) {
+                    (zir::ZirExpression::FieldElement(c), zir::ZirExpression::FieldElement(a)) => {
+                        zir::FieldElementExpression::if_else(condition.clone(), c, a).into()
+                    }
+                    (zir::ZirExpression::Boolean(c), zir::ZirExpression::Boolean(a)) => {
+                        zir::BooleanExpression::if_else(condition.clone(), c, a).into()
+                    }
+                    (zir::ZirExpression::Uint(c), zir::ZirExpression::Uint(a)) => {
+                        zir::UExpression::if_else(condition.clone(), c, a).into()
+                    }
+                    _ => unreachable!(),
+                })
+                .collect()
+        }
+        typed_absy::TupleExpressionInner::Member(box s, id) => {
+            let members = s.ty().clone();
+
+            let s = f.fold_struct_expression(s);
+
+            let offset: usize = members
+                .iter()
+                .take_while(|member| member.id != id)
+                .map(|member| member.ty.get_primitive_count())
+                .sum();
+
+            // we also need the size of this member
+            let size = t.iter().map(|ty| ty.get_primitive_count()).sum::<usize>();
+
+            s[offset..offset + size].to_vec()
+        }
+        typed_absy::TupleExpressionInner::Element(box tuple, index) => {
+            let elements = tuple.ty().clone();
+
+            let tuple = f.fold_tuple_expression(tuple);
+
+            let offset: usize = elements
+                .iter()
+                .take(index as usize)
+                .map(|ty| ty.get_primitive_count())
+                .sum();
+
+            // we also need the size of this element
+            let size = t.iter().map(|ty| ty.get_primitive_count()).sum::<usize>();
+
+            tuple[offset..offset + size].to_vec()
+        }
+        typed_absy::TupleExpressionInner::Select(box array, box index) => {
+            let array = f.fold_array_expression(array);
+            let index = f.fold_field_expression(index);
+
+            match index {
+                zir::FieldElementExpression::Number(i) => {
+                    let size = t.iter().map(|ty| ty.get_primitive_count()).sum::<usize>();
+                    let start = i.to_dec_string().parse::<usize>().unwrap() * size;
+                    let end = start + size;
+                    array[start..end].to_vec()
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+pub fn fold_vector_expression_inner<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    t: &VectorType,
+    e: typed_absy::VectorExpressionInner<'ast, T>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    match e {
+        typed_absy::VectorExpressionInner::Identifier(id) => {
+            let variables =
+                flatten_identifier_rec(f.fold_name(id), typed_absy::Type::Vector(t.clone()));
+            variables
+                .into_iter()
+                .map(|v| match v._type {
+                    zir::Type::FieldElement => zir::FieldElementExpression::Identifier(v.id).into(),
+                    zir::Type::Boolean => zir::BooleanExpression::Identifier(v.id).into(),
+                    zir::Type::Uint(bitwidth) => zir::UExpressionInner::Identifier(v.id)
+                        .annotate(bitwidth)
+                        .into(),
+                })
+                .collect()
+        }
+        typed_absy::VectorExpressionInner::FunctionCall(..) => unreachable!(),
+        typed_absy::VectorExpressionInner::IfElse(
+            box condition,
+            box consequence,
+            box alternative,
+        ) => {
+            let condition = f.fold_boolean_expression(condition);
+            let consequence = f.fold_vector_expression(consequence);
+            let alternative = f.fold_vector_expression(alternative);
+
+            assert_eq!(consequence.len(), alternative.len());
+
+            use zir::IfElse;
+
+            consequence
+                .into_iter()
+                .zip(alternative.into_iter())
+                .map(|(c, a)| match (c, a) {
+                    (zir::ZirExpression::FieldElement(c), zir::ZirExpression::FieldElement(a)) => {
+                        zir::FieldElementExpression::if_else(condition.clone(), c, a).into()
+                    }
+                    (zir::ZirExpression::Boolean(c), zir::ZirExpression::Boolean(a)) => {
+                        zir::BooleanExpression::if_else(condition.clone(), c, a).into()
+                    }
+                    (zir::ZirExpression::Uint(c), zir::ZirExpression::Uint(a)) => {
+                        zir::UExpression::if_else(condition.clone(), c, a).into()
+                    }
+                    _ => unreachable!(),
+                })
+                .collect()
+        }
+    }
+}
+
 pub fn fold_field_expression<'ast, T: Field>(
     f: &mut Flattener<T>,
     e: typed_absy::FieldElementExpression<'ast, T>,
@@ -510,6 +814,21 @@ pub fn fold_field_expression<'ast, T: Field>(
 
             s[offset].clone().try_into().unwrap()
         }
+        typed_absy::FieldElementExpression::Element(box tuple, index) => {
+            let elements = tuple.ty().clone();
+
+            let tuple = f.fold_tuple_expression(tuple);
+
+            let offset: usize = elements
+                .iter()
+                .take(index as usize)
+                .map(|ty| ty.get_primitive_count())
+                .sum();
+
+            use std::convert::TryInto;
+
+            tuple[offset].clone().try_into().unwrap()
+        }
         typed_absy::FieldElementExpression::Select(box array, box index) => {
             let array = f.fold_array_expression(array);
 
@@ -526,6 +845,12 @@ pub fn fold_field_expression<'ast, T: Field>(
                 _ => unreachable!(""),
             }
         }
+        typed_absy::FieldElementExpression::FromBool(box e) => {
+            zir::FieldElementExpression::FromBool(box f.fold_boolean_expression(e))
+        }
+        typed_absy::FieldElementExpression::FromUint(box e) => {
+            zir::FieldElementExpression::FromUint(box f.fold_uint_expression(e))
+        }
     }
 }
 
@@ -612,6 +937,26 @@ pub fn fold_boolean_expression<'ast, T: Field>(
 
             zir::BooleanExpression::UintEq(box e1, box e2)
         }
+        typed_absy::BooleanExpression::UintLt(box e1, box e2) => {
+            let e1 = f.fold_uint_expression(e1);
+            let e2 = f.fold_uint_expression(e2);
+            zir::BooleanExpression::UintLt(box e1, box e2)
+        }
+        typed_absy::BooleanExpression::UintLe(box e1, box e2) => {
+            let e1 = f.fold_uint_expression(e1);
+            let e2 = f.fold_uint_expression(e2);
+            zir::BooleanExpression::UintLe(box e1, box e2)
+        }
+        typed_absy::BooleanExpression::UintGt(box e1, box e2) => {
+            let e1 = f.fold_uint_expression(e1);
+            let e2 = f.fold_uint_expression(e2);
+            zir::BooleanExpression::UintGt(box e1, box e2)
+        }
+        typed_absy::BooleanExpression::UintGe(box e1, box e2) => {
+            let e1 = f.fold_uint_expression(e1);
+            let e2 = f.fold_uint_expression(e2);
+            zir::BooleanExpression::UintGe(box e1, box e2)
+        }
         typed_absy::BooleanExpression::Lt(box e1, box e2) => {
             let e1 = f.fold_field_expression(e1);
             let e2 = f.fold_field_expression(e2);
@@ -668,6 +1013,21 @@ pub fn fold_boolean_expression<'ast, T: Field>(
 
             s[offset].clone().try_into().unwrap()
         }
+        typed_absy::BooleanExpression::Element(box tuple, index) => {
+            let elements = tuple.ty().clone();
+
+            let tuple = f.fold_tuple_expression(tuple);
+
+            let offset: usize = elements
+                .iter()
+                .take(index as usize)
+                .map(|ty| ty.get_primitive_count())
+                .sum();
+
+            use std::convert::TryInto;
+
+            tuple[offset].clone().try_into().unwrap()
+        }
         typed_absy::BooleanExpression::Select(box array, box index) => {
             let array = f.fold_array_expression(array);
             let index = f.fold_field_expression(index);
@@ -683,6 +1043,9 @@ pub fn fold_boolean_expression<'ast, T: Field>(
                 _ => unreachable!(),
             }
         }
+        typed_absy::BooleanExpression::FromField(box e) => {
+            zir::BooleanExpression::FromField(box f.fold_field_expression(e))
+        }
     }
 }
 
@@ -691,12 +1054,12 @@ pub fn fold_uint_expression<'ast, T: Field>(
     e: typed_absy::UExpression<'ast, T>,
 ) -> zir::UExpression<'ast, T> {
     f.fold_uint_expression_inner(e.bitwidth, e.inner)
-        .annotate(e.bitwidth.to_usize())
+        .annotate(e.bitwidth)
 }
 
 pub fn fold_uint_expression_inner<'ast, T: Field>(
     f: &mut Flattener<T>,
-    bitwidth: UBitwidth,
+    bitwidth: IntType,
     e: typed_absy::UExpressionInner<'ast, T>,
 ) -> zir::UExpressionInner<'ast, T> {
     match e {
@@ -724,6 +1087,18 @@ pub fn fold_uint_expression_inner<'ast, T: Field>(
 
             zir::UExpressionInner::Mult(box left, box right)
         }
+        typed_absy::UExpressionInner::Div(box left, box right) => {
+            let left = f.fold_uint_expression(left);
+            let right = f.fold_uint_expression(right);
+
+            zir::UExpressionInner::Div(box left, box right)
+        }
+        typed_absy::UExpressionInner::Rem(box left, box right) => {
+            let left = f.fold_uint_expression(left);
+            let right = f.fold_uint_expression(right);
+
+            zir::UExpressionInner::Rem(box left, box right)
+        }
         typed_absy::UExpressionInner::Xor(box left, box right) => {
             let left = f.fold_uint_expression(left);
             let right = f.fold_uint_expression(right);
@@ -796,12 +1171,47 @@ pub fn fold_uint_expression_inner<'ast, T: Field>(
 
             res.into_inner()
         }
+        typed_absy::UExpressionInner::Element(box tuple, index) => {
+            let elements = tuple.ty().clone();
+
+            let tuple = f.fold_tuple_expression(tuple);
+
+            let offset: usize = elements
+                .iter()
+                .take(index as usize)
+                .map(|ty| ty.get_primitive_count())
+                .sum();
+
+            use std::convert::TryInto;
+
+            let res: zir::UExpression<'ast, T> = tuple[offset].clone().try_into().unwrap();
+
+            res.into_inner()
+        }
         typed_absy::UExpressionInner::IfElse(box cond, box cons, box alt) => {
             let cond = f.fold_boolean_expression(cond);
             let cons = f.fold_uint_expression(cons);
             let alt = f.fold_uint_expression(alt);
             zir::UExpressionInner::IfElse(box cond, box cons, box alt)
         }
+        typed_absy::UExpressionInner::FromField(box e) => {
+            zir::UExpressionInner::FromField(box f.fold_field_expression(e))
+        }
+        typed_absy::UExpressionInner::FromUint(box e) => {
+            zir::UExpressionInner::FromUint(box f.fold_uint_expression(e))
+        }
+        typed_absy::UExpressionInner::FromBits(..) => {
+            unreachable!("casts from bits should have been replaced by embed calls during inlining")
+        }
+        typed_absy::UExpressionInner::VectorLength(box vector) => {
+            let vector = f.fold_vector_expression(vector);
+
+            use std::convert::TryInto;
+
+            let res: zir::UExpression<'ast, T> = vector.last().unwrap().clone().try_into().unwrap();
+
+            res.into_inner()
+        }
     }
 }
 
@@ -809,16 +1219,21 @@ pub fn fold_function<'ast, T: Field>(
     f: &mut Flattener<T>,
     fun: typed_absy::TypedFunction<'ast, T>,
 ) -> zir::ZirFunction<'ast, T> {
+    let bounds: Vec<_> = fun
+        .arguments
+        .iter()
+        .filter_map(|a| f.fold_vector_length_bound(&a.id))
+        .collect();
+
     zir::ZirFunction {
         arguments: fun
             .arguments
             .into_iter()
             .flat_map(|a| f.fold_parameter(a))
             .collect(),
-        statements: fun
-            .statements
+        statements: bounds
             .into_iter()
-            .flat_map(|s| f.fold_statement(s))
+            .chain(fun.statements.into_iter().flat_map(|s| f.fold_statement(s)))
             .collect(),
         signature: fun.signature.into(),
     }
@@ -838,6 +1253,20 @@ pub fn fold_struct_expression<'ast, T: Field>(
     f.fold_struct_expression_inner(&e.ty().clone(), e.into_inner())
 }
 
+pub fn fold_tuple_expression<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    e: typed_absy::TupleExpression<'ast, T>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    f.fold_tuple_expression_inner(&e.ty().clone(), e.into_inner())
+}
+
+pub fn fold_vector_expression<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    e: typed_absy::VectorExpression<'ast, T>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    f.fold_vector_expression_inner(&e.ty().clone(), e.into_inner())
+}
+
 pub fn fold_function_symbol<'ast, T: Field>(
     f: &mut Flattener<T>,
     s: typed_absy::TypedFunctionSymbol<'ast, T>,
@@ -16,8 +16,9 @@
 
 //! where any call in `main` must be to `_SHA_256_ROUND` or `_UNPACK`
 
+use crate::embed::FlatEmbed;
 use std::collections::HashMap;
-use typed_absy::types::{FunctionKey, Type, UBitwidth};
+use typed_absy::types::{FunctionKey, IntType, Type};
 use typed_absy::{folder::*, *};
 use zokrates_field::Field;
 
@@ -56,6 +57,10 @@ pub struct Inliner<'ast, T: Field> {
     call_count: HashMap<(TypedModuleId, FunctionKey<'ast>), usize>,
     /// the cache for memoization: for each function body, tracks function calls
     call_cache: CallCache<'ast, T>,
+    /// the source location of the statement currently being inlined
+    span: Option<SourceSpan>,
+    /// the calls leading to the function currently being inlined, used to annotate source locations
+    calls: Vec<Call>,
 }
 
 impl<'ast, T: Field> Inliner<'ast, T> {
@@ -74,6 +79,8 @@ impl<'ast, T: Field> Inliner<'ast, T> {
             stack: vec![],
             call_count: HashMap::new(),
             call_cache: HashMap::new(),
+            span: None,
+            calls: vec![],
         }
     }
 
@@ -104,6 +111,10 @@ impl<'ast, T: Field> Inliner<'ast, T> {
         let unpack = crate::embed::FlatEmbed::Unpack(T::get_required_bits());
         let unpack_key = unpack.key::<T>();
 
+        // define a function in the main module for the `u64_to_bits` embed
+        let u64_to_bits = crate::embed::FlatEmbed::U64ToBits;
+        let u64_to_bits_key = u64_to_bits.key::<T>();
+
         // define a function in the main module for the `u32_to_bits` embed
         let u32_to_bits = crate::embed::FlatEmbed::U32ToBits;
         let u32_to_bits_key = u32_to_bits.key::<T>();
@@ -116,6 +127,10 @@ impl<'ast, T: Field> Inliner<'ast, T> {
         let u8_to_bits = crate::embed::FlatEmbed::U8ToBits;
         let u8_to_bits_key = u8_to_bits.key::<T>();
 
+        // define a function in the main module for the `u64_from_bits` embed
+        let u64_from_bits = crate::embed::FlatEmbed::U64FromBits;
+        let u64_from_bits_key = u64_from_bits.key::<T>();
+
         // define a function in the main module for the `u32_from_bits` embed
         let u32_from_bits = crate::embed::FlatEmbed::U32FromBits;
         let u32_from_bits_key = u32_from_bits.key::<T>();
@@ -128,6 +143,30 @@ impl<'ast, T: Field> Inliner<'ast, T> {
         let u8_from_bits = crate::embed::FlatEmbed::U8FromBits;
         let u8_from_bits_key = u8_from_bits.key::<T>();
 
+        // define a function in the main module for the `i32_to_bits` embed
+        let i32_to_bits = crate::embed::FlatEmbed::I32ToBits;
+        let i32_to_bits_key = i32_to_bits.key::<T>();
+
+        // define a function in the main module for the `i16_to_bits` embed
+        let i16_to_bits = crate::embed::FlatEmbed::I16ToBits;
+        let i16_to_bits_key = i16_to_bits.key::<T>();
+
+        // define a function in the main module for the `i8_to_bits` embed
+        let i8_to_bits = crate::embed::FlatEmbed::I8ToBits;
+        let i8_to_bits_key = i8_to_bits.key::<T>();
+
+        // define a function in the main module for the `i32_from_bits` embed
+        let i32_from_bits = crate::embed::FlatEmbed::I32FromBits;
+        let i32_from_bits_key = i32_from_bits.key::<T>();
+
+        // define a function in the main module for the `i16_from_bits` embed
+        let i16_from_bits = crate::embed::FlatEmbed::I16FromBits;
+        let i16_from_bits_key = i16_from_bits.key::<T>();
+
+        // define a function in the main module for the `i8_from_bits` embed
+        let i8_from_bits = crate::embed::FlatEmbed::I8FromBits;
+        let i8_from_bits_key = i8_from_bits.key::<T>();
+
         // return a program with a single module containing `main`, `_UNPACK`, and `_SHA256_ROUND
         TypedProgram {
             main: "main".into(),
@@ -136,12 +175,20 @@ impl<'ast, T: Field> Inliner<'ast, T> {
                 TypedModule {
                     functions: vec![
                         (unpack_key, TypedFunctionSymbol::Flat(unpack)),
+                        (u64_from_bits_key, TypedFunctionSymbol::Flat(u64_from_bits)),
                         (u32_from_bits_key, TypedFunctionSymbol::Flat(u32_from_bits)),
                         (u16_from_bits_key, TypedFunctionSymbol::Flat(u16_from_bits)),
                         (u8_from_bits_key, TypedFunctionSymbol::Flat(u8_from_bits)),
+                        (u64_to_bits_key, TypedFunctionSymbol::Flat(u64_to_bits)),
                         (u32_to_bits_key, TypedFunctionSymbol::Flat(u32_to_bits)),
                         (u16_to_bits_key, TypedFunctionSymbol::Flat(u16_to_bits)),
                         (u8_to_bits_key, TypedFunctionSymbol::Flat(u8_to_bits)),
+                        (i32_from_bits_key, TypedFunctionSymbol::Flat(i32_from_bits)),
+                        (i16_from_bits_key, TypedFunctionSymbol::Flat(i16_from_bits)),
+                        (i8_from_bits_key, TypedFunctionSymbol::Flat(i8_from_bits)),
+                        (i32_to_bits_key, TypedFunctionSymbol::Flat(i32_to_bits)),
+                        (i16_to_bits_key, TypedFunctionSymbol::Flat(i16_to_bits)),
+                        (i8_to_bits_key, TypedFunctionSymbol::Flat(i8_to_bits)),
                         (main_key, main),
                     ]
                     .into_iter()
@@ -199,6 +246,16 @@ impl<'ast, T: Field> Inliner<'ast, T> {
 
                 self.statement_buffer.extend(inputs_bindings);
 
+                // record this call so that the source locations of the callee can be traced back to it
+                let call = self.span.clone().map(|site| Call {
+                    function: key.id.to_string(),
+                    site: SourceSpan {
+                        stack: vec![],
+                        ..site
+                    },
+                });
+                self.calls.extend(call.clone());
+
                 // filter out the return statement and keep it aside
                 let (statements, mut ret): (Vec<_>, Vec<_>) = function
                     .statements
@@ -212,6 +269,10 @@ impl<'ast, T: Field> Inliner<'ast, T> {
                 // add all statements to the buffer
                 self.statement_buffer.extend(statements);
 
+                if call.is_some() {
+                    self.calls.pop();
+                }
+
                 // pop this call from the stack
                 self.stack.pop();
 
@@ -284,11 +345,53 @@ impl<'ast, T: Field> Inliner<'ast, T> {
     fn module_id(&self) -> &TypedModuleId {
         self.location.module()
     }
+
+    /// define the result of a call to `embed` in a fresh variable, to be resolved during flattening
+    fn call_embed(
+        &mut self,
+        embed: FlatEmbed,
+        expressions: Vec<TypedExpression<'ast, T>>,
+    ) -> Identifier<'ast> {
+        let key = embed.key::<T>();
+        let tys = key.signature.outputs.clone();
+        let id = Identifier {
+            id: CoreIdentifier::Call(
+                key.clone(),
+                *self
+                    .call_count
+                    .entry((self.module_id().clone(), key.clone()))
+                    .and_modify(|i| *i += 1)
+                    .or_insert(1),
+            ),
+            version: 0,
+            stack: self.stack.clone(),
+        };
+        self.statement_buffer
+            .push(TypedStatement::MultipleDefinition(
+                vec![Variable::with_id_and_type(id.clone(), tys[0].clone())],
+                TypedExpressionList::FunctionCall(key, expressions, tys),
+            ));
+        id
+    }
 }
 
 impl<'ast, T: Field> Folder<'ast, T> for Inliner<'ast, T> {
     // add extra statements before the modified statement
     fn fold_statement(&mut self, s: TypedStatement<'ast, T>) -> Vec<TypedStatement<'ast, T>> {
+        // annotate source locations with the calls they were inlined through
+        let s = match s {
+            TypedStatement::Span(span) => TypedStatement::Span(SourceSpan {
+                stack: self.calls.clone(),
+                ..span
+            }),
+            s => s,
+        };
+
+        let span = match &s {
+            TypedStatement::Span(span) => Some(span.clone()),
+            _ => self.span.clone(),
+        };
+
         let folded = match s {
             TypedStatement::MultipleDefinition(variables, elist) => match elist {
                 TypedExpressionList::FunctionCall(key, exps, types) => {
@@ -313,9 +416,47 @@ impl<'ast, T: Field> Folder<'ast, T> for Inliner<'ast, T> {
                     }
                 }
             },
+            TypedStatement::IfElse(condition, consequence, alternative) => {
+                // the statements introduced by the condition go before the branches
+                let condition = self.fold_boolean_expression(condition);
+                let mut folded: Vec<_> = self.statement_buffer.drain(..).collect();
+
+                // the checks of calls made in a branch only hold if the branch is taken, so their
+                // results must not be reused outside of it
+                let call_cache = self.call_cache.clone();
+                let consequence = consequence
+                    .into_iter()
+                    .flat_map(|s| self.fold_statement(s))
+                    .collect();
+                self.call_cache = call_cache.clone();
+                let alternative = alternative
+                    .into_iter()
+                    .flat_map(|s| self.fold_statement(s))
+                    .collect();
+                self.call_cache = call_cache;
+
+                folded.push(TypedStatement::IfElse(condition, consequence, alternative));
+                folded
+            }
             s => fold_statement(self, s),
         };
-        self.statement_buffer.drain(..).chain(folded).collect()
+
+        // if the inlined statements carry the spans of the callee, restore the span of this
+        // statement after them
+        self.span = span;
+        let resume = match self.statement_buffer.iter().any(|s| match s {
+            TypedStatement::Span(..) => true,
+            _ => false,
+        }) {
+            true => self.span.clone().map(TypedStatement::Span),
+            false => None,
+        };
+
+        self.statement_buffer
+            .drain(..)
+            .chain(resume)
+            .chain(folded)
+            .collect()
     }
 
     // prefix all names with the stack
@@ -488,6 +629,22 @@ impl<'ast, T: Field> Folder<'ast, T> for Inliner<'ast, T> {
                     }
                 }
             }
+            // casts to bits are calls to the `to_bits` embeds
+            ArrayExpressionInner::FromUint(box e) => {
+                let embed = match e.bitwidth {
+                    IntType::U64 => FlatEmbed::U64ToBits,
+                    IntType::U32 => FlatEmbed::U32ToBits,
+                    IntType::U16 => FlatEmbed::U16ToBits,
+                    IntType::U8 => FlatEmbed::U8ToBits,
+                    IntType::I32 => FlatEmbed::I32ToBits,
+                    IntType::I16 => FlatEmbed::I16ToBits,
+                    IntType::I8 => FlatEmbed::I8ToBits,
+                    _ => unreachable!(),
+                };
+                let e = self.fold_uint_expression(e);
+
+                ArrayExpressionInner::Identifier(self.call_embed(embed, vec![e.into()]))
+            }
             // default
             e => fold_array_expression_inner(self, ty, size, e),
         }
@@ -547,9 +704,117 @@ impl<'ast, T: Field> Folder<'ast, T> for Inliner<'ast, T> {
         }
     }
 
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType,
+        e: TupleExpressionInner<'ast, T>,
+    ) -> TupleExpressionInner<'ast, T> {
+        match e {
+            TupleExpressionInner::FunctionCall(key, exps) => {
+                let exps: Vec<_> = exps.into_iter().map(|e| self.fold_expression(e)).collect();
+
+                match self.try_inline_call(&key, exps) {
+                    Ok(mut ret) => match ret.pop().unwrap() {
+                        TypedExpression::Tuple(e) => e.into_inner(),
+                        _ => unreachable!(),
+                    },
+                    Err((embed_key, expressions)) => {
+                        let tys = key.signature.outputs.clone();
+                        let id = Identifier {
+                            id: CoreIdentifier::Call(
+                                key.clone(),
+                                *self
+                                    .call_count
+                                    .entry((self.module_id().clone(), embed_key.clone()))
+                                    .and_modify(|i| *i += 1)
+                                    .or_insert(1),
+                            ),
+                            version: 0,
+                            stack: self.stack.clone(),
+                        };
+                        self.statement_buffer
+                            .push(TypedStatement::MultipleDefinition(
+                                vec![Variable::with_id_and_type(id.clone(), tys[0].clone())],
+                                TypedExpressionList::FunctionCall(
+                                    key.clone(),
+                                    expressions.clone(),
+                                    tys,
+                                ),
+                            ));
+
+                        let out = TupleExpressionInner::Identifier(id);
+
+                        self.call_cache_mut()
+                            .entry(key.clone())
+                            .or_insert_with(|| HashMap::new())
+                            .insert(expressions, vec![out.clone().annotate(ty.clone()).into()]);
+
+                        out
+                    }
+                }
+            }
+            // default
+            e => fold_tuple_expression_inner(self, ty, e),
+        }
+    }
+
+    fn fold_vector_expression_inner(
+        &mut self,
+        ty: &VectorType,
+        e: VectorExpressionInner<'ast, T>,
+    ) -> VectorExpressionInner<'ast, T> {
+        match e {
+            VectorExpressionInner::FunctionCall(key, exps) => {
+                let exps: Vec<_> = exps.into_iter().map(|e| self.fold_expression(e)).collect();
+
+                match self.try_inline_call(&key, exps) {
+                    Ok(mut ret) => match ret.pop().unwrap() {
+                        TypedExpression::Vector(e) => e.into_inner(),
+                        _ => unreachable!(),
+                    },
+                    Err((embed_key, expressions)) => {
+                        let tys = key.signature.outputs.clone();
+                        let id = Identifier {
+                            id: CoreIdentifier::Call(
+                                key.clone(),
+                                *self
+                                    .call_count
+                                    .entry((self.module_id().clone(), embed_key.clone()))
+                                    .and_modify(|i| *i += 1)
+                                    .or_insert(1),
+                            ),
+                            version: 0,
+                            stack: self.stack.clone(),
+                        };
+                        self.statement_buffer
+                            .push(TypedStatement::MultipleDefinition(
+                                vec![Variable::with_id_and_type(id.clone(), tys[0].clone())],
+                                TypedExpressionList::FunctionCall(
+                                    key.clone(),
+                                    expressions.clone(),
+                                    tys,
+                                ),
+                            ));
+
+                        let out = VectorExpressionInner::Identifier(id);
+
+                        self.call_cache_mut()
+                            .entry(key.clone())
+                            .or_insert_with(|| HashMap::new())
+                            .insert(expressions, vec![out.clone().annotate(ty.clone()).into()]);
+
+                        out
+                    }
+                }
+            }
+            // default
+            e => fold_vector_expression_inner(self, ty, e),
+        }
+    }
+
     fn fold_uint_expression_inner(
         &mut self,
-        size: UBitwidth,
+        size: IntType,
         e: UExpressionInner<'ast, T>,
     ) -> UExpressionInner<'ast, T> {
         match e {
@@ -596,6 +861,22 @@ impl<'ast, T: Field> Folder<'ast, T> for Inliner<'ast, T> {
                     }
                 }
             }
+            // casts from bits are calls to the `from_bits` embeds
+            UExpressionInner::FromBits(box e) => {
+                let embed = match size {
+                    IntType::U64 => FlatEmbed::U64FromBits,
+                    IntType::U32 => FlatEmbed::U32FromBits,
+                    IntType::U16 => FlatEmbed::U16FromBits,
+                    IntType::U8 => FlatEmbed::U8FromBits,
+                    IntType::I32 => FlatEmbed::I32FromBits,
+                    IntType::I16 => FlatEmbed::I16FromBits,
+                    IntType::I8 => FlatEmbed::I8FromBits,
+                    _ => unreachable!(),
+                };
+                let e = self.fold_array_expression(e);
+
+                UExpressionInner::Identifier(self.call_embed(embed, vec![e.into()]))
+            }
             // default
             e => fold_uint_expression_inner(self, size, e),
         }
@@ -889,6 +1170,7 @@ mod tests {
                         arguments: vec![Parameter {
                             id: Variable::field_element("a"),
                             private: true,
+                            alias: None,
                         }],
                         statements: vec![
                             TypedStatement::Definition(
@@ -932,6 +1214,7 @@ mod tests {
                     arguments: vec![Parameter {
                         id: Variable::field_element("a"),
                         private: true,
+                        alias: None,
                     }],
                     statements: vec![TypedStatement::Return(vec![
                         FieldElementExpression::Identifier("a".into()).into(),
@@ -967,6 +1250,7 @@ mod tests {
                 arguments: vec![Parameter {
                     id: Variable::field_element("a"),
                     private: true,
+                    alias: None,
                 }],
                 statements: vec![
                     TypedStatement::Definition(
@@ -1043,6 +1327,7 @@ mod tests {
                         arguments: vec![Parameter {
                             id: Variable::field_element("a"),
                             private: true,
+                            alias: None,
                         }],
                         statements: vec![
                             TypedStatement::Definition(
@@ -1073,6 +1358,7 @@ mod tests {
                         arguments: vec![Parameter {
                             id: Variable::field_element("a"),
                             private: true,
+                            alias: None,
                         }],
                         statements: vec![TypedStatement::Return(vec![
                             FieldElementExpression::FunctionCall(
@@ -1103,6 +1389,7 @@ mod tests {
                     arguments: vec![Parameter {
                         id: Variable::field_element("a"),
                         private: true,
+                        alias: None,
                     }],
                     statements: vec![TypedStatement::Return(vec![
                         FieldElementExpression::Identifier("a".into()).into(),
@@ -1138,6 +1425,7 @@ mod tests {
                 arguments: vec![Parameter {
                     id: Variable::field_element("a"),
                     private: true,
+                    alias: None,
                 }],
                 statements: vec![
                     TypedStatement::Definition(
@@ -1587,4 +1875,138 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn restore_span_after_call() {
+        // def main(field a) -> field:
+        //     field b = foo(a)
+        //     return b
+        //
+        // def foo(field a) -> field:
+        //     return a
+
+        // inlined
+        // def main(field a) -> field
+        //     // main.zok:2:5
+        //     field a_0 = a
+        //     // main.zok:5:5, called from main.zok:2:5
+        //     // main.zok:2:5
+        //     field b = a_0
+        //     // main.zok:3:5
+        //     return b
+
+        let source_span = |line| SourceSpan {
+            file: "main.zok".into(),
+            from: crate::parser::Position { line, col: 5 },
+            to: crate::parser::Position { line, col: 13 },
+            stack: vec![],
+        };
+
+        let span = |line| TypedStatement::Span(source_span(line));
+
+        let signature = Signature::new()
+            .inputs(vec![Type::FieldElement])
+            .outputs(vec![Type::FieldElement]);
+
+        let main: TypedModule<Bn128Field> = TypedModule {
+            functions: vec![
+                (
+                    FunctionKey::with_id("main").signature(signature.clone()),
+                    TypedFunctionSymbol::Here(TypedFunction {
+                        arguments: vec![Parameter::private(Variable::field_element("a"))],
+                        statements: vec![
+                            span(2),
+                            TypedStatement::Definition(
+                                TypedAssignee::Identifier(Variable::field_element("b")),
+                                FieldElementExpression::FunctionCall(
+                                    FunctionKey::with_id("foo").signature(signature.clone()),
+                                    vec![FieldElementExpression::Identifier("a".into()).into()],
+                                )
+                                .into(),
+                            ),
+                            span(3),
+                            TypedStatement::Return(vec![FieldElementExpression::Identifier(
+                                "b".into(),
+                            )
+                            .into()]),
+                        ],
+                        signature: signature.clone(),
+                    }),
+                ),
+                (
+                    FunctionKey::with_id("foo").signature(signature.clone()),
+                    TypedFunctionSymbol::Here(TypedFunction {
+                        arguments: vec![Parameter::private(Variable::field_element("a"))],
+                        statements: vec![
+                            span(5),
+                            TypedStatement::Return(vec![FieldElementExpression::Identifier(
+                                "a".into(),
+                            )
+                            .into()]),
+                        ],
+                        signature: signature.clone(),
+                    }),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        };
+
+        let modules: HashMap<_, _> = vec![("main".into(), main)].into_iter().collect();
+
+        let program = TypedProgram {
+            main: "main".into(),
+            modules,
+        };
+
+        let program = Inliner::inline(program);
+
+        let stack = vec![(
+            "main".into(),
+            FunctionKey::with_id("foo").signature(signature.clone()),
+            1,
+        )];
+
+        assert_eq!(
+            program
+                .modules
+                .get(&PathBuf::from("main"))
+                .unwrap()
+                .functions
+                .get(&FunctionKey::with_id("main").signature(signature.clone()))
+                .unwrap(),
+            &TypedFunctionSymbol::Here(TypedFunction {
+                arguments: vec![Parameter::private(Variable::field_element("a"))],
+                statements: vec![
+                    span(2),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::field_element(
+                            Identifier::from("a").stack(stack.clone())
+                        )),
+                        FieldElementExpression::Identifier("a".into()).into()
+                    ),
+                    TypedStatement::Span(SourceSpan {
+                        stack: vec![Call {
+                            function: "foo".into(),
+                            site: source_span(2),
+                        }],
+                        ..source_span(5)
+                    }),
+                    span(2),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(Variable::field_element("b")),
+                        FieldElementExpression::Identifier(
+                            Identifier::from("a").stack(stack.clone())
+                        )
+                        .into()
+                    ),
+                    span(3),
+                    TypedStatement::Return(vec![
+                        FieldElementExpression::Identifier("b".into()).into()
+                    ])
+                ],
+                signature: signature.clone(),
+            })
+        );
+    }
 }
@@ -4,6 +4,7 @@
 //! @author Thibaut Schaeffer <thibaut@schaeff.fr>
 //! @date 2018
 
+mod branch_merger;
 mod flat_propagation;
 mod flatten_complex_types;
 mod inline;
@@ -16,10 +17,11 @@ mod unconstrained_vars;
 mod unroll;
 mod variable_access_remover;
 
+use self::branch_merger::BranchMerger;
 use self::flatten_complex_types::Flattener;
 use self::inline::Inliner;
 use self::propagate_unroll::PropagatedUnroller;
-use self::propagation::Propagator;
+pub use self::propagation::Propagator;
 use self::redefinition::RedefinitionOptimizer;
 use self::return_binder::ReturnBinder;
 use self::uint_optimizer::UintOptimizer;
@@ -37,8 +39,11 @@ pub trait Analyse {
 
 impl<'ast, T: Field> TypedProgram<'ast, T> {
     pub fn analyse(self) -> ZirProgram<'ast, T> {
+        // merge the definitions made in branches
+        let r = BranchMerger::merge(self);
+
         // propagated unrolling
-        let r = PropagatedUnroller::unroll(self).unwrap_or_else(|e| panic!(e));
+        let r = PropagatedUnroller::unroll(r).unwrap_or_else(|e| panic!(e));
 
         // return binding
         let r = ReturnBinder::bind(r);
@@ -48,6 +48,12 @@ impl<'ast, T: Field> Propagator<'ast, T> {
     pub fn propagate_verbose(p: TypedProgram<'ast, T>) -> TypedProgram<'ast, T> {
         Propagator::verbose().fold_program(p)
     }
+
+    /// Propagate constants in an expression which does not refer to any variable, such as the
+    /// expression of a constant definition
+    pub fn propagate_expression(e: TypedExpression<'ast, T>) -> TypedExpression<'ast, T> {
+        Propagator::new().fold_expression(e)
+    }
 }
 
 fn is_constant<'ast, T: Field>(e: &TypedExpression<'ast, T>) -> bool {
@@ -62,6 +68,10 @@ fn is_constant<'ast, T: Field>(e: &TypedExpression<'ast, T>) -> bool {
             StructExpressionInner::Value(v) => v.iter().all(|e| is_constant(e)),
             _ => false,
         },
+        TypedExpression::Tuple(a) => match a.as_inner() {
+            TupleExpressionInner::Value(v) => v.iter().all(|e| is_constant(e)),
+            _ => false,
+        },
         TypedExpression::Uint(a) => match a.as_inner() {
             UExpressionInner::Value(..) => true,
             _ => false,
@@ -79,6 +89,14 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
     fn fold_statement(&mut self, s: TypedStatement<'ast, T>) -> Vec<TypedStatement<'ast, T>> {
         let res = match s {
             TypedStatement::Declaration(v) => Some(TypedStatement::Declaration(v)),
+            TypedStatement::Span(span) => Some(TypedStatement::Span(span)),
+            TypedStatement::Log(format_string, expressions) => Some(TypedStatement::Log(
+                format_string,
+                expressions
+                    .into_iter()
+                    .map(|e| self.fold_expression(e))
+                    .collect(),
+            )),
             TypedStatement::Return(expressions) => Some(TypedStatement::Return(
                 expressions
                     .into_iter()
@@ -112,10 +130,16 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
             TypedStatement::Definition(TypedAssignee::Member(..), _) => {
                 unreachable!("struct update should have been replaced with full struct redef")
             }
+            TypedStatement::Definition(TypedAssignee::Element(..), _) => {
+                unreachable!("tuple update should have been replaced with full tuple redef")
+            }
             // propagate the boolean
-            TypedStatement::Assertion(e) => {
+            TypedStatement::Assertion(e, metadata) => {
                 // could stop execution here if condition is known to fail
-                Some(TypedStatement::Assertion(self.fold_boolean_expression(e)))
+                Some(TypedStatement::Assertion(
+                    self.fold_boolean_expression(e),
+                    metadata,
+                ))
             }
             // only loops with variable bounds are expected here
             // we stop propagation here as constants maybe be modified inside the loop body
@@ -129,6 +153,34 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
 
                 Some(TypedStatement::For(v, from, to, statements))
             }
+            // only keep the branch which is taken if the condition is constant
+            TypedStatement::IfElse(condition, consequence, alternative) => {
+                match self.fold_boolean_expression(condition) {
+                    BooleanExpression::Value(true) => {
+                        return consequence
+                            .into_iter()
+                            .flat_map(|s| self.fold_statement(s))
+                            .collect()
+                    }
+                    BooleanExpression::Value(false) => {
+                        return alternative
+                            .into_iter()
+                            .flat_map(|s| self.fold_statement(s))
+                            .collect()
+                    }
+                    condition => Some(TypedStatement::IfElse(
+                        condition,
+                        consequence
+                            .into_iter()
+                            .flat_map(|s| self.fold_statement(s))
+                            .collect(),
+                        alternative
+                            .into_iter()
+                            .flat_map(|s| self.fold_statement(s))
+                            .collect(),
+                    )),
+                }
+            }
             TypedStatement::MultipleDefinition(variables, expression_list) => {
                 let expression_list = self.fold_expression_list(expression_list);
                 match expression_list {
@@ -141,7 +193,7 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                         fn process_u_from_bits<'ast, T: Field>(
                             variables: Vec<Variable<'ast>>,
                             arguments: Vec<TypedExpression<'ast, T>>,
-                            bitwidth: UBitwidth,
+                            bitwidth: IntType,
                         ) -> TypedExpression<'ast, T> {
                             assert_eq!(variables.len(), 1);
                             assert_eq!(arguments.len(), 1);
@@ -185,7 +237,7 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                         fn process_u_to_bits<'ast, T: Field>(
                             variables: Vec<Variable<'ast>>,
                             arguments: Vec<TypedExpression<'ast, T>>,
-                            bitwidth: UBitwidth,
+                            bitwidth: IntType,
                         ) -> TypedExpression<'ast, T> {
                             assert_eq!(variables.len(), 1);
                             assert_eq!(arguments.len(), 1);
@@ -198,7 +250,7 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                                     let mut num = v;
                                     let mut res = vec![];
 
-                                    for i in (0..bitwidth as u32).rev() {
+                                    for i in (0..bitwidth.to_usize() as u32).rev() {
                                         if 2u128.pow(i) <= num {
                                             num = num - 2u128.pow(i);
                                             res.push(true);
@@ -223,35 +275,75 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                         match arguments.iter().all(|a| is_constant(a)) {
                             true => {
                                 let r: Option<TypedExpression<'ast, T>> = match key.id {
+                                    "_U64_FROM_BITS" => Some(process_u_from_bits(
+                                        variables.clone(),
+                                        arguments.clone(),
+                                        IntType::U64,
+                                    )),
                                     "_U32_FROM_BITS" => Some(process_u_from_bits(
                                         variables.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B32,
+                                        IntType::U32,
                                     )),
                                     "_U16_FROM_BITS" => Some(process_u_from_bits(
                                         variables.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B16,
+                                        IntType::U16,
                                     )),
                                     "_U8_FROM_BITS" => Some(process_u_from_bits(
                                         variables.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B8,
+                                        IntType::U8,
+                                    )),
+                                    "_U64_TO_BITS" => Some(process_u_to_bits(
+                                        variables.clone(),
+                                        arguments.clone(),
+                                        IntType::U64,
                                     )),
                                     "_U32_TO_BITS" => Some(process_u_to_bits(
                                         variables.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B32,
+                                        IntType::U32,
                                     )),
                                     "_U16_TO_BITS" => Some(process_u_to_bits(
                                         variables.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B16,
+                                        IntType::U16,
                                     )),
                                     "_U8_TO_BITS" => Some(process_u_to_bits(
                                         variables.clone(),
                                         arguments.clone(),
-                                        UBitwidth::B8,
+                                        IntType::U8,
+                                    )),
+                                    "_I32_FROM_BITS" => Some(process_u_from_bits(
+                                        variables.clone(),
+                                        arguments.clone(),
+                                        IntType::I32,
+                                    )),
+                                    "_I16_FROM_BITS" => Some(process_u_from_bits(
+                                        variables.clone(),
+                                        arguments.clone(),
+                                        IntType::I16,
+                                    )),
+                                    "_I8_FROM_BITS" => Some(process_u_from_bits(
+                                        variables.clone(),
+                                        arguments.clone(),
+                                        IntType::I8,
+                                    )),
+                                    "_I32_TO_BITS" => Some(process_u_to_bits(
+                                        variables.clone(),
+                                        arguments.clone(),
+                                        IntType::I32,
+                                    )),
+                                    "_I16_TO_BITS" => Some(process_u_to_bits(
+                                        variables.clone(),
+                                        arguments.clone(),
+                                        IntType::I16,
+                                    )),
+                                    "_I8_TO_BITS" => Some(process_u_to_bits(
+                                        variables.clone(),
+                                        arguments.clone(),
+                                        IntType::I8,
                                     )),
                                     "_UNPACK" => {
                                         assert_eq!(variables.len(), 1);
@@ -338,7 +430,7 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
 
     fn fold_uint_expression_inner(
         &mut self,
-        bitwidth: UBitwidth,
+        bitwidth: IntType,
         e: UExpressionInner<'ast, T>,
     ) -> UExpressionInner<'ast, T> {
         match e {
@@ -387,13 +479,7 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                         (v1.wrapping_sub(v2)) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
                     )
                 }
-                (e, UExpressionInner::Value(v)) | (UExpressionInner::Value(v), e) => match v {
-                    0 => e,
-                    _ => UExpressionInner::Sub(
-                        box e.annotate(bitwidth),
-                        box UExpressionInner::Value(v).annotate(bitwidth),
-                    ),
-                },
+                (e, UExpressionInner::Value(0)) => e,
                 (e1, e2) => {
                     UExpressionInner::Sub(box e1.annotate(bitwidth), box e2.annotate(bitwidth))
                 }
@@ -420,13 +506,49 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                     UExpressionInner::Mult(box e1.annotate(bitwidth), box e2.annotate(bitwidth))
                 }
             },
+            UExpressionInner::Div(box e1, box e2) => match (
+                self.fold_uint_expression(e1).into_inner(),
+                self.fold_uint_expression(e2).into_inner(),
+            ) {
+                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) if v2 != 0 => {
+                    match bitwidth.is_signed() {
+                        true => UExpressionInner::Value(
+                            bitwidth.from_signed(bitwidth.to_signed(v1) / bitwidth.to_signed(v2)),
+                        ),
+                        false => UExpressionInner::Value(v1 / v2),
+                    }
+                }
+                (e, UExpressionInner::Value(1)) => e,
+                (e1, e2) => {
+                    UExpressionInner::Div(box e1.annotate(bitwidth), box e2.annotate(bitwidth))
+                }
+            },
+            UExpressionInner::Rem(box e1, box e2) => match (
+                self.fold_uint_expression(e1).into_inner(),
+                self.fold_uint_expression(e2).into_inner(),
+            ) {
+                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) if v2 != 0 => {
+                    match bitwidth.is_signed() {
+                        true => UExpressionInner::Value(
+                            bitwidth.from_signed(bitwidth.to_signed(v1) % bitwidth.to_signed(v2)),
+                        ),
+                        false => UExpressionInner::Value(v1 % v2),
+                    }
+                }
+                (_, UExpressionInner::Value(1)) => UExpressionInner::Value(0),
+                (e1, e2) => {
+                    UExpressionInner::Rem(box e1.annotate(bitwidth), box e2.annotate(bitwidth))
+                }
+            },
             UExpressionInner::RightShift(box e, box by) => {
                 let e = self.fold_uint_expression(e);
                 let by = self.fold_field_expression(by);
                 match (e.into_inner(), by) {
                     (UExpressionInner::Value(v), FieldElementExpression::Number(by)) => {
                         let by_as_usize = by.to_dec_string().parse::<usize>().unwrap();
-                        UExpressionInner::Value(v >> by_as_usize)
+                        UExpressionInner::Value(
+                            bitwidth.from_signed(bitwidth.to_signed(v) >> by_as_usize),
+                        )
                     }
                     (e, FieldElementExpression::Number(by)) => UExpressionInner::RightShift(
                         box e.annotate(bitwidth),
@@ -444,7 +566,7 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                 match (e.into_inner(), by) {
                     (UExpressionInner::Value(v), FieldElementExpression::Number(by)) => {
                         let by_as_usize = by.to_dec_string().parse::<usize>().unwrap();
-                        UExpressionInner::Value((v << by_as_usize) & 0xffffffff)
+                        UExpressionInner::Value(bitwidth.from_signed((v << by_as_usize) as i128))
                     }
                     (e, FieldElementExpression::Number(by)) => UExpressionInner::LeftShift(
                         box e.annotate(bitwidth),
@@ -499,7 +621,9 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
             UExpressionInner::Not(box e) => {
                 let e = self.fold_uint_expression(e).into_inner();
                 match e {
-                    UExpressionInner::Value(v) => UExpressionInner::Value((!v) & 0xffffffff),
+                    UExpressionInner::Value(v) => {
+                        UExpressionInner::Value(bitwidth.from_signed(!v as i128))
+                    }
                     e => UExpressionInner::Not(box e.annotate(bitwidth)),
                 }
             }
@@ -557,6 +681,27 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                     UExpressionInner::FunctionCall(key, arguments),
                 )
             }
+            UExpressionInner::FromField(box e) => match self.fold_field_expression(e) {
+                FieldElementExpression::Number(n) => match n.to_dec_string().parse::<u128>() {
+                    Ok(v) if v >> bitwidth.to_usize() == 0 => UExpressionInner::Value(v),
+                    _ => UExpressionInner::FromField(box FieldElementExpression::Number(n)),
+                },
+                e => UExpressionInner::FromField(box e),
+            },
+            UExpressionInner::FromUint(box e) => {
+                let e = self.fold_uint_expression(e);
+
+                match e.as_inner() {
+                    UExpressionInner::Value(v) => {
+                        let v = e.bitwidth.to_signed(*v);
+                        match bitwidth.is_in_range(v) {
+                            true => UExpressionInner::Value(bitwidth.from_signed(v)),
+                            false => UExpressionInner::FromUint(box e),
+                        }
+                    }
+                    _ => UExpressionInner::FromUint(box e),
+                }
+            }
             e => fold_uint_expression_inner(self, bitwidth, e),
         }
     }
@@ -712,6 +857,21 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                     inner => FieldElementExpression::Member(box inner.annotate(members), m),
                 }
             }
+            FieldElementExpression::Element(box t, index) => {
+                let t = self.fold_tuple_expression(t);
+
+                let elements = t.ty().clone();
+
+                match t.into_inner() {
+                    TupleExpressionInner::Value(v) => {
+                        match v.into_iter().nth(index as usize).unwrap() {
+                            TypedExpression::FieldElement(e) => e,
+                            _ => unreachable!("should be a field element"),
+                        }
+                    }
+                    inner => FieldElementExpression::Element(box inner.annotate(elements), index),
+                }
+            }
             FieldElementExpression::FunctionCall(key, inputs) => {
                 assert!(
                     self.verbose,
@@ -719,6 +879,19 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                 );
                 fold_field_expression(self, FieldElementExpression::FunctionCall(key, inputs))
             }
+            FieldElementExpression::FromBool(box e) => match self.fold_boolean_expression(e) {
+                BooleanExpression::Value(true) => FieldElementExpression::Number(T::from(1)),
+                BooleanExpression::Value(false) => FieldElementExpression::Number(T::from(0)),
+                e => FieldElementExpression::FromBool(box e),
+            },
+            FieldElementExpression::FromUint(box e) => {
+                let e = self.fold_uint_expression(e);
+
+                match e.as_inner() {
+                    UExpressionInner::Value(v) => FieldElementExpression::Number(T::from(*v)),
+                    _ => FieldElementExpression::FromUint(box e),
+                }
+            }
             e => fold_field_expression(self, e),
         }
     }
@@ -821,6 +994,21 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                     inner => ArrayExpressionInner::Member(box inner.annotate(members), m),
                 }
             }
+            ArrayExpressionInner::Element(box t, index) => {
+                let t = self.fold_tuple_expression(t);
+
+                let elements = t.ty().clone();
+
+                match t.into_inner() {
+                    TupleExpressionInner::Value(v) => {
+                        match v.into_iter().nth(index as usize).unwrap() {
+                            TypedExpression::Array(e) => e.into_inner(),
+                            _ => unreachable!("should be an array"),
+                        }
+                    }
+                    inner => ArrayExpressionInner::Element(box inner.annotate(elements), index),
+                }
+            }
             ArrayExpressionInner::FunctionCall(key, inputs) => {
                 assert!(
                     self.verbose,
@@ -935,6 +1123,21 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                     inner => StructExpressionInner::Member(box inner.annotate(members), m),
                 }
             }
+            StructExpressionInner::Element(box t, index) => {
+                let t = self.fold_tuple_expression(t);
+
+                let elements = t.ty().clone();
+
+                match t.into_inner() {
+                    TupleExpressionInner::Value(v) => {
+                        match v.into_iter().nth(index as usize).unwrap() {
+                            TypedExpression::Struct(e) => e.into_inner(),
+                            _ => unreachable!("should be a struct"),
+                        }
+                    }
+                    inner => StructExpressionInner::Element(box inner.annotate(elements), index),
+                }
+            }
             StructExpressionInner::FunctionCall(key, inputs) => {
                 assert!(
                     self.verbose,
@@ -950,6 +1153,151 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
         }
     }
 
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType,
+        e: TupleExpressionInner<'ast, T>,
+    ) -> TupleExpressionInner<'ast, T> {
+        match e {
+            TupleExpressionInner::Identifier(id) => {
+                match self
+                    .constants
+                    .get(&TypedAssignee::Identifier(Variable::tuple(
+                        id.clone(),
+                        ty.clone(),
+                    ))) {
+                    Some(e) => match e {
+                        TypedExpression::Tuple(e) => e.as_inner().clone(),
+                        _ => panic!("constant stored for a tuple should be a tuple"),
+                    },
+                    None => TupleExpressionInner::Identifier(id),
+                }
+            }
+            TupleExpressionInner::Select(box array, box index) => {
+                let array = self.fold_array_expression(array);
+                let index = self.fold_field_expression(index);
+
+                let inner_type = array.inner_type().clone();
+                let size = array.size();
+
+                match (array.into_inner(), index) {
+                    (ArrayExpressionInner::Value(v), FieldElementExpression::Number(n)) => {
+                        let n_as_usize = n.to_dec_string().parse::<usize>().unwrap();
+                        if n_as_usize < size {
+                            TupleExpression::try_from(v[n_as_usize].clone())
+                                .unwrap()
+                                .into_inner()
+                        } else {
+                            unreachable!(
+                                "out of bounds index ({} >= {}) found during static analysis",
+                                n_as_usize, size
+                            );
+                        }
+                    }
+                    (ArrayExpressionInner::Identifier(id), FieldElementExpression::Number(n)) => {
+                        match self.constants.get(&TypedAssignee::Select(
+                            box TypedAssignee::Identifier(Variable::array(
+                                id.clone(),
+                                inner_type.clone(),
+                                size,
+                            )),
+                            box FieldElementExpression::Number(n.clone()).into(),
+                        )) {
+                            Some(e) => match e {
+                                TypedExpression::Tuple(e) => e.clone().into_inner(),
+                                _ => unreachable!("should be a tuple"),
+                            },
+                            None => TupleExpressionInner::Select(
+                                box ArrayExpressionInner::Identifier(id).annotate(inner_type, size),
+                                box FieldElementExpression::Number(n),
+                            ),
+                        }
+                    }
+                    (a, i) => TupleExpressionInner::Select(box a.annotate(inner_type, size), box i),
+                }
+            }
+            TupleExpressionInner::IfElse(box condition, box consequence, box alternative) => {
+                let consequence = self.fold_tuple_expression(consequence);
+                let alternative = self.fold_tuple_expression(alternative);
+                match self.fold_boolean_expression(condition) {
+                    BooleanExpression::Value(true) => consequence.into_inner(),
+                    BooleanExpression::Value(false) => alternative.into_inner(),
+                    c => TupleExpressionInner::IfElse(box c, box consequence, box alternative),
+                }
+            }
+            TupleExpressionInner::Member(box s, m) => {
+                let s = self.fold_struct_expression(s);
+
+                let members = match s.get_type() {
+                    Type::Struct(members) => members,
+                    _ => unreachable!("should be a struct"),
+                };
+
+                match s.into_inner() {
+                    StructExpressionInner::Value(v) => {
+                        match members
+                            .iter()
+                            .zip(v)
+                            .find(|(member, _)| member.id == m)
+                            .unwrap()
+                            .1
+                        {
+                            TypedExpression::Tuple(t) => t.into_inner(),
+                            _ => unreachable!("should be a tuple"),
+                        }
+                    }
+                    inner => TupleExpressionInner::Member(box inner.annotate(members), m),
+                }
+            }
+            TupleExpressionInner::Element(box t, index) => {
+                let t = self.fold_tuple_expression(t);
+
+                let elements = t.ty().clone();
+
+                match t.into_inner() {
+                    TupleExpressionInner::Value(v) => {
+                        match v.into_iter().nth(index as usize).unwrap() {
+                            TypedExpression::Tuple(e) => e.into_inner(),
+                            _ => unreachable!("should be a tuple"),
+                        }
+                    }
+                    inner => TupleExpressionInner::Element(box inner.annotate(elements), index),
+                }
+            }
+            TupleExpressionInner::FunctionCall(key, inputs) => {
+                assert!(
+                    self.verbose,
+                    "function calls should only exist out of multidef in verbose mode"
+                );
+                fold_tuple_expression_inner(
+                    self,
+                    ty,
+                    TupleExpressionInner::FunctionCall(key, inputs),
+                )
+            }
+            e => fold_tuple_expression_inner(self, ty, e),
+        }
+    }
+
+    fn fold_vector_expression_inner(
+        &mut self,
+        ty: &VectorType,
+        e: VectorExpressionInner<'ast, T>,
+    ) -> VectorExpressionInner<'ast, T> {
+        match e {
+            VectorExpressionInner::IfElse(box condition, box consequence, box alternative) => {
+                let consequence = self.fold_vector_expression(consequence);
+                let alternative = self.fold_vector_expression(alternative);
+                match self.fold_boolean_expression(condition) {
+                    BooleanExpression::Value(true) => consequence.into_inner(),
+                    BooleanExpression::Value(false) => alternative.into_inner(),
+                    c => VectorExpressionInner::IfElse(box c, box consequence, box alternative),
+                }
+            }
+            e => fold_vector_expression_inner(self, ty, e),
+        }
+    }
+
     fn fold_boolean_expression(
         &mut self,
         e: BooleanExpression<'ast, T>,
@@ -1036,6 +1384,58 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                     (e1, e2) => BooleanExpression::Ge(box e1, box e2),
                 }
             }
+            BooleanExpression::UintLt(box e1, box e2) => {
+                let e1 = self.fold_uint_expression(e1);
+                let e2 = self.fold_uint_expression(e2);
+                let bitwidth = e1.bitwidth;
+
+                match (e1.as_inner(), e2.as_inner()) {
+                    (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
+                        let (v1, v2) = (bitwidth.to_signed(*v1), bitwidth.to_signed(*v2));
+                        BooleanExpression::Value(v1 < v2)
+                    }
+                    _ => BooleanExpression::UintLt(box e1, box e2),
+                }
+            }
+            BooleanExpression::UintLe(box e1, box e2) => {
+                let e1 = self.fold_uint_expression(e1);
+                let e2 = self.fold_uint_expression(e2);
+                let bitwidth = e1.bitwidth;
+
+                match (e1.as_inner(), e2.as_inner()) {
+                    (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
+                        let (v1, v2) = (bitwidth.to_signed(*v1), bitwidth.to_signed(*v2));
+                        BooleanExpression::Value(v1 <= v2)
+                    }
+                    _ => BooleanExpression::UintLe(box e1, box e2),
+                }
+            }
+            BooleanExpression::UintGt(box e1, box e2) => {
+                let e1 = self.fold_uint_expression(e1);
+                let e2 = self.fold_uint_expression(e2);
+                let bitwidth = e1.bitwidth;
+
+                match (e1.as_inner(), e2.as_inner()) {
+                    (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
+                        let (v1, v2) = (bitwidth.to_signed(*v1), bitwidth.to_signed(*v2));
+                        BooleanExpression::Value(v1 > v2)
+                    }
+                    _ => BooleanExpression::UintGt(box e1, box e2),
+                }
+            }
+            BooleanExpression::UintGe(box e1, box e2) => {
+                let e1 = self.fold_uint_expression(e1);
+                let e2 = self.fold_uint_expression(e2);
+                let bitwidth = e1.bitwidth;
+
+                match (e1.as_inner(), e2.as_inner()) {
+                    (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
+                        let (v1, v2) = (bitwidth.to_signed(*v1), bitwidth.to_signed(*v2));
+                        BooleanExpression::Value(v1 >= v2)
+                    }
+                    _ => BooleanExpression::UintGe(box e1, box e2),
+                }
+            }
             BooleanExpression::Or(box e1, box e2) => {
                 let e1 = self.fold_boolean_expression(e1);
                 let e2 = self.fold_boolean_expression(e2);
@@ -1155,6 +1555,21 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                     inner => BooleanExpression::Member(box inner.annotate(members), m),
                 }
             }
+            BooleanExpression::Element(box t, index) => {
+                let t = self.fold_tuple_expression(t);
+
+                let elements = t.ty().clone();
+
+                match t.into_inner() {
+                    TupleExpressionInner::Value(v) => {
+                        match v.into_iter().nth(index as usize).unwrap() {
+                            TypedExpression::Boolean(e) => e,
+                            _ => unreachable!("should be a boolean"),
+                        }
+                    }
+                    inner => BooleanExpression::Element(box inner.annotate(elements), index),
+                }
+            }
             BooleanExpression::FunctionCall(key, inputs) => {
                 assert!(
                     self.verbose,
@@ -1162,6 +1577,15 @@ impl<'ast, T: Field> Folder<'ast, T> for Propagator<'ast, T> {
                 );
                 fold_boolean_expression(self, BooleanExpression::FunctionCall(key, inputs))
             }
+            BooleanExpression::FromField(box e) => match self.fold_field_expression(e) {
+                FieldElementExpression::Number(n) if n == T::from(0) => {
+                    BooleanExpression::Value(false)
+                }
+                FieldElementExpression::Number(n) if n == T::from(1) => {
+                    BooleanExpression::Value(true)
+                }
+                e => BooleanExpression::FromField(box e),
+            },
             e => fold_boolean_expression(self, e),
         }
     }
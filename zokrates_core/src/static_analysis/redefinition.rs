@@ -31,6 +31,14 @@ fn try_id<'ast, T: Field>(e: &TypedExpression<'ast, T>) -> Option<Identifier<'as
             StructExpressionInner::Identifier(id) => Some(id.clone()),
             _ => None,
         },
+        TypedExpression::Tuple(a) => match a.as_inner() {
+            TupleExpressionInner::Identifier(id) => Some(id.clone()),
+            _ => None,
+        },
+        TypedExpression::Vector(a) => match a.as_inner() {
+            VectorExpressionInner::Identifier(id) => Some(id.clone()),
+            _ => None,
+        },
         TypedExpression::Uint(a) => match a.as_inner() {
             UExpressionInner::Identifier(id) => Some(id.clone()),
             _ => None,
@@ -31,6 +31,12 @@ impl<'ast, T: Field> Folder<'ast, T> for ReturnBinder {
                         Type::Struct(struct_type) => StructExpressionInner::Identifier(i.clone())
                             .annotate(struct_type)
                             .into(),
+                        Type::Tuple(tuple_type) => TupleExpressionInner::Identifier(i.clone())
+                            .annotate(tuple_type)
+                            .into(),
+                        Type::Vector(vector_type) => VectorExpressionInner::Identifier(i.clone())
+                            .annotate(vector_type)
+                            .into(),
                         Type::Uint(bitwidth) => UExpressionInner::Identifier(i.clone())
                             .annotate(bitwidth)
                             .into(),
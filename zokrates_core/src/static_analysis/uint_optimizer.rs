@@ -53,6 +53,20 @@ fn force_no_reduce<'ast, T: Field>(e: UExpression<'ast, T>) -> UExpression<'ast,
 }
 
 impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
+    fn fold_field_expression(
+        &mut self,
+        e: FieldElementExpression<'ast, T>,
+    ) -> FieldElementExpression<'ast, T> {
+        match e {
+            FieldElementExpression::FromUint(box e) => {
+                let e = self.fold_uint_expression(e);
+
+                FieldElementExpression::FromUint(box force_reduce(e))
+            }
+            e => fold_field_expression(self, e),
+        }
+    }
+
     fn fold_boolean_expression(
         &mut self,
         e: BooleanExpression<'ast, T>,
@@ -67,6 +81,42 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
 
                 BooleanExpression::UintEq(box left, box right)
             }
+            BooleanExpression::UintLt(box left, box right) => {
+                let left = self.fold_uint_expression(left);
+                let right = self.fold_uint_expression(right);
+
+                let left = force_reduce(left);
+                let right = force_reduce(right);
+
+                BooleanExpression::UintLt(box left, box right)
+            }
+            BooleanExpression::UintLe(box left, box right) => {
+                let left = self.fold_uint_expression(left);
+                let right = self.fold_uint_expression(right);
+
+                let left = force_reduce(left);
+                let right = force_reduce(right);
+
+                BooleanExpression::UintLe(box left, box right)
+            }
+            BooleanExpression::UintGe(box left, box right) => {
+                let left = self.fold_uint_expression(left);
+                let right = self.fold_uint_expression(right);
+
+                let left = force_reduce(left);
+                let right = force_reduce(right);
+
+                BooleanExpression::UintGe(box left, box right)
+            }
+            BooleanExpression::UintGt(box left, box right) => {
+                let left = self.fold_uint_expression(left);
+                let right = self.fold_uint_expression(right);
+
+                let left = force_reduce(left);
+                let right = force_reduce(right);
+
+                BooleanExpression::UintGt(box left, box right)
+            }
             e => fold_boolean_expression(self, e),
         }
     }
@@ -78,9 +128,11 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
 
         let max_bitwidth = T::get_required_bits() - 1;
 
-        let range = e.bitwidth.to_usize();
+        let bitwidth = e.bitwidth;
+
+        let range = bitwidth.to_usize();
 
-        let range_max: T = (2_usize.pow(range as u32) - 1).into();
+        let range_max: T = T::from(2).pow(range) - T::from(1);
 
         assert!(range < max_bitwidth / 2);
 
@@ -89,10 +141,10 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
         use self::UExpressionInner::*;
 
         let res = match inner {
-            Value(v) => Value(v).annotate(range).with_max(v),
-            Identifier(id) => Identifier(id.clone()).annotate(range).metadata(
+            Value(v) => Value(v).annotate(bitwidth).with_max(v),
+            Identifier(id) => Identifier(id.clone()).annotate(bitwidth).metadata(
                 self.ids
-                    .get(&Variable::uint(id.clone(), range))
+                    .get(&Variable::uint(id.clone(), bitwidth))
                     .cloned()
                     .expect(&format!("identifier should have been defined: {}", id)),
             ),
@@ -258,11 +310,25 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
 
                 UExpression::mult(left, right).with_max(max)
             }
+            Div(box left, box right) => {
+                // reduce the two terms
+                let left = self.fold_uint_expression(left);
+                let right = self.fold_uint_expression(right);
+
+                UExpression::div(force_reduce(left), force_reduce(right)).with_max(range_max)
+            }
+            Rem(box left, box right) => {
+                // reduce the two terms
+                let left = self.fold_uint_expression(left);
+                let right = self.fold_uint_expression(right);
+
+                UExpression::rem(force_reduce(left), force_reduce(right)).with_max(range_max)
+            }
             Not(box e) => {
                 let e = self.fold_uint_expression(e);
 
                 UExpressionInner::Not(box force_reduce(e))
-                    .annotate(range)
+                    .annotate(bitwidth)
                     .with_max(range_max)
             }
             LeftShift(box e, box by) => {
@@ -296,11 +362,16 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                     _ => unreachable!(),
                 };
 
-                let bitwidth = e.metadata.clone().unwrap().bitwidth();
+                let max = match bitwidth.is_signed() {
+                    // the sign bit is copied to the most significant bits
+                    true => range_max,
+                    false => {
+                        let bitwidth = e.metadata.clone().unwrap().bitwidth();
 
-                let max = T::from(2)
-                    .pow(bitwidth as usize - std::cmp::min(by_u, bitwidth as usize))
-                    - T::from(1);
+                        T::from(2).pow(bitwidth as usize - std::cmp::min(by_u, bitwidth as usize))
+                            - T::from(1)
+                    }
+                };
 
                 UExpression::right_shift(force_reduce(e), by).with_max(max)
             }
@@ -321,6 +392,26 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                 )
                 .with_max(T::try_from(max).unwrap())
             }
+            FromField(box e) => {
+                let e = self.fold_field_expression(e);
+
+                // the field element is decomposed to the target bitwidth
+                FromField(box e).annotate(bitwidth).with_max(range_max)
+            }
+            FromUint(box e) => {
+                let e = self.fold_uint_expression(e);
+
+                // widening an unsigned integer keeps its value, otherwise the value is decomposed
+                let max =
+                    match !e.bitwidth.is_signed() && e.bitwidth.to_usize() < bitwidth.to_usize() {
+                        true => T::from(2).pow(e.bitwidth.to_usize()) - T::from(1),
+                        false => range_max,
+                    };
+
+                FromUint(box force_reduce(e))
+                    .annotate(bitwidth)
+                    .with_max(max)
+            }
         };
 
         assert!(res.metadata.is_some());
@@ -361,6 +452,20 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
             )],
             ZirStatement::MultipleDefinition(lhs, rhs) => match rhs {
                 ZirExpressionList::FunctionCall(key, arguments, ty) => match key.clone().id {
+                    "_U64_FROM_BITS" => {
+                        assert_eq!(lhs.len(), 1);
+                        self.register(
+                            lhs[0].clone(),
+                            UMetadata {
+                                max: T::from(2).pow(64) - T::from(1),
+                                should_reduce: ShouldReduce::False,
+                            },
+                        );
+                        vec![ZirStatement::MultipleDefinition(
+                            lhs,
+                            ZirExpressionList::FunctionCall(key, arguments, ty),
+                        )]
+                    }
                     "_U32_FROM_BITS" => {
                         assert_eq!(lhs.len(), 1);
                         self.register(
@@ -403,6 +508,48 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                             ZirExpressionList::FunctionCall(key, arguments, ty),
                         )]
                     }
+                    "_I32_FROM_BITS" => {
+                        assert_eq!(lhs.len(), 1);
+                        self.register(
+                            lhs[0].clone(),
+                            UMetadata {
+                                max: T::from(2).pow(32) - T::from(1),
+                                should_reduce: ShouldReduce::False,
+                            },
+                        );
+                        vec![ZirStatement::MultipleDefinition(
+                            lhs,
+                            ZirExpressionList::FunctionCall(key, arguments, ty),
+                        )]
+                    }
+                    "_I16_FROM_BITS" => {
+                        assert_eq!(lhs.len(), 1);
+                        self.register(
+                            lhs[0].clone(),
+                            UMetadata {
+                                max: T::from(2).pow(16) - T::from(1),
+                                should_reduce: ShouldReduce::False,
+                            },
+                        );
+                        vec![ZirStatement::MultipleDefinition(
+                            lhs,
+                            ZirExpressionList::FunctionCall(key, arguments, ty),
+                        )]
+                    }
+                    "_I8_FROM_BITS" => {
+                        assert_eq!(lhs.len(), 1);
+                        self.register(
+                            lhs[0].clone(),
+                            UMetadata {
+                                max: T::from(2).pow(8) - T::from(1),
+                                should_reduce: ShouldReduce::False,
+                            },
+                        );
+                        vec![ZirStatement::MultipleDefinition(
+                            lhs,
+                            ZirExpressionList::FunctionCall(key, arguments, ty),
+                        )]
+                    }
                     _ => vec![ZirStatement::MultipleDefinition(
                         lhs,
                         ZirExpressionList::FunctionCall(
@@ -416,7 +563,7 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                     )],
                 },
             },
-            ZirStatement::Assertion(BooleanExpression::UintEq(box left, box right)) => {
+            ZirStatement::Assertion(BooleanExpression::UintEq(box left, box right), metadata) => {
                 let left = self.fold_uint_expression(left);
                 let right = self.fold_uint_expression(right);
 
@@ -424,10 +571,28 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                 let left = force_reduce(left);
                 let right = force_reduce(right);
 
-                vec![ZirStatement::Assertion(BooleanExpression::UintEq(
-                    box left, box right,
-                ))]
+                vec![ZirStatement::Assertion(
+                    BooleanExpression::UintEq(box left, box right),
+                    metadata,
+                )]
             }
+            // we need to put back in range to log
+            ZirStatement::Log(format_string, expressions) => vec![ZirStatement::Log(
+                format_string,
+                expressions
+                    .into_iter()
+                    .map(|e| match e {
+                        ZirExpression::Uint(e) => {
+                            let e = self.fold_uint_expression(e);
+
+                            let e = force_reduce(e);
+
+                            ZirExpression::Uint(e)
+                        }
+                        e => self.fold_expression(e),
+                    })
+                    .collect(),
+            )],
             s => fold_statement(self, s),
         }
     }
@@ -1,7 +1,7 @@
 use crate::ir::Prog;
 use flat_absy::FlatVariable;
-use ir::folder::Folder;
-use ir::Directive;
+use ir::folder::{fold_statement, Folder};
+use ir::{Directive, Statement};
 use std::collections::HashSet;
 use zokrates_field::Field;
 
@@ -49,6 +49,13 @@ impl<T: Field> Folder<T> for UnconstrainedVariableDetector {
         self.variables.extend(d.outputs.iter());
         d
     }
+    fn fold_statement(&mut self, s: Statement<T>) -> Vec<Statement<T>> {
+        match s {
+            // logging a variable does not constrain it
+            Statement::Log(..) => vec![s],
+            s => fold_statement(self, s),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -82,6 +89,7 @@ mod tests {
                 LinComb::summand(1, out_0),
             )],
             returns: vec![out_0],
+            source_map: Default::default(),
         };
 
         let p: Prog<Bn128Field> = Prog {
@@ -106,6 +114,7 @@ mod tests {
             arguments: vec![_0],
             statements: vec![Statement::definition(out_0, LinComb::from(_0))],
             returns: vec![out_0],
+            source_map: Default::default(),
         };
 
         let p: Prog<Bn128Field> = Prog {
@@ -164,6 +173,7 @@ mod tests {
                 ),
             ],
             returns: vec![out_0],
+            source_map: Default::default(),
         };
 
         let p: Prog<Bn128Field> = Prog {
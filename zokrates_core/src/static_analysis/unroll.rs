@@ -90,6 +90,7 @@ impl<'ast> Unroller<'ast> {
                                     box FieldElementExpression::Number(T::from(size)),
                                 )
                                 .into(),
+                                None,
                             ));
 
                             ArrayExpressionInner::Value(
@@ -149,6 +150,33 @@ impl<'ast> Unroller<'ast> {
                                             ),
                                         )
                                         .into(),
+                                        Type::Tuple(..) => TupleExpression::if_else(
+                                            BooleanExpression::FieldEq(
+                                                box FieldElementExpression::Number(T::from(i)),
+                                                box head.clone(),
+                                            ),
+                                            match Self::choose_many(
+                                                TupleExpression::select(
+                                                    base.clone(),
+                                                    FieldElementExpression::Number(T::from(i)),
+                                                )
+                                                .into(),
+                                                tail.clone(),
+                                                new_expression.clone(),
+                                                statements,
+                                            ) {
+                                                TypedExpression::Tuple(e) => e,
+                                                e => unreachable!(
+                                            "the interior was expected to be a tuple, was {}",
+                                            e.get_type()
+                                        ),
+                                            },
+                                            TupleExpression::select(
+                                                base.clone(),
+                                                FieldElementExpression::Number(T::from(i)),
+                                            ),
+                                        )
+                                        .into(),
                                         Type::FieldElement => FieldElementExpression::if_else(
                                             BooleanExpression::FieldEq(
                                                 box FieldElementExpression::Number(T::from(i)),
@@ -230,6 +258,9 @@ impl<'ast> Unroller<'ast> {
                                             ),
                                         )
                                         .into(),
+                                        Type::Vector(..) => {
+                                            unreachable!("vectors cannot be nested in other types")
+                                        }
                                     })
                                     .collect(),
                             )
@@ -237,6 +268,7 @@ impl<'ast> Unroller<'ast> {
                             .into()
                         }
                         Access::Member(..) => unreachable!("can't get a member from an array"),
+                        Access::Element(..) => unreachable!("can't get an element from an array"),
                     }
                 }
                 TypedExpression::Struct(base) => {
@@ -342,12 +374,90 @@ impl<'ast> Unroller<'ast> {
                                             .into()
                                         }
                                     }
+                                    Type::Tuple(..) => {
+                                        if member.id == head {
+                                            Self::choose_many(
+                                                TupleExpression::member(base.clone(), head.clone())
+                                                    .into(),
+                                                tail.clone(),
+                                                new_expression.clone(),
+                                                statements,
+                                            )
+                                        } else {
+                                            TupleExpression::member(base.clone(), member.id.clone())
+                                                .into()
+                                        }
+                                    }
+                                    Type::Vector(..) => {
+                                        unreachable!("vectors cannot be nested in other types")
+                                    }
                                 })
                                 .collect(),
                         )
                         .annotate(members)
                         .into(),
                         Access::Select(..) => unreachable!("can't get a element from a struct"),
+                        Access::Element(..) => unreachable!("can't get an element from a struct"),
+                    }
+                }
+                TypedExpression::Tuple(base) => {
+                    let elements = match base.get_type() {
+                        Type::Tuple(elements) => elements.clone(),
+                        _ => unreachable!(),
+                    };
+
+                    let head = indices.remove(0);
+                    let tail = indices;
+
+                    match head {
+                        Access::Element(head) => TupleExpressionInner::Value(
+                            elements
+                                .clone()
+                                .into_iter()
+                                .enumerate()
+                                .map(|(i, ty)| {
+                                    let i = i as u32;
+                                    let element: TypedExpression<'ast, T> = match ty {
+                                        Type::FieldElement => {
+                                            FieldElementExpression::element(base.clone(), i).into()
+                                        }
+                                        Type::Uint(..) => {
+                                            UExpression::element(base.clone(), i).into()
+                                        }
+                                        Type::Boolean => {
+                                            BooleanExpression::element(base.clone(), i).into()
+                                        }
+                                        Type::Array(..) => {
+                                            ArrayExpression::element(base.clone(), i).into()
+                                        }
+                                        Type::Struct(..) => {
+                                            StructExpression::element(base.clone(), i).into()
+                                        }
+                                        Type::Tuple(..) => {
+                                            TupleExpression::element(base.clone(), i).into()
+                                        }
+                                        Type::Vector(..) => {
+                                            unreachable!("vectors cannot be nested in other types")
+                                        }
+                                    };
+
+                                    if i == head {
+                                        Self::choose_many(
+                                            element,
+                                            tail.clone(),
+                                            new_expression.clone(),
+                                            statements,
+                                        )
+                                    } else {
+                                        element
+                                    }
+                                })
+                                .collect(),
+                        )
+                        .annotate(elements)
+                        .into(),
+                        Access::Select(..) => unreachable!("can't get a element from a tuple"),
+                        Access::Member(..) => unreachable!("can't get a member from a tuple"),
                     }
                 }
                 e => unreachable!("can't make an access on a {}", e.get_type()),
@@ -360,6 +470,7 @@ impl<'ast> Unroller<'ast> {
 enum Access<'ast, T: Field> {
     Select(FieldElementExpression<'ast, T>),
     Member(MemberId),
+    Element(u32),
 }
 /// Turn an assignee into its representation as a base variable and a list accesses
 /// a[2][3][4] -> (a, [2, 3, 4])
@@ -376,6 +487,11 @@ fn linear<'ast, T: Field>(a: TypedAssignee<'ast, T>) -> (Variable, Vec<Access<'a
             indices.push(Access::Member(m));
             (v, indices)
         }
+        TypedAssignee::Element(box t, i) => {
+            let (v, mut indices) = linear(t);
+            indices.push(Access::Element(i));
+            (v, indices)
+        }
     }
 }
 
@@ -411,6 +527,16 @@ impl<'ast, T: Field> Folder<'ast, T> for Unroller<'ast> {
                             .annotate(members)
                             .into()
                     }
+                    Type::Tuple(elements) => {
+                        TupleExpressionInner::Identifier(variable.id.clone().into())
+                            .annotate(elements)
+                            .into()
+                    }
+                    Type::Vector(vector_type) => {
+                        VectorExpressionInner::Identifier(variable.id.clone().into())
+                            .annotate(vector_type)
+                            .into()
+                    }
                 };
 
                 let base = self.fold_expression(base);
@@ -1094,6 +1220,7 @@ mod tests {
                             box FieldElementExpression::Number(Bn128Field::from(2))
                         )
                         .into(),
+                        None,
                     ),
                     TypedStatement::Definition(
                         TypedAssignee::Identifier(Variable::field_array(
@@ -1231,6 +1358,7 @@ mod tests {
                             box FieldElementExpression::Number(Bn128Field::from(2))
                         )
                         .into(),
+                        None,
                     ),
                     TypedStatement::Definition(
                         TypedAssignee::Identifier(Variable::with_id_and_type(
@@ -7,31 +7,311 @@
 //!
 //! Would become
 //! ```zokrates
-//! if(index == 0, a[0], if(index == 1, a[1], ...))
+//! s_0 = index == 0
+//! s_1 = index == 1
+//! ...
+//! assert(s_0 as field + s_1 as field + ... == 1)
+//! if(s_0, a[0], if(s_1, a[1], ...))
+//! ```
+//!
+//! The selectors `s_k` form a one-hot vector which is computed once per index and shared between
+//! all reads and writes using that index, so that further accesses only cost the selection itself.
+//!
+//! When many field elements are read from the same array, the reads are instead batched into a
+//! single call to the `_LOOKUP` embed, which sorts the array and the accesses together, if that
+//! is cheaper:
+//! ```zokrates
+//! a[i] + a[j]
+//! ```
+//!
+//! Would become
+//! ```zokrates
+//! v_0, v_1 = _LOOKUP(a, [i, j])
+//! v_0 + v_1
 //! ```
 
+use embed::{lookup_cost, FlatEmbed};
+use std::collections::{HashMap, HashSet};
 use typed_absy::{folder::*, *};
 use zokrates_field::Field;
 
 pub struct VariableAccessRemover<'ast, T: Field> {
     statements: Vec<TypedStatement<'ast, T>>,
+    // the selector `index == value` for each index compared to a constant
+    selectors: HashMap<(FieldElementExpression<'ast, T>, T), Identifier<'ast>>,
+    // the indices already checked to be in the bounds of an array of a given size
+    checked: HashSet<(FieldElementExpression<'ast, T>, usize)>,
+    // the value of each read of an array at an index, obtained by a batched lookup
+    lookups: HashMap<(Identifier<'ast>, FieldElementExpression<'ast, T>), Identifier<'ast>>,
+    // the lookup embeds used so far, to be defined in the module
+    embeds: Vec<FlatEmbed>,
+    count: usize,
+}
+
+/// The reads of an array at variable indices which are looked up together, before the statement
+/// at `position`
+struct Batch<'ast, T> {
+    array: Identifier<'ast>,
+    size: usize,
+    position: usize,
+    indices: Vec<FieldElementExpression<'ast, T>>,
+    reads: usize,
+}
+
+impl<'ast, T: Field> Batch<'ast, T> {
+    // whether the lookup is cheaper than one-hot selections, with one selector vector and bounds
+    // check per index and one selection per read
+    fn is_worth_it(&self) -> bool {
+        let (size, count) = (self.size, self.indices.len());
+
+        size > 1
+            && count > 1
+            && lookup_cost(size, count) < count * (2 * size + 3) + self.reads * 2 * (size - 1)
+    }
+}
+
+/// Collects the reads of field elements in arrays at variable indices
+struct Reads<'ast, T> {
+    reads: Vec<(Identifier<'ast>, usize, FieldElementExpression<'ast, T>)>,
+}
+
+impl<'ast, T: Field> Folder<'ast, T> for Reads<'ast, T> {
+    fn fold_field_expression(
+        &mut self,
+        e: FieldElementExpression<'ast, T>,
+    ) -> FieldElementExpression<'ast, T> {
+        if let FieldElementExpression::Select(ref a, ref i) = e {
+            match (a.as_inner(), &**i) {
+                (_, FieldElementExpression::Number(_)) => {}
+                (ArrayExpressionInner::Identifier(id), i) => {
+                    self.reads.push((id.clone(), a.size(), i.clone()))
+                }
+                _ => {}
+            }
+        }
+
+        fold_field_expression(self, e)
+    }
+}
+
+/// Collects the identifiers used in an expression
+struct Names<'ast> {
+    names: Vec<Identifier<'ast>>,
+}
+
+impl<'ast, T: Field> Folder<'ast, T> for Names<'ast> {
+    fn fold_name(&mut self, n: Identifier<'ast>) -> Identifier<'ast> {
+        self.names.push(n.clone());
+        n
+    }
+}
+
+// the identifiers defined by a statement
+fn definitions<'ast, T: Field>(s: &TypedStatement<'ast, T>) -> Vec<Identifier<'ast>> {
+    fn base<'ast, T>(a: &TypedAssignee<'ast, T>) -> Identifier<'ast> {
+        match *a {
+            TypedAssignee::Identifier(ref v) => v.id.clone(),
+            TypedAssignee::Select(ref a, _)
+            | TypedAssignee::Member(ref a, _)
+            | TypedAssignee::Element(ref a, _) => base(a),
+        }
+    }
+
+    match s {
+        TypedStatement::Definition(a, _) => vec![base(a)],
+        TypedStatement::MultipleDefinition(variables, _) => {
+            variables.iter().map(|v| v.id.clone()).collect()
+        }
+        TypedStatement::For(v, _, _, statements) => std::iter::once(v.id.clone())
+            .chain(statements.iter().flat_map(definitions))
+            .collect(),
+        TypedStatement::IfElse(_, consequence, alternative) => consequence
+            .iter()
+            .chain(alternative.iter())
+            .flat_map(definitions)
+            .collect(),
+        _ => vec![],
+    }
 }
 
 impl<'ast, T: Field> VariableAccessRemover<'ast, T> {
     fn new() -> Self {
-        Self { statements: vec![] }
+        Self {
+            statements: vec![],
+            selectors: HashMap::new(),
+            checked: HashSet::new(),
+            lookups: HashMap::new(),
+            embeds: vec![],
+            count: 0,
+        }
     }
 
     pub fn apply(p: TypedProgram<'ast, T>) -> TypedProgram<'ast, T> {
         Self::new().fold_program(p)
     }
 
+    // get the selector for `index == value`, defining it if it does not exist yet
+    fn selector(
+        &mut self,
+        index: FieldElementExpression<'ast, T>,
+        value: T,
+    ) -> BooleanExpression<'ast, T> {
+        let key = (index, value);
+
+        let id = match self.selectors.get(&key) {
+            Some(id) => id.clone(),
+            None => {
+                let v = Variable::boolean(CoreIdentifier::Internal("SELECTOR", self.count));
+                self.count += 1;
+
+                let (index, value) = key.clone();
+
+                self.statements.push(TypedStatement::Declaration(v.clone()));
+                self.statements.push(TypedStatement::Definition(
+                    TypedAssignee::Identifier(v.clone()),
+                    BooleanExpression::FieldEq(
+                        box index,
+                        box FieldElementExpression::Number(value),
+                    )
+                    .into(),
+                ));
+
+                self.selectors.insert(key, v.id.clone());
+                v.id
+            }
+        };
+
+        BooleanExpression::Identifier(id)
+    }
+
+    // group the reads of field elements at variable indices in the top-level statements of `f` by
+    // array, such that the array and all indices of a batch are defined before its first read
+    fn batches(f: &TypedFunction<'ast, T>) -> Vec<Batch<'ast, T>> {
+        // the position after the definition of each identifier
+        let mut defined: HashMap<Identifier<'ast>, usize> =
+            f.arguments.iter().map(|p| (p.id.id.clone(), 0)).collect();
+        // the identifiers defined more than once, whose reads are not batched
+        let mut redefined = HashSet::new();
+
+        let mut reads = vec![];
+
+        for (position, s) in f.statements.iter().enumerate() {
+            match s {
+                // reads in branches only happen if the branch is taken
+                TypedStatement::IfElse(..) | TypedStatement::For(..) => {}
+                s => {
+                    let mut collector = Reads { reads: vec![] };
+                    collector.fold_statement(s.clone());
+                    reads.extend(collector.reads.into_iter().map(|r| (position, r)));
+                }
+            }
+
+            for id in definitions(s) {
+                if defined.insert(id.clone(), position + 1).is_some() {
+                    redefined.insert(id);
+                }
+            }
+        }
+
+        let mut batches: Vec<Batch<'ast, T>> = vec![];
+        // the last batch of each array, which further reads may join
+        let mut open = HashMap::new();
+
+        for (position, (array, size, index)) in reads {
+            let mut names = Names {
+                names: vec![array.clone()],
+            };
+            names.fold_field_expression(index.clone());
+
+            // the first position at which the read can be made
+            let ready = names
+                .names
+                .iter()
+                .map(|name| match redefined.contains(name) {
+                    true => None,
+                    false => defined.get(name).cloned(),
+                })
+                .fold(Some(0), |acc, p| acc.and_then(|acc| p.map(|p| p.max(acc))));
+
+            match ready {
+                Some(ready) if ready <= position => match open.get(&array) {
+                    Some(&i) if ready <= batches[i].position => {
+                        let batch = &mut batches[i];
+                        if !batch.indices.contains(&index) {
+                            batch.indices.push(index);
+                        }
+                        batch.reads += 1;
+                    }
+                    _ => {
+                        open.insert(array.clone(), batches.len());
+                        batches.push(Batch {
+                            array,
+                            size,
+                            position,
+                            indices: vec![index],
+                            reads: 1,
+                        });
+                    }
+                },
+                _ => {}
+            }
+        }
+
+        batches.retain(|batch| batch.is_worth_it());
+        batches
+    }
+
+    // look up the values of a batch, to be substituted for the reads
+    fn lookup(&mut self, batch: Batch<'ast, T>) -> Vec<TypedStatement<'ast, T>> {
+        let embed = FlatEmbed::Lookup(batch.size, batch.indices.len());
+
+        let values: Vec<_> = batch
+            .indices
+            .iter()
+            .map(|_| {
+                let v = Variable::field_element(CoreIdentifier::Internal("LOOKUP", self.count));
+                self.count += 1;
+                v
+            })
+            .collect();
+
+        let statements = self.fold_statement(TypedStatement::MultipleDefinition(
+            values.clone(),
+            TypedExpressionList::FunctionCall(
+                embed.key::<T>(),
+                vec![
+                    ArrayExpressionInner::Identifier(batch.array.clone())
+                        .annotate(Type::FieldElement, batch.size)
+                        .into(),
+                    ArrayExpressionInner::Value(
+                        batch.indices.iter().cloned().map(|i| i.into()).collect(),
+                    )
+                    .annotate(Type::FieldElement, batch.indices.len())
+                    .into(),
+                ],
+                vec![Type::FieldElement; batch.indices.len()],
+            ),
+        ));
+
+        for (index, value) in batch.indices.into_iter().zip(values) {
+            self.lookups.insert((batch.array.clone(), index), value.id);
+        }
+
+        if !self.embeds.contains(&embed) {
+            self.embeds.push(embed);
+        }
+
+        statements
+    }
+
     fn select<U: Select<'ast, T> + IfElse<'ast, T>>(
         &mut self,
         a: ArrayExpression<'ast, T>,
         i: FieldElementExpression<'ast, T>,
     ) -> U {
-        match i {
+        let a = self.fold_array_expression(a);
+
+        match self.fold_field_expression(i) {
             FieldElementExpression::Number(i) => U::select(a, FieldElementExpression::Number(i)),
             i => {
                 let size = match a.get_type().clone() {
@@ -39,35 +319,34 @@ impl<'ast, T: Field> VariableAccessRemover<'ast, T> {
                     _ => unreachable!(),
                 };
 
-                self.statements.push(TypedStatement::Assertion(
-                    (0..size)
-                        .map(|index| {
-                            BooleanExpression::FieldEq(
-                                box i.clone(),
-                                box FieldElementExpression::Number(index.into()).into(),
-                            )
-                        })
-                        .fold(None, |acc, e| match acc {
-                            Some(acc) => Some(BooleanExpression::Or(box acc, box e)),
-                            None => Some(e),
-                        })
-                        .unwrap()
-                        .into(),
-                ));
+                let selectors: Vec<_> = (0..size)
+                    .map(|index| self.selector(i.clone(), T::from(index)))
+                    .collect();
+
+                // exactly one selector is set if and only if the index is in bounds
+                if self.checked.insert((i.clone(), size)) {
+                    self.statements.push(TypedStatement::Assertion(
+                        BooleanExpression::FieldEq(
+                            box selectors
+                                .iter()
+                                .map(|s| FieldElementExpression::FromBool(box s.clone()))
+                                .fold(None, |acc, e| match acc {
+                                    Some(acc) => Some(FieldElementExpression::Add(box acc, box e)),
+                                    None => Some(e),
+                                })
+                                .unwrap(),
+                            box FieldElementExpression::Number(T::from(1)),
+                        ),
+                        None,
+                    ));
+                }
 
                 (0..size)
                     .map(|i| U::select(a.clone(), FieldElementExpression::Number(i.into())))
-                    .enumerate()
+                    .zip(selectors)
                     .rev()
-                    .fold(None, |acc, (index, res)| match acc {
-                        Some(acc) => Some(U::if_else(
-                            BooleanExpression::FieldEq(
-                                box i.clone(),
-                                box FieldElementExpression::Number(index.into()),
-                            ),
-                            res,
-                            acc,
-                        )),
+                    .fold(None, |acc, (res, selector)| match acc {
+                        Some(acc) => Some(U::if_else(selector, res, acc)),
                         None => Some(res),
                     })
                     .unwrap()
@@ -82,7 +361,19 @@ impl<'ast, T: Field> Folder<'ast, T> for VariableAccessRemover<'ast, T> {
         e: FieldElementExpression<'ast, T>,
     ) -> FieldElementExpression<'ast, T> {
         match e {
-            FieldElementExpression::Select(box a, box i) => self.select(a, i),
+            FieldElementExpression::Select(box a, box i) => {
+                let value = match a.as_inner() {
+                    ArrayExpressionInner::Identifier(id) => {
+                        self.lookups.get(&(id.clone(), i.clone())).cloned()
+                    }
+                    _ => None,
+                };
+
+                match value {
+                    Some(value) => FieldElementExpression::Identifier(value),
+                    None => self.select(a, i),
+                }
+            }
             e => fold_field_expression(self, e),
         }
     }
@@ -93,6 +384,24 @@ impl<'ast, T: Field> Folder<'ast, T> for VariableAccessRemover<'ast, T> {
     ) -> BooleanExpression<'ast, T> {
         match e {
             BooleanExpression::Select(box a, box i) => self.select(a, i),
+            // comparisons of an index to a constant, such as the ones introduced for writes at a
+            // variable index, reuse the selectors
+            BooleanExpression::FieldEq(box lhs, box rhs) => {
+                let lhs = self.fold_field_expression(lhs);
+                let rhs = self.fold_field_expression(rhs);
+
+                match (lhs, rhs) {
+                    (FieldElementExpression::Number(lhs), FieldElementExpression::Number(rhs)) => {
+                        BooleanExpression::FieldEq(
+                            box FieldElementExpression::Number(lhs),
+                            box FieldElementExpression::Number(rhs),
+                        )
+                    }
+                    (FieldElementExpression::Number(value), index)
+                    | (index, FieldElementExpression::Number(value)) => self.selector(index, value),
+                    (lhs, rhs) => BooleanExpression::FieldEq(box lhs, box rhs),
+                }
+            }
             e => fold_boolean_expression(self, e),
         }
     }
@@ -124,9 +433,22 @@ impl<'ast, T: Field> Folder<'ast, T> for VariableAccessRemover<'ast, T> {
         }
     }
 
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType,
+        e: TupleExpressionInner<'ast, T>,
+    ) -> TupleExpressionInner<'ast, T> {
+        match e {
+            TupleExpressionInner::Select(box a, box i) => {
+                self.select::<TupleExpression<'ast, T>>(a, i).into_inner()
+            }
+            e => fold_tuple_expression_inner(self, ty, e),
+        }
+    }
+
     fn fold_uint_expression_inner(
         &mut self,
-        bitwidth: UBitwidth,
+        bitwidth: IntType,
         e: UExpressionInner<'ast, T>,
     ) -> UExpressionInner<'ast, T> {
         match e {
@@ -137,9 +459,88 @@ impl<'ast, T: Field> Folder<'ast, T> for VariableAccessRemover<'ast, T> {
         }
     }
 
+    fn fold_module(&mut self, m: TypedModule<'ast, T>) -> TypedModule<'ast, T> {
+        let mut m = fold_module(self, m);
+
+        m.functions.extend(
+            self.embeds
+                .drain(..)
+                .map(|embed| (embed.key::<T>(), TypedFunctionSymbol::Flat(embed))),
+        );
+
+        m
+    }
+
+    fn fold_function(&mut self, f: TypedFunction<'ast, T>) -> TypedFunction<'ast, T> {
+        self.selectors = HashMap::new();
+        self.checked = HashSet::new();
+        self.lookups = HashMap::new();
+
+        let mut batches: HashMap<usize, Vec<_>> = HashMap::new();
+        for batch in Self::batches(&f) {
+            batches.entry(batch.position).or_default().push(batch);
+        }
+
+        let mut statements = vec![];
+        for (position, s) in f.statements.into_iter().enumerate() {
+            for batch in batches.remove(&position).unwrap_or_default() {
+                statements.extend(self.lookup(batch));
+            }
+            statements.extend(self.fold_statement(s));
+        }
+
+        TypedFunction {
+            arguments: f
+                .arguments
+                .into_iter()
+                .map(|a| self.fold_parameter(a))
+                .collect(),
+            statements,
+            ..f
+        }
+    }
+
     fn fold_statement(&mut self, s: TypedStatement<'ast, T>) -> Vec<TypedStatement<'ast, T>> {
-        let s = fold_statement(self, s);
-        self.statements.drain(..).chain(s).collect()
+        match s {
+            TypedStatement::IfElse(condition, consequence, alternative) => {
+                // the checks introduced by the condition go before the branches
+                let condition = self.fold_boolean_expression(condition);
+                let checks: Vec<_> = self.statements.drain(..).collect();
+
+                // the selectors defined in a branch are not available outside of it
+                let selectors = self.selectors.clone();
+                let checked = self.checked.clone();
+
+                let consequence = consequence
+                    .into_iter()
+                    .flat_map(|s| self.fold_statement(s))
+                    .collect();
+
+                self.selectors = selectors.clone();
+                self.checked = checked.clone();
+
+                let alternative = alternative
+                    .into_iter()
+                    .flat_map(|s| self.fold_statement(s))
+                    .collect();
+
+                self.selectors = selectors;
+                self.checked = checked;
+
+                checks
+                    .into_iter()
+                    .chain(std::iter::once(TypedStatement::IfElse(
+                        condition,
+                        consequence,
+                        alternative,
+                    )))
+                    .collect()
+            }
+            s => {
+                let s = fold_statement(self, s);
+                self.statements.drain(..).chain(s).collect()
+            }
+        }
     }
 }
 
@@ -148,14 +549,20 @@ mod tests {
     use super::*;
     use zokrates_field::Bn128Field;
 
+    fn selector<'ast>(index: usize) -> Variable<'ast> {
+        Variable::boolean(CoreIdentifier::Internal("SELECTOR", index))
+    }
+
     #[test]
     fn select() {
         // b = a[i]
 
         // ->
 
-        // i <= 1 == true
-        // b = if i == 0 then a[0] else if i == 1 then a[1] else 0
+        // s_0 = i == 0
+        // s_1 = i == 1
+        // s_0 as field + s_1 as field == 1
+        // b = if s_0 then a[0] else a[1]
 
         let access: TypedStatement<Bn128Field> = TypedStatement::Definition(
             TypedAssignee::Identifier(Variable::field_element("b")),
@@ -169,26 +576,42 @@ mod tests {
         assert_eq!(
             VariableAccessRemover::new().fold_statement(access),
             vec![
+                TypedStatement::Declaration(selector(0)),
+                TypedStatement::Definition(
+                    TypedAssignee::Identifier(selector(0)),
+                    BooleanExpression::FieldEq(
+                        box FieldElementExpression::Identifier("i".into()),
+                        box FieldElementExpression::Number(0.into())
+                    )
+                    .into()
+                ),
+                TypedStatement::Declaration(selector(1)),
+                TypedStatement::Definition(
+                    TypedAssignee::Identifier(selector(1)),
+                    BooleanExpression::FieldEq(
+                        box FieldElementExpression::Identifier("i".into()),
+                        box FieldElementExpression::Number(1.into())
+                    )
+                    .into()
+                ),
                 TypedStatement::Assertion(
-                    BooleanExpression::Or(
-                        box BooleanExpression::FieldEq(
-                            box FieldElementExpression::Identifier("i".into()),
-                            box FieldElementExpression::Number(0.into())
+                    BooleanExpression::FieldEq(
+                        box FieldElementExpression::Add(
+                            box FieldElementExpression::FromBool(
+                                box BooleanExpression::Identifier(selector(0).id)
+                            ),
+                            box FieldElementExpression::FromBool(
+                                box BooleanExpression::Identifier(selector(1).id)
+                            ),
                         ),
-                        box BooleanExpression::FieldEq(
-                            box FieldElementExpression::Identifier("i".into()),
-                            box FieldElementExpression::Number(1.into())
-                        )
-                    )
-                    .into(),
+                        box FieldElementExpression::Number(1.into())
+                    ),
+                    None,
                 ),
                 TypedStatement::Definition(
                     TypedAssignee::Identifier(Variable::field_element("b")),
                     FieldElementExpression::if_else(
-                        BooleanExpression::FieldEq(
-                            box FieldElementExpression::Identifier("i".into()),
-                            box FieldElementExpression::Number(0.into())
-                        ),
+                        BooleanExpression::Identifier(selector(0).id),
                         FieldElementExpression::Select(
                             box ArrayExpressionInner::Identifier("a".into())
                                 .annotate(Type::FieldElement, 2),
@@ -205,4 +628,192 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn shared_selectors() {
+        // b = a[i]
+        // c = if i == 1 then 42 else 0
+
+        // ->
+
+        // [...]
+        // c = if s_1 then 42 else 0
+
+        let mut remover = VariableAccessRemover::new();
+
+        let read: TypedStatement<Bn128Field> = TypedStatement::Definition(
+            TypedAssignee::Identifier(Variable::field_element("b")),
+            FieldElementExpression::Select(
+                box ArrayExpressionInner::Identifier("a".into()).annotate(Type::FieldElement, 2),
+                box FieldElementExpression::Identifier("i".into()),
+            )
+            .into(),
+        );
+
+        assert_eq!(remover.fold_statement(read.clone()).len(), 6);
+
+        // reading again at the same index does not introduce new selectors nor checks
+        assert_eq!(
+            remover.fold_statement(read),
+            vec![TypedStatement::Definition(
+                TypedAssignee::Identifier(Variable::field_element("b")),
+                FieldElementExpression::if_else(
+                    BooleanExpression::Identifier(selector(0).id),
+                    FieldElementExpression::Select(
+                        box ArrayExpressionInner::Identifier("a".into())
+                            .annotate(Type::FieldElement, 2),
+                        box FieldElementExpression::Number(0.into()),
+                    ),
+                    FieldElementExpression::Select(
+                        box ArrayExpressionInner::Identifier("a".into())
+                            .annotate(Type::FieldElement, 2),
+                        box FieldElementExpression::Number(1.into()),
+                    )
+                )
+                .into()
+            )]
+        );
+
+        let comparison: TypedStatement<Bn128Field> = TypedStatement::Definition(
+            TypedAssignee::Identifier(Variable::field_element("c")),
+            FieldElementExpression::if_else(
+                BooleanExpression::FieldEq(
+                    box FieldElementExpression::Number(1.into()),
+                    box FieldElementExpression::Identifier("i".into()),
+                ),
+                FieldElementExpression::Number(42.into()),
+                FieldElementExpression::Number(0.into()),
+            )
+            .into(),
+        );
+
+        assert_eq!(
+            remover.fold_statement(comparison),
+            vec![TypedStatement::Definition(
+                TypedAssignee::Identifier(Variable::field_element("c")),
+                FieldElementExpression::if_else(
+                    BooleanExpression::Identifier(selector(1).id),
+                    FieldElementExpression::Number(42.into()),
+                    FieldElementExpression::Number(0.into()),
+                )
+                .into()
+            )]
+        );
+    }
+
+    // def main(field[size] a, field[count] b) -> field:
+    //     c = a[b[0]] + ... + a[b[count - 1]]
+    //     return c
+    fn reads<'ast>(size: usize, count: usize) -> TypedFunction<'ast, Bn128Field> {
+        let a = || ArrayExpressionInner::Identifier("a".into()).annotate(Type::FieldElement, size);
+        let index = |k: usize| {
+            FieldElementExpression::Select(
+                box ArrayExpressionInner::Identifier("b".into())
+                    .annotate(Type::FieldElement, count),
+                box FieldElementExpression::Number(k.into()),
+            )
+        };
+
+        TypedFunction {
+            arguments: vec![
+                Parameter::private(Variable::array("a", Type::FieldElement, size)),
+                Parameter::private(Variable::array("b", Type::FieldElement, count)),
+            ],
+            statements: vec![
+                TypedStatement::Definition(
+                    TypedAssignee::Identifier(Variable::field_element("c")),
+                    (1..count)
+                        .fold(
+                            FieldElementExpression::Select(box a(), box index(0)),
+                            |acc, k| {
+                                FieldElementExpression::Add(
+                                    box acc,
+                                    box FieldElementExpression::Select(box a(), box index(k)),
+                                )
+                            },
+                        )
+                        .into(),
+                ),
+                TypedStatement::Return(vec![FieldElementExpression::Identifier("c".into()).into()]),
+            ],
+            signature: Signature::new()
+                .inputs(vec![
+                    Type::array(Type::FieldElement, size),
+                    Type::array(Type::FieldElement, count),
+                ])
+                .outputs(vec![Type::FieldElement]),
+        }
+    }
+
+    #[test]
+    fn batched_reads() {
+        // def main(field[16] a, field[16] b) -> field:
+        //     v_0, ..., v_15 = _LOOKUP(a, [b[0], ..., b[15]])
+        //     c = v_0 + ... + v_15
+        //     return c
+
+        let mut remover = VariableAccessRemover::new();
+        let f = remover.fold_function(reads(16, 16));
+
+        let lookup = |k: usize| Variable::field_element(CoreIdentifier::Internal("LOOKUP", k));
+
+        assert_eq!(
+            f.statements[0],
+            TypedStatement::MultipleDefinition(
+                (0..16).map(lookup).collect(),
+                TypedExpressionList::FunctionCall(
+                    FlatEmbed::Lookup(16, 16).key::<Bn128Field>(),
+                    vec![
+                        ArrayExpressionInner::Identifier("a".into())
+                            .annotate(Type::FieldElement, 16)
+                            .into(),
+                        ArrayExpressionInner::Value(
+                            (0..16)
+                                .map(|k| {
+                                    FieldElementExpression::Select(
+                                        box ArrayExpressionInner::Identifier("b".into())
+                                            .annotate(Type::FieldElement, 16),
+                                        box FieldElementExpression::Number(k.into()),
+                                    )
+                                    .into()
+                                })
+                                .collect()
+                        )
+                        .annotate(Type::FieldElement, 16)
+                        .into(),
+                    ],
+                    vec![Type::FieldElement; 16],
+                )
+            )
+        );
+        assert_eq!(
+            f.statements[1],
+            TypedStatement::Definition(
+                TypedAssignee::Identifier(Variable::field_element("c")),
+                (1..16)
+                    .fold(
+                        FieldElementExpression::Identifier(lookup(0).id),
+                        |acc, k| {
+                            FieldElementExpression::Add(
+                                box acc,
+                                box FieldElementExpression::Identifier(lookup(k).id),
+                            )
+                        }
+                    )
+                    .into()
+            )
+        );
+        assert_eq!(f.statements.len(), 3);
+        assert_eq!(remover.embeds, vec![FlatEmbed::Lookup(16, 16)]);
+    }
+
+    #[test]
+    fn few_reads() {
+        // two reads in an array of size 8 are cheaper with one-hot selectors
+        let mut remover = VariableAccessRemover::new();
+        let f = remover.fold_function(reads(8, 2));
+
+        assert_eq!(f.statements[0], TypedStatement::Declaration(selector(0)));
+        assert!(remover.embeds.is_empty());
+    }
 }
@@ -5,6 +5,8 @@ use typed_absy::Type;
 pub struct AbiInput {
     pub name: String,
     pub public: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
     #[serde(flatten)]
     pub ty: Type,
 }
@@ -30,9 +32,9 @@ impl Abi {
 mod tests {
     use super::*;
     use std::collections::HashMap;
-    use typed_absy::types::{ArrayType, FunctionKey, StructMember, StructType};
+    use typed_absy::types::{ArrayType, FunctionKey, StructMember, StructType, TupleType};
     use typed_absy::{
-        Parameter, Type, TypedFunction, TypedFunctionSymbol, TypedModule, TypedProgram, UBitwidth,
+        IntType, Parameter, Type, TypedFunction, TypedFunctionSymbol, TypedModule, TypedProgram,
         Variable,
     };
     use zokrates_field::Bn128Field;
@@ -47,10 +49,12 @@ mod tests {
                     Parameter {
                         id: Variable::field_element("a"),
                         private: true,
+                        alias: None,
                     },
                     Parameter {
                         id: Variable::boolean("b"),
                         private: false,
+                        alias: None,
                     },
                 ],
                 statements: vec![],
@@ -74,11 +78,13 @@ mod tests {
                 AbiInput {
                     name: String::from("a"),
                     public: false,
+                    alias: None,
                     ty: Type::FieldElement,
                 },
                 AbiInput {
                     name: String::from("b"),
                     public: true,
+                    alias: None,
                     ty: Type::Boolean,
                 },
             ],
@@ -108,11 +114,13 @@ mod tests {
                 AbiInput {
                     name: String::from("a"),
                     public: true,
+                    alias: None,
                     ty: Type::FieldElement,
                 },
                 AbiInput {
                     name: String::from("b"),
                     public: true,
+                    alias: None,
                     ty: Type::FieldElement,
                 },
             ],
@@ -154,17 +162,26 @@ mod tests {
                 AbiInput {
                     name: String::from("a"),
                     public: true,
-                    ty: Type::Uint(UBitwidth::B8),
+                    alias: None,
+                    ty: Type::Uint(IntType::U8),
                 },
                 AbiInput {
                     name: String::from("b"),
                     public: true,
-                    ty: Type::Uint(UBitwidth::B16),
+                    alias: None,
+                    ty: Type::Uint(IntType::U16),
                 },
                 AbiInput {
                     name: String::from("c"),
                     public: true,
-                    ty: Type::Uint(UBitwidth::B32),
+                    alias: None,
+                    ty: Type::Uint(IntType::U32),
+                },
+                AbiInput {
+                    name: String::from("d"),
+                    public: true,
+                    alias: None,
+                    ty: Type::Uint(IntType::U64),
                 },
             ],
             outputs: vec![],
@@ -189,6 +206,11 @@ mod tests {
       "name": "c",
       "public": true,
       "type": "u32"
+    },
+    {
+      "name": "d",
+      "public": true,
+      "type": "u64"
     }
   ],
   "outputs": []
@@ -205,6 +227,7 @@ mod tests {
             inputs: vec![AbiInput {
                 name: String::from("foo"),
                 public: true,
+                alias: None,
                 ty: Type::Struct(StructType::new(
                     "".into(),
                     "Foo".into(),
@@ -279,6 +302,7 @@ mod tests {
             inputs: vec![AbiInput {
                 name: String::from("foo"),
                 public: true,
+                alias: None,
                 ty: Type::Struct(StructType::new(
                     "".into(),
                     "Foo".into(),
@@ -345,6 +369,7 @@ mod tests {
             inputs: vec![AbiInput {
                 name: String::from("a"),
                 public: false,
+                alias: None,
                 ty: Type::Array(ArrayType::new(
                     Type::Struct(StructType::new(
                         "".into(),
@@ -400,12 +425,72 @@ mod tests {
         assert_eq!(de_abi, abi);
     }
 
+    #[test]
+    fn serialize_tuple() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: false,
+                alias: None,
+                ty: Type::Tuple(TupleType::new(vec![
+                    Type::FieldElement,
+                    Type::Array(ArrayType::new(Type::Boolean, 2)),
+                ])),
+            }],
+            outputs: vec![Type::Tuple(TupleType::new(vec![Type::FieldElement]))],
+        };
+
+        let json = serde_json::to_string_pretty(&abi).unwrap();
+        assert_eq!(
+            &json,
+            r#"{
+  "inputs": [
+    {
+      "name": "a",
+      "public": false,
+      "type": "tuple",
+      "components": {
+        "elements": [
+          {
+            "type": "field"
+          },
+          {
+            "type": "array",
+            "components": {
+              "size": 2,
+              "type": "bool"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "outputs": [
+    {
+      "type": "tuple",
+      "components": {
+        "elements": [
+          {
+            "type": "field"
+          }
+        ]
+      }
+    }
+  ]
+}"#
+        );
+
+        let de_abi: Abi = serde_json::from_str(json.as_ref()).unwrap();
+        assert_eq!(de_abi, abi);
+    }
+
     #[test]
     fn serialize_multi_dimensional_array() {
         let abi: Abi = Abi {
             inputs: vec![AbiInput {
                 name: String::from("a"),
                 public: false,
+                alias: None,
                 ty: Type::Array(ArrayType::new(
                     Type::Array(ArrayType::new(Type::FieldElement, 2)),
                     2,
@@ -16,8 +16,12 @@ mod variable;
 
 pub use self::identifier::CoreIdentifier;
 pub use self::parameter::Parameter;
-pub use self::types::{Signature, StructType, Type, UBitwidth};
+pub use self::types::{
+    IntType, Signature, Signedness, StructType, TupleType, Type, UBitwidth, VectorType,
+};
 pub use self::variable::Variable;
+pub use flat_absy::AssertionMetadata;
+pub use source_map::{Call, SourceSpan};
 use std::path::PathBuf;
 pub use typed_absy::uint::{bitwidth, UExpression, UExpressionInner, UMetadata};
 
@@ -72,6 +76,7 @@ impl<'ast, T: Field> TypedProgram<'ast, T> {
                 .map(|p| AbiInput {
                     public: !p.private,
                     name: p.id.id.to_string(),
+                    alias: p.alias.clone(),
                     ty: p.id._type.clone(),
                 })
                 .collect(),
@@ -257,6 +262,7 @@ pub enum TypedAssignee<'ast, T> {
         Box<FieldElementExpression<'ast, T>>,
     ),
     Member(Box<TypedAssignee<'ast, T>>, MemberId),
+    Element(Box<TypedAssignee<'ast, T>>, u32),
 }
 
 impl<'ast, T> Typed for TypedAssignee<'ast, T> {
@@ -282,6 +288,13 @@ impl<'ast, T> Typed for TypedAssignee<'ast, T> {
                     _ => unreachable!("a struct access should only be defined over structs"),
                 }
             }
+            TypedAssignee::Element(ref t, index) => {
+                let t_type = t.get_type();
+                match t_type {
+                    Type::Tuple(tuple_type) => tuple_type.elements[index as usize].clone(),
+                    _ => unreachable!("a tuple access should only be defined over tuples"),
+                }
+            }
         }
     }
 }
@@ -292,6 +305,7 @@ impl<'ast, T: fmt::Debug> fmt::Debug for TypedAssignee<'ast, T> {
             TypedAssignee::Identifier(ref s) => write!(f, "{}", s.id),
             TypedAssignee::Select(ref a, ref e) => write!(f, "Select({:?}, {:?})", a, e),
             TypedAssignee::Member(ref s, ref m) => write!(f, "Member({:?}, {:?})", s, m),
+            TypedAssignee::Element(ref t, ref i) => write!(f, "Element({:?}, {:?})", t, i),
         }
     }
 }
@@ -302,6 +316,7 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedAssignee<'ast, T> {
             TypedAssignee::Identifier(ref s) => write!(f, "{}", s.id),
             TypedAssignee::Select(ref a, ref e) => write!(f, "{}[{}]", a, e),
             TypedAssignee::Member(ref s, ref m) => write!(f, "{}.{}", s, m),
+            TypedAssignee::Element(ref t, ref i) => write!(f, "{}.{}", t, i),
         }
     }
 }
@@ -312,14 +327,22 @@ pub enum TypedStatement<'ast, T> {
     Return(Vec<TypedExpression<'ast, T>>),
     Definition(TypedAssignee<'ast, T>, TypedExpression<'ast, T>),
     Declaration(Variable<'ast>),
-    Assertion(BooleanExpression<'ast, T>),
+    Assertion(BooleanExpression<'ast, T>, Option<AssertionMetadata>),
     For(
         Variable<'ast>,
         FieldElementExpression<'ast, T>,
         FieldElementExpression<'ast, T>,
         Vec<TypedStatement<'ast, T>>,
     ),
+    IfElse(
+        BooleanExpression<'ast, T>,
+        Vec<TypedStatement<'ast, T>>,
+        Vec<TypedStatement<'ast, T>>,
+    ),
     MultipleDefinition(Vec<Variable<'ast>>, TypedExpressionList<'ast, T>),
+    /// Marks the following statements as compiled from the source statement at this location
+    Span(SourceSpan),
+    Log(String, Vec<TypedExpression<'ast, T>>),
 }
 
 impl<'ast, T: fmt::Debug> fmt::Debug for TypedStatement<'ast, T> {
@@ -339,7 +362,9 @@ impl<'ast, T: fmt::Debug> fmt::Debug for TypedStatement<'ast, T> {
             TypedStatement::Definition(ref lhs, ref rhs) => {
                 write!(f, "Definition({:?}, {:?})", lhs, rhs)
             }
-            TypedStatement::Assertion(ref e) => write!(f, "Assertion({:?})", e),
+            TypedStatement::Assertion(ref e, ref metadata) => {
+                write!(f, "Assertion({:?}, {:?})", e, metadata)
+            }
             TypedStatement::For(ref var, ref start, ref stop, ref list) => {
                 write!(f, "for {:?} in {:?}..{:?} do\n", var, start, stop)?;
                 for l in list {
@@ -347,9 +372,18 @@ impl<'ast, T: fmt::Debug> fmt::Debug for TypedStatement<'ast, T> {
                 }
                 write!(f, "\tendfor")
             }
+            TypedStatement::IfElse(ref condition, ref consequence, ref alternative) => write!(
+                f,
+                "IfElse({:?}, {:?}, {:?})",
+                condition, consequence, alternative
+            ),
             TypedStatement::MultipleDefinition(ref lhs, ref rhs) => {
                 write!(f, "MultipleDefinition({:?}, {:?})", lhs, rhs)
             }
+            TypedStatement::Span(ref span) => write!(f, "Span({:?})", span),
+            TypedStatement::Log(ref format_string, ref expressions) => {
+                write!(f, "Log({:?}, {:?})", format_string, expressions)
+            }
         }
     }
 }
@@ -366,6 +400,20 @@ impl<'ast, T: fmt::Display> TypedStatement<'ast, T> {
                 }
                 writeln!(f, "{}endfor", "\t".repeat(depth))
             }
+            TypedStatement::IfElse(condition, consequence, alternative) => {
+                write!(f, "{}", "\t".repeat(depth))?;
+                writeln!(f, "if {}:", condition)?;
+                for s in consequence {
+                    s.fmt_indented(f, depth + 1)?;
+                    writeln!(f, "")?;
+                }
+                writeln!(f, "{}else:", "\t".repeat(depth))?;
+                for s in alternative {
+                    s.fmt_indented(f, depth + 1)?;
+                    writeln!(f, "")?;
+                }
+                writeln!(f, "{}endif", "\t".repeat(depth))
+            }
             s => write!(f, "{}{}", "\t".repeat(depth), s),
         }
     }
@@ -386,7 +434,13 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedStatement<'ast, T> {
             }
             TypedStatement::Declaration(ref var) => write!(f, "{}", var),
             TypedStatement::Definition(ref lhs, ref rhs) => write!(f, "{} = {}", lhs, rhs),
-            TypedStatement::Assertion(ref e) => write!(f, "assert({})", e),
+            TypedStatement::Assertion(ref e, ref metadata) => match metadata {
+                Some(AssertionMetadata {
+                    message: Some(message),
+                    ..
+                }) => write!(f, "assert({}, \"{}\")", e, message),
+                _ => write!(f, "assert({})", e),
+            },
             TypedStatement::For(ref var, ref start, ref stop, ref list) => {
                 write!(f, "for {} in {}..{} do\n", var, start, stop)?;
                 for l in list {
@@ -394,6 +448,17 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedStatement<'ast, T> {
                 }
                 write!(f, "\tendfor")
             }
+            TypedStatement::IfElse(ref condition, ref consequence, ref alternative) => {
+                write!(f, "if {}:\n", condition)?;
+                for l in consequence {
+                    write!(f, "\t\t{}\n", l)?;
+                }
+                write!(f, "\telse:\n")?;
+                for l in alternative {
+                    write!(f, "\t\t{}\n", l)?;
+                }
+                write!(f, "\tendif")
+            }
             TypedStatement::MultipleDefinition(ref ids, ref rhs) => {
                 for (i, id) in ids.iter().enumerate() {
                     write!(f, "{}", id)?;
@@ -403,6 +468,14 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedStatement<'ast, T> {
                 }
                 write!(f, " = {}", rhs)
             }
+            TypedStatement::Span(ref span) => write!(f, "// {}", span),
+            TypedStatement::Log(ref format_string, ref expressions) => {
+                write!(f, "log(\"{}\"", format_string)?;
+                for e in expressions {
+                    write!(f, ", {}", e)?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -419,6 +492,8 @@ pub enum TypedExpression<'ast, T> {
     Uint(UExpression<'ast, T>),
     Array(ArrayExpression<'ast, T>),
     Struct(StructExpression<'ast, T>),
+    Tuple(TupleExpression<'ast, T>),
+    Vector(VectorExpression<'ast, T>),
 }
 
 impl<'ast, T> From<BooleanExpression<'ast, T>> for TypedExpression<'ast, T> {
@@ -451,6 +526,18 @@ impl<'ast, T> From<StructExpression<'ast, T>> for TypedExpression<'ast, T> {
     }
 }
 
+impl<'ast, T> From<TupleExpression<'ast, T>> for TypedExpression<'ast, T> {
+    fn from(e: TupleExpression<'ast, T>) -> TypedExpression<T> {
+        TypedExpression::Tuple(e)
+    }
+}
+
+impl<'ast, T> From<VectorExpression<'ast, T>> for TypedExpression<'ast, T> {
+    fn from(e: VectorExpression<'ast, T>) -> TypedExpression<T> {
+        TypedExpression::Vector(e)
+    }
+}
+
 impl<'ast, T: fmt::Display> fmt::Display for TypedExpression<'ast, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -459,6 +546,8 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedExpression<'ast, T> {
             TypedExpression::Uint(ref e) => write!(f, "{}", e),
             TypedExpression::Array(ref e) => write!(f, "{}", e),
             TypedExpression::Struct(ref s) => write!(f, "{}", s),
+            TypedExpression::Tuple(ref t) => write!(f, "{}", t),
+            TypedExpression::Vector(ref v) => write!(f, "{}", v),
         }
     }
 }
@@ -471,6 +560,8 @@ impl<'ast, T: fmt::Debug> fmt::Debug for TypedExpression<'ast, T> {
             TypedExpression::Uint(ref e) => write!(f, "{:?}", e),
             TypedExpression::Array(ref e) => write!(f, "{:?}", e),
             TypedExpression::Struct(ref s) => write!(f, "{:?}", s),
+            TypedExpression::Tuple(ref t) => write!(f, "{:?}", t),
+            TypedExpression::Vector(ref v) => write!(f, "{:?}", v),
         }
     }
 }
@@ -521,6 +612,7 @@ impl<'ast, T: fmt::Display> fmt::Display for StructExpression<'ast, T> {
             }
             StructExpressionInner::Member(ref struc, ref id) => write!(f, "{}.{}", struc, id),
             StructExpressionInner::Select(ref id, ref index) => write!(f, "{}[{}]", id, index),
+            StructExpressionInner::Element(ref t, ref index) => write!(f, "{}.{}", t, index),
         }
     }
 }
@@ -531,6 +623,84 @@ impl<'ast, T: fmt::Debug> fmt::Debug for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: fmt::Display> fmt::Display for TupleExpression<'ast, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.inner {
+            TupleExpressionInner::Identifier(ref var) => write!(f, "{}", var),
+            TupleExpressionInner::Value(ref values) => {
+                write!(f, "(")?;
+                for (i, value) in values.iter().enumerate() {
+                    write!(f, "{}", value)?;
+                    if i < values.len() - 1 || values.len() == 1 {
+                        write!(f, ",")?;
+                    }
+                    if i < values.len() - 1 {
+                        write!(f, " ")?;
+                    }
+                }
+                write!(f, ")")
+            }
+            TupleExpressionInner::FunctionCall(ref key, ref p) => {
+                write!(f, "{}(", key.id,)?;
+                for (i, param) in p.iter().enumerate() {
+                    write!(f, "{}", param)?;
+                    if i < p.len() - 1 {
+                        write!(f, ", ")?;
+                    }
+                }
+                write!(f, ")")
+            }
+            TupleExpressionInner::IfElse(ref condition, ref consequent, ref alternative) => {
+                write!(
+                    f,
+                    "if {} then {} else {} fi",
+                    condition, consequent, alternative
+                )
+            }
+            TupleExpressionInner::Member(ref struc, ref id) => write!(f, "{}.{}", struc, id),
+            TupleExpressionInner::Select(ref id, ref index) => write!(f, "{}[{}]", id, index),
+            TupleExpressionInner::Element(ref t, ref index) => write!(f, "{}.{}", t, index),
+        }
+    }
+}
+
+impl<'ast, T: fmt::Debug> fmt::Debug for TupleExpression<'ast, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
+impl<'ast, T: fmt::Display> fmt::Display for VectorExpression<'ast, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.inner {
+            VectorExpressionInner::Identifier(ref var) => write!(f, "{}", var),
+            VectorExpressionInner::FunctionCall(ref key, ref p) => {
+                write!(f, "{}(", key.id,)?;
+                for (i, param) in p.iter().enumerate() {
+                    write!(f, "{}", param)?;
+                    if i < p.len() - 1 {
+                        write!(f, ", ")?;
+                    }
+                }
+                write!(f, ")")
+            }
+            VectorExpressionInner::IfElse(ref condition, ref consequent, ref alternative) => {
+                write!(
+                    f,
+                    "if {} then {} else {} fi",
+                    condition, consequent, alternative
+                )
+            }
+        }
+    }
+}
+
+impl<'ast, T: fmt::Debug> fmt::Debug for VectorExpression<'ast, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.inner)
+    }
+}
+
 impl<'ast, T> Typed for TypedExpression<'ast, T> {
     fn get_type(&self) -> Type {
         match *self {
@@ -539,6 +709,8 @@ impl<'ast, T> Typed for TypedExpression<'ast, T> {
             TypedExpression::Array(ref e) => e.get_type(),
             TypedExpression::Uint(ref e) => e.get_type(),
             TypedExpression::Struct(ref s) => s.get_type(),
+            TypedExpression::Tuple(ref t) => t.get_type(),
+            TypedExpression::Vector(ref v) => v.get_type(),
         }
     }
 }
@@ -555,6 +727,18 @@ impl<'ast, T> Typed for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T> Typed for TupleExpression<'ast, T> {
+    fn get_type(&self) -> Type {
+        Type::Tuple(self.ty.clone())
+    }
+}
+
+impl<'ast, T> Typed for VectorExpression<'ast, T> {
+    fn get_type(&self) -> Type {
+        Type::Vector(self.ty.clone())
+    }
+}
+
 impl<'ast, T> Typed for FieldElementExpression<'ast, T> {
     fn get_type(&self) -> Type {
         Type::FieldElement
@@ -626,6 +810,9 @@ pub enum FieldElementExpression<'ast, T> {
         Box<ArrayExpression<'ast, T>>,
         Box<FieldElementExpression<'ast, T>>,
     ),
+    Element(Box<TupleExpression<'ast, T>>, u32),
+    FromBool(Box<BooleanExpression<'ast, T>>),
+    FromUint(Box<UExpression<'ast, T>>),
 }
 
 /// An expression of type `bool`
@@ -655,6 +842,10 @@ pub enum BooleanExpression<'ast, T> {
         Box<StructExpression<'ast, T>>,
     ),
     UintEq(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    UintLt(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    UintLe(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    UintGe(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    UintGt(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     Ge(
         Box<FieldElementExpression<'ast, T>>,
         Box<FieldElementExpression<'ast, T>>,
@@ -683,6 +874,8 @@ pub enum BooleanExpression<'ast, T> {
         Box<ArrayExpression<'ast, T>>,
         Box<FieldElementExpression<'ast, T>>,
     ),
+    Element(Box<TupleExpression<'ast, T>>, u32),
+    FromField(Box<FieldElementExpression<'ast, T>>),
 }
 
 /// An expression of type `array`
@@ -712,6 +905,10 @@ pub enum ArrayExpressionInner<'ast, T> {
         Box<ArrayExpression<'ast, T>>,
         Box<FieldElementExpression<'ast, T>>,
     ),
+    Element(Box<TupleExpression<'ast, T>>, u32),
+    FromUint(Box<UExpression<'ast, T>>),
+    /// The elements of a vector, including the inactive ones beyond its length
+    VectorElements(Box<VectorExpression<'ast, T>>),
 }
 
 impl<'ast, T> ArrayExpressionInner<'ast, T> {
@@ -777,6 +974,7 @@ pub enum StructExpressionInner<'ast, T> {
         Box<ArrayExpression<'ast, T>>,
         Box<FieldElementExpression<'ast, T>>,
     ),
+    Element(Box<TupleExpression<'ast, T>>, u32),
 }
 
 impl<'ast, T> StructExpressionInner<'ast, T> {
@@ -785,6 +983,88 @@ impl<'ast, T> StructExpressionInner<'ast, T> {
     }
 }
 
+#[derive(Clone, PartialEq, Hash, Eq)]
+pub struct TupleExpression<'ast, T> {
+    ty: TupleType,
+    inner: TupleExpressionInner<'ast, T>,
+}
+
+impl<'ast, T> TupleExpression<'ast, T> {
+    pub fn ty(&self) -> &TupleType {
+        &self.ty
+    }
+
+    pub fn as_inner(&self) -> &TupleExpressionInner<'ast, T> {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> TupleExpressionInner<'ast, T> {
+        self.inner
+    }
+}
+
+#[derive(Clone, PartialEq, Hash, Eq)]
+pub enum TupleExpressionInner<'ast, T> {
+    Identifier(Identifier<'ast>),
+    Value(Vec<TypedExpression<'ast, T>>),
+    FunctionCall(FunctionKey<'ast>, Vec<TypedExpression<'ast, T>>),
+    IfElse(
+        Box<BooleanExpression<'ast, T>>,
+        Box<TupleExpression<'ast, T>>,
+        Box<TupleExpression<'ast, T>>,
+    ),
+    Member(Box<StructExpression<'ast, T>>, MemberId),
+    Select(
+        Box<ArrayExpression<'ast, T>>,
+        Box<FieldElementExpression<'ast, T>>,
+    ),
+    Element(Box<TupleExpression<'ast, T>>, u32),
+}
+
+impl<'ast, T> TupleExpressionInner<'ast, T> {
+    pub fn annotate(self, ty: TupleType) -> TupleExpression<'ast, T> {
+        TupleExpression { ty, inner: self }
+    }
+}
+
+/// An expression of type `vector`, holding up to `capacity` elements along with its length
+#[derive(Clone, PartialEq, Hash, Eq)]
+pub struct VectorExpression<'ast, T> {
+    ty: VectorType,
+    inner: VectorExpressionInner<'ast, T>,
+}
+
+impl<'ast, T> VectorExpression<'ast, T> {
+    pub fn ty(&self) -> &VectorType {
+        &self.ty
+    }
+
+    pub fn as_inner(&self) -> &VectorExpressionInner<'ast, T> {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> VectorExpressionInner<'ast, T> {
+        self.inner
+    }
+}
+
+#[derive(Clone, PartialEq, Hash, Eq)]
+pub enum VectorExpressionInner<'ast, T> {
+    Identifier(Identifier<'ast>),
+    FunctionCall(FunctionKey<'ast>, Vec<TypedExpression<'ast, T>>),
+    IfElse(
+        Box<BooleanExpression<'ast, T>>,
+        Box<VectorExpression<'ast, T>>,
+        Box<VectorExpression<'ast, T>>,
+    ),
+}
+
+impl<'ast, T> VectorExpressionInner<'ast, T> {
+    pub fn annotate(self, ty: VectorType) -> VectorExpression<'ast, T> {
+        VectorExpression { ty, inner: self }
+    }
+}
+
 // Downcasts
 // Due to the fact that we keep TypedExpression simple, we end up with ArrayExpressionInner::Value whose elements are any TypedExpression, but we enforce by
 // construction that these elements are of the type declared in the corresponding ArrayExpression. As we know this by construction, we can downcast the TypedExpression to the correct type
@@ -846,6 +1126,28 @@ impl<'ast, T> TryFrom<TypedExpression<'ast, T>> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T> TryFrom<TypedExpression<'ast, T>> for TupleExpression<'ast, T> {
+    type Error = ();
+
+    fn try_from(te: TypedExpression<'ast, T>) -> Result<TupleExpression<'ast, T>, Self::Error> {
+        match te {
+            TypedExpression::Tuple(e) => Ok(e),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'ast, T> TryFrom<TypedExpression<'ast, T>> for VectorExpression<'ast, T> {
+    type Error = ();
+
+    fn try_from(te: TypedExpression<'ast, T>) -> Result<VectorExpression<'ast, T>, Self::Error> {
+        match te {
+            TypedExpression::Vector(e) => Ok(e),
+            _ => Err(()),
+        }
+    }
+}
+
 impl<'ast, T: fmt::Display> fmt::Display for FieldElementExpression<'ast, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -875,6 +1177,9 @@ impl<'ast, T: fmt::Display> fmt::Display for FieldElementExpression<'ast, T> {
             }
             FieldElementExpression::Member(ref struc, ref id) => write!(f, "{}.{}", struc, id),
             FieldElementExpression::Select(ref id, ref index) => write!(f, "{}[{}]", id, index),
+            FieldElementExpression::Element(ref t, ref index) => write!(f, "{}.{}", t, index),
+            FieldElementExpression::FromBool(ref e) => write!(f, "({} as field)", e),
+            FieldElementExpression::FromUint(ref e) => write!(f, "({} as field)", e),
         }
     }
 }
@@ -890,6 +1195,8 @@ impl<'ast, T: fmt::Display> fmt::Display for UExpression<'ast, T> {
             UExpressionInner::Xor(ref lhs, ref rhs) => write!(f, "({} ^ {})", lhs, rhs),
             UExpressionInner::Sub(ref lhs, ref rhs) => write!(f, "({} - {})", lhs, rhs),
             UExpressionInner::Mult(ref lhs, ref rhs) => write!(f, "({} * {})", lhs, rhs),
+            UExpressionInner::Div(ref lhs, ref rhs) => write!(f, "({} / {})", lhs, rhs),
+            UExpressionInner::Rem(ref lhs, ref rhs) => write!(f, "({} % {})", lhs, rhs),
             UExpressionInner::RightShift(ref e, ref by) => write!(f, "({} >> {})", e, by),
             UExpressionInner::LeftShift(ref e, ref by) => write!(f, "({} << {})", e, by),
             UExpressionInner::Not(ref e) => write!(f, "!{}", e),
@@ -910,6 +1217,17 @@ impl<'ast, T: fmt::Display> fmt::Display for UExpression<'ast, T> {
                 condition, consequent, alternative
             ),
             UExpressionInner::Member(ref struc, ref id) => write!(f, "{}.{}", struc, id),
+            UExpressionInner::Element(ref t, ref index) => write!(f, "{}.{}", t, index),
+            UExpressionInner::FromField(ref e) => {
+                write!(f, "({} as {})", e, Type::Uint(self.bitwidth))
+            }
+            UExpressionInner::FromUint(ref e) => {
+                write!(f, "({} as {})", e, Type::Uint(self.bitwidth))
+            }
+            UExpressionInner::FromBits(ref e) => {
+                write!(f, "({} as {})", e, Type::Uint(self.bitwidth))
+            }
+            UExpressionInner::VectorLength(ref v) => write!(f, "{}.len()", v),
         }
     }
 }
@@ -925,6 +1243,10 @@ impl<'ast, T: fmt::Display> fmt::Display for BooleanExpression<'ast, T> {
             BooleanExpression::ArrayEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
             BooleanExpression::StructEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
             BooleanExpression::UintEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
+            BooleanExpression::UintLt(ref lhs, ref rhs) => write!(f, "{} < {}", lhs, rhs),
+            BooleanExpression::UintLe(ref lhs, ref rhs) => write!(f, "{} <= {}", lhs, rhs),
+            BooleanExpression::UintGe(ref lhs, ref rhs) => write!(f, "{} >= {}", lhs, rhs),
+            BooleanExpression::UintGt(ref lhs, ref rhs) => write!(f, "{} > {}", lhs, rhs),
             BooleanExpression::Ge(ref lhs, ref rhs) => write!(f, "{} >= {}", lhs, rhs),
             BooleanExpression::Gt(ref lhs, ref rhs) => write!(f, "{} > {}", lhs, rhs),
             BooleanExpression::Or(ref lhs, ref rhs) => write!(f, "{} || {}", lhs, rhs),
@@ -948,6 +1270,8 @@ impl<'ast, T: fmt::Display> fmt::Display for BooleanExpression<'ast, T> {
             ),
             BooleanExpression::Member(ref struc, ref id) => write!(f, "{}.{}", struc, id),
             BooleanExpression::Select(ref id, ref index) => write!(f, "{}[{}]", id, index),
+            BooleanExpression::Element(ref t, ref index) => write!(f, "{}.{}", t, index),
+            BooleanExpression::FromField(ref e) => write!(f, "({} as bool)", e),
         }
     }
 }
@@ -982,6 +1306,11 @@ impl<'ast, T: fmt::Display> fmt::Display for ArrayExpressionInner<'ast, T> {
             ),
             ArrayExpressionInner::Member(ref s, ref id) => write!(f, "{}.{}", s, id),
             ArrayExpressionInner::Select(ref id, ref index) => write!(f, "{}[{}]", id, index),
+            ArrayExpressionInner::Element(ref t, ref index) => write!(f, "{}.{}", t, index),
+            ArrayExpressionInner::FromUint(ref e) => {
+                write!(f, "({} as bool[{}])", e, e.bitwidth.to_usize())
+            }
+            ArrayExpressionInner::VectorElements(ref v) => write!(f, "{}", v),
         }
     }
 }
@@ -1013,6 +1342,18 @@ impl<'ast, T: fmt::Debug> fmt::Debug for BooleanExpression<'ast, T> {
             BooleanExpression::UintEq(ref lhs, ref rhs) => {
                 write!(f, "UintEq({:?}, {:?})", lhs, rhs)
             }
+            BooleanExpression::UintLt(ref lhs, ref rhs) => {
+                write!(f, "UintLt({:?}, {:?})", lhs, rhs)
+            }
+            BooleanExpression::UintLe(ref lhs, ref rhs) => {
+                write!(f, "UintLe({:?}, {:?})", lhs, rhs)
+            }
+            BooleanExpression::UintGe(ref lhs, ref rhs) => {
+                write!(f, "UintGe({:?}, {:?})", lhs, rhs)
+            }
+            BooleanExpression::UintGt(ref lhs, ref rhs) => {
+                write!(f, "UintGt({:?}, {:?})", lhs, rhs)
+            }
             BooleanExpression::Ge(ref lhs, ref rhs) => write!(f, "Ge({:?}, {:?})", lhs, rhs),
             BooleanExpression::Gt(ref lhs, ref rhs) => write!(f, "Gt({:?}, {:?})", lhs, rhs),
             BooleanExpression::And(ref lhs, ref rhs) => write!(f, "And({:?}, {:?})", lhs, rhs),
@@ -1028,7 +1369,11 @@ impl<'ast, T: fmt::Debug> fmt::Debug for BooleanExpression<'ast, T> {
             BooleanExpression::Member(ref struc, ref id) => {
                 write!(f, "Access({:?}, {:?})", struc, id)
             }
+            BooleanExpression::Element(ref t, ref index) => {
+                write!(f, "Element({:?}, {:?})", t, index)
+            }
             BooleanExpression::Or(ref lhs, ref rhs) => write!(f, "Or({:?}, {:?})", lhs, rhs),
+            BooleanExpression::FromField(ref e) => write!(f, "FromField({:?})", e),
         }
     }
 }
@@ -1063,6 +1408,11 @@ impl<'ast, T: fmt::Debug> fmt::Debug for FieldElementExpression<'ast, T> {
             FieldElementExpression::Select(ref id, ref index) => {
                 write!(f, "Select({:?}, {:?})", id, index)
             }
+            FieldElementExpression::Element(ref t, ref index) => {
+                write!(f, "Element({:?}, {:?})", t, index)
+            }
+            FieldElementExpression::FromBool(ref e) => write!(f, "FromBool({:?})", e),
+            FieldElementExpression::FromUint(ref e) => write!(f, "FromUint({:?})", e),
         }
     }
 }
@@ -1088,6 +1438,11 @@ impl<'ast, T: fmt::Debug> fmt::Debug for ArrayExpressionInner<'ast, T> {
             ArrayExpressionInner::Select(ref id, ref index) => {
                 write!(f, "Select({:?}, {:?})", id, index)
             }
+            ArrayExpressionInner::Element(ref t, ref index) => {
+                write!(f, "Element({:?}, {:?})", t, index)
+            }
+            ArrayExpressionInner::FromUint(ref e) => write!(f, "FromUint({:?})", e),
+            ArrayExpressionInner::VectorElements(ref v) => write!(f, "VectorElements({:?})", v),
         }
     }
 }
@@ -1115,6 +1470,59 @@ impl<'ast, T: fmt::Debug> fmt::Debug for StructExpressionInner<'ast, T> {
             StructExpressionInner::Select(ref id, ref index) => {
                 write!(f, "Select({:?}, {:?})", id, index)
             }
+            StructExpressionInner::Element(ref t, ref index) => {
+                write!(f, "Element({:?}, {:?})", t, index)
+            }
+        }
+    }
+}
+
+impl<'ast, T: fmt::Debug> fmt::Debug for TupleExpressionInner<'ast, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TupleExpressionInner::Identifier(ref var) => write!(f, "{:?}", var),
+            TupleExpressionInner::Value(ref values) => write!(f, "{:?}", values),
+            TupleExpressionInner::FunctionCall(ref i, ref p) => {
+                write!(f, "FunctionCall({:?}, (", i)?;
+                f.debug_list().entries(p.iter()).finish()?;
+                write!(f, ")")
+            }
+            TupleExpressionInner::IfElse(ref condition, ref consequent, ref alternative) => {
+                write!(
+                    f,
+                    "IfElse({:?}, {:?}, {:?})",
+                    condition, consequent, alternative
+                )
+            }
+            TupleExpressionInner::Member(ref struc, ref id) => {
+                write!(f, "Member({:?}, {:?})", struc, id)
+            }
+            TupleExpressionInner::Select(ref id, ref index) => {
+                write!(f, "Select({:?}, {:?})", id, index)
+            }
+            TupleExpressionInner::Element(ref t, ref index) => {
+                write!(f, "Element({:?}, {:?})", t, index)
+            }
+        }
+    }
+}
+
+impl<'ast, T: fmt::Debug> fmt::Debug for VectorExpressionInner<'ast, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VectorExpressionInner::Identifier(ref var) => write!(f, "{:?}", var),
+            VectorExpressionInner::FunctionCall(ref i, ref p) => {
+                write!(f, "FunctionCall({:?}, (", i)?;
+                f.debug_list().entries(p.iter()).finish()?;
+                write!(f, ")")
+            }
+            VectorExpressionInner::IfElse(ref condition, ref consequent, ref alternative) => {
+                write!(
+                    f,
+                    "IfElse({:?}, {:?}, {:?})",
+                    condition, consequent, alternative
+                )
+            }
         }
     }
 }
@@ -1211,6 +1619,28 @@ impl<'ast, T> IfElse<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T> IfElse<'ast, T> for TupleExpression<'ast, T> {
+    fn if_else(
+        condition: BooleanExpression<'ast, T>,
+        consequence: Self,
+        alternative: Self,
+    ) -> Self {
+        let ty = consequence.ty().clone();
+        TupleExpressionInner::IfElse(box condition, box consequence, box alternative).annotate(ty)
+    }
+}
+
+impl<'ast, T> IfElse<'ast, T> for VectorExpression<'ast, T> {
+    fn if_else(
+        condition: BooleanExpression<'ast, T>,
+        consequence: Self,
+        alternative: Self,
+    ) -> Self {
+        let ty = consequence.ty().clone();
+        VectorExpressionInner::IfElse(box condition, box consequence, box alternative).annotate(ty)
+    }
+}
+
 pub trait Select<'ast, T> {
     fn select(array: ArrayExpression<'ast, T>, index: FieldElementExpression<'ast, T>) -> Self;
 }
@@ -1260,6 +1690,17 @@ impl<'ast, T> Select<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T> Select<'ast, T> for TupleExpression<'ast, T> {
+    fn select(array: ArrayExpression<'ast, T>, index: FieldElementExpression<'ast, T>) -> Self {
+        let elements = match array.inner_type().clone() {
+            Type::Tuple(elements) => elements,
+            _ => unreachable!(),
+        };
+
+        TupleExpressionInner::Select(box array, box index).annotate(elements)
+    }
+}
+
 pub trait Member<'ast, T> {
     fn member(s: StructExpression<'ast, T>, member_id: MemberId) -> Self;
 }
@@ -1332,3 +1773,82 @@ impl<'ast, T> Member<'ast, T> for StructExpression<'ast, T> {
         StructExpressionInner::Member(box s, member_id).annotate(members)
     }
 }
+
+impl<'ast, T> Member<'ast, T> for TupleExpression<'ast, T> {
+    fn member(s: StructExpression<'ast, T>, member_id: MemberId) -> Self {
+        let members = s.ty().clone();
+
+        let ty = members
+            .into_iter()
+            .find(|member| *member.id == member_id)
+            .unwrap()
+            .ty;
+
+        let elements = match *ty {
+            Type::Tuple(elements) => elements,
+            _ => unreachable!(),
+        };
+
+        TupleExpressionInner::Member(box s, member_id).annotate(elements)
+    }
+}
+
+pub trait Element<'ast, T> {
+    fn element(t: TupleExpression<'ast, T>, index: u32) -> Self;
+}
+
+impl<'ast, T> Element<'ast, T> for FieldElementExpression<'ast, T> {
+    fn element(t: TupleExpression<'ast, T>, index: u32) -> Self {
+        FieldElementExpression::Element(box t, index)
+    }
+}
+
+impl<'ast, T> Element<'ast, T> for BooleanExpression<'ast, T> {
+    fn element(t: TupleExpression<'ast, T>, index: u32) -> Self {
+        BooleanExpression::Element(box t, index)
+    }
+}
+
+impl<'ast, T> Element<'ast, T> for UExpression<'ast, T> {
+    fn element(t: TupleExpression<'ast, T>, index: u32) -> Self {
+        let bitwidth = match t.ty().elements[index as usize] {
+            Type::Uint(bitwidth) => bitwidth,
+            _ => unreachable!(),
+        };
+
+        UExpressionInner::Element(box t, index).annotate(bitwidth)
+    }
+}
+
+impl<'ast, T> Element<'ast, T> for ArrayExpression<'ast, T> {
+    fn element(t: TupleExpression<'ast, T>, index: u32) -> Self {
+        let (ty, size) = match t.ty().elements[index as usize].clone() {
+            Type::Array(array_type) => (array_type.ty, array_type.size),
+            _ => unreachable!(),
+        };
+
+        ArrayExpressionInner::Element(box t, index).annotate(*ty, size)
+    }
+}
+
+impl<'ast, T> Element<'ast, T> for StructExpression<'ast, T> {
+    fn element(t: TupleExpression<'ast, T>, index: u32) -> Self {
+        let members = match t.ty().elements[index as usize].clone() {
+            Type::Struct(members) => members,
+            _ => unreachable!(),
+        };
+
+        StructExpressionInner::Element(box t, index).annotate(members)
+    }
+}
+
+impl<'ast, T> Element<'ast, T> for TupleExpression<'ast, T> {
+    fn element(t: TupleExpression<'ast, T>, index: u32) -> Self {
+        let elements = match t.ty().elements[index as usize].clone() {
+            Type::Tuple(elements) => elements,
+            _ => unreachable!(),
+        };
+
+        TupleExpressionInner::Element(box t, index).annotate(elements)
+    }
+}
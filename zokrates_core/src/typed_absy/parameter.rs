@@ -5,6 +5,8 @@ use std::fmt;
 pub struct Parameter<'ast> {
     pub id: Variable<'ast>,
     pub private: bool,
+    /// The name of the type alias this parameter was declared with, if any
+    pub alias: Option<String>,
 }
 
 impl<'ast> Parameter<'ast> {
@@ -13,6 +15,7 @@ impl<'ast> Parameter<'ast> {
         Parameter {
             id: v,
             private: true,
+            alias: None,
         }
     }
 }
@@ -20,6 +20,14 @@ pub struct ArrayType {
     pub ty: Box<Type>,
 }
 
+/// A vector holding at most `capacity` elements, along with its runtime length
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct VectorType {
+    pub capacity: usize,
+    #[serde(flatten)]
+    pub ty: Box<Type>,
+}
+
 #[derive(Debug, Clone, Hash, Serialize, Deserialize, PartialOrd, Ord, Eq, PartialEq)]
 pub struct StructLocation {
     #[serde(skip)]
@@ -74,6 +82,41 @@ impl StructType {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct TupleType {
+    pub elements: Vec<Type>,
+}
+
+impl TupleType {
+    pub fn new(elements: Vec<Type>) -> Self {
+        TupleType { elements }
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<Type> {
+        self.elements.iter()
+    }
+}
+
+impl fmt::Display for TupleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, t) in self.elements.iter().enumerate() {
+            write!(f, "{}", t)?;
+            if i < self.elements.len() - 1 || self.elements.len() == 1 {
+                write!(f, ",")?;
+            }
+            if i < self.elements.len() - 1 {
+                write!(f, " ")?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
 impl IntoIterator for StructType {
     type Item = StructMember;
     type IntoIter = std::vec::IntoIter<Self::Item>;
@@ -91,6 +134,8 @@ pub enum UBitwidth {
     B16 = 16,
     #[serde(rename = "32")]
     B32 = 32,
+    #[serde(rename = "64")]
+    B64 = 64,
 }
 
 impl UBitwidth {
@@ -105,6 +150,7 @@ impl From<usize> for UBitwidth {
             8 => UBitwidth::B8,
             16 => UBitwidth::B16,
             32 => UBitwidth::B32,
+            64 => UBitwidth::B64,
             _ => unreachable!(),
         }
     }
@@ -116,13 +162,104 @@ impl fmt::Display for UBitwidth {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
+pub enum Signedness {
+    Unsigned,
+    /// Values are encoded in two's complement
+    Signed,
+}
+
+/// The type of an integer, given by its bitwidth and its signedness
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
+pub struct IntType {
+    pub bitwidth: UBitwidth,
+    pub signedness: Signedness,
+}
+
+impl IntType {
+    pub const U8: IntType = IntType::unsigned(UBitwidth::B8);
+    pub const U16: IntType = IntType::unsigned(UBitwidth::B16);
+    pub const U32: IntType = IntType::unsigned(UBitwidth::B32);
+    pub const U64: IntType = IntType::unsigned(UBitwidth::B64);
+    pub const I8: IntType = IntType::signed(UBitwidth::B8);
+    pub const I16: IntType = IntType::signed(UBitwidth::B16);
+    pub const I32: IntType = IntType::signed(UBitwidth::B32);
+
+    pub const fn unsigned(bitwidth: UBitwidth) -> Self {
+        IntType {
+            bitwidth,
+            signedness: Signedness::Unsigned,
+        }
+    }
+
+    pub const fn signed(bitwidth: UBitwidth) -> Self {
+        IntType {
+            bitwidth,
+            signedness: Signedness::Signed,
+        }
+    }
+
+    pub fn to_usize(&self) -> usize {
+        self.bitwidth.to_usize()
+    }
+
+    pub fn is_signed(&self) -> bool {
+        self.signedness == Signedness::Signed
+    }
+
+    /// Interpret `v`, the two's complement encoding of a value of this type, as a signed value
+    pub fn to_signed(&self, v: u128) -> i128 {
+        let v = v as i128;
+        match self.is_signed() && v >> (self.to_usize() - 1) == 1 {
+            true => v - (1 << self.to_usize()),
+            false => v,
+        }
+    }
+
+    /// Encode `v` in two's complement on the bitwidth of this type
+    pub fn from_signed(&self, v: i128) -> u128 {
+        (v as u128) & ((1 << self.to_usize()) - 1)
+    }
+
+    /// Returns whether `v` is representable by this type
+    pub fn is_in_range(&self, v: i128) -> bool {
+        match self.is_signed() {
+            true => v >= -(1 << (self.to_usize() - 1)) && v < 1 << (self.to_usize() - 1),
+            false => v >= 0 && v < 1 << self.to_usize(),
+        }
+    }
+}
+
+impl From<UBitwidth> for IntType {
+    fn from(b: UBitwidth) -> Self {
+        IntType::unsigned(b)
+    }
+}
+
+impl From<usize> for IntType {
+    fn from(b: usize) -> Self {
+        IntType::unsigned(b.into())
+    }
+}
+
+impl fmt::Display for IntType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.signedness {
+            Signedness::Unsigned => write!(f, "u{}", self.bitwidth),
+            Signedness::Signed => write!(f, "i{}", self.bitwidth),
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Type {
     FieldElement,
     Boolean,
     Array(ArrayType),
     Struct(StructType),
-    Uint(UBitwidth),
+    Uint(IntType),
+    Tuple(TupleType),
+    Vector(VectorType),
 }
 
 impl Serialize for Type {
@@ -145,12 +282,21 @@ impl Serialize for Type {
                 map.serialize_entry("components", struct_type)?;
                 map.end()
             }
-            Type::Uint(width) => s.serialize_newtype_variant(
-                "Type",
-                4,
-                "type",
-                format!("u{}", width.to_usize()).as_str(),
-            ),
+            Type::Uint(int_type) => {
+                s.serialize_newtype_variant("Type", 4, "type", int_type.to_string().as_str())
+            }
+            Type::Tuple(tuple_type) => {
+                let mut map = s.serialize_map(Some(2))?;
+                map.serialize_entry("type", "tuple")?;
+                map.serialize_entry("components", tuple_type)?;
+                map.end()
+            }
+            Type::Vector(vector_type) => {
+                let mut map = s.serialize_map(Some(2))?;
+                map.serialize_entry("type", "vector")?;
+                map.serialize_entry("components", vector_type)?;
+                map.end()
+            }
         }
     }
 }
@@ -165,6 +311,8 @@ impl<'de> Deserialize<'de> for Type {
         enum Components {
             Array(ArrayType),
             Struct(StructType),
+            Tuple(TupleType),
+            Vector(VectorType),
         }
 
         #[derive(Debug, Deserialize)]
@@ -214,9 +362,39 @@ impl<'de> Deserialize<'de> for Type {
                     ))),
                 }
             }
-            "u8" => strict_type(mapping, Type::Uint(UBitwidth::B8)),
-            "u16" => strict_type(mapping, Type::Uint(UBitwidth::B16)),
-            "u32" => strict_type(mapping, Type::Uint(UBitwidth::B32)),
+            "tuple" => {
+                let components = mapping.components.ok_or(D::Error::custom(format_args!(
+                    "missing `components` field for type `{}'",
+                    mapping.ty
+                )))?;
+                match components {
+                    Components::Tuple(tuple_type) => Ok(Type::Tuple(tuple_type)),
+                    _ => Err(D::Error::custom(format!(
+                        "invalid `components` variant for type `{}`",
+                        mapping.ty
+                    ))),
+                }
+            }
+            "vector" => {
+                let components = mapping.components.ok_or(D::Error::custom(format_args!(
+                    "missing `components` field for type `{}'",
+                    mapping.ty
+                )))?;
+                match components {
+                    Components::Vector(vector_type) => Ok(Type::Vector(vector_type)),
+                    _ => Err(D::Error::custom(format!(
+                        "invalid `components` variant for type `{}`",
+                        mapping.ty
+                    ))),
+                }
+            }
+            "u8" => strict_type(mapping, Type::Uint(IntType::U8)),
+            "u16" => strict_type(mapping, Type::Uint(IntType::U16)),
+            "u32" => strict_type(mapping, Type::Uint(IntType::U32)),
+            "u64" => strict_type(mapping, Type::Uint(IntType::U64)),
+            "i8" => strict_type(mapping, Type::Uint(IntType::I8)),
+            "i16" => strict_type(mapping, Type::Uint(IntType::I16)),
+            "i32" => strict_type(mapping, Type::Uint(IntType::I32)),
             _ => Err(D::Error::custom(format!("invalid type `{}`", mapping.ty))),
         }
     }
@@ -231,6 +409,15 @@ impl ArrayType {
     }
 }
 
+impl VectorType {
+    pub fn new(ty: Type, capacity: usize) -> Self {
+        VectorType {
+            ty: Box::new(ty),
+            capacity,
+        }
+    }
+}
+
 impl StructMember {
     pub fn new(id: String, ty: Type) -> Self {
         StructMember {
@@ -245,7 +432,7 @@ impl fmt::Display for Type {
         match self {
             Type::FieldElement => write!(f, "field"),
             Type::Boolean => write!(f, "bool"),
-            Type::Uint(ref bitwidth) => write!(f, "u{}", bitwidth),
+            Type::Uint(ref int_type) => write!(f, "{}", int_type),
             Type::Array(ref array_type) => write!(f, "{}[{}]", array_type.ty, array_type.size),
             Type::Struct(ref struct_type) => write!(
                 f,
@@ -258,6 +445,10 @@ impl fmt::Display for Type {
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
+            Type::Tuple(ref tuple_type) => write!(f, "{}", tuple_type),
+            Type::Vector(ref vector_type) => {
+                write!(f, "{}[..{}]", vector_type.ty, vector_type.capacity)
+            }
         }
     }
 }
@@ -267,9 +458,13 @@ impl fmt::Debug for Type {
         match self {
             Type::FieldElement => write!(f, "field"),
             Type::Boolean => write!(f, "bool"),
-            Type::Uint(ref bitwidth) => write!(f, "u{}", bitwidth),
+            Type::Uint(ref int_type) => write!(f, "{}", int_type),
             Type::Array(ref array_type) => write!(f, "{}[{}]", array_type.ty, array_type.size),
             Type::Struct(ref struct_type) => write!(f, "{:?}", struct_type),
+            Type::Tuple(ref tuple_type) => write!(f, "{}", tuple_type),
+            Type::Vector(ref vector_type) => {
+                write!(f, "{}[..{}]", vector_type.ty, vector_type.capacity)
+            }
         }
     }
 }
@@ -283,15 +478,27 @@ impl Type {
         Type::Struct(struct_ty)
     }
 
-    pub fn uint<W: Into<UBitwidth>>(b: W) -> Self {
+    pub fn uint<W: Into<IntType>>(b: W) -> Self {
         Type::Uint(b.into())
     }
 
+    pub fn int(b: usize) -> Self {
+        Type::Uint(IntType::signed(b.into()))
+    }
+
+    pub fn tuple(tuple_ty: TupleType) -> Self {
+        Type::Tuple(tuple_ty)
+    }
+
+    pub fn vector(ty: Type, capacity: usize) -> Self {
+        Type::Vector(VectorType::new(ty, capacity))
+    }
+
     fn to_slug(&self) -> String {
         match self {
             Type::FieldElement => String::from("f"),
             Type::Boolean => String::from("b"),
-            Type::Uint(bitwidth) => format!("u{}", bitwidth),
+            Type::Uint(int_type) => int_type.to_string(),
             Type::Array(array_type) => format!("{}[{}]", array_type.ty.to_slug(), array_type.size),
             Type::Struct(struct_type) => format!(
                 "{{{}}}",
@@ -301,6 +508,17 @@ impl Type {
                     .collect::<Vec<_>>()
                     .join(",")
             ),
+            Type::Tuple(tuple_type) => format!(
+                "({})",
+                tuple_type
+                    .iter()
+                    .map(|ty| ty.to_slug())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            Type::Vector(vector_type) => {
+                format!("{}[..{}]", vector_type.ty.to_slug(), vector_type.capacity)
+            }
         }
     }
 
@@ -315,6 +533,11 @@ impl Type {
                 .iter()
                 .map(|member| member.ty.get_primitive_count())
                 .sum(),
+            Type::Tuple(tuple_type) => tuple_type.iter().map(|ty| ty.get_primitive_count()).sum(),
+            // the elements are followed by the length, encoded as a `u32`
+            Type::Vector(vector_type) => {
+                vector_type.capacity * vector_type.ty.get_primitive_count() + 1
+            }
         }
     }
 }
@@ -534,4 +757,35 @@ mod tests {
         let t = Type::Array(ArrayType::new(Type::FieldElement, 42));
         assert_eq!(t.get_primitive_count(), 42);
     }
+
+    #[test]
+    fn tuple() {
+        let t = Type::Tuple(TupleType::new(vec![
+            Type::array(Type::FieldElement, 2),
+            Type::Boolean,
+        ]));
+        assert_eq!(t.get_primitive_count(), 3);
+        assert_eq!(t.to_string(), "(field[2], bool)");
+    }
+
+    #[test]
+    fn vector() {
+        let t = Type::vector(Type::array(Type::FieldElement, 2), 3);
+        assert_eq!(t.get_primitive_count(), 7);
+        assert_eq!(t.to_string(), "field[2][..3]");
+    }
+
+    #[test]
+    fn int() {
+        let t = Type::int(8);
+        assert_eq!(t, Type::Uint(IntType::signed(UBitwidth::B8)));
+        assert_eq!(t.to_string(), "i8");
+        assert_eq!(Type::uint(8).to_string(), "u8");
+
+        assert_eq!(IntType::I8.to_signed(0xff), -1);
+        assert_eq!(IntType::U8.to_signed(0xff), 255);
+        assert_eq!(IntType::I8.from_signed(-128), 0x80);
+        assert!(IntType::I8.is_in_range(-128));
+        assert!(!IntType::U8.is_in_range(-1));
+    }
 }
@@ -1,4 +1,4 @@
-use typed_absy::types::{FunctionKey, UBitwidth};
+use typed_absy::types::{FunctionKey, IntType};
 use typed_absy::*;
 use zokrates_field::Field;
 
@@ -23,6 +23,18 @@ impl<'ast, T: Field> UExpression<'ast, T> {
         UExpressionInner::Mult(box self, box other).annotate(bitwidth)
     }
 
+    pub fn div(self, other: Self) -> UExpression<'ast, T> {
+        let bitwidth = self.bitwidth;
+        assert_eq!(bitwidth, other.bitwidth);
+        UExpressionInner::Div(box self, box other).annotate(bitwidth)
+    }
+
+    pub fn rem(self, other: Self) -> UExpression<'ast, T> {
+        let bitwidth = self.bitwidth;
+        assert_eq!(bitwidth, other.bitwidth);
+        UExpressionInner::Rem(box self, box other).annotate(bitwidth)
+    }
+
     pub fn xor(self, other: Self) -> UExpression<'ast, T> {
         let bitwidth = self.bitwidth;
         assert_eq!(bitwidth, other.bitwidth);
@@ -46,6 +58,11 @@ impl<'ast, T: Field> UExpression<'ast, T> {
         UExpressionInner::Not(box self).annotate(bitwidth)
     }
 
+    pub fn neg(self) -> UExpression<'ast, T> {
+        let bitwidth = self.bitwidth;
+        UExpressionInner::Value(0).annotate(bitwidth).sub(self)
+    }
+
     pub fn left_shift(self, by: FieldElementExpression<'ast, T>) -> UExpression<'ast, T> {
         let bitwidth = self.bitwidth;
         UExpressionInner::LeftShift(box self, box by).annotate(bitwidth)
@@ -77,7 +94,7 @@ pub struct UMetadata {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct UExpression<'ast, T> {
-    pub bitwidth: UBitwidth,
+    pub bitwidth: IntType,
     pub metadata: Option<UMetadata>,
     pub inner: UExpressionInner<'ast, T>,
 }
@@ -89,6 +106,8 @@ pub enum UExpressionInner<'ast, T> {
     Add(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     Sub(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     Mult(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    Div(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    Rem(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     Xor(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     And(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     Or(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
@@ -112,10 +131,15 @@ pub enum UExpressionInner<'ast, T> {
         Box<ArrayExpression<'ast, T>>,
         Box<FieldElementExpression<'ast, T>>,
     ),
+    Element(Box<TupleExpression<'ast, T>>, u32),
+    FromField(Box<FieldElementExpression<'ast, T>>),
+    FromUint(Box<UExpression<'ast, T>>),
+    FromBits(Box<ArrayExpression<'ast, T>>),
+    VectorLength(Box<VectorExpression<'ast, T>>),
 }
 
 impl<'ast, T> UExpressionInner<'ast, T> {
-    pub fn annotate<W: Into<UBitwidth>>(self, bitwidth: W) -> UExpression<'ast, T> {
+    pub fn annotate<W: Into<IntType>>(self, bitwidth: W) -> UExpression<'ast, T> {
         UExpression {
             metadata: None,
             bitwidth: bitwidth.into(),
@@ -138,7 +162,7 @@ pub fn bitwidth(a: u128) -> Bitwidth {
 }
 
 impl<'ast, T: Field> UExpression<'ast, T> {
-    pub fn bitwidth(&self) -> UBitwidth {
+    pub fn bitwidth(&self) -> IntType {
         self.bitwidth
     }
 
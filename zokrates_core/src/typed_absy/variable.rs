@@ -1,7 +1,7 @@
 use crate::typed_absy::types::Type;
 use crate::typed_absy::Identifier;
 use std::fmt;
-use typed_absy::types::{StructType, UBitwidth};
+use typed_absy::types::{IntType, StructType, TupleType};
 
 #[derive(Clone, PartialEq, Hash, Eq)]
 pub struct Variable<'ast> {
@@ -18,10 +18,7 @@ impl<'ast> Variable<'ast> {
         Self::with_id_and_type(id, Type::Boolean)
     }
 
-    pub fn uint<I: Into<Identifier<'ast>>, W: Into<UBitwidth>>(
-        id: I,
-        bitwidth: W,
-    ) -> Variable<'ast> {
+    pub fn uint<I: Into<Identifier<'ast>>, W: Into<IntType>>(id: I, bitwidth: W) -> Variable<'ast> {
         Self::with_id_and_type(id, Type::uint(bitwidth))
     }
 
@@ -38,6 +35,10 @@ impl<'ast> Variable<'ast> {
         Self::with_id_and_type(id, Type::Struct(ty))
     }
 
+    pub fn tuple<I: Into<Identifier<'ast>>>(id: I, ty: TupleType) -> Variable<'ast> {
+        Self::with_id_and_type(id, Type::Tuple(ty))
+    }
+
     pub fn with_id_and_type<I: Into<Identifier<'ast>>>(id: I, _type: Type) -> Variable<'ast> {
         Variable {
             id: id.into(),
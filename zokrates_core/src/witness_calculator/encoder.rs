@@ -0,0 +1,396 @@
+//! Minimal encoder for WebAssembly modules in the binary format
+//!
+//! Only the instructions and sections used by the witness calculator are supported.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValType {
+    I32,
+    I64,
+}
+
+impl ValType {
+    fn code(self) -> u8 {
+        match self {
+            ValType::I32 => 0x7f,
+            ValType::I64 => 0x7e,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuncType {
+    pub params: Vec<ValType>,
+    pub results: Vec<ValType>,
+}
+
+/// The body of a function, built instruction by instruction
+#[derive(Debug, Clone, Default)]
+pub struct Code {
+    locals: Vec<ValType>,
+    bytes: Vec<u8>,
+}
+
+impl Code {
+    /// Declares a local of type `ty` and returns its index, given the number of parameters
+    pub fn local(&mut self, params: usize, ty: ValType) -> u32 {
+        self.locals.push(ty);
+        (params + self.locals.len() - 1) as u32
+    }
+
+    fn op(&mut self, opcode: u8) -> &mut Self {
+        self.bytes.push(opcode);
+        self
+    }
+
+    fn op_u32(&mut self, opcode: u8, immediate: u32) -> &mut Self {
+        self.bytes.push(opcode);
+        write_u32(&mut self.bytes, immediate);
+        self
+    }
+
+    fn memory_op(&mut self, opcode: u8, align: u32, offset: u32) -> &mut Self {
+        self.bytes.push(opcode);
+        write_u32(&mut self.bytes, align);
+        write_u32(&mut self.bytes, offset);
+        self
+    }
+
+    pub fn block(&mut self) -> &mut Self {
+        self.op(0x02).op(0x40)
+    }
+
+    pub fn loop_(&mut self) -> &mut Self {
+        self.op(0x03).op(0x40)
+    }
+
+    pub fn if_(&mut self) -> &mut Self {
+        self.op(0x04).op(0x40)
+    }
+
+    pub fn else_(&mut self) -> &mut Self {
+        self.op(0x05)
+    }
+
+    pub fn end(&mut self) -> &mut Self {
+        self.op(0x0b)
+    }
+
+    pub fn br(&mut self, depth: u32) -> &mut Self {
+        self.op_u32(0x0c, depth)
+    }
+
+    pub fn br_if(&mut self, depth: u32) -> &mut Self {
+        self.op_u32(0x0d, depth)
+    }
+
+    pub fn return_(&mut self) -> &mut Self {
+        self.op(0x0f)
+    }
+
+    pub fn call(&mut self, function: u32) -> &mut Self {
+        self.op_u32(0x10, function)
+    }
+
+    pub fn drop(&mut self) -> &mut Self {
+        self.op(0x1a)
+    }
+
+    pub fn local_get(&mut self, local: u32) -> &mut Self {
+        self.op_u32(0x20, local)
+    }
+
+    pub fn local_set(&mut self, local: u32) -> &mut Self {
+        self.op_u32(0x21, local)
+    }
+
+    pub fn local_tee(&mut self, local: u32) -> &mut Self {
+        self.op_u32(0x22, local)
+    }
+
+    pub fn i32_load(&mut self, offset: u32) -> &mut Self {
+        self.memory_op(0x28, 2, offset)
+    }
+
+    pub fn i64_load32_u(&mut self, offset: u32) -> &mut Self {
+        self.memory_op(0x35, 2, offset)
+    }
+
+    pub fn i32_store(&mut self, offset: u32) -> &mut Self {
+        self.memory_op(0x36, 2, offset)
+    }
+
+    pub fn i64_store32(&mut self, offset: u32) -> &mut Self {
+        self.memory_op(0x3e, 2, offset)
+    }
+
+    pub fn i32_const(&mut self, value: i32) -> &mut Self {
+        self.bytes.push(0x41);
+        write_i64(&mut self.bytes, value as i64);
+        self
+    }
+
+    pub fn i64_const(&mut self, value: i64) -> &mut Self {
+        self.bytes.push(0x42);
+        write_i64(&mut self.bytes, value);
+        self
+    }
+
+    pub fn i32_eqz(&mut self) -> &mut Self {
+        self.op(0x45)
+    }
+
+    pub fn i32_ne(&mut self) -> &mut Self {
+        self.op(0x47)
+    }
+
+    pub fn i32_lt_u(&mut self) -> &mut Self {
+        self.op(0x49)
+    }
+
+    pub fn i64_eqz(&mut self) -> &mut Self {
+        self.op(0x50)
+    }
+
+    pub fn i64_ne(&mut self) -> &mut Self {
+        self.op(0x52)
+    }
+
+    pub fn i64_gt_u(&mut self) -> &mut Self {
+        self.op(0x56)
+    }
+
+    pub fn i32_add(&mut self) -> &mut Self {
+        self.op(0x6a)
+    }
+
+    pub fn i32_sub(&mut self) -> &mut Self {
+        self.op(0x6b)
+    }
+
+    pub fn i32_mul(&mut self) -> &mut Self {
+        self.op(0x6c)
+    }
+
+    pub fn i32_and(&mut self) -> &mut Self {
+        self.op(0x71)
+    }
+
+    pub fn i32_or(&mut self) -> &mut Self {
+        self.op(0x72)
+    }
+
+    pub fn i32_shl(&mut self) -> &mut Self {
+        self.op(0x74)
+    }
+
+    pub fn i32_shr_u(&mut self) -> &mut Self {
+        self.op(0x76)
+    }
+
+    pub fn i32_wrap_i64(&mut self) -> &mut Self {
+        self.op(0xa7)
+    }
+
+    pub fn i64_add(&mut self) -> &mut Self {
+        self.op(0x7c)
+    }
+
+    pub fn i64_sub(&mut self) -> &mut Self {
+        self.op(0x7d)
+    }
+
+    pub fn i64_mul(&mut self) -> &mut Self {
+        self.op(0x7e)
+    }
+
+    pub fn i64_and(&mut self) -> &mut Self {
+        self.op(0x83)
+    }
+
+    pub fn i64_shr_u(&mut self) -> &mut Self {
+        self.op(0x88)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut res = vec![];
+        // consecutive locals of the same type are declared together
+        let mut groups: Vec<(u32, ValType)> = vec![];
+        for ty in &self.locals {
+            match groups.last_mut() {
+                Some((count, last)) if last == ty => *count += 1,
+                _ => groups.push((1, *ty)),
+            }
+        }
+        write_u32(&mut res, groups.len() as u32);
+        for (count, ty) in groups {
+            write_u32(&mut res, count);
+            res.push(ty.code());
+        }
+        res.extend(&self.bytes);
+        res.push(0x0b);
+        res
+    }
+}
+
+pub enum Export {
+    Function(u32),
+    Memory,
+}
+
+/// A module with a single memory and no imports
+#[derive(Default)]
+pub struct Module {
+    types: Vec<FuncType>,
+    functions: Vec<(u32, Code)>,
+    exports: Vec<(String, Export)>,
+    pages: u32,
+    data: Vec<(u32, Vec<u8>)>,
+}
+
+impl Module {
+    /// Reserves the index of a function, to be defined later with `define`
+    pub fn declare(&mut self, ty: FuncType) -> u32 {
+        let type_index = match self.types.iter().position(|t| *t == ty) {
+            Some(index) => index,
+            None => {
+                self.types.push(ty);
+                self.types.len() - 1
+            }
+        } as u32;
+        self.functions.push((type_index, Code::default()));
+        (self.functions.len() - 1) as u32
+    }
+
+    pub fn define(&mut self, function: u32, code: Code) {
+        self.functions[function as usize].1 = code;
+    }
+
+    pub fn export(&mut self, name: &str, export: Export) {
+        self.exports.push((name.to_string(), export));
+    }
+
+    pub fn pages(&mut self, pages: u32) {
+        self.pages = pages;
+    }
+
+    pub fn data(&mut self, offset: u32, bytes: Vec<u8>) {
+        self.data.push((offset, bytes));
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut res = b"\0asm".to_vec();
+        res.extend(&[1, 0, 0, 0]);
+
+        let mut types = vec![];
+        write_u32(&mut types, self.types.len() as u32);
+        for ty in &self.types {
+            types.push(0x60);
+            write_u32(&mut types, ty.params.len() as u32);
+            types.extend(ty.params.iter().map(|p| p.code()));
+            write_u32(&mut types, ty.results.len() as u32);
+            types.extend(ty.results.iter().map(|r| r.code()));
+        }
+        section(&mut res, 1, types);
+
+        let mut functions = vec![];
+        write_u32(&mut functions, self.functions.len() as u32);
+        for (ty, _) in &self.functions {
+            write_u32(&mut functions, *ty);
+        }
+        section(&mut res, 3, functions);
+
+        let mut memory = vec![1, 0];
+        write_u32(&mut memory, self.pages);
+        section(&mut res, 5, memory);
+
+        let mut exports = vec![];
+        write_u32(&mut exports, self.exports.len() as u32);
+        for (name, export) in &self.exports {
+            write_u32(&mut exports, name.len() as u32);
+            exports.extend(name.as_bytes());
+            match export {
+                Export::Function(index) => {
+                    exports.push(0x00);
+                    write_u32(&mut exports, *index);
+                }
+                Export::Memory => exports.extend(&[0x02, 0x00]),
+            }
+        }
+        section(&mut res, 7, exports);
+
+        let mut code = vec![];
+        write_u32(&mut code, self.functions.len() as u32);
+        for (_, function) in &self.functions {
+            let body = function.encode();
+            write_u32(&mut code, body.len() as u32);
+            code.extend(body);
+        }
+        section(&mut res, 10, code);
+
+        let mut data = vec![];
+        write_u32(&mut data, self.data.len() as u32);
+        for (offset, bytes) in &self.data {
+            data.push(0x00);
+            data.push(0x41);
+            write_i64(&mut data, *offset as i64);
+            data.push(0x0b);
+            write_u32(&mut data, bytes.len() as u32);
+            data.extend(bytes);
+        }
+        section(&mut res, 11, data);
+
+        res
+    }
+}
+
+fn section(module: &mut Vec<u8>, id: u8, content: Vec<u8>) {
+    module.push(id);
+    write_u32(module, content.len() as u32);
+    module.extend(content);
+}
+
+/// Writes `value` in the unsigned LEB128 encoding
+fn write_u32(bytes: &mut Vec<u8>, mut value: u32) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+/// Writes `value` in the signed LEB128 encoding
+fn write_i64(bytes: &mut Vec<u8>, mut value: i64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leb128() {
+        let mut bytes = vec![];
+        write_u32(&mut bytes, 624485);
+        assert_eq!(bytes, vec![0xe5, 0x8e, 0x26]);
+
+        let mut bytes = vec![];
+        write_i64(&mut bytes, -123456);
+        assert_eq!(bytes, vec![0xc0, 0xbb, 0x78]);
+
+        let mut bytes = vec![];
+        write_i64(&mut bytes, 64);
+        assert_eq!(bytes, vec![0xc0, 0x00]);
+    }
+}
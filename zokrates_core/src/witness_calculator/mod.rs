@@ -0,0 +1,742 @@
+//! Module emitting a standalone WebAssembly module which computes the witness of a program
+//!
+//! The emitted module has no imports. It exports its memory and a `compute` function which
+//! runs the statements of the program in order, exactly like `ir::Interpreter::execute`.
+//!
+//! Field elements are exchanged through the memory as little-endian unsigned integers of
+//! `field_size()` bytes. The caller writes the `input_count()` arguments at `input_offset()`,
+//! calls `compute()` and reads the witness at `witness_offset()`: `~one` first, then `_i` at
+//! index `i + 1` and `~out_i` at index `variable_count() + i`. `compute()` returns one of the
+//! `STATUS_*` codes.
+//!
+//! @file mod.rs
+//! @date 2020
+
+mod encoder;
+mod runtime;
+
+use self::encoder::{Code, Export, FuncType, Module, ValType};
+use self::runtime::{to_limbs, FieldParameters, Layout, Runtime};
+use crate::flat_absy::FlatVariable;
+use crate::ir::{LinComb, Prog, QuadComb, Statement};
+use crate::solvers::Solver;
+use crate::witness_generator::variable_count;
+use num_bigint::BigUint;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use zokrates_field::Field;
+
+/// The witness was computed
+pub const STATUS_OK: i32 = 0;
+/// A constraint is not satisfied by the inputs
+pub const STATUS_UNSATISFIED_CONSTRAINT: i32 = 1;
+/// A constraint is not satisfied after a solver failed, for example on a division by zero
+pub const STATUS_SOLVER: i32 = 2;
+/// An input is not smaller than the modulus of the field
+pub const STATUS_INVALID_INPUT: i32 = 3;
+
+/// A compiled witness calculator
+#[derive(Debug, Clone, PartialEq)]
+pub struct WitnessCalculator {
+    pub wasm: Vec<u8>,
+}
+
+impl WitnessCalculator {
+    pub fn new<T: Field>(program: &Prog<T>) -> Self {
+        WitnessCalculator {
+            wasm: Emitter::new(program).emit(),
+        }
+    }
+
+    /// Writes the module to the file at `path`
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        File::create(path)?.write_all(&self.wasm)
+    }
+}
+
+// the elements at the start of the memory
+const MODULUS: u32 = 0;
+const R2: u32 = 1;
+const ONE: u32 = 2;
+const EXPONENT: u32 = 3;
+const MUL_TMP: u32 = 4;
+const DIV_TMP: u32 = 5;
+const INVERSE_ACC: u32 = 6;
+const BITS_NUM: u32 = 7;
+const BITS_POW: u32 = 8;
+const T1: u32 = 9;
+const T2: u32 = 10;
+// the factors of a quadratic combination
+const LEFT: u32 = 11;
+const RIGHT: u32 = 12;
+// the sides of a constraint
+const QUAD: u32 = 13;
+const LIN: u32 = 14;
+const TERM: u32 = 15;
+const SOLVER_INPUTS: u32 = 16;
+
+const PAGE_SIZE: u32 = 1 << 16;
+
+struct Emitter<'ast, T> {
+    program: &'ast Prog<T>,
+    field: FieldParameters,
+    runtime: Runtime,
+    module: Module,
+    /// The number of variables which are not outputs, including `~one`
+    variable_count: u32,
+    solver_outputs: u32,
+    inputs: u32,
+    witness: u32,
+    /// The constants used in the program, stored after the witness
+    constants: Vec<T>,
+    constant_indices: HashMap<T, u32>,
+    /// The variables assigned so far
+    assigned: HashSet<FlatVariable>,
+    /// Whether a directive was emitted so far, in which case a solver may have failed
+    has_directive: bool,
+}
+
+impl<'ast, T: Field> Emitter<'ast, T> {
+    fn new(program: &'ast Prog<T>) -> Self {
+        let modulus = T::max_value().to_biguint() + 1u32;
+        let field = FieldParameters::new(&modulus);
+        let size = field.size();
+        let exponent = modulus - 2u32;
+
+        let layout = Layout {
+            modulus: MODULUS * size,
+            r2: R2 * size,
+            one: ONE * size,
+            exponent: EXPONENT * size,
+            exponent_bits: exponent.bits() as u32,
+            mul_tmp: MUL_TMP * size,
+            div_tmp: DIV_TMP * size,
+            inverse_acc: INVERSE_ACC * size,
+            bits_num: BITS_NUM * size,
+            bits_pow: BITS_POW * size,
+            t1: T1 * size,
+            t2: T2 * size,
+        };
+
+        let mut module = Module::default();
+        let runtime = Runtime::new(&mut module, &field, &layout);
+
+        let directives = || {
+            program.main.statements.iter().filter_map(|s| match s {
+                Statement::Directive(d) => Some(d),
+                _ => None,
+            })
+        };
+        let max_solver_inputs = directives()
+            .map(|d| d.inputs.len() as u32)
+            .max()
+            .unwrap_or(0);
+        let max_solver_outputs = directives()
+            .map(|d| d.outputs.len() as u32)
+            .max()
+            .unwrap_or(0);
+
+        let solver_outputs = (SOLVER_INPUTS + max_solver_inputs) * size;
+        let inputs = solver_outputs + max_solver_outputs * size;
+        let witness = inputs + program.main.arguments.len() as u32 * size;
+
+        Emitter {
+            program,
+            field,
+            runtime,
+            module,
+            variable_count: variable_count(program) as u32,
+            solver_outputs,
+            inputs,
+            witness,
+            constants: vec![],
+            constant_indices: HashMap::new(),
+            assigned: HashSet::new(),
+            has_directive: false,
+        }
+    }
+
+    fn element(&self, index: u32) -> i32 {
+        (index * self.field.size()) as i32
+    }
+
+    /// The address of `v` in the witness
+    fn variable(&self, v: &FlatVariable) -> i32 {
+        let index = match v.is_output() {
+            true => self.variable_count + v.index() as u32,
+            false => v.index() as u32,
+        };
+        (self.witness + index * self.field.size()) as i32
+    }
+
+    fn output_count(&self) -> u32 {
+        self.program.main.returns.len() as u32
+    }
+
+    fn constants_offset(&self) -> u32 {
+        self.witness + (self.variable_count + self.output_count()) * self.field.size()
+    }
+
+    /// The address of the constant `value`
+    fn constant(&mut self, value: &T) -> i32 {
+        let constants = &mut self.constants;
+        let index = *self
+            .constant_indices
+            .entry(value.clone())
+            .or_insert_with(|| {
+                constants.push(value.clone());
+                constants.len() as u32 - 1
+            });
+        (self.constants_offset() + index * self.field.size()) as i32
+    }
+
+    /// Evaluates `l` into the element at `target`
+    fn linear_combination(&mut self, code: &mut Code, l: &LinComb<T>, target: i32) {
+        match l.0.as_slice() {
+            [(variable, coefficient)] if *coefficient == T::one() => {
+                code.i32_const(self.variable(variable))
+                    .i32_const(target)
+                    .call(self.runtime.copy);
+            }
+            summands => {
+                code.i32_const(target).call(self.runtime.zero);
+                for (variable, coefficient) in summands {
+                    let summand = match *coefficient == T::one() {
+                        true => self.variable(variable),
+                        false => {
+                            code.i32_const(self.variable(variable))
+                                .i32_const(self.constant(coefficient))
+                                .i32_const(self.element(TERM))
+                                .call(self.runtime.mul);
+                            self.element(TERM)
+                        }
+                    };
+                    code.i32_const(target)
+                        .i32_const(summand)
+                        .i32_const(target)
+                        .call(self.runtime.add);
+                }
+            }
+        }
+    }
+
+    /// Evaluates `q` into the element at `target`
+    fn quadratic_combination(&mut self, code: &mut Code, q: &QuadComb<T>, target: i32) {
+        let one = LinComb::one();
+
+        // directive inputs are usually of the form `(~one) * (x)`
+        if q.left == one {
+            self.linear_combination(code, &q.right, target);
+        } else if q.right == one {
+            self.linear_combination(code, &q.left, target);
+        } else {
+            self.linear_combination(code, &q.left, self.element(LEFT));
+            self.linear_combination(code, &q.right, self.element(RIGHT));
+            code.i32_const(self.element(LEFT))
+                .i32_const(self.element(RIGHT))
+                .i32_const(target)
+                .call(self.runtime.mul);
+        }
+    }
+
+    fn statement(&mut self, code: &mut Code, statement: &Statement<T>, solver_failed: u32) {
+        match statement {
+            Statement::Constraint(quad, lin, _) => {
+                let assignee = match lin.0.as_slice() {
+                    [(variable, coefficient)]
+                        if *coefficient == T::one() && !self.assigned.contains(variable) =>
+                    {
+                        Some(variable)
+                    }
+                    _ => None,
+                };
+
+                match assignee {
+                    Some(variable) => {
+                        self.assigned.insert(variable.clone());
+                        let target = self.variable(variable);
+                        self.quadratic_combination(code, quad, target);
+                    }
+                    None => {
+                        let (left, right) = (self.element(QUAD), self.element(LIN));
+                        self.quadratic_combination(code, quad, left);
+                        self.linear_combination(code, lin, right);
+                        code.i32_const(left)
+                            .i32_const(right)
+                            .call(self.runtime.eq)
+                            .i32_eqz()
+                            .if_()
+                            .i32_const(STATUS_UNSATISFIED_CONSTRAINT);
+                        if self.has_directive {
+                            code.local_get(solver_failed).i32_add();
+                        }
+                        code.return_().end();
+                    }
+                }
+            }
+            Statement::Directive(d) => {
+                self.has_directive = true;
+                self.assigned.extend(d.outputs.iter().cloned());
+
+                for (i, input) in d.inputs.iter().enumerate() {
+                    let target = self.element(SOLVER_INPUTS + i as u32);
+                    self.quadratic_combination(code, input, target);
+                }
+
+                code.i32_const(self.element(SOLVER_INPUTS))
+                    .i32_const(self.solver_outputs as i32);
+                let solver = match d.solver {
+                    Solver::ConditionEq => self.runtime.condition_eq,
+                    Solver::Bits(bit_width) => {
+                        code.i32_const(bit_width as i32);
+                        self.runtime.bits
+                    }
+                    Solver::Div => self.runtime.div_solver,
+                    Solver::EuclideanDiv => self.runtime.euclidean_div,
+                    Solver::Xor => self.runtime.xor,
+                    Solver::Or => self.runtime.or,
+                    Solver::ShaAndXorAndXorAnd => self.runtime.sha_and_xor_and_xor_and,
+                    Solver::ShaCh => self.runtime.sha_ch,
+                    Solver::Gt => self.runtime.gt,
+                    Solver::Select(size) => {
+                        code.i32_const(size as i32);
+                        self.runtime.select
+                    }
+                };
+                code.call(solver);
+
+                // the directive may be in a branch which is not taken, in which case its
+                // constraints are not enforced: only fail if one is unsatisfied later on
+                code.if_();
+                for output in &d.outputs {
+                    code.i32_const(self.variable(output))
+                        .call(self.runtime.zero);
+                }
+                code.i32_const(1).local_set(solver_failed).else_();
+                for (i, output) in d.outputs.iter().enumerate() {
+                    code.i32_const(self.solver_outputs as i32 + self.element(i as u32))
+                        .i32_const(self.variable(output))
+                        .call(self.runtime.copy);
+                }
+                code.end();
+            }
+            // `log` statements are only printed by the interpreter in verbose mode
+            Statement::Log(..) => {}
+        }
+    }
+
+    fn compute(&mut self) -> Code {
+        let program = self.program;
+        let main = &program.main;
+        let size = self.field.size() as i32;
+
+        let mut code = Code::default();
+        let solver_failed = code.local(0, ValType::I32);
+        let address = code.local(0, ValType::I32);
+
+        for i in 0..main.arguments.len() as i32 {
+            code.i32_const(self.inputs as i32 + i * size)
+                .i32_const(self.element(MODULUS))
+                .call(self.runtime.gte)
+                .if_()
+                .i32_const(STATUS_INVALID_INPUT)
+                .return_()
+                .end();
+        }
+
+        // clear the witness of a previous run
+        code.i32_const(self.witness as i32)
+            .local_set(address)
+            .block()
+            .loop_()
+            .local_get(address)
+            .i32_const(self.constants_offset() as i32)
+            .i32_sub()
+            .i32_eqz()
+            .br_if(1)
+            .local_get(address)
+            .call(self.runtime.zero)
+            .local_get(address)
+            .i32_const(size)
+            .i32_add()
+            .local_set(address)
+            .br(0)
+            .end()
+            .end();
+
+        code.i32_const(self.variable(&FlatVariable::one()))
+            .call(self.runtime.one);
+        self.assigned.insert(FlatVariable::one());
+
+        for (i, argument) in main.arguments.iter().enumerate() {
+            self.assigned.insert(argument.clone());
+            code.i32_const(self.inputs as i32 + i as i32 * size)
+                .i32_const(self.variable(argument))
+                .call(self.runtime.copy);
+        }
+
+        for statement in &main.statements {
+            self.statement(&mut code, statement, solver_failed);
+        }
+
+        code.i32_const(STATUS_OK);
+        code
+    }
+
+    /// Exports a function returning the constant `value`
+    fn getter(&mut self, name: &str, value: u32) {
+        let function = self.module.declare(FuncType {
+            params: vec![],
+            results: vec![ValType::I32],
+        });
+        let mut code = Code::default();
+        code.i32_const(value as i32);
+        self.module.define(function, code);
+        self.module.export(name, Export::Function(function));
+    }
+
+    fn emit(mut self) -> Vec<u8> {
+        let compute = self.module.declare(FuncType {
+            params: vec![],
+            results: vec![ValType::I32],
+        });
+        let code = self.compute();
+        self.module.define(compute, code);
+        self.module.export("compute", Export::Function(compute));
+        self.module.export("memory", Export::Memory);
+
+        self.getter("field_size", self.field.size());
+        self.getter("input_count", self.program.main.arguments.len() as u32);
+        self.getter("input_offset", self.inputs);
+        self.getter("variable_count", self.variable_count);
+        self.getter("output_count", self.output_count());
+        self.getter("witness_offset", self.witness);
+
+        let limbs = self.field.limbs;
+        let modulus = T::max_value().to_biguint() + 1u32;
+        let r2 = (BigUint::from(1u32) << (64 * limbs as usize)) % &modulus;
+        let exponent = &modulus - 2u32;
+        let header = [modulus, r2, BigUint::from(1u32), exponent];
+        self.module
+            .data(self.element(MODULUS) as u32, to_bytes(header.iter(), limbs));
+
+        let constants: Vec<_> = self.constants.iter().map(|c| c.to_biguint()).collect();
+        self.module
+            .data(self.constants_offset(), to_bytes(constants.iter(), limbs));
+
+        let memory_size = self.constants_offset() + self.constants.len() as u32 * self.field.size();
+        self.module.pages((memory_size + PAGE_SIZE - 1) / PAGE_SIZE);
+
+        self.module.encode()
+    }
+}
+
+fn to_bytes<'a, I: Iterator<Item = &'a BigUint>>(values: I, limbs: u32) -> Vec<u8> {
+    values
+        .flat_map(|v| to_limbs(v, limbs))
+        .flat_map(|limb| limb.to_le_bytes().to_vec())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate wasmi;
+
+    use self::wasmi::{
+        ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, NopExternals, RuntimeValue,
+    };
+    use super::*;
+    use crate::ir::{Directive, Function, Interpreter};
+    use zokrates_field::{Bn128Field, Bw6_761Field};
+
+    struct Instance {
+        instance: ModuleRef,
+        memory: MemoryRef,
+    }
+
+    impl Instance {
+        fn new<T: Field>(program: &Prog<T>) -> Self {
+            let wasm = WitnessCalculator::new(program).wasm;
+            let module = wasmi::Module::from_buffer(&wasm).unwrap();
+            let instance = ModuleInstance::new(&module, &ImportsBuilder::default())
+                .unwrap()
+                .assert_no_start();
+            let memory = instance
+                .export_by_name("memory")
+                .unwrap()
+                .as_memory()
+                .unwrap()
+                .clone();
+            Instance { instance, memory }
+        }
+
+        fn get(&self, name: &str) -> i32 {
+            match self
+                .instance
+                .invoke_export(name, &[], &mut NopExternals)
+                .unwrap()
+            {
+                Some(RuntimeValue::I32(value)) => value,
+                value => panic!("unexpected return value {:?}", value),
+            }
+        }
+
+        fn compute<T: Field>(&self, inputs: &[T]) -> Result<Vec<T>, i32> {
+            let size = self.get("field_size") as usize;
+            assert_eq!(self.get("input_count") as usize, inputs.len());
+
+            for (i, input) in inputs.iter().enumerate() {
+                let mut bytes = input.to_biguint().to_bytes_le();
+                bytes.resize(size, 0);
+                self.memory
+                    .set(
+                        (self.get("input_offset") as usize + i * size) as u32,
+                        &bytes,
+                    )
+                    .unwrap();
+            }
+
+            match self.get("compute") {
+                STATUS_OK => {
+                    let count = (self.get("variable_count") + self.get("output_count")) as usize;
+                    let witness = self
+                        .memory
+                        .get(self.get("witness_offset") as u32, count * size)
+                        .unwrap();
+                    Ok(witness
+                        .chunks(size)
+                        .map(|bytes| T::try_from(BigUint::from_bytes_le(bytes)).unwrap())
+                        .collect())
+                }
+                status => Err(status),
+            }
+        }
+    }
+
+    // compares the witness with the one computed by the interpreter
+    fn check<T: Field>(program: &Prog<T>, inputs: Vec<T>) {
+        let instance = Instance::new(program);
+        let witness = instance.compute(&inputs).unwrap();
+        let expected = Interpreter::default().execute(program, &inputs).unwrap().0;
+
+        let variable_count = instance.get("variable_count") as usize;
+        for (variable, value) in expected.iter() {
+            let index = match variable.is_output() {
+                true => variable_count + variable.index(),
+                false => variable.index(),
+            };
+            assert_eq!(witness[index], *value, "{}", variable);
+        }
+    }
+
+    fn directive<T: Field>(
+        solver: Solver,
+        inputs: Vec<usize>,
+        outputs: Vec<usize>,
+    ) -> Statement<T> {
+        Statement::Directive(Directive {
+            inputs: inputs
+                .into_iter()
+                .map(|i| FlatVariable::new(i).into())
+                .collect(),
+            outputs: outputs.into_iter().map(FlatVariable::new).collect(),
+            solver,
+        })
+    }
+
+    // def main(_0, _1, _2) -> (3):
+    //     # _3, _4 = ConditionEq(_0)
+    //     # _5 = Div(_0, _1)
+    //     # _6, _7 = EuclideanDiv(_0, _1)
+    //     # _8 = Xor(_1, _2)
+    //     # _9 = Or(_1, _2)
+    //     # _10 = ShaAndXorAndXorAnd(_0, _1, _2)
+    //     # _11 = ShaCh(_0, _1, _2)
+    //     # _12, ..., _19 = Bits(8)(_1)
+    //     # _21 = Gt(_0, _1)
+    //     # _22 = Select(2)(_1, _2, _0)
+    //     _1 * _5 == _0
+    //     _20 = _0 * _0
+    //     (7 * _0) * _0 == 7 * _20
+    //     ~out_0 = (_3 + 3 * _6) * (_4 + _7)
+    //     ~out_1 = (_20 + _8 + _9 + _10) * (_11)
+    //     ~out_2 = (_12 + 2 * _19 + _21 + _22) * (~one)
+    fn program<T: Field>() -> Prog<T> {
+        Prog {
+            private: vec![false, true, true],
+            main: Function {
+                id: "main".to_string(),
+                arguments: vec![0, 1, 2].into_iter().map(FlatVariable::new).collect(),
+                returns: vec![0, 1, 2]
+                    .into_iter()
+                    .map(FlatVariable::public)
+                    .collect(),
+                statements: vec![
+                    directive(Solver::ConditionEq, vec![0], vec![3, 4]),
+                    directive(Solver::Div, vec![0, 1], vec![5]),
+                    directive(Solver::EuclideanDiv, vec![0, 1], vec![6, 7]),
+                    directive(Solver::Xor, vec![1, 2], vec![8]),
+                    directive(Solver::Or, vec![1, 2], vec![9]),
+                    directive(Solver::ShaAndXorAndXorAnd, vec![0, 1, 2], vec![10]),
+                    directive(Solver::ShaCh, vec![0, 1, 2], vec![11]),
+                    directive(Solver::Bits(8), vec![1], (12..20).collect()),
+                    directive(Solver::Gt, vec![0, 1], vec![21]),
+                    directive(Solver::Select(2), vec![1, 2, 0], vec![22]),
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            FlatVariable::new(1).into(),
+                            FlatVariable::new(5).into(),
+                        ),
+                        FlatVariable::new(0),
+                    ),
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            FlatVariable::new(0).into(),
+                            FlatVariable::new(0).into(),
+                        ),
+                        FlatVariable::new(20),
+                    ),
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            LinComb::summand(7, FlatVariable::new(0)),
+                            FlatVariable::new(0).into(),
+                        ),
+                        LinComb::summand(7, FlatVariable::new(20)),
+                    ),
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            LinComb::from(FlatVariable::new(3))
+                                + LinComb::summand(3, FlatVariable::new(6)),
+                            LinComb::from(FlatVariable::new(4))
+                                + LinComb::from(FlatVariable::new(7)),
+                        ),
+                        FlatVariable::public(0),
+                    ),
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            LinComb::from(FlatVariable::new(20))
+                                + LinComb::from(FlatVariable::new(8))
+                                + LinComb::from(FlatVariable::new(9))
+                                + LinComb::from(FlatVariable::new(10)),
+                            FlatVariable::new(11).into(),
+                        ),
+                        FlatVariable::public(1),
+                    ),
+                    Statement::constraint(
+                        LinComb::from(FlatVariable::new(12))
+                            + LinComb::summand(2, FlatVariable::new(19))
+                            + LinComb::from(FlatVariable::new(21))
+                            + LinComb::from(FlatVariable::new(22)),
+                        FlatVariable::public(2),
+                    ),
+                ],
+                source_map: Default::default(),
+            },
+        }
+    }
+
+    #[test]
+    fn solvers() {
+        let bn128 = program::<Bn128Field>();
+        check(
+            &bn128,
+            vec![0, 1, 1].into_iter().map(Bn128Field::from).collect(),
+        );
+        check(
+            &bn128,
+            vec![42, 5, 0].into_iter().map(Bn128Field::from).collect(),
+        );
+        check(
+            &bn128,
+            vec![1, 7, 9].into_iter().map(Bn128Field::from).collect(),
+        );
+        check(
+            &bn128,
+            vec![1000, 201, 1]
+                .into_iter()
+                .map(Bn128Field::from)
+                .collect(),
+        );
+        check(
+            &bn128,
+            vec![
+                Bn128Field::max_value(),
+                Bn128Field::from(3),
+                Bn128Field::from(1),
+            ],
+        );
+
+        let bw6 = program::<Bw6_761Field>();
+        check(
+            &bw6,
+            vec![42, 5, 0].into_iter().map(Bw6_761Field::from).collect(),
+        );
+        check(
+            &bw6,
+            vec![
+                Bw6_761Field::max_value(),
+                Bw6_761Field::max_value(),
+                Bw6_761Field::from(1),
+            ],
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let program = program::<Bn128Field>();
+        let instance = Instance::new(&program);
+
+        // `_1 * _5 == _0` fails after the division by zero
+        assert_eq!(
+            instance.compute(&[
+                Bn128Field::from(3),
+                Bn128Field::from(0),
+                Bn128Field::from(1)
+            ]),
+            Err(STATUS_SOLVER)
+        );
+        // the division by zero is harmless when the dividend is zero
+        assert!(instance
+            .compute(&[
+                Bn128Field::from(0),
+                Bn128Field::from(0),
+                Bn128Field::from(1)
+            ])
+            .is_ok());
+
+        // def main(_0) -> ():
+        //     _0 * ~one == 2 * ~one
+        let program: Prog<Bn128Field> = Prog {
+            private: vec![false],
+            main: Function {
+                id: "main".to_string(),
+                arguments: vec![FlatVariable::new(0)],
+                returns: vec![],
+                statements: vec![Statement::constraint(
+                    FlatVariable::new(0),
+                    LinComb::summand(2, FlatVariable::one()),
+                )],
+                source_map: Default::default(),
+            },
+        };
+        let instance = Instance::new(&program);
+
+        assert_eq!(
+            instance.compute(&[Bn128Field::from(3)]),
+            Err(STATUS_UNSATISFIED_CONSTRAINT)
+        );
+        assert!(instance.compute(&[Bn128Field::from(2)]).is_ok());
+
+        // the modulus is not a valid input
+        let size = instance.get("field_size") as usize;
+        let mut modulus = (Bn128Field::max_value().to_biguint() + 1u32).to_bytes_le();
+        modulus.resize(size, 0);
+        instance
+            .memory
+            .set(instance.get("input_offset") as u32, &modulus)
+            .unwrap();
+        assert_eq!(instance.get("compute"), STATUS_INVALID_INPUT);
+    }
+}
@@ -0,0 +1,903 @@
+//! The field arithmetic and the solvers of the witness calculator, written in WebAssembly
+//!
+//! Field elements are stored in memory in canonical form, as little-endian 32-bit limbs.
+//! Multiplication goes through the Montgomery form, and inversion uses Fermat's little theorem.
+//! The solvers compute the same values as `ir::Interpreter::execute_solver`.
+
+use super::encoder::{Code, FuncType, Module, ValType};
+use num_bigint::BigUint;
+
+/// The field the module computes in
+pub struct FieldParameters {
+    /// The number of 32-bit limbs of an element
+    pub limbs: u32,
+    pub modulus: Vec<u32>,
+    /// `-modulus^-1 mod 2^32`
+    pub inv: u32,
+}
+
+impl FieldParameters {
+    pub fn new(modulus: &BigUint) -> Self {
+        let limbs = (modulus.bits() as u32 + 31) / 32;
+        let modulus = to_limbs(modulus, limbs);
+
+        // Newton iteration for the inverse of the lowest limb modulo 2^32
+        let mut inv = 1u32;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(modulus[0].wrapping_mul(inv)));
+        }
+
+        FieldParameters {
+            limbs,
+            modulus,
+            inv: inv.wrapping_neg(),
+        }
+    }
+
+    /// The size of an element in bytes
+    pub fn size(&self) -> u32 {
+        4 * self.limbs
+    }
+}
+
+/// Returns the `limbs` little-endian 32-bit limbs of `value`
+pub fn to_limbs(value: &BigUint, limbs: u32) -> Vec<u32> {
+    let mut bytes = value.to_bytes_le();
+    bytes.resize(4 * limbs as usize, 0);
+    bytes
+        .chunks(4)
+        .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+        .collect()
+}
+
+/// The addresses of the elements used by the runtime
+pub struct Layout {
+    pub modulus: u32,
+    /// `R^2 mod modulus`, with `R = 2^(32 * limbs)`
+    pub r2: u32,
+    pub one: u32,
+    /// `modulus - 2`, the exponent used for inversion
+    pub exponent: u32,
+    /// The number of bits of `exponent`
+    pub exponent_bits: u32,
+    /// Scratch elements, owned by a single runtime function each
+    pub mul_tmp: u32,
+    pub div_tmp: u32,
+    pub inverse_acc: u32,
+    pub bits_num: u32,
+    pub bits_pow: u32,
+    /// Scratch elements for the solvers
+    pub t1: u32,
+    pub t2: u32,
+}
+
+/// The indices of the runtime functions, which all take pointers to elements
+pub struct Runtime {
+    /// `(a, b, r) -> carry` computes `r = a + b mod 2^(32 * limbs)`
+    pub add_raw: u32,
+    /// `(a, b, r) -> borrow` computes `r = a - b mod 2^(32 * limbs)`
+    pub sub_raw: u32,
+    /// `(a, b) -> a >= b`
+    pub gte: u32,
+    /// `(a, b) -> a == b`
+    pub eq: u32,
+    /// `(a) -> a == 0`
+    pub is_zero: u32,
+    /// `(src, dst)`
+    pub copy: u32,
+    /// `(dst)`
+    pub zero: u32,
+    /// `(dst)`
+    pub one: u32,
+    /// `(a, b, r)`
+    pub add: u32,
+    /// `(a, b, r)`
+    pub sub: u32,
+    /// `(a, b, r)` computes `r = a * b * R^-1`
+    pub mont_mul: u32,
+    /// `(a, b, r)`
+    pub mul: u32,
+    /// `(a, r)`
+    pub inverse: u32,
+    /// `(a, b, r)`
+    pub div: u32,
+    /// The solvers take pointers to their consecutive inputs and outputs and return `0` on
+    /// success
+    pub condition_eq: u32,
+    /// `(inputs, outputs, bit_width) -> status`
+    pub bits: u32,
+    pub div_solver: u32,
+    pub euclidean_div: u32,
+    pub xor: u32,
+    pub or: u32,
+    pub sha_and_xor_and_xor_and: u32,
+    pub sha_ch: u32,
+    pub gt: u32,
+    pub select: u32,
+}
+
+const MASK: i64 = 0xffff_ffff;
+
+fn ty(params: usize, results: usize) -> FuncType {
+    FuncType {
+        params: vec![ValType::I32; params],
+        results: vec![ValType::I32; results],
+    }
+}
+
+impl Runtime {
+    pub fn new(module: &mut Module, field: &FieldParameters, layout: &Layout) -> Self {
+        let runtime = Runtime {
+            add_raw: module.declare(ty(3, 1)),
+            sub_raw: module.declare(ty(3, 1)),
+            gte: module.declare(ty(2, 1)),
+            eq: module.declare(ty(2, 1)),
+            is_zero: module.declare(ty(1, 1)),
+            copy: module.declare(ty(2, 0)),
+            zero: module.declare(ty(1, 0)),
+            one: module.declare(ty(1, 0)),
+            add: module.declare(ty(3, 0)),
+            sub: module.declare(ty(3, 0)),
+            mont_mul: module.declare(ty(3, 0)),
+            mul: module.declare(ty(3, 0)),
+            inverse: module.declare(ty(2, 0)),
+            div: module.declare(ty(3, 0)),
+            condition_eq: module.declare(ty(2, 1)),
+            bits: module.declare(ty(3, 1)),
+            div_solver: module.declare(ty(2, 1)),
+            euclidean_div: module.declare(ty(2, 1)),
+            xor: module.declare(ty(2, 1)),
+            or: module.declare(ty(2, 1)),
+            sha_and_xor_and_xor_and: module.declare(ty(2, 1)),
+            sha_ch: module.declare(ty(2, 1)),
+            gt: module.declare(ty(2, 1)),
+            select: module.declare(ty(3, 1)),
+        };
+
+        module.define(runtime.add_raw, runtime.add_raw_code(field));
+        module.define(runtime.sub_raw, runtime.sub_raw_code(field));
+        module.define(runtime.gte, runtime.gte_code(field));
+        module.define(runtime.eq, runtime.eq_code(field));
+        module.define(runtime.is_zero, runtime.is_zero_code(field));
+        module.define(runtime.copy, runtime.copy_code(field));
+        module.define(runtime.zero, runtime.zero_code(field));
+        module.define(runtime.one, runtime.one_code(layout));
+        module.define(runtime.add, runtime.add_code(layout));
+        module.define(runtime.sub, runtime.sub_code(layout));
+        module.define(runtime.mont_mul, runtime.mont_mul_code(field, layout));
+        module.define(runtime.mul, runtime.mul_code(layout));
+        module.define(runtime.inverse, runtime.inverse_code(layout));
+        module.define(runtime.div, runtime.div_code(layout));
+        module.define(runtime.condition_eq, runtime.condition_eq_code(field));
+        module.define(runtime.bits, runtime.bits_code(field, layout));
+        module.define(runtime.div_solver, runtime.div_solver_code(field));
+        module.define(runtime.euclidean_div, runtime.euclidean_div_code(field));
+        module.define(runtime.xor, runtime.xor_code(field, layout));
+        module.define(runtime.or, runtime.or_code(field, layout));
+        module.define(
+            runtime.sha_and_xor_and_xor_and,
+            runtime.sha_and_xor_and_xor_and_code(field, layout),
+        );
+        module.define(runtime.sha_ch, runtime.sha_ch_code(field, layout));
+        module.define(runtime.gt, runtime.gt_code(field));
+        module.define(runtime.select, runtime.select_code(field));
+
+        runtime
+    }
+
+    fn add_raw_code(&self, field: &FieldParameters) -> Code {
+        let (a, b, r) = (0, 1, 2);
+        let mut code = Code::default();
+        let carry = code.local(3, ValType::I64);
+        let t = code.local(3, ValType::I64);
+
+        for i in 0..field.limbs {
+            code.local_get(r)
+                .local_get(a)
+                .i64_load32_u(4 * i)
+                .local_get(b)
+                .i64_load32_u(4 * i)
+                .i64_add()
+                .local_get(carry)
+                .i64_add()
+                .local_tee(t)
+                .i64_store32(4 * i)
+                .local_get(t)
+                .i64_const(32)
+                .i64_shr_u()
+                .local_set(carry);
+        }
+
+        code.local_get(carry).i32_wrap_i64();
+        code
+    }
+
+    fn sub_raw_code(&self, field: &FieldParameters) -> Code {
+        let (a, b, r) = (0, 1, 2);
+        let mut code = Code::default();
+        let borrow = code.local(3, ValType::I64);
+        let t = code.local(3, ValType::I64);
+
+        for i in 0..field.limbs {
+            code.local_get(r)
+                .local_get(a)
+                .i64_load32_u(4 * i)
+                .local_get(b)
+                .i64_load32_u(4 * i)
+                .i64_sub()
+                .local_get(borrow)
+                .i64_sub()
+                .local_tee(t)
+                .i64_store32(4 * i)
+                // the difference is negative exactly when there is a borrow
+                .local_get(t)
+                .i64_const(63)
+                .i64_shr_u()
+                .local_set(borrow);
+        }
+
+        code.local_get(borrow).i32_wrap_i64();
+        code
+    }
+
+    fn gte_code(&self, field: &FieldParameters) -> Code {
+        let (a, b) = (0, 1);
+        let mut code = Code::default();
+        let x = code.local(2, ValType::I64);
+        let y = code.local(2, ValType::I64);
+
+        // compare from the most significant limb
+        for i in (0..field.limbs).rev() {
+            code.local_get(a)
+                .i64_load32_u(4 * i)
+                .local_set(x)
+                .local_get(b)
+                .i64_load32_u(4 * i)
+                .local_set(y)
+                .local_get(x)
+                .local_get(y)
+                .i64_ne()
+                .if_()
+                .local_get(x)
+                .local_get(y)
+                .i64_gt_u()
+                .return_()
+                .end();
+        }
+
+        code.i32_const(1);
+        code
+    }
+
+    fn eq_code(&self, field: &FieldParameters) -> Code {
+        let (a, b) = (0, 1);
+        let mut code = Code::default();
+
+        for i in 0..field.limbs {
+            code.local_get(a)
+                .i32_load(4 * i)
+                .local_get(b)
+                .i32_load(4 * i)
+                .i32_ne()
+                .if_()
+                .i32_const(0)
+                .return_()
+                .end();
+        }
+
+        code.i32_const(1);
+        code
+    }
+
+    fn is_zero_code(&self, field: &FieldParameters) -> Code {
+        let a = 0;
+        let mut code = Code::default();
+
+        code.i32_const(0);
+        for i in 0..field.limbs {
+            code.local_get(a).i32_load(4 * i).i32_or();
+        }
+        code.i32_eqz();
+        code
+    }
+
+    fn copy_code(&self, field: &FieldParameters) -> Code {
+        let (src, dst) = (0, 1);
+        let mut code = Code::default();
+
+        for i in 0..field.limbs {
+            code.local_get(dst)
+                .local_get(src)
+                .i32_load(4 * i)
+                .i32_store(4 * i);
+        }
+        code
+    }
+
+    fn zero_code(&self, field: &FieldParameters) -> Code {
+        let dst = 0;
+        let mut code = Code::default();
+
+        for i in 0..field.limbs {
+            code.local_get(dst).i32_const(0).i32_store(4 * i);
+        }
+        code
+    }
+
+    fn one_code(&self, layout: &Layout) -> Code {
+        let dst = 0;
+        let mut code = Code::default();
+
+        code.i32_const(layout.one as i32)
+            .local_get(dst)
+            .call(self.copy);
+        code
+    }
+
+    fn add_code(&self, layout: &Layout) -> Code {
+        let (a, b, r) = (0, 1, 2);
+        let mut code = Code::default();
+
+        // the sum is smaller than `2 * modulus`, subtract `modulus` once if needed
+        code.local_get(a)
+            .local_get(b)
+            .local_get(r)
+            .call(self.add_raw)
+            .local_get(r)
+            .i32_const(layout.modulus as i32)
+            .call(self.gte)
+            .i32_or()
+            .if_()
+            .local_get(r)
+            .i32_const(layout.modulus as i32)
+            .local_get(r)
+            .call(self.sub_raw)
+            .drop()
+            .end();
+        code
+    }
+
+    fn sub_code(&self, layout: &Layout) -> Code {
+        let (a, b, r) = (0, 1, 2);
+        let mut code = Code::default();
+
+        code.local_get(a)
+            .local_get(b)
+            .local_get(r)
+            .call(self.sub_raw)
+            .if_()
+            .local_get(r)
+            .i32_const(layout.modulus as i32)
+            .local_get(r)
+            .call(self.add_raw)
+            .drop()
+            .end();
+        code
+    }
+
+    // Montgomery multiplication with the CIOS method of Koç, Acar and Kaliski
+    fn mont_mul_code(&self, field: &FieldParameters, layout: &Layout) -> Code {
+        let (a, b, r) = (0, 1, 2);
+        let n = field.limbs as usize;
+        let mut code = Code::default();
+        let t: Vec<_> = (0..n + 2).map(|_| code.local(3, ValType::I64)).collect();
+        let c = code.local(3, ValType::I64);
+        let m = code.local(3, ValType::I64);
+        let bi = code.local(3, ValType::I64);
+        let u = code.local(3, ValType::I64);
+
+        // u = ...; target = u mod 2^32; c = u / 2^32
+        let split = |code: &mut Code, target: u32| {
+            code.local_tee(u)
+                .i64_const(MASK)
+                .i64_and()
+                .local_set(target)
+                .local_get(u)
+                .i64_const(32)
+                .i64_shr_u()
+                .local_set(c);
+        };
+
+        for i in 0..n {
+            code.local_get(b).i64_load32_u(4 * i as u32).local_set(bi);
+            code.i64_const(0).local_set(c);
+
+            for (j, &tj) in t[..n].iter().enumerate() {
+                code.local_get(tj)
+                    .local_get(a)
+                    .i64_load32_u(4 * j as u32)
+                    .local_get(bi)
+                    .i64_mul()
+                    .i64_add()
+                    .local_get(c)
+                    .i64_add();
+                split(&mut code, tj);
+            }
+            code.local_get(t[n]).local_get(c).i64_add();
+            split(&mut code, t[n]);
+            code.local_get(c).local_set(t[n + 1]);
+
+            code.local_get(t[0])
+                .i64_const(field.inv as i64)
+                .i64_mul()
+                .i64_const(MASK)
+                .i64_and()
+                .local_set(m);
+
+            code.local_get(t[0])
+                .local_get(m)
+                .i64_const(field.modulus[0] as i64)
+                .i64_mul()
+                .i64_add()
+                .i64_const(32)
+                .i64_shr_u()
+                .local_set(c);
+
+            for (pair, &limb) in t[..n].windows(2).zip(&field.modulus[1..]) {
+                code.local_get(pair[1])
+                    .local_get(m)
+                    .i64_const(limb as i64)
+                    .i64_mul()
+                    .i64_add()
+                    .local_get(c)
+                    .i64_add();
+                split(&mut code, pair[0]);
+            }
+            code.local_get(t[n]).local_get(c).i64_add();
+            split(&mut code, t[n - 1]);
+            code.local_get(t[n + 1])
+                .local_get(c)
+                .i64_add()
+                .local_set(t[n]);
+        }
+
+        for (j, &tj) in t[..n].iter().enumerate() {
+            code.local_get(r).local_get(tj).i64_store32(4 * j as u32);
+        }
+
+        // the result is smaller than `2 * modulus`, subtract `modulus` once if needed
+        code.local_get(t[n])
+            .i64_eqz()
+            .i32_eqz()
+            .local_get(r)
+            .i32_const(layout.modulus as i32)
+            .call(self.gte)
+            .i32_or()
+            .if_()
+            .local_get(r)
+            .i32_const(layout.modulus as i32)
+            .local_get(r)
+            .call(self.sub_raw)
+            .drop()
+            .end();
+        code
+    }
+
+    fn mul_code(&self, layout: &Layout) -> Code {
+        let (a, b, r) = (0, 1, 2);
+        let mut code = Code::default();
+
+        // (a * b * R^-1) * R^2 * R^-1 = a * b
+        code.local_get(a)
+            .local_get(b)
+            .i32_const(layout.mul_tmp as i32)
+            .call(self.mont_mul)
+            .i32_const(layout.mul_tmp as i32)
+            .i32_const(layout.r2 as i32)
+            .local_get(r)
+            .call(self.mont_mul);
+        code
+    }
+
+    fn inverse_code(&self, layout: &Layout) -> Code {
+        let (a, r) = (0, 1);
+        let acc = layout.inverse_acc as i32;
+        let mut code = Code::default();
+        let i = code.local(2, ValType::I32);
+        let exponent = code.local(2, ValType::I32);
+
+        // a^(modulus - 2), square and multiply from the most significant bit of the exponent
+        code.i32_const(acc).call(self.one);
+        code.i32_const(layout.exponent as i32).local_set(exponent);
+        code.i32_const(layout.exponent_bits as i32 - 1).local_set(i);
+        code.block().loop_();
+        code.i32_const(acc)
+            .i32_const(acc)
+            .i32_const(acc)
+            .call(self.mul);
+        bit(&mut code, exponent, i);
+        code.if_()
+            .i32_const(acc)
+            .local_get(a)
+            .i32_const(acc)
+            .call(self.mul)
+            .end();
+        code.local_get(i)
+            .i32_eqz()
+            .br_if(1)
+            .local_get(i)
+            .i32_const(1)
+            .i32_sub()
+            .local_set(i)
+            .br(0);
+        code.end().end();
+
+        code.i32_const(acc).local_get(r).call(self.copy);
+        code
+    }
+
+    fn div_code(&self, layout: &Layout) -> Code {
+        let (a, b, r) = (0, 1, 2);
+        let mut code = Code::default();
+
+        code.local_get(b)
+            .i32_const(layout.div_tmp as i32)
+            .call(self.inverse)
+            .local_get(a)
+            .i32_const(layout.div_tmp as i32)
+            .local_get(r)
+            .call(self.mul);
+        code
+    }
+
+    fn condition_eq_code(&self, field: &FieldParameters) -> Code {
+        let (inputs, outputs) = (0, 1);
+        let mut code = Code::default();
+
+        code.local_get(inputs)
+            .call(self.is_zero)
+            .if_()
+            .local_get(outputs)
+            .call(self.zero);
+        element(&mut code, outputs, 1, field).call(self.one);
+        code.else_().local_get(outputs).call(self.one);
+        code.local_get(inputs);
+        element(&mut code, outputs, 1, field).call(self.inverse);
+        code.end().i32_const(0);
+        code
+    }
+
+    fn bits_code(&self, field: &FieldParameters, layout: &Layout) -> Code {
+        let (inputs, outputs, bit_width) = (0, 1, 2);
+        let num = layout.bits_num as i32;
+        let pow = layout.bits_pow as i32;
+        let mut code = Code::default();
+        let pow_pointer = code.local(3, ValType::I32);
+
+        // from the most significant bit, subtract `2^i` from the input whenever it is at least
+        // `2^i`. `i` is smaller than the number of bits of the modulus, so `2^i` is canonical.
+        code.local_get(inputs).i32_const(num).call(self.copy);
+        code.i32_const(pow).local_set(pow_pointer);
+        code.block().loop_();
+        code.local_get(bit_width)
+            .i32_eqz()
+            .br_if(1)
+            .local_get(bit_width)
+            .i32_const(1)
+            .i32_sub()
+            .local_set(bit_width);
+        code.i32_const(pow).call(self.zero);
+        set_bit(&mut code, pow_pointer, bit_width);
+        code.i32_const(num)
+            .i32_const(pow)
+            .call(self.gte)
+            .if_()
+            .i32_const(num)
+            .i32_const(pow)
+            .i32_const(num)
+            .call(self.sub_raw)
+            .drop()
+            .local_get(outputs)
+            .call(self.one)
+            .else_()
+            .local_get(outputs)
+            .call(self.zero)
+            .end();
+        code.local_get(outputs)
+            .i32_const(field.size() as i32)
+            .i32_add()
+            .local_set(outputs)
+            .br(0);
+        code.end().end();
+        code.i32_const(0);
+        code
+    }
+
+    fn div_solver_code(&self, field: &FieldParameters) -> Code {
+        let (inputs, outputs) = (0, 1);
+        let mut code = Code::default();
+
+        element(&mut code, inputs, 1, field)
+            .call(self.is_zero)
+            .if_()
+            .i32_const(1)
+            .return_()
+            .end();
+        code.local_get(inputs);
+        element(&mut code, inputs, 1, field)
+            .local_get(outputs)
+            .call(self.div);
+        code.i32_const(0);
+        code
+    }
+
+    fn euclidean_div_code(&self, field: &FieldParameters) -> Code {
+        let (inputs, outputs) = (0, 1);
+        let mut code = Code::default();
+        let d = code.local(2, ValType::I32);
+        let rem = code.local(2, ValType::I32);
+        let i = code.local(2, ValType::I32);
+
+        element(&mut code, inputs, 1, field).local_set(d);
+        element(&mut code, outputs, 1, field).local_set(rem);
+
+        code.local_get(d)
+            .call(self.is_zero)
+            .if_()
+            .i32_const(1)
+            .return_()
+            .end();
+
+        // binary long division of the canonical values
+        code.local_get(outputs).call(self.zero);
+        code.local_get(rem).call(self.zero);
+        code.i32_const(32 * field.limbs as i32).local_set(i);
+        code.block().loop_();
+        code.local_get(i)
+            .i32_eqz()
+            .br_if(1)
+            .local_get(i)
+            .i32_const(1)
+            .i32_sub()
+            .local_set(i);
+
+        // rem = 2 * rem + bit i of the dividend
+        for j in (1..field.limbs).rev() {
+            code.local_get(rem)
+                .local_get(rem)
+                .i32_load(4 * j)
+                .i32_const(1)
+                .i32_shl()
+                .local_get(rem)
+                .i32_load(4 * (j - 1))
+                .i32_const(31)
+                .i32_shr_u()
+                .i32_or()
+                .i32_store(4 * j);
+        }
+        code.local_get(rem)
+            .local_get(rem)
+            .i32_load(0)
+            .i32_const(1)
+            .i32_shl();
+        bit(&mut code, inputs, i);
+        code.i32_or().i32_store(0);
+
+        code.local_get(rem)
+            .local_get(d)
+            .call(self.gte)
+            .if_()
+            .local_get(rem)
+            .local_get(d)
+            .local_get(rem)
+            .call(self.sub_raw)
+            .drop();
+        set_bit(&mut code, outputs, i);
+        code.end().br(0);
+        code.end().end();
+        code.i32_const(0);
+        code
+    }
+
+    fn xor_code(&self, field: &FieldParameters, layout: &Layout) -> Code {
+        let (inputs, outputs) = (0, 1);
+        let t1 = layout.t1 as i32;
+        let mut code = Code::default();
+
+        // x + y - 2 * x * y
+        code.local_get(inputs);
+        element(&mut code, inputs, 1, field)
+            .i32_const(t1)
+            .call(self.mul)
+            .i32_const(t1)
+            .i32_const(t1)
+            .i32_const(t1)
+            .call(self.add)
+            .local_get(inputs);
+        element(&mut code, inputs, 1, field)
+            .local_get(outputs)
+            .call(self.add)
+            .local_get(outputs)
+            .i32_const(t1)
+            .local_get(outputs)
+            .call(self.sub)
+            .i32_const(0);
+        code
+    }
+
+    fn or_code(&self, field: &FieldParameters, layout: &Layout) -> Code {
+        let (inputs, outputs) = (0, 1);
+        let t1 = layout.t1 as i32;
+        let mut code = Code::default();
+
+        // x + y - x * y
+        code.local_get(inputs);
+        element(&mut code, inputs, 1, field)
+            .i32_const(t1)
+            .call(self.mul)
+            .local_get(inputs);
+        element(&mut code, inputs, 1, field)
+            .local_get(outputs)
+            .call(self.add)
+            .local_get(outputs)
+            .i32_const(t1)
+            .local_get(outputs)
+            .call(self.sub)
+            .i32_const(0);
+        code
+    }
+
+    fn sha_and_xor_and_xor_and_code(&self, field: &FieldParameters, layout: &Layout) -> Code {
+        let (inputs, outputs) = (0, 1);
+        let (t1, t2) = (layout.t1 as i32, layout.t2 as i32);
+        let mut code = Code::default();
+
+        // b * c - (2 * b * c - b - c) * a
+        element(&mut code, inputs, 1, field);
+        element(&mut code, inputs, 2, field)
+            .i32_const(t1)
+            .call(self.mul)
+            .i32_const(t1)
+            .i32_const(t1)
+            .i32_const(t2)
+            .call(self.add)
+            .i32_const(t2);
+        element(&mut code, inputs, 1, field)
+            .i32_const(t2)
+            .call(self.sub)
+            .i32_const(t2);
+        element(&mut code, inputs, 2, field)
+            .i32_const(t2)
+            .call(self.sub)
+            .i32_const(t2)
+            .local_get(inputs)
+            .i32_const(t2)
+            .call(self.mul)
+            .i32_const(t1)
+            .i32_const(t2)
+            .local_get(outputs)
+            .call(self.sub)
+            .i32_const(0);
+        code
+    }
+
+    fn sha_ch_code(&self, field: &FieldParameters, layout: &Layout) -> Code {
+        let (inputs, outputs) = (0, 1);
+        let t1 = layout.t1 as i32;
+        let mut code = Code::default();
+
+        // a * (b - c) + c
+        element(&mut code, inputs, 1, field);
+        element(&mut code, inputs, 2, field)
+            .i32_const(t1)
+            .call(self.sub)
+            .local_get(inputs)
+            .i32_const(t1)
+            .i32_const(t1)
+            .call(self.mul)
+            .i32_const(t1);
+        element(&mut code, inputs, 2, field)
+            .local_get(outputs)
+            .call(self.add)
+            .i32_const(0);
+        code
+    }
+
+    fn gt_code(&self, field: &FieldParameters) -> Code {
+        let (inputs, outputs) = (0, 1);
+        let mut code = Code::default();
+
+        element(&mut code, inputs, 1, field)
+            .local_get(inputs)
+            .call(self.gte)
+            .if_()
+            .local_get(outputs)
+            .call(self.zero)
+            .else_()
+            .local_get(outputs)
+            .call(self.one)
+            .end()
+            .i32_const(0);
+        code
+    }
+
+    fn select_code(&self, field: &FieldParameters) -> Code {
+        let (inputs, outputs, size) = (0, 1, 2);
+        let mut code = Code::default();
+        let index = code.local(3, ValType::I32);
+
+        // the index is the input after the `size` values
+        code.local_get(inputs)
+            .local_get(size)
+            .i32_const(field.size() as i32)
+            .i32_mul()
+            .i32_add()
+            .local_set(index);
+
+        // an index out of bounds selects zero, the lookup's constraints reject it
+        code.i32_const(0);
+        for i in 1..field.limbs {
+            code.local_get(index).i32_load(4 * i).i32_or();
+        }
+        code.i32_eqz()
+            .local_get(index)
+            .i32_load(0)
+            .local_get(size)
+            .i32_lt_u()
+            .i32_and()
+            .if_()
+            .local_get(inputs)
+            .local_get(index)
+            .i32_load(0)
+            .i32_const(field.size() as i32)
+            .i32_mul()
+            .i32_add()
+            .local_get(outputs)
+            .call(self.copy)
+            .else_()
+            .local_get(outputs)
+            .call(self.zero)
+            .end()
+            .i32_const(0);
+        code
+    }
+}
+
+/// Pushes the address of the element at `index` after the one `pointer` points to
+fn element<'a>(
+    code: &'a mut Code,
+    pointer: u32,
+    index: u32,
+    field: &FieldParameters,
+) -> &'a mut Code {
+    code.local_get(pointer)
+        .i32_const((index * field.size()) as i32)
+        .i32_add()
+}
+
+/// Pushes the address of the limb holding bit `i` of the element `pointer` points to
+fn limb_address(code: &mut Code, pointer: u32, i: u32) {
+    code.local_get(pointer)
+        .local_get(i)
+        .i32_const(5)
+        .i32_shr_u()
+        .i32_const(2)
+        .i32_shl()
+        .i32_add();
+}
+
+/// Pushes bit `i` of the element `pointer` points to
+fn bit(code: &mut Code, pointer: u32, i: u32) {
+    limb_address(code, pointer, i);
+    code.i32_load(0)
+        .local_get(i)
+        .i32_const(31)
+        .i32_and()
+        .i32_shr_u()
+        .i32_const(1)
+        .i32_and();
+}
+
+/// Sets bit `i` of the element `pointer` points to
+fn set_bit(code: &mut Code, pointer: u32, i: u32) {
+    limb_address(code, pointer, i);
+    limb_address(code, pointer, i);
+    code.i32_load(0)
+        .i32_const(1)
+        .local_get(i)
+        .i32_const(31)
+        .i32_and()
+        .i32_shl()
+        .i32_or()
+        .i32_store(0);
+}
@@ -0,0 +1,660 @@
+//! Module emitting a standalone Rust crate which computes the witness of a program
+//!
+//! The emitted crate only depends on `zokrates_field`, taken from the location given by a
+//! `FieldDependency`. Its `compute` function runs the statements of the program in order, exactly
+//! like `ir::Interpreter::execute`.
+//!
+//! @file witness_generator.rs
+//! @date 2020
+
+use crate::flat_absy::FlatVariable;
+use crate::ir::{LinComb, Prog, QuadComb, Statement};
+use crate::solvers::Solver;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use zokrates_field::Field;
+
+/// The location of the `zokrates_field` crate the emitted crate depends on
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldDependency {
+    /// A tag of a git repository containing the crate
+    Git { repository: String, tag: String },
+    /// A local directory containing the crate
+    Path(PathBuf),
+}
+
+impl FieldDependency {
+    fn to_toml(&self) -> String {
+        match self {
+            FieldDependency::Git { repository, tag } => format!(
+                "{{ git = {}, tag = {} }}",
+                toml_string(repository),
+                toml_string(tag)
+            ),
+            FieldDependency::Path(path) => {
+                format!("{{ path = {} }}", toml_string(&path.to_string_lossy()))
+            }
+        }
+    }
+}
+
+/// Quotes `s` as a TOML basic string
+fn toml_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// The sources of a witness generator crate
+#[derive(Debug, Clone, PartialEq)]
+pub struct WitnessGenerator {
+    pub cargo_toml: String,
+    pub lib: String,
+}
+
+impl WitnessGenerator {
+    pub fn new<T: Field>(name: &str, program: &Prog<T>, field: &FieldDependency) -> Self {
+        WitnessGenerator {
+            cargo_toml: format!(
+                r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2018"
+
+[dependencies]
+zokrates_field = {}
+"#,
+                name,
+                field.to_toml()
+            ),
+            lib: Emitter::new(program).emit(),
+        }
+    }
+
+    /// Writes the crate to the directory at `path`, creating it if needed
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        fs::create_dir_all(path.join("src"))?;
+        File::create(path.join("Cargo.toml"))?.write_all(self.cargo_toml.as_bytes())?;
+        File::create(path.join("src").join("lib.rs"))?.write_all(self.lib.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// The name of the `zokrates_field` type for the field `T`
+fn field_type<T: Field>() -> &'static str {
+    match T::name() {
+        "bn128" => "Bn128Field",
+        "bls12_381" => "Bls12_381Field",
+        "bls12_377" => "Bls12_377Field",
+        "bw6_761" => "Bw6_761Field",
+        name => unreachable!("unknown field {}", name),
+    }
+}
+
+struct Emitter<'ast, T> {
+    program: &'ast Prog<T>,
+    /// The number of variables which are not outputs, including `~one`
+    variable_count: usize,
+    /// The constants used in the program, emitted as the `CONSTANTS` table
+    constants: Vec<T>,
+    constant_indices: HashMap<T, usize>,
+    /// The variables assigned so far
+    assigned: HashSet<FlatVariable>,
+    /// Whether a directive was emitted so far, in which case a solver may have failed
+    has_directive: bool,
+}
+
+impl<'ast, T: Field> Emitter<'ast, T> {
+    fn new(program: &'ast Prog<T>) -> Self {
+        Emitter {
+            program,
+            variable_count: variable_count(program),
+            constants: vec![],
+            constant_indices: HashMap::new(),
+            assigned: HashSet::new(),
+            has_directive: false,
+        }
+    }
+
+    /// The index of `v` in the witness vector
+    fn index(&self, v: &FlatVariable) -> usize {
+        match v.is_output() {
+            true => self.variable_count + v.index(),
+            false => v.index(),
+        }
+    }
+
+    fn constant(&mut self, value: &T) -> usize {
+        let constants = &mut self.constants;
+        *self
+            .constant_indices
+            .entry(value.clone())
+            .or_insert_with(|| {
+                constants.push(value.clone());
+                constants.len() - 1
+            })
+    }
+
+    fn linear_combination(&mut self, l: &LinComb<T>) -> String {
+        if l.0.is_empty() {
+            return String::from("Fr::from(0)");
+        }
+
+        l.0.iter()
+            .map(|(variable, coefficient)| match *coefficient == T::one() {
+                true => format!("w[{}].clone()", self.index(variable)),
+                false => format!(
+                    "w[{}].clone() * &c[{}]",
+                    self.index(variable),
+                    self.constant(coefficient)
+                ),
+            })
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+
+    fn quadratic_combination(&mut self, q: &QuadComb<T>) -> String {
+        format!(
+            "({}) * ({})",
+            self.linear_combination(&q.left),
+            self.linear_combination(&q.right)
+        )
+    }
+
+    fn statement(&mut self, statement: &Statement<T>) -> Option<String> {
+        match statement {
+            Statement::Constraint(quad, lin, metadata) => {
+                let assignee = match lin.0.as_slice() {
+                    [(variable, coefficient)]
+                        if *coefficient == T::one() && !self.assigned.contains(variable) =>
+                    {
+                        Some(variable)
+                    }
+                    _ => None,
+                };
+
+                match assignee {
+                    Some(variable) => {
+                        self.assigned.insert(variable.clone());
+                        Some(format!(
+                            "w[{}] = {};",
+                            self.index(variable),
+                            self.quadratic_combination(quad)
+                        ))
+                    }
+                    None => Some(format!(
+                        "runtime::check({}, {}, {}, {})?;",
+                        self.quadratic_combination(quad),
+                        self.linear_combination(lin),
+                        match self.has_directive {
+                            true => "solver_failed",
+                            false => "false",
+                        },
+                        match metadata {
+                            Some(metadata) => format!("Some({:?})", metadata.to_string()),
+                            None => String::from("None"),
+                        }
+                    )),
+                }
+            }
+            Statement::Directive(d) => {
+                self.has_directive = true;
+                self.assigned.extend(d.outputs.iter().cloned());
+
+                let inputs = d
+                    .inputs
+                    .iter()
+                    .map(|i| self.quadratic_combination(i))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let solver = match d.solver {
+                    Solver::ConditionEq => format!("condition_eq(&[{}])", inputs),
+                    Solver::Bits(bit_width) => format!("bits(&[{}], {})", inputs, bit_width),
+                    Solver::Div => format!("div(&[{}])", inputs),
+                    Solver::EuclideanDiv => format!("euclidean_div(&[{}])", inputs),
+                    Solver::Xor => format!("xor(&[{}])", inputs),
+                    Solver::Or => format!("or(&[{}])", inputs),
+                    Solver::ShaAndXorAndXorAnd => format!("sha_and_xor_and_xor_and(&[{}])", inputs),
+                    Solver::ShaCh => format!("sha_ch(&[{}])", inputs),
+                    Solver::Gt => format!("gt(&[{}])", inputs),
+                    Solver::Select(size) => format!("select(&[{}], {})", inputs, size),
+                };
+
+                Some(format!(
+                    "runtime::set(runtime::{}, &mut w, &[{}], &mut solver_failed);",
+                    solver,
+                    d.outputs
+                        .iter()
+                        .map(|o| self.index(o).to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            }
+            // `log` statements are only printed by the interpreter in verbose mode
+            Statement::Log(..) => None,
+        }
+    }
+
+    fn emit(mut self) -> String {
+        let program = self.program;
+        let main = &program.main;
+
+        let mut body = vec![String::from("w[0] = Fr::from(1);")];
+        self.assigned.insert(FlatVariable::one());
+
+        for (i, argument) in main.arguments.iter().enumerate() {
+            self.assigned.insert(argument.clone());
+            body.push(format!(
+                "w[{}] = inputs[{}].clone();",
+                self.index(argument),
+                i
+            ));
+        }
+
+        for statement in &main.statements {
+            body.extend(self.statement(statement));
+        }
+
+        let mut preamble = vec![];
+        if !self.constants.is_empty() {
+            preamble.push(String::from(
+                "let c: Vec<Fr> = CONSTANTS.iter().map(|s| Fr::try_from_dec_str(s).unwrap()).collect();",
+            ));
+        }
+        preamble.push(String::from(
+            "let mut w = vec![Fr::from(0); VARIABLE_COUNT + OUTPUT_COUNT];",
+        ));
+        if self.has_directive {
+            preamble.push(String::from("// whether a solver failed so far"));
+            preamble.push(String::from("let mut solver_failed = false;"));
+        }
+
+        format!(
+            r#"//! Witness generator generated by `zokrates export-witness-generator`
+
+use std::fmt;
+use zokrates_field::Field;
+
+pub use zokrates_field::{field} as Fr;
+
+/// The number of arguments of the program
+pub const INPUT_COUNT: usize = {input_count};
+/// The number of variables of the program which are not outputs, including `~one`
+pub const VARIABLE_COUNT: usize = {variable_count};
+/// The number of outputs of the program
+pub const OUTPUT_COUNT: usize = {output_count};
+
+const CONSTANTS: &[&str] = &[{constants}];
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Error {{
+    UnsatisfiedConstraint {{
+        left: Fr,
+        right: Fr,
+        message: Option<&'static str>,
+    }},
+    Solver,
+    WrongInputCount {{
+        expected: usize,
+        received: usize,
+    }},
+}}
+
+impl fmt::Display for Error {{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {{
+        match self {{
+            Error::UnsatisfiedConstraint {{
+                left,
+                right,
+                message,
+            }} => match message {{
+                Some(message) => write!(f, "{{}}", message),
+                None => write!(
+                    f,
+                    "Expected {{}} to equal {{}}",
+                    left.to_dec_string(),
+                    right.to_dec_string()
+                ),
+            }},
+            Error::Solver => write!(f, ""),
+            Error::WrongInputCount {{ expected, received }} => write!(
+                f,
+                "Program takes {{}} input{{}} but was passed {{}} value{{}}",
+                expected,
+                if *expected == 1 {{ "" }} else {{ "s" }},
+                received,
+                if *received == 1 {{ "" }} else {{ "s" }}
+            ),
+        }}
+    }}
+}}
+
+/// Computes the witness of the program for `inputs`
+///
+/// The value of `~one` is at index `0`, the value of `_i` at index `i + 1` and the value of
+/// `~out_i` at index `VARIABLE_COUNT + i`
+pub fn compute(inputs: &[Fr]) -> Result<Vec<Fr>, Error> {{
+    if inputs.len() != INPUT_COUNT {{
+        return Err(Error::WrongInputCount {{
+            expected: INPUT_COUNT,
+            received: inputs.len(),
+        }});
+    }}
+
+{body}
+
+    Ok(w)
+}}
+{runtime}"#,
+            field = field_type::<T>(),
+            input_count = main.arguments.len(),
+            variable_count = self.variable_count,
+            output_count = main.returns.len(),
+            constants = self
+                .constants
+                .iter()
+                .map(|c| format!("\"{}\"", c.to_dec_string()))
+                .collect::<Vec<_>>()
+                .join(", "),
+            body = preamble
+                .into_iter()
+                .chain(body)
+                .map(|line| format!("    {}", line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            runtime = RUNTIME
+        )
+    }
+}
+
+/// The number of variables of `program` which are not outputs, including `~one`
+pub(crate) fn variable_count<T>(program: &Prog<T>) -> usize {
+    let mut variables: Vec<&FlatVariable> = program
+        .main
+        .arguments
+        .iter()
+        .chain(program.main.returns.iter())
+        .collect();
+
+    for statement in &program.main.statements {
+        match statement {
+            Statement::Constraint(quad, lin, _) => {
+                variables.extend(quad_variables(quad));
+                variables.extend(lin.0.iter().map(|(v, _)| v));
+            }
+            Statement::Directive(d) => {
+                variables.extend(d.inputs.iter().flat_map(quad_variables));
+                variables.extend(d.outputs.iter());
+            }
+            Statement::Log(_, expressions) => {
+                variables.extend(expressions.iter().flat_map(quad_variables));
+            }
+        }
+    }
+
+    variables
+        .into_iter()
+        .filter(|v| !v.is_output())
+        .map(|v| v.index() + 1)
+        .max()
+        .unwrap_or(0)
+        .max(1)
+}
+
+fn quad_variables<T>(q: &QuadComb<T>) -> impl Iterator<Item = &FlatVariable> {
+    q.left.0.iter().chain(q.right.0.iter()).map(|(v, _)| v)
+}
+
+/// The functions called by `compute`, mirroring `ir::Interpreter`
+const RUNTIME: &str = r#"
+mod runtime {
+    #![allow(dead_code)]
+
+    use super::{Error, Fr};
+    use std::convert::TryFrom;
+    use zokrates_field::{Field, Pow};
+
+    pub fn check(
+        left: Fr,
+        right: Fr,
+        solver_failed: bool,
+        message: Option<&'static str>,
+    ) -> Result<(), Error> {
+        if left == right {
+            return Ok(());
+        }
+
+        match solver_failed {
+            true => Err(Error::Solver),
+            false => Err(Error::UnsatisfiedConstraint {
+                left,
+                right,
+                message,
+            }),
+        }
+    }
+
+    // the directive may be in a branch which is not taken, in which case its constraints are not
+    // enforced: only fail if one is unsatisfied later on
+    pub fn set(
+        res: Result<Vec<Fr>, ()>,
+        w: &mut [Fr],
+        outputs: &[usize],
+        solver_failed: &mut bool,
+    ) {
+        match res {
+            Ok(res) => {
+                for (o, v) in outputs.iter().zip(res) {
+                    w[*o] = v;
+                }
+            }
+            Err(()) => {
+                for o in outputs {
+                    w[*o] = Fr::from(0);
+                }
+                *solver_failed = true;
+            }
+        }
+    }
+
+    pub fn condition_eq(inputs: &[Fr]) -> Result<Vec<Fr>, ()> {
+        Ok(match inputs[0] == Fr::from(0) {
+            true => vec![Fr::from(0), Fr::from(1)],
+            false => vec![Fr::from(1), Fr::from(1) / inputs[0].clone()],
+        })
+    }
+
+    pub fn bits(inputs: &[Fr], bit_width: usize) -> Result<Vec<Fr>, ()> {
+        let mut num = inputs[0].clone();
+        let mut res = vec![];
+
+        for i in (0..bit_width).rev() {
+            if Fr::from(2).pow(i) <= num {
+                num = num - Fr::from(2).pow(i);
+                res.push(Fr::from(1));
+            } else {
+                res.push(Fr::from(0));
+            }
+        }
+        Ok(res)
+    }
+
+    pub fn xor(inputs: &[Fr]) -> Result<Vec<Fr>, ()> {
+        let x = inputs[0].clone();
+        let y = inputs[1].clone();
+
+        Ok(vec![x.clone() + y.clone() - Fr::from(2) * x * y])
+    }
+
+    pub fn or(inputs: &[Fr]) -> Result<Vec<Fr>, ()> {
+        let x = inputs[0].clone();
+        let y = inputs[1].clone();
+
+        Ok(vec![x.clone() + y.clone() - x * y])
+    }
+
+    // res = b * c - (2b * c - b - c) * (a)
+    pub fn sha_and_xor_and_xor_and(inputs: &[Fr]) -> Result<Vec<Fr>, ()> {
+        let a = inputs[0].clone();
+        let b = inputs[1].clone();
+        let c = inputs[2].clone();
+
+        Ok(vec![
+            b.clone() * c.clone() - (Fr::from(2) * b.clone() * c.clone() - b - c) * a,
+        ])
+    }
+
+    // res = a(b - c) + c
+    pub fn sha_ch(inputs: &[Fr]) -> Result<Vec<Fr>, ()> {
+        let a = inputs[0].clone();
+        let b = inputs[1].clone();
+        let c = inputs[2].clone();
+
+        Ok(vec![a * (b - c.clone()) + c])
+    }
+
+    pub fn div(inputs: &[Fr]) -> Result<Vec<Fr>, ()> {
+        if inputs[1] == Fr::from(0) {
+            return Err(());
+        }
+
+        Ok(vec![inputs[0].clone() / inputs[1].clone()])
+    }
+
+    pub fn euclidean_div(inputs: &[Fr]) -> Result<Vec<Fr>, ()> {
+        if inputs[1] == Fr::from(0) {
+            return Err(());
+        }
+
+        let n = inputs[0].to_biguint();
+        let d = inputs[1].to_biguint();
+
+        let q = n.clone() / d.clone();
+        let r = n - q.clone() * d;
+
+        Ok(vec![Fr::try_from(q).unwrap(), Fr::try_from(r).unwrap()])
+    }
+
+    pub fn gt(inputs: &[Fr]) -> Result<Vec<Fr>, ()> {
+        Ok(vec![match inputs[0] > inputs[1] {
+            true => Fr::from(1),
+            false => Fr::from(0),
+        }])
+    }
+
+    pub fn select(inputs: &[Fr], size: usize) -> Result<Vec<Fr>, ()> {
+        Ok(vec![inputs[size]
+            .to_dec_string()
+            .parse::<usize>()
+            .ok()
+            .filter(|index| *index < size)
+            .map(|index| inputs[index].clone())
+            .unwrap_or_else(|| Fr::from(0))])
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{Directive, Function};
+    use zokrates_field::Bn128Field;
+
+    #[test]
+    fn emit() {
+        // def main(_0) -> (1):
+        //     # _1, _2 = ConditionEq(_0)
+        //     ~out_0 = _1 * ~one
+        //     _0 * _2 == 5 * _1
+
+        let program: Prog<Bn128Field> = Prog {
+            private: vec![true],
+            main: Function {
+                id: "main".to_string(),
+                arguments: vec![FlatVariable::new(0)],
+                returns: vec![FlatVariable::public(0)],
+                statements: vec![
+                    Statement::Directive(Directive {
+                        inputs: vec![FlatVariable::new(0).into()],
+                        outputs: vec![FlatVariable::new(1), FlatVariable::new(2)],
+                        solver: Solver::ConditionEq,
+                    }),
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            FlatVariable::new(1).into(),
+                            FlatVariable::one().into(),
+                        ),
+                        FlatVariable::public(0),
+                    ),
+                    Statement::constraint(
+                        QuadComb::from_linear_combinations(
+                            FlatVariable::new(0).into(),
+                            FlatVariable::new(2).into(),
+                        ),
+                        LinComb::summand(5, FlatVariable::new(1)),
+                    ),
+                ],
+                source_map: Default::default(),
+            },
+        };
+
+        let lib = WitnessGenerator::new(
+            "witness_generator",
+            &program,
+            &FieldDependency::Path(PathBuf::from("zokrates_field")),
+        )
+        .lib;
+
+        assert!(lib.contains("pub use zokrates_field::Bn128Field as Fr;"));
+        assert!(lib.contains("pub const VARIABLE_COUNT: usize = 4;"));
+        assert!(lib.contains("pub const OUTPUT_COUNT: usize = 1;"));
+        assert!(lib.contains("const CONSTANTS: &[&str] = &[\"5\"];"));
+
+        let body = [
+            "    w[0] = Fr::from(1);",
+            "    w[1] = inputs[0].clone();",
+            "    runtime::set(runtime::condition_eq(&[(w[0].clone()) * (w[1].clone())]), &mut w, &[2, 3], &mut solver_failed);",
+            "    w[4] = (w[2].clone()) * (w[0].clone());",
+            "    runtime::check((w[1].clone()) * (w[3].clone()), w[2].clone() * &c[0], solver_failed, None)?;",
+        ]
+        .join("\n");
+
+        assert!(lib.contains(&body));
+    }
+
+    #[test]
+    fn field_dependency() {
+        let program: Prog<Bn128Field> = Prog {
+            private: vec![],
+            main: Function {
+                id: "main".to_string(),
+                arguments: vec![],
+                returns: vec![],
+                statements: vec![],
+                source_map: Default::default(),
+            },
+        };
+
+        let git = WitnessGenerator::new(
+            "witness_generator",
+            &program,
+            &FieldDependency::Git {
+                repository: String::from("https://github.com/JacobEberhardt/ZoKrates.git"),
+                tag: String::from("0.6.2"),
+            },
+        );
+        assert!(git.cargo_toml.contains(
+            "zokrates_field = { git = \"https://github.com/JacobEberhardt/ZoKrates.git\", tag = \"0.6.2\" }"
+        ));
+
+        let path = WitnessGenerator::new(
+            "witness_generator",
+            &program,
+            &FieldDependency::Path(PathBuf::from("C:\\ZoKrates\\zokrates_field")),
+        );
+        assert!(path
+            .cargo_toml
+            .contains("zokrates_field = { path = \"C:\\\\ZoKrates\\\\zokrates_field\" }"));
+    }
+}
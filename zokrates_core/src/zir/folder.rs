@@ -1,6 +1,6 @@
 // Generic walk through a typed AST. Not mutating in place
 
-use crate::zir::types::UBitwidth;
+use crate::zir::types::IntType;
 use crate::zir::*;
 use zokrates_field::Field;
 
@@ -94,7 +94,7 @@ pub trait Folder<'ast, T: Field>: Sized {
 
     fn fold_uint_expression_inner(
         &mut self,
-        bitwidth: UBitwidth,
+        bitwidth: IntType,
         e: UExpressionInner<'ast, T>,
     ) -> UExpressionInner<'ast, T> {
         fold_uint_expression_inner(self, bitwidth, e)
@@ -130,11 +130,32 @@ pub fn fold_statement<'ast, T: Field, F: Folder<'ast, T>>(
             ZirStatement::Definition(f.fold_assignee(a), f.fold_expression(e))
         }
         ZirStatement::Declaration(v) => ZirStatement::Declaration(f.fold_variable(v)),
-        ZirStatement::Assertion(e) => ZirStatement::Assertion(f.fold_boolean_expression(e)),
+        ZirStatement::Assertion(e, metadata) => {
+            ZirStatement::Assertion(f.fold_boolean_expression(e), metadata)
+        }
+        ZirStatement::IfElse(condition, consequence, alternative) => ZirStatement::IfElse(
+            f.fold_boolean_expression(condition),
+            consequence
+                .into_iter()
+                .flat_map(|s| f.fold_statement(s))
+                .collect(),
+            alternative
+                .into_iter()
+                .flat_map(|s| f.fold_statement(s))
+                .collect(),
+        ),
         ZirStatement::MultipleDefinition(variables, elist) => ZirStatement::MultipleDefinition(
             variables.into_iter().map(|v| f.fold_variable(v)).collect(),
             f.fold_expression_list(elist),
         ),
+        ZirStatement::Span(span) => ZirStatement::Span(span),
+        ZirStatement::Log(format_string, expressions) => ZirStatement::Log(
+            format_string,
+            expressions
+                .into_iter()
+                .map(|e| f.fold_expression(e))
+                .collect(),
+        ),
     };
     vec![res]
 }
@@ -179,6 +200,14 @@ pub fn fold_field_expression<'ast, T: Field, F: Folder<'ast, T>>(
             let alt = f.fold_field_expression(alt);
             FieldElementExpression::IfElse(box cond, box cons, box alt)
         }
+        FieldElementExpression::FromBool(box e) => {
+            let e = f.fold_boolean_expression(e);
+            FieldElementExpression::FromBool(box e)
+        }
+        FieldElementExpression::FromUint(box e) => {
+            let e = f.fold_uint_expression(e);
+            FieldElementExpression::FromUint(box e)
+        }
     }
 }
 
@@ -204,6 +233,26 @@ pub fn fold_boolean_expression<'ast, T: Field, F: Folder<'ast, T>>(
             let e2 = f.fold_uint_expression(e2);
             BooleanExpression::UintEq(box e1, box e2)
         }
+        BooleanExpression::UintLt(box e1, box e2) => {
+            let e1 = f.fold_uint_expression(e1);
+            let e2 = f.fold_uint_expression(e2);
+            BooleanExpression::UintLt(box e1, box e2)
+        }
+        BooleanExpression::UintLe(box e1, box e2) => {
+            let e1 = f.fold_uint_expression(e1);
+            let e2 = f.fold_uint_expression(e2);
+            BooleanExpression::UintLe(box e1, box e2)
+        }
+        BooleanExpression::UintGe(box e1, box e2) => {
+            let e1 = f.fold_uint_expression(e1);
+            let e2 = f.fold_uint_expression(e2);
+            BooleanExpression::UintGe(box e1, box e2)
+        }
+        BooleanExpression::UintGt(box e1, box e2) => {
+            let e1 = f.fold_uint_expression(e1);
+            let e2 = f.fold_uint_expression(e2);
+            BooleanExpression::UintGt(box e1, box e2)
+        }
         BooleanExpression::Lt(box e1, box e2) => {
             let e1 = f.fold_field_expression(e1);
             let e2 = f.fold_field_expression(e2);
@@ -244,6 +293,10 @@ pub fn fold_boolean_expression<'ast, T: Field, F: Folder<'ast, T>>(
             let alt = f.fold_boolean_expression(alt);
             BooleanExpression::IfElse(box cond, box cons, box alt)
         }
+        BooleanExpression::FromField(box e) => {
+            let e = f.fold_field_expression(e);
+            BooleanExpression::FromField(box e)
+        }
     }
 }
 
@@ -259,7 +312,7 @@ pub fn fold_uint_expression<'ast, T: Field, F: Folder<'ast, T>>(
 
 pub fn fold_uint_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
     f: &mut F,
-    _: UBitwidth,
+    _: IntType,
     e: UExpressionInner<'ast, T>,
 ) -> UExpressionInner<'ast, T> {
     match e {
@@ -283,6 +336,18 @@ pub fn fold_uint_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
 
             UExpressionInner::Mult(box left, box right)
         }
+        UExpressionInner::Div(box left, box right) => {
+            let left = f.fold_uint_expression(left);
+            let right = f.fold_uint_expression(right);
+
+            UExpressionInner::Div(box left, box right)
+        }
+        UExpressionInner::Rem(box left, box right) => {
+            let left = f.fold_uint_expression(left);
+            let right = f.fold_uint_expression(right);
+
+            UExpressionInner::Rem(box left, box right)
+        }
         UExpressionInner::Xor(box left, box right) => {
             let left = f.fold_uint_expression(left);
             let right = f.fold_uint_expression(right);
@@ -324,6 +389,14 @@ pub fn fold_uint_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
             let alt = f.fold_uint_expression(alt);
             UExpressionInner::IfElse(box cond, box cons, box alt)
         }
+        UExpressionInner::FromField(box e) => {
+            let e = f.fold_field_expression(e);
+            UExpressionInner::FromField(box e)
+        }
+        UExpressionInner::FromUint(box e) => {
+            let e = f.fold_uint_expression(e);
+            UExpressionInner::FromUint(box e)
+        }
     }
 }
 
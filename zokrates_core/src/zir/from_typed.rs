@@ -18,11 +18,21 @@ impl From<typed_absy::types::Signature> for zir::types::Signature {
     }
 }
 
+impl From<typed_absy::types::IntType> for zir::types::IntType {
+    fn from(t: typed_absy::types::IntType) -> zir::types::IntType {
+        let bitwidth = t.bitwidth.to_usize().into();
+        match t.signedness {
+            typed_absy::types::Signedness::Unsigned => zir::types::IntType::unsigned(bitwidth),
+            typed_absy::types::Signedness::Signed => zir::types::IntType::signed(bitwidth),
+        }
+    }
+}
+
 fn from_type(t: typed_absy::types::Type) -> Vec<zir::types::Type> {
     match t {
         typed_absy::Type::FieldElement => vec![zir::Type::FieldElement],
         typed_absy::Type::Boolean => vec![zir::Type::Boolean],
-        typed_absy::Type::Uint(bitwidth) => vec![zir::Type::uint(bitwidth.to_usize())],
+        typed_absy::Type::Uint(bitwidth) => vec![zir::Type::uint(bitwidth)],
         typed_absy::Type::Array(array_type) => {
             let inner = from_type(*array_type.ty);
             (0..array_type.size).flat_map(|_| inner.clone()).collect()
@@ -31,5 +41,17 @@ fn from_type(t: typed_absy::types::Type) -> Vec<zir::types::Type> {
             .into_iter()
             .flat_map(|struct_member| from_type(*struct_member.ty))
             .collect(),
+        typed_absy::Type::Tuple(tuple_type) => tuple_type
+            .elements
+            .into_iter()
+            .flat_map(|ty| from_type(ty))
+            .collect(),
+        typed_absy::Type::Vector(vector_type) => {
+            let inner = from_type(*vector_type.ty);
+            (0..vector_type.capacity)
+                .flat_map(|_| inner.clone())
+                .chain(std::iter::once(zir::Type::uint(32)))
+                .collect()
+        }
     }
 }
@@ -14,6 +14,8 @@ pub enum SourceIdentifier<'ast> {
     Basic(CoreIdentifier<'ast>),
     Select(Box<SourceIdentifier<'ast>>, usize),
     Member(Box<SourceIdentifier<'ast>>, MemberId),
+    Element(Box<SourceIdentifier<'ast>>, u32),
+    Length(Box<SourceIdentifier<'ast>>),
 }
 
 impl<'ast> fmt::Display for SourceIdentifier<'ast> {
@@ -22,6 +24,8 @@ impl<'ast> fmt::Display for SourceIdentifier<'ast> {
             SourceIdentifier::Basic(i) => write!(f, "{}", i),
             SourceIdentifier::Select(box i, index) => write!(f, "{}~{}", i, index),
             SourceIdentifier::Member(box i, m) => write!(f, "{}.{}", i, m),
+            SourceIdentifier::Element(box i, index) => write!(f, "{}.{}", i, index),
+            SourceIdentifier::Length(box i) => write!(f, "{}.len", i),
         }
     }
 }
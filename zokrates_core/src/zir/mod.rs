@@ -13,6 +13,8 @@ use std::path::PathBuf;
 pub use zir::uint::{ShouldReduce, UExpression, UExpressionInner, UMetadata};
 
 use embed::FlatEmbed;
+use flat_absy::AssertionMetadata;
+use source_map::SourceSpan;
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fmt;
@@ -191,8 +193,16 @@ pub enum ZirStatement<'ast, T> {
     Return(Vec<ZirExpression<'ast, T>>),
     Definition(ZirAssignee<'ast>, ZirExpression<'ast, T>),
     Declaration(Variable<'ast>),
-    Assertion(BooleanExpression<'ast, T>),
+    Assertion(BooleanExpression<'ast, T>, Option<AssertionMetadata>),
+    IfElse(
+        BooleanExpression<'ast, T>,
+        Vec<ZirStatement<'ast, T>>,
+        Vec<ZirStatement<'ast, T>>,
+    ),
     MultipleDefinition(Vec<Variable<'ast>>, ZirExpressionList<'ast, T>),
+    /// Marks the following statements as compiled from the source statement at this location
+    Span(SourceSpan),
+    Log(String, Vec<ZirExpression<'ast, T>>),
 }
 
 impl<'ast, T: fmt::Debug> fmt::Debug for ZirStatement<'ast, T> {
@@ -212,10 +222,21 @@ impl<'ast, T: fmt::Debug> fmt::Debug for ZirStatement<'ast, T> {
             ZirStatement::Definition(ref lhs, ref rhs) => {
                 write!(f, "Definition({:?}, {:?})", lhs, rhs)
             }
-            ZirStatement::Assertion(ref e) => write!(f, "Assertion({:?})", e),
+            ZirStatement::Assertion(ref e, ref metadata) => {
+                write!(f, "Assertion({:?}, {:?})", e, metadata)
+            }
+            ZirStatement::IfElse(ref condition, ref consequence, ref alternative) => write!(
+                f,
+                "IfElse({:?}, {:?}, {:?})",
+                condition, consequence, alternative
+            ),
             ZirStatement::MultipleDefinition(ref lhs, ref rhs) => {
                 write!(f, "MultipleDefinition({:?}, {:?})", lhs, rhs)
             }
+            ZirStatement::Span(ref span) => write!(f, "Span({:?})", span),
+            ZirStatement::Log(ref format_string, ref expressions) => {
+                write!(f, "Log({:?}, {:?})", format_string, expressions)
+            }
         }
     }
 }
@@ -235,7 +256,18 @@ impl<'ast, T: fmt::Display> fmt::Display for ZirStatement<'ast, T> {
             }
             ZirStatement::Declaration(ref var) => write!(f, "assert({})", var),
             ZirStatement::Definition(ref lhs, ref rhs) => write!(f, "{} = {}", lhs, rhs),
-            ZirStatement::Assertion(ref e) => write!(f, "{}", e),
+            ZirStatement::Assertion(ref e, _) => write!(f, "{}", e),
+            ZirStatement::IfElse(ref condition, ref consequence, ref alternative) => {
+                write!(f, "if {}:\n", condition)?;
+                for l in consequence {
+                    write!(f, "\t\t{}\n", l)?;
+                }
+                write!(f, "\telse:\n")?;
+                for l in alternative {
+                    write!(f, "\t\t{}\n", l)?;
+                }
+                write!(f, "\tendif")
+            }
             ZirStatement::MultipleDefinition(ref ids, ref rhs) => {
                 for (i, id) in ids.iter().enumerate() {
                     write!(f, "{}", id)?;
@@ -245,6 +277,14 @@ impl<'ast, T: fmt::Display> fmt::Display for ZirStatement<'ast, T> {
                 }
                 write!(f, " = {}", rhs)
             }
+            ZirStatement::Span(ref span) => write!(f, "// {}", span),
+            ZirStatement::Log(ref format_string, ref expressions) => {
+                write!(f, "log(\"{}\"", format_string)?;
+                for e in expressions {
+                    write!(f, ", {}", e)?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -374,6 +414,8 @@ pub enum FieldElementExpression<'ast, T> {
         Box<FieldElementExpression<'ast, T>>,
         Box<FieldElementExpression<'ast, T>>,
     ),
+    FromBool(Box<BooleanExpression<'ast, T>>),
+    FromUint(Box<UExpression<'ast, T>>),
 }
 
 /// An expression of type `bool`
@@ -398,6 +440,10 @@ pub enum BooleanExpression<'ast, T> {
         Box<BooleanExpression<'ast, T>>,
     ),
     UintEq(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    UintLt(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    UintLe(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    UintGe(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    UintGt(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     Ge(
         Box<FieldElementExpression<'ast, T>>,
         Box<FieldElementExpression<'ast, T>>,
@@ -420,6 +466,7 @@ pub enum BooleanExpression<'ast, T> {
         Box<BooleanExpression<'ast, T>>,
         Box<BooleanExpression<'ast, T>>,
     ),
+    FromField(Box<FieldElementExpression<'ast, T>>),
 }
 
 // Downcasts
@@ -475,6 +522,8 @@ impl<'ast, T: fmt::Display> fmt::Display for FieldElementExpression<'ast, T> {
                     condition, consequent, alternative
                 )
             }
+            FieldElementExpression::FromBool(ref e) => write!(f, "({} as field)", e),
+            FieldElementExpression::FromUint(ref e) => write!(f, "({} as field)", e),
         }
     }
 }
@@ -487,6 +536,8 @@ impl<'ast, T: fmt::Display> fmt::Display for UExpression<'ast, T> {
             UExpressionInner::Add(ref lhs, ref rhs) => write!(f, "({} + {})", lhs, rhs),
             UExpressionInner::Sub(ref lhs, ref rhs) => write!(f, "({} - {})", lhs, rhs),
             UExpressionInner::Mult(ref lhs, ref rhs) => write!(f, "({} * {})", lhs, rhs),
+            UExpressionInner::Div(ref lhs, ref rhs) => write!(f, "({} / {})", lhs, rhs),
+            UExpressionInner::Rem(ref lhs, ref rhs) => write!(f, "({} % {})", lhs, rhs),
             UExpressionInner::Xor(ref lhs, ref rhs) => write!(f, "({} ^ {})", lhs, rhs),
             UExpressionInner::And(ref lhs, ref rhs) => write!(f, "({} & {})", lhs, rhs),
             UExpressionInner::Or(ref lhs, ref rhs) => write!(f, "({} | {})", lhs, rhs),
@@ -498,6 +549,12 @@ impl<'ast, T: fmt::Display> fmt::Display for UExpression<'ast, T> {
                 "if {} then {} else {} fi",
                 condition, consequent, alternative
             ),
+            UExpressionInner::FromField(ref e) => {
+                write!(f, "({} as {})", e, Type::Uint(self.bitwidth))
+            }
+            UExpressionInner::FromUint(ref e) => {
+                write!(f, "({} as {})", e, Type::Uint(self.bitwidth))
+            }
         }
     }
 }
@@ -511,6 +568,10 @@ impl<'ast, T: fmt::Display> fmt::Display for BooleanExpression<'ast, T> {
             BooleanExpression::FieldEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
             BooleanExpression::BoolEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
             BooleanExpression::UintEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
+            BooleanExpression::UintLt(ref lhs, ref rhs) => write!(f, "{} < {}", lhs, rhs),
+            BooleanExpression::UintLe(ref lhs, ref rhs) => write!(f, "{} <= {}", lhs, rhs),
+            BooleanExpression::UintGe(ref lhs, ref rhs) => write!(f, "{} >= {}", lhs, rhs),
+            BooleanExpression::UintGt(ref lhs, ref rhs) => write!(f, "{} > {}", lhs, rhs),
             BooleanExpression::Ge(ref lhs, ref rhs) => write!(f, "{} >= {}", lhs, rhs),
             BooleanExpression::Gt(ref lhs, ref rhs) => write!(f, "{} > {}", lhs, rhs),
             BooleanExpression::Or(ref lhs, ref rhs) => write!(f, "{} || {}", lhs, rhs),
@@ -522,6 +583,7 @@ impl<'ast, T: fmt::Display> fmt::Display for BooleanExpression<'ast, T> {
                 "if {} then {} else {} fi",
                 condition, consequent, alternative
             ),
+            BooleanExpression::FromField(ref e) => write!(f, "({} as bool)", e),
         }
     }
 }
@@ -551,6 +613,8 @@ impl<'ast, T: fmt::Debug> fmt::Debug for FieldElementExpression<'ast, T> {
                     condition, consequent, alternative
                 )
             }
+            FieldElementExpression::FromBool(ref e) => write!(f, "FromBool({:?})", e),
+            FieldElementExpression::FromUint(ref e) => write!(f, "FromUint({:?})", e),
         }
     }
 }
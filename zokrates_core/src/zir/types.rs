@@ -8,7 +8,7 @@ pub type MemberId = String;
 pub enum Type {
     FieldElement,
     Boolean,
-    Uint(UBitwidth),
+    Uint(IntType),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
@@ -19,15 +19,13 @@ pub enum UBitwidth {
     B16 = 16,
     #[serde(rename = "32")]
     B32 = 32,
+    #[serde(rename = "64")]
+    B64 = 64,
 }
 
 impl UBitwidth {
     pub fn to_usize(&self) -> usize {
-        match self {
-            UBitwidth::B8 => 8,
-            UBitwidth::B16 => 16,
-            UBitwidth::B32 => 32,
-        }
+        *self as u32 as usize
     }
 }
 
@@ -37,6 +35,7 @@ impl From<usize> for UBitwidth {
             8 => UBitwidth::B8,
             16 => UBitwidth::B16,
             32 => UBitwidth::B32,
+            64 => UBitwidth::B64,
             _ => unreachable!(),
         }
     }
@@ -48,12 +47,77 @@ impl fmt::Display for UBitwidth {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
+pub enum Signedness {
+    Unsigned,
+    Signed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
+pub struct IntType {
+    pub bitwidth: UBitwidth,
+    pub signedness: Signedness,
+}
+
+impl IntType {
+    pub const U8: IntType = IntType::unsigned(UBitwidth::B8);
+    pub const U16: IntType = IntType::unsigned(UBitwidth::B16);
+    pub const U32: IntType = IntType::unsigned(UBitwidth::B32);
+    pub const U64: IntType = IntType::unsigned(UBitwidth::B64);
+    pub const I8: IntType = IntType::signed(UBitwidth::B8);
+    pub const I16: IntType = IntType::signed(UBitwidth::B16);
+    pub const I32: IntType = IntType::signed(UBitwidth::B32);
+
+    pub const fn unsigned(bitwidth: UBitwidth) -> Self {
+        IntType {
+            bitwidth,
+            signedness: Signedness::Unsigned,
+        }
+    }
+
+    pub const fn signed(bitwidth: UBitwidth) -> Self {
+        IntType {
+            bitwidth,
+            signedness: Signedness::Signed,
+        }
+    }
+
+    pub fn to_usize(&self) -> usize {
+        self.bitwidth.to_usize()
+    }
+
+    pub fn is_signed(&self) -> bool {
+        self.signedness == Signedness::Signed
+    }
+}
+
+impl From<UBitwidth> for IntType {
+    fn from(b: UBitwidth) -> Self {
+        IntType::unsigned(b)
+    }
+}
+
+impl From<usize> for IntType {
+    fn from(b: usize) -> Self {
+        IntType::unsigned(b.into())
+    }
+}
+
+impl fmt::Display for IntType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.signedness {
+            Signedness::Unsigned => write!(f, "u{}", self.bitwidth),
+            Signedness::Signed => write!(f, "i{}", self.bitwidth),
+        }
+    }
+}
+
 impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Type::FieldElement => write!(f, "field"),
             Type::Boolean => write!(f, "bool"),
-            Type::Uint(ref bitwidth) => write!(f, "u{}", bitwidth),
+            Type::Uint(ref int_type) => write!(f, "{}", int_type),
         }
     }
 }
@@ -63,7 +127,7 @@ impl fmt::Debug for Type {
         match self {
             Type::FieldElement => write!(f, "field"),
             Type::Boolean => write!(f, "bool"),
-            Type::Uint(ref bitwidth) => write!(f, "u{}", bitwidth),
+            Type::Uint(ref int_type) => write!(f, "{}", int_type),
         }
     }
 }
@@ -73,11 +137,11 @@ impl Type {
         match self {
             Type::FieldElement => String::from("f"),
             Type::Boolean => String::from("b"),
-            Type::Uint(bitwidth) => format!("u{}", bitwidth),
+            Type::Uint(int_type) => int_type.to_string(),
         }
     }
 
-    pub fn uint<W: Into<UBitwidth>>(b: W) -> Self {
+    pub fn uint<W: Into<IntType>>(b: W) -> Self {
         Type::Uint(b.into())
     }
 
@@ -1,5 +1,5 @@
 use zir::identifier::Identifier;
-use zir::types::UBitwidth;
+use zir::types::IntType;
 use zir::{BooleanExpression, FieldElementExpression};
 use zokrates_field::Field;
 
@@ -22,6 +22,18 @@ impl<'ast, T: Field> UExpression<'ast, T> {
         UExpressionInner::Mult(box self, box other).annotate(bitwidth)
     }
 
+    pub fn div(self, other: Self) -> UExpression<'ast, T> {
+        let bitwidth = self.bitwidth;
+        assert_eq!(bitwidth, other.bitwidth);
+        UExpressionInner::Div(box self, box other).annotate(bitwidth)
+    }
+
+    pub fn rem(self, other: Self) -> UExpression<'ast, T> {
+        let bitwidth = self.bitwidth;
+        assert_eq!(bitwidth, other.bitwidth);
+        UExpressionInner::Rem(box self, box other).annotate(bitwidth)
+    }
+
     pub fn xor(self, other: Self) -> UExpression<'ast, T> {
         let bitwidth = self.bitwidth;
         assert_eq!(bitwidth, other.bitwidth);
@@ -126,7 +138,7 @@ impl<T: Field> UMetadata<T> {
     }
 
     // issue the metadata for a parameter of a given bitwidth
-    pub fn parameter<W: Into<UBitwidth>>(bitwidth: W) -> Self {
+    pub fn parameter<W: Into<IntType>>(bitwidth: W) -> Self {
         Self {
             should_reduce: ShouldReduce::False,
             max: T::from(2_u32).pow(bitwidth.into().to_usize()) - T::from(1),
@@ -136,7 +148,7 @@ impl<T: Field> UMetadata<T> {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct UExpression<'ast, T> {
-    pub bitwidth: UBitwidth,
+    pub bitwidth: IntType,
     pub metadata: Option<UMetadata<T>>,
     pub inner: UExpressionInner<'ast, T>,
 }
@@ -148,6 +160,8 @@ pub enum UExpressionInner<'ast, T> {
     Add(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     Sub(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     Mult(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    Div(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
+    Rem(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     Xor(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     And(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     Or(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
@@ -165,10 +179,12 @@ pub enum UExpressionInner<'ast, T> {
         Box<UExpression<'ast, T>>,
         Box<UExpression<'ast, T>>,
     ),
+    FromField(Box<FieldElementExpression<'ast, T>>),
+    FromUint(Box<UExpression<'ast, T>>),
 }
 
 impl<'ast, T> UExpressionInner<'ast, T> {
-    pub fn annotate<W: Into<UBitwidth>>(self, bitwidth: W) -> UExpression<'ast, T> {
+    pub fn annotate<W: Into<IntType>>(self, bitwidth: W) -> UExpression<'ast, T> {
         UExpression {
             metadata: None,
             bitwidth: bitwidth.into(),
@@ -194,7 +210,7 @@ impl<'ast, T: Field> UExpression<'ast, T> {
 }
 
 impl<'ast, T> UExpression<'ast, T> {
-    pub fn bitwidth(&self) -> UBitwidth {
+    pub fn bitwidth(&self) -> IntType {
         self.bitwidth
     }
 
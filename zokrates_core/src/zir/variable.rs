@@ -1,5 +1,5 @@
 use std::fmt;
-use zir::types::{Type, UBitwidth};
+use zir::types::{IntType, Type};
 use zir::Identifier;
 
 #[derive(Clone, PartialEq, Hash, Eq)]
@@ -17,7 +17,7 @@ impl<'ast> Variable<'ast> {
         Self::with_id_and_type(id, Type::Boolean)
     }
 
-    pub fn uint<W: Into<UBitwidth>>(id: Identifier<'ast>, bitwidth: W) -> Variable<'ast> {
+    pub fn uint<W: Into<IntType>>(id: Identifier<'ast>, bitwidth: W) -> Variable<'ast> {
         Self::with_id_and_type(id, Type::uint(bitwidth))
     }
 
@@ -22,7 +22,9 @@ fn generate_proof() {
             statements: vec![Statement::Constraint(
                 FlatVariable::new(0).into(),
                 FlatVariable::new(0).into(),
+                None,
             )],
+            source_map: Default::default(),
         },
         private: vec![false],
     };
@@ -0,0 +1,48 @@
+#[macro_use]
+extern crate criterion;
+extern crate zokrates_field;
+
+use criterion::{black_box, Criterion};
+use zokrates_field::{Bls12_381Field, Bn128Field, Bw6_761Field, Field};
+
+fn operands<T: Field>() -> (T, T) {
+    (
+        T::try_from_dec_str(
+            "6042471409729479866150380306128222617399890671095126975526159292198160466142",
+        )
+        .unwrap(),
+        T::try_from_dec_str("218882428715392752222464057432572755886923").unwrap(),
+    )
+}
+
+fn bench_add<T: Field>(c: &mut Criterion, name: &str) {
+    let (x, y) = operands::<T>();
+    c.bench_function(name, |b| b.iter(|| black_box(x.clone()) + &y));
+}
+
+fn bench_mul<T: Field>(c: &mut Criterion, name: &str) {
+    let (x, y) = operands::<T>();
+    c.bench_function(name, |b| b.iter(|| black_box(x.clone()) * &y));
+}
+
+fn bench_inverse<T: Field>(c: &mut Criterion, name: &str) {
+    let (x, _) = operands::<T>();
+    c.bench_function(name, |b| b.iter(|| black_box(&x).inverse_mul()));
+}
+
+fn bn128(c: &mut Criterion) {
+    bench_add::<Bn128Field>(c, "bn128_add");
+    bench_mul::<Bn128Field>(c, "bn128_mul");
+    bench_inverse::<Bn128Field>(c, "bn128_inverse");
+}
+
+fn bls12_381(c: &mut Criterion) {
+    bench_mul::<Bls12_381Field>(c, "bls12_381_mul");
+}
+
+fn bw6_761(c: &mut Criterion) {
+    bench_mul::<Bw6_761Field>(c, "bw6_761_mul");
+}
+
+criterion_group!(benches, bn128, bls12_381, bw6_761);
+criterion_main!(benches);
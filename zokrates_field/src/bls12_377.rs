@@ -2,6 +2,7 @@ use algebra::bls12_377::Bls12_377;
 
 prime_field!(
     b"8444461749428370424248824938781546531375899335154063827935233455917409239041",
-    "bls12_377"
+    "bls12_377",
+    4
 );
 zexe_extensions!(Bls12_377);
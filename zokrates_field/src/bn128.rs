@@ -3,7 +3,8 @@ use bellman_ce::pairing::bn256::{Bn256, Fq2};
 
 prime_field!(
     b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
-    "bn128"
+    "bn128",
+    4
 );
 
 bellman_extensions!(Bn256, Fq2);
@@ -37,7 +38,7 @@ mod tests {
         fn positive_number() {
             assert_eq!(
                 "1234245612".parse::<BigInt>().unwrap(),
-                FieldPrime::from("1234245612").value
+                FieldPrime::from("1234245612").to_bigint()
             );
         }
 
@@ -45,7 +46,7 @@ mod tests {
         fn negative_number() {
             assert_eq!(
                 P.checked_sub(&"12".parse::<BigInt>().unwrap()).unwrap(),
-                FieldPrime::from("-12").value
+                FieldPrime::from("-12").to_bigint()
             );
         }
 
@@ -53,11 +54,11 @@ mod tests {
         fn addition() {
             assert_eq!(
                 "65484493".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("65416358") + FieldPrime::from("68135")).value
+                (FieldPrime::from("65416358") + FieldPrime::from("68135")).to_bigint()
             );
             assert_eq!(
                 "65484493".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("65416358") + &FieldPrime::from("68135")).value
+                (FieldPrime::from("65416358") + &FieldPrime::from("68135")).to_bigint()
             );
         }
 
@@ -65,11 +66,11 @@ mod tests {
         fn addition_negative_small() {
             assert_eq!(
                 "3".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("5") + FieldPrime::from("-2")).value
+                (FieldPrime::from("5") + FieldPrime::from("-2")).to_bigint()
             );
             assert_eq!(
                 "3".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("5") + &FieldPrime::from("-2")).value
+                (FieldPrime::from("5") + &FieldPrime::from("-2")).to_bigint()
             );
         }
 
@@ -77,11 +78,11 @@ mod tests {
         fn addition_negative() {
             assert_eq!(
                 "65348223".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("65416358") + FieldPrime::from("-68135")).value
+                (FieldPrime::from("65416358") + FieldPrime::from("-68135")).to_bigint()
             );
             assert_eq!(
                 "65348223".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("65416358") + &FieldPrime::from("-68135")).value
+                (FieldPrime::from("65416358") + &FieldPrime::from("-68135")).to_bigint()
             );
         }
 
@@ -89,11 +90,11 @@ mod tests {
         fn subtraction() {
             assert_eq!(
                 "65348223".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("65416358") - FieldPrime::from("68135")).value
+                (FieldPrime::from("65416358") - FieldPrime::from("68135")).to_bigint()
             );
             assert_eq!(
                 "65348223".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("65416358") - &FieldPrime::from("68135")).value
+                (FieldPrime::from("65416358") - &FieldPrime::from("68135")).to_bigint()
             );
         }
 
@@ -101,11 +102,11 @@ mod tests {
         fn subtraction_negative() {
             assert_eq!(
                 "65484493".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("65416358") - FieldPrime::from("-68135")).value
+                (FieldPrime::from("65416358") - FieldPrime::from("-68135")).to_bigint()
             );
             assert_eq!(
                 "65484493".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("65416358") - &FieldPrime::from("-68135")).value
+                (FieldPrime::from("65416358") - &FieldPrime::from("-68135")).to_bigint()
             );
         }
 
@@ -115,13 +116,13 @@ mod tests {
                 "21888242871839275222246405745257275088548364400416034343698204186575743147394"
                     .parse::<BigInt>()
                     .unwrap(),
-                (FieldPrime::from("68135") - FieldPrime::from("65416358")).value
+                (FieldPrime::from("68135") - FieldPrime::from("65416358")).to_bigint()
             );
             assert_eq!(
                 "21888242871839275222246405745257275088548364400416034343698204186575743147394"
                     .parse::<BigInt>()
                     .unwrap(),
-                (FieldPrime::from("68135") - &FieldPrime::from("65416358")).value
+                (FieldPrime::from("68135") - &FieldPrime::from("65416358")).to_bigint()
             );
         }
 
@@ -129,11 +130,11 @@ mod tests {
         fn multiplication() {
             assert_eq!(
                 "13472".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("32") * FieldPrime::from("421")).value
+                (FieldPrime::from("32") * FieldPrime::from("421")).to_bigint()
             );
             assert_eq!(
                 "13472".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("32") * &FieldPrime::from("421")).value
+                (FieldPrime::from("32") * &FieldPrime::from("421")).to_bigint()
             );
         }
 
@@ -143,13 +144,13 @@ mod tests {
                 "21888242871839275222246405745257275088548364400416034343698204186575808014369"
                     .parse::<BigInt>()
                     .unwrap(),
-                (FieldPrime::from("54") * FieldPrime::from("-8912")).value
+                (FieldPrime::from("54") * FieldPrime::from("-8912")).to_bigint()
             );
             assert_eq!(
                 "21888242871839275222246405745257275088548364400416034343698204186575808014369"
                     .parse::<BigInt>()
                     .unwrap(),
-                (FieldPrime::from("54") * &FieldPrime::from("-8912")).value
+                (FieldPrime::from("54") * &FieldPrime::from("-8912")).to_bigint()
             );
         }
 
@@ -157,11 +158,11 @@ mod tests {
         fn multiplication_two_negative() {
             assert_eq!(
                 "648".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("-54") * FieldPrime::from("-12")).value
+                (FieldPrime::from("-54") * FieldPrime::from("-12")).to_bigint()
             );
             assert_eq!(
                 "648".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("-54") * &FieldPrime::from("-12")).value
+                (FieldPrime::from("-54") * &FieldPrime::from("-12")).to_bigint()
             );
         }
 
@@ -174,7 +175,7 @@ mod tests {
                 (FieldPrime::from(
                     "21888242871839225222246405785257275088694311157297823662689037894645225727"
                 ) * FieldPrime::from("218882428715392752222464057432572755886923"))
-                .value
+                .to_bigint()
             );
             assert_eq!(
                 "6042471409729479866150380306128222617399890671095126975526159292198160466142"
@@ -183,7 +184,7 @@ mod tests {
                 (FieldPrime::from(
                     "21888242871839225222246405785257275088694311157297823662689037894645225727"
                 ) * &FieldPrime::from("218882428715392752222464057432572755886923"))
-                    .value
+                    .to_bigint()
             );
         }
 
@@ -215,11 +216,11 @@ mod tests {
         fn pow_small() {
             assert_eq!(
                 "8".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("2").pow(FieldPrime::from("3"))).value
+                (FieldPrime::from("2").pow(FieldPrime::from("3"))).to_bigint()
             );
             assert_eq!(
                 "8".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("2").pow(&FieldPrime::from("3"))).value
+                (FieldPrime::from("2").pow(&FieldPrime::from("3"))).to_bigint()
             );
         }
 
@@ -227,7 +228,7 @@ mod tests {
         fn pow_usize() {
             assert_eq!(
                 "614787626176508399616".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("54").pow(12)).value
+                (FieldPrime::from("54").pow(12)).to_bigint()
             );
         }
 
@@ -235,11 +236,11 @@ mod tests {
         fn pow() {
             assert_eq!(
                 "614787626176508399616".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("54").pow(FieldPrime::from("12"))).value
+                (FieldPrime::from("54").pow(FieldPrime::from("12"))).to_bigint()
             );
             assert_eq!(
                 "614787626176508399616".parse::<BigInt>().unwrap(),
-                (FieldPrime::from("54").pow(&FieldPrime::from("12"))).value
+                (FieldPrime::from("54").pow(&FieldPrime::from("12"))).to_bigint()
             );
         }
 
@@ -249,13 +250,13 @@ mod tests {
                 "21888242871839275222246405745257275088548364400416034343686819230535502784513"
                     .parse::<BigInt>()
                     .unwrap(),
-                (FieldPrime::from("-54").pow(FieldPrime::from("11"))).value
+                (FieldPrime::from("-54").pow(FieldPrime::from("11"))).to_bigint()
             );
             assert_eq!(
                 "21888242871839275222246405745257275088548364400416034343686819230535502784513"
                     .parse::<BigInt>()
                     .unwrap(),
-                (FieldPrime::from("-54").pow(&FieldPrime::from("11"))).value
+                (FieldPrime::from("-54").pow(&FieldPrime::from("11"))).to_bigint()
             );
         }
 
@@ -266,6 +267,26 @@ mod tests {
             assert_eq!(FieldPrime::from("11"), deserialized);
         }
 
+        #[test]
+        fn serde_bigint_compatibility() {
+            // elements are serialized as they were when stored as a `BigInt`
+            #[derive(Serialize, Deserialize)]
+            struct BigIntFieldPrime {
+                value: BigInt,
+            }
+
+            let legacy = BigIntFieldPrime {
+                value: "11".parse::<BigInt>().unwrap(),
+            };
+            let serialized = &serialize(&legacy, Infinite).unwrap();
+            assert_eq!(
+                serialized,
+                &serialize(&FieldPrime::from("11"), Infinite).unwrap()
+            );
+            let deserialized: FieldPrime = deserialize(serialized).unwrap();
+            assert_eq!(FieldPrime::from("11"), deserialized);
+        }
+
         #[test]
         fn serde_json_ser_deser() {
             let serialized = serde_json::to_string(&FieldPrime::from("11")).unwrap();
@@ -366,9 +387,7 @@ mod tests {
         );
         let (b, s, _) = extended_euclid(&ToBigInt::to_bigint(&253).unwrap(), &*P);
         assert_eq!(b, BigInt::one());
-        let s_field = FieldPrime {
-            value: &s - s.div_floor(&*P) * &*P,
-        };
+        let s_field = FieldPrime::from_bigint(&s);
         assert_eq!(
             FieldPrime::from(
                 "12717674712096337777352654721552646000065650461901806515903699665717959876900"
@@ -2,7 +2,8 @@ use algebra::bw6_761::BW6_761;
 
 prime_field!(
     b"258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177",
-    "bw6_761"
+    "bw6_761",
+    6
 );
 
 zexe_extensions!(BW6_761);
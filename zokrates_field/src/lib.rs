@@ -124,78 +124,127 @@ pub trait Field:
 #[macro_use]
 mod prime_field {
     macro_rules! prime_field {
-        ($modulus:expr, $name:expr) => {
+        ($modulus:expr, $name:expr, $limbs:expr) => {
             use crate::{Field, Pow};
             use lazy_static::lazy_static;
             use num_bigint::{BigInt, BigUint, Sign, ToBigInt};
             use num_integer::Integer;
             use num_traits::{One, Zero};
             use serde_derive::{Deserialize, Serialize};
+            use std::cmp::Ordering;
             use std::convert::From;
             use std::convert::TryFrom;
             use std::fmt;
             use std::fmt::{Debug, Display};
             use std::ops::{Add, Div, Mul, Sub};
 
+            /// The number of 64-bit limbs used to represent an element
+            const LIMBS: usize = $limbs;
+
+            type Limbs = [u64; LIMBS];
+
             lazy_static! {
                 static ref P: BigInt = BigInt::parse_bytes($modulus, 10).unwrap();
+                static ref MODULUS: Limbs = limbs(&P.to_biguint().unwrap());
+                /// `R mod p` where `R = 2^(64 * LIMBS)`, which is one in Montgomery form
+                static ref R: Limbs =
+                    limbs(&((BigUint::one() << (64 * LIMBS)) % P.to_biguint().unwrap()));
+                /// `R^2 mod p`, used to convert values to Montgomery form
+                static ref R2: Limbs =
+                    limbs(&((BigUint::one() << (128 * LIMBS)) % P.to_biguint().unwrap()));
+                /// `-p^(-1) mod 2^64`
+                static ref INV: u64 = {
+                    let mut inv = 1u64;
+                    for _ in 0..63 {
+                        inv = inv.wrapping_mul(inv);
+                        inv = inv.wrapping_mul(MODULUS[0]);
+                    }
+                    inv.wrapping_neg()
+                };
             }
 
-            #[derive(PartialEq, PartialOrd, Clone, Eq, Ord, Hash, Serialize, Deserialize)]
+            /// An element of the field, stored in Montgomery form `value = x * R mod p`
+            #[derive(PartialEq, Clone, Eq, Hash)]
             pub struct FieldPrime {
+                value: Limbs,
+            }
+
+            /// The serialized form of an element, which is the same as when elements were stored as a `BigInt`
+            #[derive(Serialize, Deserialize)]
+            #[serde(rename = "FieldPrime")]
+            struct FieldPrimeRepr {
                 value: BigInt,
             }
 
+            impl FieldPrime {
+                fn from_canonical(value: &Limbs) -> FieldPrime {
+                    FieldPrime {
+                        value: mont_mul(value, &R2),
+                    }
+                }
+
+                fn canonical(&self) -> Limbs {
+                    let mut one = [0u64; LIMBS];
+                    one[0] = 1;
+                    mont_mul(&self.value, &one)
+                }
+
+                fn from_biguint(value: &BigUint) -> FieldPrime {
+                    Self::from_canonical(&limbs(&(value % P.to_biguint().unwrap())))
+                }
+
+                fn from_bigint(value: &BigInt) -> FieldPrime {
+                    Self::from_biguint(&value.mod_floor(&*P).to_biguint().unwrap())
+                }
+
+                fn to_bigint(&self) -> BigInt {
+                    BigInt::from_biguint(Sign::Plus, self.to_biguint())
+                }
+            }
+
             impl Field for FieldPrime {
                 fn bits(&self) -> u32 {
-                    self.value.bits() as u32
+                    let value = self.canonical();
+                    (0..LIMBS)
+                        .rev()
+                        .find(|&i| value[i] != 0)
+                        .map(|i| (64 * i + 64) as u32 - value[i].leading_zeros())
+                        .unwrap_or(0)
                 }
 
                 fn to_biguint(&self) -> BigUint {
-                    self.value.to_biguint().unwrap()
+                    from_limbs(&self.canonical())
                 }
 
                 fn into_byte_vector(&self) -> Vec<u8> {
-                    match self.value.to_biguint() {
-                        Option::Some(val) => val.to_bytes_le(),
-                        Option::None => panic!("Should never happen."),
-                    }
+                    self.to_biguint().to_bytes_le()
                 }
 
                 fn from_byte_vector(bytes: Vec<u8>) -> Self {
-                    let uval = BigUint::from_bytes_le(bytes.as_slice());
-                    FieldPrime {
-                        value: BigInt::from_biguint(Sign::Plus, uval),
-                    }
+                    Self::from_biguint(&BigUint::from_bytes_le(bytes.as_slice()))
                 }
 
                 fn to_dec_string(&self) -> String {
-                    self.value.to_str_radix(10)
+                    self.to_biguint().to_str_radix(10)
                 }
 
                 fn inverse_mul(&self) -> FieldPrime {
-                    let (b, s, _) = extended_euclid(&self.value, &*P);
+                    let (b, s, _) = extended_euclid(&self.to_bigint(), &*P);
                     assert_eq!(b, BigInt::one());
-                    FieldPrime {
-                        value: &s - s.div_floor(&*P) * &*P,
-                    }
+                    FieldPrime::from_bigint(&s)
                 }
                 fn min_value() -> FieldPrime {
-                    FieldPrime {
-                        value: ToBigInt::to_bigint(&0).unwrap(),
-                    }
+                    FieldPrime::zero()
                 }
                 fn max_value() -> FieldPrime {
-                    FieldPrime {
-                        value: &*P - ToBigInt::to_bigint(&1).unwrap(),
-                    }
+                    FieldPrime::from_bigint(&(&*P - BigInt::one()))
                 }
                 fn max_unique_value() -> FieldPrime {
                     use num_traits::Pow;
 
-                    FieldPrime {
-                        value: BigInt::from(2u32).pow(Self::get_required_bits() - 1) - 1,
-                    }
+                    FieldPrime::from_bigint(
+                        &(BigInt::from(2u32).pow(Self::get_required_bits() - 1) - 1),
+                    )
                 }
                 fn get_required_bits() -> usize {
                     (*P).bits()
@@ -205,20 +254,15 @@ mod prime_field {
                 }
                 fn try_from_str(s: &str, radix: u32) -> Result<Self, ()> {
                     let x = BigInt::parse_bytes(s.as_bytes(), radix).ok_or(())?;
-                    Ok(FieldPrime {
-                        value: &x - x.div_floor(&*P) * &*P,
-                    })
+                    Ok(FieldPrime::from_bigint(&x))
                 }
                 fn to_compact_dec_string(&self) -> String {
                     // values up to (p-1)/2 included are represented as positive, values between (p+1)/2 and p-1 as represented as negative by subtracting p
-                    if self.value <= FieldPrime::max_value().value / 2 {
-                        format!("{}", self.value.to_str_radix(10))
+                    let value = self.to_bigint();
+                    if value <= FieldPrime::max_value().to_bigint() / 2 {
+                        format!("{}", value.to_str_radix(10))
                     } else {
-                        format!(
-                            "({})",
-                            (&self.value - (FieldPrime::max_value().value + BigInt::one()))
-                                .to_str_radix(10)
-                        )
+                        format!("({})", (value - &*P).to_str_radix(10))
                     }
                 }
                 fn id() -> [u8; 4] {
@@ -238,57 +282,86 @@ mod prime_field {
 
             impl Default for FieldPrime {
                 fn default() -> Self {
-                    FieldPrime {
-                        value: BigInt::default(),
-                    }
+                    FieldPrime::zero()
                 }
             }
 
             impl Display for FieldPrime {
                 fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                    write!(f, "{}", self.value.to_str_radix(10))
+                    write!(f, "{}", self.to_dec_string())
                 }
             }
 
             impl Debug for FieldPrime {
                 fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                    write!(f, "{}", self.value.to_str_radix(10))
+                    write!(f, "{}", self.to_dec_string())
+                }
+            }
+
+            // elements are ordered by their canonical value rather than by their Montgomery form
+            impl PartialOrd for FieldPrime {
+                fn partial_cmp(&self, other: &FieldPrime) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for FieldPrime {
+                fn cmp(&self, other: &FieldPrime) -> Ordering {
+                    self.canonical()
+                        .iter()
+                        .rev()
+                        .cmp(other.canonical().iter().rev())
+                }
+            }
+
+            impl serde::Serialize for FieldPrime {
+                fn serialize<S: serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    serde::Serialize::serialize(
+                        &FieldPrimeRepr {
+                            value: self.to_bigint(),
+                        },
+                        serializer,
+                    )
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for FieldPrime {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    let repr: FieldPrimeRepr = serde::Deserialize::deserialize(deserializer)?;
+                    Ok(FieldPrime::from_bigint(&repr.value))
                 }
             }
 
             impl From<i32> for FieldPrime {
                 fn from(num: i32) -> Self {
-                    let x = ToBigInt::to_bigint(&num).unwrap();
-                    FieldPrime {
-                        value: &x - x.div_floor(&*P) * &*P,
-                    }
+                    FieldPrime::from_bigint(&ToBigInt::to_bigint(&num).unwrap())
                 }
             }
 
             impl From<u32> for FieldPrime {
                 fn from(num: u32) -> Self {
-                    let x = ToBigInt::to_bigint(&num).unwrap();
-                    FieldPrime {
-                        value: &x - x.div_floor(&*P) * &*P,
-                    }
+                    FieldPrime::from(num as u128)
                 }
             }
 
             impl From<usize> for FieldPrime {
                 fn from(num: usize) -> Self {
-                    let x = ToBigInt::to_bigint(&num).unwrap();
-                    FieldPrime {
-                        value: &x - x.div_floor(&*P) * &*P,
-                    }
+                    FieldPrime::from(num as u128)
                 }
             }
 
             impl From<u128> for FieldPrime {
                 fn from(num: u128) -> Self {
-                    let x = ToBigInt::to_bigint(&num).unwrap();
-                    FieldPrime {
-                        value: &x - x.div_floor(&*P) * &*P,
-                    }
+                    // the modulus is larger than 2^128, so no reduction is needed
+                    let mut value = [0u64; LIMBS];
+                    value[0] = num as u64;
+                    value[1] = (num >> 64) as u64;
+                    FieldPrime::from_canonical(&value)
                 }
             }
 
@@ -297,10 +370,7 @@ mod prime_field {
 
                 fn try_from(value: BigUint) -> Result<Self, ()> {
                     match value <= Self::max_value().to_biguint() {
-                        true => {
-                            let x = ToBigInt::to_bigint(&value).unwrap();
-                            Ok(FieldPrime { value: x })
-                        }
+                        true => Ok(FieldPrime::from_biguint(&value)),
                         false => Err(()),
                     }
                 }
@@ -309,19 +379,17 @@ mod prime_field {
             impl Zero for FieldPrime {
                 fn zero() -> FieldPrime {
                     FieldPrime {
-                        value: ToBigInt::to_bigint(&0).unwrap(),
+                        value: [0u64; LIMBS],
                     }
                 }
                 fn is_zero(&self) -> bool {
-                    self.value == ToBigInt::to_bigint(&0).unwrap()
+                    self.value.iter().all(|l| *l == 0)
                 }
             }
 
             impl One for FieldPrime {
                 fn one() -> FieldPrime {
-                    FieldPrime {
-                        value: ToBigInt::to_bigint(&1).unwrap(),
-                    }
+                    FieldPrime { value: *R }
                 }
             }
 
@@ -329,9 +397,7 @@ mod prime_field {
                 type Output = FieldPrime;
 
                 fn add(self, other: FieldPrime) -> FieldPrime {
-                    FieldPrime {
-                        value: (self.value + other.value) % &*P,
-                    }
+                    self + &other
                 }
             }
 
@@ -340,7 +406,7 @@ mod prime_field {
 
                 fn add(self, other: &FieldPrime) -> FieldPrime {
                     FieldPrime {
-                        value: (self.value + other.value.clone()) % &*P,
+                        value: add_mod(&self.value, &other.value),
                     }
                 }
             }
@@ -349,10 +415,7 @@ mod prime_field {
                 type Output = FieldPrime;
 
                 fn sub(self, other: FieldPrime) -> FieldPrime {
-                    let x = self.value - other.value;
-                    FieldPrime {
-                        value: &x - x.div_floor(&*P) * &*P,
-                    }
+                    self - &other
                 }
             }
 
@@ -360,9 +423,8 @@ mod prime_field {
                 type Output = FieldPrime;
 
                 fn sub(self, other: &FieldPrime) -> FieldPrime {
-                    let x = self.value - other.value.clone();
                     FieldPrime {
-                        value: &x - x.div_floor(&*P) * &*P,
+                        value: sub_mod(&self.value, &other.value),
                     }
                 }
             }
@@ -371,9 +433,7 @@ mod prime_field {
                 type Output = FieldPrime;
 
                 fn mul(self, other: FieldPrime) -> FieldPrime {
-                    FieldPrime {
-                        value: (self.value * other.value) % &*P,
-                    }
+                    self * &other
                 }
             }
 
@@ -382,7 +442,7 @@ mod prime_field {
 
                 fn mul(self, other: &FieldPrime) -> FieldPrime {
                     FieldPrime {
-                        value: (self.value * other.value.clone()) % &*P,
+                        value: mont_mul(&self.value, &other.value),
                     }
                 }
             }
@@ -407,9 +467,15 @@ mod prime_field {
                 type Output = FieldPrime;
 
                 fn pow(self, exp: usize) -> FieldPrime {
-                    let mut res = FieldPrime::from(1);
-                    for _ in 0..exp {
-                        res = res * &self;
+                    let mut res = FieldPrime::one();
+                    let mut base = self;
+                    let mut exp = exp;
+                    while exp > 0 {
+                        if exp & 1 == 1 {
+                            res = res * &base;
+                        }
+                        base = base.clone() * &base;
+                        exp >>= 1;
                     }
                     res
                 }
@@ -419,15 +485,7 @@ mod prime_field {
                 type Output = FieldPrime;
 
                 fn pow(self, exp: FieldPrime) -> FieldPrime {
-                    let mut res = FieldPrime::one();
-                    let mut current = FieldPrime::zero();
-                    loop {
-                        if current >= exp {
-                            return res;
-                        }
-                        res = res * &self;
-                        current = current + FieldPrime::one();
-                    }
+                    self.pow(&exp)
                 }
             }
 
@@ -435,15 +493,18 @@ mod prime_field {
                 type Output = FieldPrime;
 
                 fn pow(self, exp: &'a FieldPrime) -> FieldPrime {
+                    let bits = exp.bits() as usize;
+                    let exp = exp.canonical();
+
+                    // square and multiply, starting from the most significant bit
                     let mut res = FieldPrime::one();
-                    let mut current = FieldPrime::zero();
-                    loop {
-                        if &current >= exp {
-                            return res;
+                    for i in (0..bits).rev() {
+                        res = res.clone() * &res;
+                        if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+                            res = res * &self;
                         }
-                        res = res * &self;
-                        current = current + FieldPrime::one();
                     }
+                    res
                 }
             }
 
@@ -454,12 +515,12 @@ mod prime_field {
                     assert!(self <= &bound);
                     assert!(other <= &bound);
 
-                    let big_res = self.value.clone() + other.value.clone();
+                    let big_res = self.to_bigint() + other.to_bigint();
 
-                    if big_res > bound.value {
+                    if big_res > bound.to_bigint() {
                         None
                     } else {
-                        Some(FieldPrime { value: big_res })
+                        Some(FieldPrime::from_bigint(&big_res))
                     }
                 }
             }
@@ -471,15 +532,147 @@ mod prime_field {
                     assert!(self <= &bound);
                     assert!(other <= &bound);
 
-                    let big_res = self.value.clone() * other.value.clone();
+                    let big_res = self.to_bigint() * other.to_bigint();
 
                     // we only go up to 2**(bitwidth - 1) because after that we lose uniqueness of bit decomposition
-                    if big_res > bound.value {
+                    if big_res > bound.to_bigint() {
                         None
                     } else {
-                        Some(FieldPrime { value: big_res })
+                        Some(FieldPrime::from_bigint(&big_res))
+                    }
+                }
+            }
+
+            /// Splits a value smaller than `2^(64 * LIMBS)` into little-endian limbs
+            fn limbs(value: &BigUint) -> Limbs {
+                let mut res = [0u64; LIMBS];
+                for (i, chunk) in value.to_bytes_le().chunks(8).enumerate() {
+                    let mut bytes = [0u8; 8];
+                    bytes[..chunk.len()].copy_from_slice(chunk);
+                    res[i] = u64::from_le_bytes(bytes);
+                }
+                res
+            }
+
+            fn from_limbs(value: &Limbs) -> BigUint {
+                let bytes: Vec<u8> = value
+                    .iter()
+                    .flat_map(|l| l.to_le_bytes().to_vec())
+                    .collect();
+                BigUint::from_bytes_le(&bytes)
+            }
+
+            /// Returns `a + b + carry` and the new carry
+            #[inline(always)]
+            fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+                let t = a as u128 + b as u128 + carry as u128;
+                (t as u64, (t >> 64) as u64)
+            }
+
+            /// Returns `a - b - borrow` and the new borrow
+            #[inline(always)]
+            fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+                let t = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+                (t as u64, (t >> 127) as u64)
+            }
+
+            /// Returns `a + b * c + carry` and the new carry
+            #[inline(always)]
+            fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+                let t = a as u128 + (b as u128) * (c as u128) + carry as u128;
+                (t as u64, (t >> 64) as u64)
+            }
+
+            fn is_below_modulus(value: &Limbs) -> bool {
+                let p = &*MODULUS;
+                for i in (0..LIMBS).rev() {
+                    if value[i] != p[i] {
+                        return value[i] < p[i];
                     }
                 }
+                false
+            }
+
+            fn sub_modulus(value: &mut Limbs) {
+                let p = &*MODULUS;
+                let mut borrow = 0;
+                for i in 0..LIMBS {
+                    let (r, b) = sbb(value[i], p[i], borrow);
+                    value[i] = r;
+                    borrow = b;
+                }
+            }
+
+            fn add_mod(a: &Limbs, b: &Limbs) -> Limbs {
+                let mut res = [0u64; LIMBS];
+                let mut carry = 0;
+                for i in 0..LIMBS {
+                    let (r, c) = adc(a[i], b[i], carry);
+                    res[i] = r;
+                    carry = c;
+                }
+                if carry != 0 || !is_below_modulus(&res) {
+                    sub_modulus(&mut res);
+                }
+                res
+            }
+
+            fn sub_mod(a: &Limbs, b: &Limbs) -> Limbs {
+                let mut res = [0u64; LIMBS];
+                let mut borrow = 0;
+                for i in 0..LIMBS {
+                    let (r, b) = sbb(a[i], b[i], borrow);
+                    res[i] = r;
+                    borrow = b;
+                }
+                if borrow != 0 {
+                    let p = &*MODULUS;
+                    let mut carry = 0;
+                    for i in 0..LIMBS {
+                        let (r, c) = adc(res[i], p[i], carry);
+                        res[i] = r;
+                        carry = c;
+                    }
+                }
+                res
+            }
+
+            /// Montgomery multiplication, returning `a * b * R^(-1) mod p`
+            fn mont_mul(a: &Limbs, b: &Limbs) -> Limbs {
+                let p = &*MODULUS;
+                let inv = *INV;
+
+                let mut t = [0u64; LIMBS + 2];
+                for i in 0..LIMBS {
+                    let mut carry = 0;
+                    for j in 0..LIMBS {
+                        let (r, c) = mac(t[j], a[j], b[i], carry);
+                        t[j] = r;
+                        carry = c;
+                    }
+                    let (r, c) = adc(t[LIMBS], carry, 0);
+                    t[LIMBS] = r;
+                    t[LIMBS + 1] = c;
+
+                    // add a multiple of p so that the lowest limb becomes zero, and shift it out
+                    let m = t[0].wrapping_mul(inv);
+                    let (_, mut carry) = mac(t[0], m, p[0], 0);
+                    for j in 1..LIMBS {
+                        let (r, c) = mac(t[j], m, p[j], carry);
+                        t[j - 1] = r;
+                        carry = c;
+                    }
+                    let (r, c) = adc(t[LIMBS], carry, 0);
+                    t[LIMBS - 1] = r;
+                    t[LIMBS] = t[LIMBS + 1] + c;
+                }
+
+                let mut res = [0u64; LIMBS];
+                res.copy_from_slice(&t[..LIMBS]);
+                if t[LIMBS] != 0 || !is_below_modulus(&res) {
+                    sub_modulus(&mut res);
+                }
+                res
             }
 
             /// Calculates the gcd using an iterative implementation of the extended euclidian algorithm.
@@ -113,6 +113,45 @@ mod tests {
             };
         }
 
+        #[test]
+        fn parse_generic_function() {
+            parses_to! {
+                parser: ZoKratesParser,
+                input: "def foo<N>(field[N] a): return
+                ",
+                rule: Rule::function_definition,
+                tokens: [
+                    function_definition(0, 31, [
+                        identifier(4, 7),
+                        // constant_generics_declaration is not created (silent rule)
+                        identifier(8, 9),
+                        parameter(11, 21, [
+                            ty(11, 20, [
+                                ty_array(11, 20, [
+                                    ty_basic_or_struct(11, 16, [
+                                        ty_basic(11, 16, [
+                                            ty_field(11, 16)
+                                        ])
+                                    ]),
+                                    expression(17, 18, [
+                                        term(17, 18, [
+                                            primary_expression(17, 18, [
+                                                identifier(17, 18)
+                                            ])
+                                        ])
+                                    ])
+                                ])
+                            ]),
+                            identifier(20, 21)
+                        ]),
+                        statement(24, 31, [
+                            return_statement(24, 30)
+                        ])
+                    ])
+                ]
+            };
+        }
+
         #[test]
         fn parse_single_def_to_multi() {
             parses_to! {
@@ -272,6 +311,320 @@ mod tests {
             };
         }
 
+        #[test]
+        fn parse_const_def() {
+            parses_to! {
+                parser: ZoKratesParser,
+                input: "const u32 N = 42",
+                rule: Rule::const_definition,
+                tokens: [
+                    const_definition(0, 16, [
+                        ty(6, 9, [
+                            ty_basic(6, 9, [
+                                ty_u32(6, 9)
+                            ])
+                        ]),
+                        identifier(10, 11),
+                        expression(14, 16, [
+                            term(14, 16, [
+                                primary_expression(14, 16, [
+                                    constant(14, 16, [
+                                        decimal_number(14, 16)
+                                    ])
+                                ])
+                            ])
+                        ])
+                    ])
+                ]
+            };
+        }
+
+        #[test]
+        fn parse_tuple_type() {
+            parses_to! {
+                parser: ZoKratesParser,
+                input: "(field, bool)",
+                rule: Rule::ty,
+                tokens: [
+                    ty(0, 13, [
+                        ty_tuple(0, 13, [
+                            ty(1, 6, [
+                                ty_basic(1, 6, [
+                                    ty_field(1, 6)
+                                ])
+                            ]),
+                            ty(8, 12, [
+                                ty_basic(8, 12, [
+                                    ty_bool(8, 12)
+                                ])
+                            ])
+                        ])
+                    ])
+                ]
+            };
+        }
+
+        #[test]
+        fn parse_tuple_access() {
+            let input = "t.0.1";
+
+            let parse = ZoKratesParser::parse(Rule::postfix_expression, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_tuple_destructuring() {
+            let input = "(a, bool b) = foo()";
+
+            let parse = ZoKratesParser::parse(Rule::definition_statement, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_assertion_with_message() {
+            let input = r#"assert(a == b, "a should equal b")"#;
+
+            let parse = ZoKratesParser::parse(Rule::expression_statement, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_log() {
+            let input = r#"log("a = {}, b = {}", a, b)"#;
+
+            let parse = ZoKratesParser::parse(Rule::log_statement, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_if_else() {
+            let input = "if a == 1:\n assert(b == 2)\n else:\n c = 3\n endif";
+
+            let parse = ZoKratesParser::parse(Rule::conditional_statement, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_if_without_else() {
+            let input = "if a == 1:\n c = 3\n endif";
+
+            let parse = ZoKratesParser::parse(Rule::conditional_statement, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_signed_number() {
+            parses_to! {
+                parser: ZoKratesParser,
+                input: "-42i8",
+                rule: Rule::constant,
+                tokens: [
+                    constant(0, 5, [
+                        signed_number_8(0, 5)
+                    ])
+                ]
+            };
+        }
+
+        #[test]
+        fn parse_negation() {
+            let input = "a - -b";
+
+            let parse = ZoKratesParser::parse(Rule::expression, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_cast() {
+            parses_to! {
+                parser: ZoKratesParser,
+                input: "a as u8",
+                rule: Rule::term,
+                tokens: [
+                    term(0, 7, [
+                        primary_expression(0, 1, [
+                            identifier(0, 1)
+                        ]),
+                        cast(2, 7, [
+                            ty(5, 7, [
+                                ty_basic(5, 7, [
+                                    ty_u8(5, 7)
+                                ])
+                            ])
+                        ])
+                    ])
+                ]
+            };
+        }
+
+        #[test]
+        fn parse_type_alias() {
+            parses_to! {
+                parser: ZoKratesParser,
+                input: "type Hash = u32",
+                rule: Rule::type_definition,
+                tokens: [
+                    type_definition(0, 15, [
+                        ty_alias_definition(0, 15, [
+                            identifier(5, 9),
+                            ty(12, 15, [
+                                ty_basic(12, 15, [
+                                    ty_u32(12, 15)
+                                ])
+                            ])
+                        ])
+                    ])
+                ]
+            };
+        }
+
+        #[test]
+        fn parse_multiple_imports() {
+            parses_to! {
+                parser: ZoKratesParser,
+                input: r#"from "foo" import a, b as c"#,
+                rule: Rule::from_import_directive,
+                tokens: [
+                    from_import_directive(0, 27, [
+                        import_source(6, 9),
+                        import_symbol(18, 19, [
+                            identifier(18, 19)
+                        ]),
+                        import_symbol(21, 27, [
+                            identifier(21, 22),
+                            identifier(26, 27)
+                        ])
+                    ])
+                ]
+            };
+        }
+
+        #[test]
+        fn parse_glob_import() {
+            parses_to! {
+                parser: ZoKratesParser,
+                input: r#"from "foo" import *"#,
+                rule: Rule::from_import_directive,
+                tokens: [
+                    from_import_directive(0, 19, [
+                        import_source(6, 9),
+                        import_glob(18, 19)
+                    ])
+                ]
+            };
+        }
+
+        #[test]
+        fn parse_export_function() {
+            parses_to! {
+                parser: ZoKratesParser,
+                input: "export def foo():\n\treturn\n",
+                rule: Rule::function_definition,
+                tokens: [
+                    function_definition(0, 26, [
+                        vis_export(0, 6),
+                        identifier(11, 14),
+                        statement(19, 26, [
+                            return_statement(19, 25)
+                        ])
+                    ])
+                ]
+            };
+        }
+
+        #[test]
+        fn parse_associated_call() {
+            parses_to! {
+                parser: ZoKratesParser,
+                input: "Foo::new(a).x",
+                rule: Rule::postfix_expression,
+                tokens: [
+                    postfix_expression(0, 13, [
+                        identifier(0, 3),
+                        associated_call(3, 11, [
+                            identifier(5, 8),
+                            call_access(8, 11, [
+                                expression(9, 10, [
+                                    term(9, 10, [
+                                        primary_expression(9, 10, [
+                                            identifier(9, 10)
+                                        ])
+                                    ])
+                                ])
+                            ])
+                        ]),
+                        access(11, 13, [
+                            member_access(11, 13, [
+                                identifier(12, 13)
+                            ])
+                        ])
+                    ])
+                ]
+            };
+        }
+
+        #[test]
+        fn parse_impl() {
+            parses_to! {
+                parser: ZoKratesParser,
+                input: "impl Foo {\n\tdef get(self, field a) -> field:\n\t\treturn a\n}",
+                rule: Rule::impl_definition,
+                tokens: [
+                    impl_definition(0, 57, [
+                        identifier(5, 8),
+                        method_definition(12, 56, [
+                            identifier(16, 19),
+                            self_parameter(20, 24),
+                            parameter(26, 33, [
+                                ty(26, 31, [
+                                    ty_basic(26, 31, [
+                                        ty_field(26, 31)
+                                    ])
+                                ]),
+                                identifier(32, 33)
+                            ]),
+                            ty(38, 43, [
+                                ty_basic(38, 43, [
+                                    ty_field(38, 43)
+                                ])
+                            ]),
+                            statement(47, 56, [
+                                return_statement(47, 55, [
+                                    expression(54, 55, [
+                                        term(54, 55, [
+                                            primary_expression(54, 55, [
+                                                identifier(54, 55)
+                                            ])
+                                        ])
+                                    ])
+                                ])
+                            ])
+                        ])
+                    ])
+                ]
+            };
+        }
+
+        #[test]
+        fn parse_generic_struct_type() {
+            parses_to! {
+                parser: ZoKratesParser,
+                input: "Foo<N, 3>",
+                rule: Rule::ty_struct,
+                tokens: [
+                    ty_struct(0, 9, [
+                        identifier(0, 3),
+                        constant_generic_value(4, 5, [
+                            identifier(4, 5)
+                        ]),
+                        constant_generic_value(7, 8, [
+                            decimal_number(7, 8)
+                        ])
+                    ])
+                ]
+            };
+        }
+
         #[test]
         fn parse_invalid_identifier_because_keyword() {
             fails_with! {
@@ -291,5 +644,16 @@ mod tests {
             let parse = ZoKratesParser::parse(Rule::iteration_statement, input);
             assert!(parse.is_ok());
         }
+
+        #[test]
+        fn parse_vector_iteration() {
+            let input = "for field e in v do \n c = c + e \n endfor";
+
+            let parse = ZoKratesParser::parse(Rule::vector_iteration_statement, input);
+            assert!(parse.is_ok());
+
+            let parse = ZoKratesParser::parse(Rule::ty, "u32[..N]");
+            assert!(parse.is_ok());
+        }
     }
 }
@@ -8,14 +8,19 @@ use zokrates_parser::Rule;
 extern crate lazy_static;
 
 pub use ast::{
-    Access, ArrayAccess, ArrayInitializerExpression, ArrayType, AssertionStatement, Assignee,
-    AssigneeAccess, BasicOrStructType, BasicType, BinaryExpression, BinaryOperator, CallAccess,
-    ConstantExpression, DecimalNumberExpression, DefinitionStatement, Expression, FieldType, File,
-    FromExpression, Function, IdentifierExpression, ImportDirective, ImportSource,
-    InlineArrayExpression, InlineStructExpression, InlineStructMember, IterationStatement,
-    OptionallyTypedAssignee, Parameter, PostfixExpression, Range, RangeOrExpression,
-    ReturnStatement, Span, Spread, SpreadOrExpression, Statement, StructDefinition, StructField,
-    TernaryExpression, ToExpression, Type, UnaryExpression, UnaryOperator, Visibility,
+    Access, AliasDefinition, ArrayAccess, ArrayInitializerExpression, ArrayType,
+    AssertionStatement, Assignee, AssigneeAccess, AssociatedCall, BasicOrStructType, BasicType,
+    BinaryExpression, BinaryOperator, CallAccess, CastExpression, ConditionalStatement,
+    ConstantDefinition, ConstantExpression, ConstantGenericValue, DecimalNumberExpression,
+    DefinitionStatement, ElseClause, ExportVisibility, Expression, FieldType, File, FromExpression,
+    Function, IdentifierExpression, ImplDefinition, ImportDirective, ImportGlob, ImportSource,
+    ImportSymbol, InlineArrayExpression, InlineStructExpression, InlineStructMember,
+    InlineTupleExpression, IterationStatement, LogStatement, Method, OptionallyTypedAssignee,
+    Parameter, PostfixExpression, QuotedString, Range, RangeOrExpression, ReturnStatement,
+    SelfParameter, Span, Spread, SpreadOrExpression, Statement, StructDefinition, StructField,
+    StructType, SymbolDefinition, TernaryExpression, ToExpression, TupleAccess, TupleType, Type,
+    TypeDefinition, UnaryExpression, UnaryOperator, VectorIterationStatement, VectorType,
+    Visibility,
 };
 
 mod ast {
@@ -49,7 +54,9 @@ mod ast {
             Operator::new(Rule::op_left_shift, Assoc::Left)
                 | Operator::new(Rule::op_right_shift, Assoc::Left),
             Operator::new(Rule::op_add, Assoc::Left) | Operator::new(Rule::op_sub, Assoc::Left),
-            Operator::new(Rule::op_mul, Assoc::Left) | Operator::new(Rule::op_div, Assoc::Left),
+            Operator::new(Rule::op_mul, Assoc::Left)
+                | Operator::new(Rule::op_div, Assoc::Left)
+                | Operator::new(Rule::op_rem, Assoc::Left),
             Operator::new(Rule::op_pow, Assoc::Left),
         ])
     }
@@ -71,6 +78,7 @@ mod ast {
             Rule::op_sub => Expression::binary(BinaryOperator::Sub, lhs, rhs, span),
             Rule::op_mul => Expression::binary(BinaryOperator::Mul, lhs, rhs, span),
             Rule::op_div => Expression::binary(BinaryOperator::Div, lhs, rhs, span),
+            Rule::op_rem => Expression::binary(BinaryOperator::Rem, lhs, rhs, span),
             Rule::op_pow => Expression::binary(BinaryOperator::Pow, lhs, rhs, span),
             Rule::op_equal => Expression::binary(BinaryOperator::Eq, lhs, rhs, span),
             Rule::op_not_equal => Expression::binary(BinaryOperator::NotEq, lhs, rhs, span),
@@ -101,9 +109,13 @@ mod ast {
             Rule::term => {
                 // clone the pair to peek into what we should create
                 let clone = pair.clone();
+                // the start of this term, which is also the start of the casts applied to it
+                let start = pair.as_span().start_pos();
+                // the casts applied to this term, which follow the child pair
+                let casts: Vec<_> = clone.clone().into_inner().skip(1).collect();
                 // define the child pair
                 let next = clone.into_inner().next().unwrap();
-                match next.as_rule() {
+                let expression = match next.as_rule() {
                     // this happens when we have an expression in parentheses: it needs to be processed as another sequence of terms and operators
                     Rule::expression => Expression::from_pest(&mut pair.into_inner()).unwrap(),
                     Rule::conditional_expression => Expression::Ternary(
@@ -131,6 +143,9 @@ mod ast {
                     Rule::postfix_expression => Expression::Postfix(
                         PostfixExpression::from_pest(&mut pair.into_inner()).unwrap(),
                     ),
+                    Rule::inline_tuple_expression => Expression::InlineTuple(
+                        InlineTupleExpression::from_pest(&mut pair.into_inner()).unwrap(),
+                    ),
                     Rule::inline_struct_expression => Expression::InlineStruct(
                         InlineStructExpression::from_pest(&mut pair.into_inner()).unwrap(),
                     ),
@@ -155,7 +170,16 @@ mod ast {
                         })
                     },
                     r => unreachable!("`term` should contain one of [`expression`, `conditional_expression`, `primary_expression`, `postfix_expression`, `inline_array_expression`, `unary_expression`, `array_initializer_expression`], found {:#?}", r)
-                }
+                };
+                // apply the casts from left to right
+                casts.into_iter().fold(expression, |expression, cast| {
+                    let span = start.span(&cast.as_span().end_pos());
+                    Expression::Cast(CastExpression {
+                        expression: Box::new(expression),
+                        ty: Type::from_pest(&mut cast.into_inner()).unwrap(),
+                        span,
+                    })
+                })
             }
             r => unreachable!(
                 "`build_factor` can only be called on `term`, found {:#?}",
@@ -169,8 +193,9 @@ mod ast {
     pub struct File<'ast> {
         pub pragma: Option<Pragma<'ast>>,
         pub imports: Vec<ImportDirective<'ast>>,
-        pub structs: Vec<StructDefinition<'ast>>,
-        pub functions: Vec<Function<'ast>>,
+        pub types: Vec<TypeDefinition<'ast>>,
+        pub constants: Vec<ConstantDefinition<'ast>>,
+        pub symbols: Vec<SymbolDefinition<'ast>>,
         pub eoi: EOI,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
@@ -193,10 +218,18 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::type_definition))]
+    pub enum TypeDefinition<'ast> {
+        Struct(StructDefinition<'ast>),
+        Alias(AliasDefinition<'ast>),
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::ty_struct_definition))]
     pub struct StructDefinition<'ast> {
         pub id: IdentifierExpression<'ast>,
+        pub generics: Vec<IdentifierExpression<'ast>>,
         pub fields: Vec<StructField<'ast>>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
@@ -211,10 +244,38 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_alias_definition))]
+    pub struct AliasDefinition<'ast> {
+        pub id: IdentifierExpression<'ast>,
+        pub ty: Type<'ast>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::const_definition))]
+    pub struct ConstantDefinition<'ast> {
+        pub ty: Type<'ast>,
+        pub id: IdentifierExpression<'ast>,
+        pub expression: Expression<'ast>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::symbol_definition))]
+    pub enum SymbolDefinition<'ast> {
+        Function(Function<'ast>),
+        Impl(ImplDefinition<'ast>),
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::function_definition))]
     pub struct Function<'ast> {
+        pub export: Option<ExportVisibility>,
         pub id: IdentifierExpression<'ast>,
+        pub generics: Vec<IdentifierExpression<'ast>>,
         pub parameters: Vec<Parameter<'ast>>,
         pub returns: Vec<Type<'ast>>,
         pub statements: Vec<Statement<'ast>>,
@@ -222,6 +283,36 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::impl_definition))]
+    pub struct ImplDefinition<'ast> {
+        pub id: IdentifierExpression<'ast>,
+        pub methods: Vec<Method<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::method_definition))]
+    pub struct Method<'ast> {
+        pub export: Option<ExportVisibility>,
+        pub id: IdentifierExpression<'ast>,
+        pub generics: Vec<IdentifierExpression<'ast>>,
+        pub receiver: Option<SelfParameter<'ast>>,
+        pub parameters: Vec<Parameter<'ast>>,
+        pub returns: Vec<Type<'ast>>,
+        pub statements: Vec<Statement<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::self_parameter))]
+    pub struct SelfParameter<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::import_directive))]
     pub enum ImportDirective<'ast> {
@@ -242,12 +333,28 @@ mod ast {
     #[pest_ast(rule(Rule::from_import_directive))]
     pub struct FromImportDirective<'ast> {
         pub source: ImportSource<'ast>,
-        pub symbol: IdentifierExpression<'ast>,
+        pub glob: Option<ImportGlob<'ast>>,
+        pub symbols: Vec<ImportSymbol<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::import_symbol))]
+    pub struct ImportSymbol<'ast> {
+        pub id: IdentifierExpression<'ast>,
         pub alias: Option<IdentifierExpression<'ast>>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::import_glob))]
+    pub struct ImportGlob<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::import_source))]
     pub struct ImportSource<'ast> {
@@ -263,6 +370,8 @@ mod ast {
         Basic(BasicType<'ast>),
         Array(ArrayType<'ast>),
         Struct(StructType<'ast>),
+        Tuple(TupleType<'ast>),
+        Vector(VectorType<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -273,6 +382,10 @@ mod ast {
         U8(U8Type<'ast>),
         U16(U16Type<'ast>),
         U32(U32Type<'ast>),
+        U64(U64Type<'ast>),
+        I8(I8Type<'ast>),
+        I16(I16Type<'ast>),
+        I32(I32Type<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -291,11 +404,21 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_vector))]
+    pub struct VectorType<'ast> {
+        pub ty: BasicOrStructType<'ast>,
+        pub capacity: Expression<'ast>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::ty_basic_or_struct))]
     pub enum BasicOrStructType<'ast> {
         Struct(StructType<'ast>),
         Basic(BasicType<'ast>),
+        Tuple(TupleType<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -326,10 +449,54 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_u64))]
+    pub struct U64Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_i8))]
+    pub struct I8Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_i16))]
+    pub struct I16Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_i32))]
+    pub struct I32Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::ty_struct))]
     pub struct StructType<'ast> {
         pub id: IdentifierExpression<'ast>,
+        pub explicit_generics: Vec<ConstantGenericValue<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::constant_generic_value))]
+    pub enum ConstantGenericValue<'ast> {
+        Value(DecimalNumberExpression<'ast>),
+        Identifier(IdentifierExpression<'ast>),
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_tuple))]
+    pub struct TupleType<'ast> {
+        pub elements: Vec<Type<'ast>>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
     }
@@ -359,6 +526,10 @@ mod ast {
     #[pest_ast(rule(Rule::vis_private))]
     pub struct PrivateVisibility {}
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::vis_export))]
+    pub struct ExportVisibility {}
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::statement))]
     pub enum Statement<'ast> {
@@ -366,6 +537,9 @@ mod ast {
         Definition(DefinitionStatement<'ast>),
         Assertion(AssertionStatement<'ast>),
         Iteration(IterationStatement<'ast>),
+        VectorIteration(VectorIterationStatement<'ast>),
+        Conditional(ConditionalStatement<'ast>),
+        Log(LogStatement<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -381,6 +555,25 @@ mod ast {
     #[pest_ast(rule(Rule::expression_statement))]
     pub struct AssertionStatement<'ast> {
         pub expression: Expression<'ast>,
+        pub message: Option<QuotedString<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::log_statement))]
+    pub struct LogStatement<'ast> {
+        pub format_string: QuotedString<'ast>,
+        pub expressions: Vec<Expression<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::quoted_string))]
+    pub struct QuotedString<'ast> {
+        #[pest_ast(outer(with(span_into_unquoted_str)))]
+        pub value: String,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
     }
@@ -397,6 +590,35 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::vector_iteration_statement))]
+    pub struct VectorIterationStatement<'ast> {
+        pub ty: Type<'ast>,
+        pub index: IdentifierExpression<'ast>,
+        pub vector: Expression<'ast>,
+        pub statements: Vec<Statement<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::conditional_statement))]
+    pub struct ConditionalStatement<'ast> {
+        pub condition: Expression<'ast>,
+        pub consequence: Vec<Statement<'ast>>,
+        pub alternative: Option<ElseClause<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::else_clause))]
+    pub struct ElseClause<'ast> {
+        pub statements: Vec<Statement<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::return_statement))]
     pub struct ReturnStatement<'ast> {
@@ -418,6 +640,7 @@ mod ast {
         Sub,
         Mul,
         Div,
+        Rem,
         Eq,
         NotEq,
         Lt,
@@ -431,6 +654,7 @@ mod ast {
     #[pest_ast(rule(Rule::op_unary))]
     pub enum UnaryOperator<'ast> {
         Not(Not<'ast>),
+        Neg(Neg<'ast>),
     }
 
     #[derive(Debug, PartialEq, FromPest, Clone)]
@@ -440,6 +664,13 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, PartialEq, FromPest, Clone)]
+    #[pest_ast(rule(Rule::op_neg))]
+    pub struct Neg<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, PartialEq, Clone)]
     pub enum Expression<'ast> {
         Ternary(TernaryExpression<'ast>),
@@ -449,8 +680,10 @@ mod ast {
         Constant(ConstantExpression<'ast>),
         InlineArray(InlineArrayExpression<'ast>),
         InlineStruct(InlineStructExpression<'ast>),
+        InlineTuple(InlineTupleExpression<'ast>),
         ArrayInitializer(ArrayInitializerExpression<'ast>),
         Unary(UnaryExpression<'ast>),
+        Cast(CastExpression<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -496,6 +729,7 @@ mod ast {
     #[pest_ast(rule(Rule::postfix_expression))]
     pub struct PostfixExpression<'ast> {
         pub id: IdentifierExpression<'ast>,
+        pub associated_call: Option<AssociatedCall<'ast>>,
         pub accesses: Vec<Access<'ast>>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
@@ -510,6 +744,13 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, PartialEq, Clone)]
+    pub struct CastExpression<'ast> {
+        pub expression: Box<Expression<'ast>>,
+        pub ty: Type<'ast>,
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::inline_array_expression))]
     pub struct InlineArrayExpression<'ast> {
@@ -536,6 +777,14 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::inline_tuple_expression))]
+    pub struct InlineTupleExpression<'ast> {
+        pub expressions: Vec<Expression<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::array_initializer_expression))]
     pub struct ArrayInitializerExpression<'ast> {
@@ -560,6 +809,7 @@ mod ast {
         Call(CallAccess<'ast>),
         Select(ArrayAccess<'ast>),
         Member(MemberAccess<'ast>),
+        Tuple(TupleAccess<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -567,6 +817,16 @@ mod ast {
     pub enum AssigneeAccess<'ast> {
         Select(ArrayAccess<'ast>),
         Member(MemberAccess<'ast>),
+        Tuple(TupleAccess<'ast>),
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::associated_call))]
+    pub struct AssociatedCall<'ast> {
+        pub id: IdentifierExpression<'ast>,
+        pub call: CallAccess<'ast>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -593,6 +853,14 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::tuple_access))]
+    pub struct TupleAccess<'ast> {
+        pub index: DecimalNumberExpression<'ast>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, PartialEq, Clone)]
     pub struct BinaryExpression<'ast> {
         pub op: BinaryOperator,
@@ -649,8 +917,10 @@ mod ast {
                 Expression::Postfix(p) => &p.span,
                 Expression::InlineArray(a) => &a.span,
                 Expression::InlineStruct(s) => &s.span,
+                Expression::InlineTuple(t) => &t.span,
                 Expression::ArrayInitializer(a) => &a.span,
                 Expression::Unary(u) => &u.span,
+                Expression::Cast(c) => &c.span,
             }
         }
     }
@@ -686,6 +956,10 @@ mod ast {
         U8(U8NumberExpression<'ast>),
         U16(U16NumberExpression<'ast>),
         U32(U32NumberExpression<'ast>),
+        U64(U64NumberExpression<'ast>),
+        I8(I8NumberExpression<'ast>),
+        I16(I16NumberExpression<'ast>),
+        I32(I32NumberExpression<'ast>),
     }
 
     impl<'ast> ConstantExpression<'ast> {
@@ -696,6 +970,10 @@ mod ast {
                 ConstantExpression::U8(c) => &c.span,
                 ConstantExpression::U16(c) => &c.span,
                 ConstantExpression::U32(c) => &c.span,
+                ConstantExpression::U64(c) => &c.span,
+                ConstantExpression::I8(c) => &c.span,
+                ConstantExpression::I16(c) => &c.span,
+                ConstantExpression::I32(c) => &c.span,
             }
         }
     }
@@ -745,6 +1023,42 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::hex_number_64))]
+    pub struct U64NumberExpression<'ast> {
+        #[pest_ast(outer(with(span_into_str)))]
+        pub value: String,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::signed_number_8))]
+    pub struct I8NumberExpression<'ast> {
+        #[pest_ast(outer(with(span_into_str)))]
+        pub value: String,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::signed_number_16))]
+    pub struct I16NumberExpression<'ast> {
+        #[pest_ast(outer(with(span_into_str)))]
+        pub value: String,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::signed_number_32))]
+    pub struct I32NumberExpression<'ast> {
+        #[pest_ast(outer(with(span_into_str)))]
+        pub value: String,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::identifier))]
     pub struct IdentifierExpression<'ast> {
@@ -767,6 +1081,10 @@ mod ast {
         span.as_str().to_string()
     }
 
+    fn span_into_unquoted_str(span: Span) -> String {
+        span.as_str().trim_matches('"').to_string()
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::EOI))]
     pub struct EOI;
@@ -861,12 +1179,15 @@ mod tests {
             generate_ast(&source),
             Ok(File {
                 pragma: None,
-                structs: vec![],
-                functions: vec![Function {
+                types: vec![],
+                constants: vec![],
+                symbols: vec![SymbolDefinition::Function(Function {
+                    export: None,
                     id: IdentifierExpression {
                         value: String::from("main"),
                         span: Span::new(&source, 33, 37).unwrap()
                     },
+                    generics: vec![],
                     parameters: vec![],
                     returns: vec![Type::Basic(BasicType::Field(FieldType {
                         span: Span::new(&source, 44, 49).unwrap()
@@ -890,7 +1211,7 @@ mod tests {
                         span: Span::new(&source, 52, 64).unwrap(),
                     })],
                     span: Span::new(&source, 29, source.len()).unwrap(),
-                }],
+                })],
                 imports: vec![ImportDirective::Main(MainImportDirective {
                     source: ImportSource {
                         value: String::from("foo"),
@@ -914,12 +1235,15 @@ mod tests {
             generate_ast(&source),
             Ok(File {
                 pragma: None,
-                structs: vec![],
-                functions: vec![Function {
+                types: vec![],
+                constants: vec![],
+                symbols: vec![SymbolDefinition::Function(Function {
+                    export: None,
                     id: IdentifierExpression {
                         value: String::from("main"),
                         span: Span::new(&source, 33, 37).unwrap()
                     },
+                    generics: vec![],
                     parameters: vec![],
                     returns: vec![Type::Basic(BasicType::Field(FieldType {
                         span: Span::new(&source, 44, 49).unwrap()
@@ -961,7 +1285,7 @@ mod tests {
                         span: Span::new(&source, 52, 73).unwrap(),
                     })],
                     span: Span::new(&source, 29, 74).unwrap(),
-                }],
+                })],
                 imports: vec![ImportDirective::Main(MainImportDirective {
                     source: ImportSource {
                         value: String::from("foo"),
@@ -985,12 +1309,15 @@ mod tests {
             generate_ast(&source),
             Ok(File {
                 pragma: None,
-                structs: vec![],
-                functions: vec![Function {
+                types: vec![],
+                constants: vec![],
+                symbols: vec![SymbolDefinition::Function(Function {
+                    export: None,
                     id: IdentifierExpression {
                         value: String::from("main"),
                         span: Span::new(&source, 33, 37).unwrap()
                     },
+                    generics: vec![],
                     parameters: vec![],
                     returns: vec![Type::Basic(BasicType::Field(FieldType {
                         span: Span::new(&source, 44, 49).unwrap()
@@ -1020,7 +1347,7 @@ mod tests {
                         span: Span::new(&source, 52, 80).unwrap(),
                     })],
                     span: Span::new(&source, 29, 81).unwrap(),
-                }],
+                })],
                 imports: vec![ImportDirective::Main(MainImportDirective {
                     source: ImportSource {
                         value: String::from("foo"),
@@ -1043,12 +1370,15 @@ mod tests {
             generate_ast(&source),
             Ok(File {
                 pragma: None,
-                structs: vec![],
-                functions: vec![Function {
+                types: vec![],
+                constants: vec![],
+                symbols: vec![SymbolDefinition::Function(Function {
+                    export: None,
                     id: IdentifierExpression {
                         value: String::from("main"),
                         span: Span::new(&source, 4, 8).unwrap()
                     },
+                    generics: vec![],
                     parameters: vec![],
                     returns: vec![Type::Basic(BasicType::Field(FieldType {
                         span: Span::new(&source, 15, 20).unwrap()
@@ -1063,7 +1393,7 @@ mod tests {
                         span: Span::new(&source, 23, 33).unwrap(),
                     })],
                     span: Span::new(&source, 0, 34).unwrap(),
-                }],
+                })],
                 imports: vec![],
                 eoi: EOI {},
                 span: Span::new(&source, 0, 34).unwrap()
@@ -1079,12 +1409,15 @@ mod tests {
             generate_ast(&source),
             Ok(File {
                 pragma: None,
-                structs: vec![],
-                functions: vec![Function {
+                types: vec![],
+                constants: vec![],
+                symbols: vec![SymbolDefinition::Function(Function {
+                    export: None,
                     id: IdentifierExpression {
                         value: String::from("main"),
                         span: Span::new(&source, 4, 8).unwrap()
                     },
+                    generics: vec![],
                     parameters: vec![],
                     returns: vec![Type::Basic(BasicType::Field(FieldType {
                         span: Span::new(&source, 15, 20).unwrap()
@@ -1123,6 +1456,7 @@ mod tests {
                                 value: String::from("foo"),
                                 span: Span::new(&source, 36, 39).unwrap()
                             },
+                            associated_call: None,
                             accesses: vec![Access::Call(CallAccess {
                                 expressions: vec![
                                     Expression::Constant(ConstantExpression::DecimalNumber(
@@ -1154,7 +1488,7 @@ mod tests {
                         span: Span::new(&source, 23, 49).unwrap()
                     })],
                     span: Span::new(&source, 0, 50).unwrap(),
-                }],
+                })],
                 imports: vec![],
                 eoi: EOI {},
                 span: Span::new(&source, 0, 50).unwrap()
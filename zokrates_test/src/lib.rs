@@ -46,7 +46,7 @@ type Val = String;
 
 fn parse_val<T: Field>(s: String) -> T {
     let s = if s.starts_with("0x") {
-        u32::from_str_radix(s.trim_start_matches("0x"), 16)
+        u128::from_str_radix(s.trim_start_matches("0x"), 16)
             .unwrap()
             .to_string()
     } else {
@@ -122,6 +122,14 @@ fn compile_and_run<T: Field>(t: Tests) {
                 t.entry_point.display(),
                 (count as f32) / (target_count as f32) * 100_f32
             );
+
+            assert!(
+                count <= target_count,
+                "{} has {} constraints, more than the maximum of {}",
+                t.entry_point.display(),
+                count,
+                target_count
+            );
         }
         _ => {}
     };